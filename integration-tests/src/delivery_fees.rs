@@ -0,0 +1,188 @@
+#![cfg(test)]
+
+use crate::kusama_test_net::*;
+
+use basilisk_runtime::{
+	AssetRegistry, Balances, DeliveryFeeAssetId, MultiTransactionPayment, PolkadotXcm, Runtime, RuntimeOrigin, Tokens,
+	TreasuryAccount, XTokens,
+};
+use frame_support::{assert_ok, weights::Weight};
+use orml_traits::MultiCurrency;
+use pallet_transaction_multi_payment::Price;
+use polkadot_xcm::{v5::prelude::*, VersionedAssets, VersionedLocation, VersionedXcm};
+use xcm_emulator::TestExt;
+use xcm_runtime_apis::fees::runtime_decl_for_xcm_payment_api::XcmPaymentApiV1;
+
+const AMOUNT_TO_TRANSFER: u128 = 10 * UNITS;
+
+/// Asset representing BSX on the sibling chain
+const SIBLING_BSX: primitives::AssetId = AUSD;
+
+fn init() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		assert_ok!(MultiTransactionPayment::add_currency(
+			RuntimeOrigin::root(),
+			KSM,
+			Price::from_rational(1, 10),
+		));
+		assert_ok!(Tokens::transfer(
+			RuntimeOrigin::signed(ALICE.into()),
+			BOB.into(),
+			KSM,
+			10 * UNITS,
+		));
+	});
+
+	OtherParachain::execute_with(|| {
+		assert_ok!(AssetRegistry::set_location(
+			RuntimeOrigin::root(),
+			SIBLING_BSX,
+			basilisk_runtime::AssetLocation(Location::new(1, [Parachain(BASILISK_PARA_ID), GeneralIndex(0)]))
+		));
+	});
+}
+
+fn transfer_bsx_to_sibling() {
+	assert_ok!(XTokens::transfer(
+		RuntimeOrigin::signed(BOB.into()),
+		BSX,
+		AMOUNT_TO_TRANSFER,
+		Box::new(
+			Location::new(
+				1,
+				[
+					Parachain(OTHER_PARA_ID),
+					Junction::AccountId32 { id: BOB, network: None }
+				]
+			)
+			.into()
+		),
+		WeightLimit::Unlimited,
+	));
+}
+
+#[test]
+fn delivery_fee_should_be_charged_in_native_currency_when_fee_currency_is_not_set() {
+	// Arrange
+	init();
+
+	Basilisk::execute_with(|| {
+		let treasury_balance = Balances::free_balance(TreasuryAccount::get());
+
+		// Act
+		transfer_bsx_to_sibling();
+
+		// Assert
+		let fee = Balances::free_balance(TreasuryAccount::get()) - treasury_balance;
+		assert!(fee > 0);
+		assert_eq!(
+			Balances::free_balance(AccountId::from(BOB)),
+			BOB_INITIAL_BSX_BALANCE - AMOUNT_TO_TRANSFER - fee
+		);
+	});
+
+	OtherParachain::execute_with(|| {
+		assert!(Tokens::free_balance(SIBLING_BSX, &AccountId::from(BOB)) > BOB_INITIAL_AUSD_BALANCE_ON_OTHER_PARACHAIN);
+	});
+}
+
+#[test]
+fn delivery_fee_should_be_charged_in_fee_currency_of_sender() {
+	// Arrange
+	init();
+
+	Basilisk::execute_with(|| {
+		assert_ok!(MultiTransactionPayment::set_currency(
+			RuntimeOrigin::signed(BOB.into()),
+			KSM
+		));
+		let treasury_bsx_balance = Balances::free_balance(TreasuryAccount::get());
+
+		// Act
+		transfer_bsx_to_sibling();
+
+		// Assert
+		let fee = Tokens::free_balance(KSM, &TreasuryAccount::get());
+		assert!(fee > 0);
+		assert_eq!(Tokens::free_balance(KSM, &AccountId::from(BOB)), 10 * UNITS - fee);
+		assert_eq!(
+			Balances::free_balance(AccountId::from(BOB)),
+			BOB_INITIAL_BSX_BALANCE - AMOUNT_TO_TRANSFER
+		);
+		assert_eq!(Balances::free_balance(TreasuryAccount::get()), treasury_bsx_balance);
+	});
+}
+
+#[test]
+fn delivery_fee_should_be_waived_when_message_is_not_sent_by_local_account() {
+	// Arrange
+	init();
+
+	Basilisk::execute_with(|| {
+		let treasury_balance = Balances::free_balance(TreasuryAccount::get());
+
+		// Act
+		assert_ok!(PolkadotXcm::send_xcm(
+			Here,
+			Location::new(1, [Parachain(OTHER_PARA_ID)]),
+			Xcm::<()>(vec![ClearOrigin])
+		));
+
+		// Assert
+		assert_eq!(Balances::free_balance(TreasuryAccount::get()), treasury_balance);
+	});
+}
+
+#[test]
+fn delivery_fee_should_grow_with_message_size() {
+	Basilisk::execute_with(|| {
+		let fee_of = |message: Xcm<()>| -> u128 {
+			let fees: Assets = Runtime::query_delivery_fees(
+				VersionedLocation::from(Location::new(1, [Parachain(OTHER_PARA_ID)])),
+				VersionedXcm::from(message),
+			)
+			.map(|fees: VersionedAssets| fees.try_into().unwrap())
+			.unwrap();
+			match fees.inner() {
+				[Asset {
+					id,
+					fun: Fungible(amount),
+				}] if *id == DeliveryFeeAssetId::get() => *amount,
+				_ => panic!("delivery fee should be priced in the delivery fee asset"),
+			}
+		};
+
+		let small = fee_of(Xcm(vec![ClearOrigin]));
+		let large = fee_of(Xcm(vec![ClearOrigin; 100]));
+
+		assert!(small > 0);
+		assert!(large > small);
+	});
+}
+
+#[test]
+fn delivery_fee_asset_should_not_be_withdrawn_by_program() {
+	// Arrange
+	init();
+
+	Basilisk::execute_with(|| {
+		let treasury_balance = Balances::free_balance(TreasuryAccount::get());
+		let fee_asset: Asset = (DeliveryFeeAssetId::get(), UNITS).into();
+		// nested programs are checked too
+		let message = Xcm(vec![SetAppendix(Xcm(vec![WithdrawAsset(fee_asset.into())]))]);
+
+		// Act
+		assert!(PolkadotXcm::execute(
+			RuntimeOrigin::signed(BOB.into()),
+			Box::new(VersionedXcm::from(message)),
+			Weight::from_parts(100_000_000_000, 1_000_000),
+		)
+		.is_err());
+
+		// Assert
+		assert_eq!(Balances::free_balance(AccountId::from(BOB)), BOB_INITIAL_BSX_BALANCE);
+		assert_eq!(Balances::free_balance(TreasuryAccount::get()), treasury_balance);
+	});
+}
@@ -0,0 +1,249 @@
+#![cfg(test)]
+
+use crate::kusama_test_net::*;
+
+use basilisk_adapters::xcm_exchange::RouteHint;
+use basilisk_runtime::{AssetRegistry, Balances, CurrencyIdConvert, PolkadotXcm, RuntimeOrigin, Tokens, XYK};
+use basilisk_traits::router::PoolType;
+use frame_support::{assert_ok, traits::PalletInfoAccess, weights::Weight};
+use orml_traits::MultiCurrency;
+use pallet_route_executor::Trade;
+use polkadot_xcm::{v5::prelude::*, VersionedXcm};
+use primitives::AssetId;
+use sp_runtime::traits::Convert;
+use xcm_emulator::TestExt;
+
+const BOB_INITIAL_MOVR_BALANCE: u128 = 100 * UNITS;
+
+fn init() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		register_location(AUSD, Location::new(1, [Parachain(OTHER_PARA_ID), GeneralIndex(0)]));
+		register_location(MOVR, Location::new(1, [Parachain(2023), PalletInstance(10)]));
+		register_location(KSM, Location::parent());
+
+		assert_ok!(XYK::create_pool(
+			RuntimeOrigin::signed(ALICE.into()),
+			AUSD,
+			100 * UNITS,
+			BSX,
+			50 * UNITS,
+		));
+		assert_ok!(XYK::create_pool(
+			RuntimeOrigin::signed(ALICE.into()),
+			MOVR,
+			100 * UNITS,
+			BSX,
+			50 * UNITS,
+		));
+
+		assert_ok!(Tokens::transfer(
+			RuntimeOrigin::signed(ALICE.into()),
+			BOB.into(),
+			MOVR,
+			BOB_INITIAL_MOVR_BALANCE,
+		));
+	});
+}
+
+fn register_location(asset_id: AssetId, location: Location) {
+	assert_ok!(AssetRegistry::set_location(
+		RuntimeOrigin::root(),
+		asset_id,
+		basilisk_runtime::AssetLocation(location)
+	));
+}
+
+fn asset(asset_id: AssetId, amount: u128) -> Asset {
+	(CurrencyIdConvert::convert(asset_id).unwrap(), amount).into()
+}
+
+fn exchange(give: Vec<Asset>, want: Vec<Asset>, maximal: bool) {
+	let message = Xcm(vec![
+		WithdrawAsset(give.clone().into()),
+		ExchangeAsset {
+			give: Wild(AllCounted(give.len() as u32)),
+			want: want.into(),
+			maximal,
+		},
+		DepositAsset {
+			assets: Wild(AllCounted(4)),
+			beneficiary: Junction::AccountId32 { id: BOB, network: None }.into(),
+		},
+	]);
+
+	assert_ok!(PolkadotXcm::execute(
+		RuntimeOrigin::signed(BOB.into()),
+		Box::new(VersionedXcm::from(message)),
+		Weight::from_parts(100_000_000_000, 1_000_000),
+	));
+}
+
+#[test]
+fn exchange_asset_should_sell_all_give_assets_for_want_asset() {
+	init();
+
+	Basilisk::execute_with(|| {
+		// Act
+		exchange(
+			vec![asset(AUSD, 10 * UNITS), asset(MOVR, 10 * UNITS)],
+			vec![asset(BSX, UNITS)],
+			true,
+		);
+
+		// Assert
+		assert_eq!(
+			Tokens::free_balance(AUSD, &AccountId::from(BOB)),
+			BOB_INITIAL_AUSD_BALANCE - 10 * UNITS
+		);
+		assert_eq!(
+			Tokens::free_balance(MOVR, &AccountId::from(BOB)),
+			BOB_INITIAL_MOVR_BALANCE - 10 * UNITS
+		);
+		assert!(Balances::free_balance(AccountId::from(BOB)) > BOB_INITIAL_BSX_BALANCE + 2 * UNITS);
+	});
+}
+
+#[test]
+fn exchange_asset_should_buy_all_want_assets_with_give_asset() {
+	init();
+
+	Basilisk::execute_with(|| {
+		// Act
+		exchange(
+			vec![asset(BSX, 100 * UNITS)],
+			vec![asset(AUSD, 5 * UNITS), asset(MOVR, 5 * UNITS)],
+			false,
+		);
+
+		// Assert
+		assert_eq!(
+			Tokens::free_balance(AUSD, &AccountId::from(BOB)),
+			BOB_INITIAL_AUSD_BALANCE + 5 * UNITS
+		);
+		assert_eq!(
+			Tokens::free_balance(MOVR, &AccountId::from(BOB)),
+			BOB_INITIAL_MOVR_BALANCE + 5 * UNITS
+		);
+		// left over of the give asset is returned
+		assert!(Balances::free_balance(AccountId::from(BOB)) > BOB_INITIAL_BSX_BALANCE - 100 * UNITS);
+	});
+}
+
+#[test]
+fn exchange_asset_should_fill_partially_when_want_asset_cannot_be_bought() {
+	init();
+
+	Basilisk::execute_with(|| {
+		// Act
+		// there is no KSM pool
+		exchange(
+			vec![asset(BSX, 100 * UNITS)],
+			vec![asset(AUSD, 5 * UNITS), asset(KSM, 5 * UNITS)],
+			false,
+		);
+
+		// Assert
+		assert_eq!(
+			Tokens::free_balance(AUSD, &AccountId::from(BOB)),
+			BOB_INITIAL_AUSD_BALANCE + 5 * UNITS
+		);
+		assert_eq!(Tokens::free_balance(KSM, &AccountId::from(BOB)), 0);
+		assert!(Balances::free_balance(AccountId::from(BOB)) > BOB_INITIAL_BSX_BALANCE - 100 * UNITS);
+	});
+}
+
+#[test]
+fn exchange_asset_should_fail_when_give_asset_is_want_asset() {
+	init();
+
+	Basilisk::execute_with(|| {
+		let give = vec![asset(AUSD, 10 * UNITS), asset(BSX, 10 * UNITS)];
+		let message = Xcm(vec![
+			WithdrawAsset(give.clone().into()),
+			ExchangeAsset {
+				give: Wild(AllCounted(give.len() as u32)),
+				want: vec![asset(BSX, 10 * UNITS)].into(),
+				maximal: true,
+			},
+			DepositAsset {
+				assets: Wild(AllCounted(4)),
+				beneficiary: Junction::AccountId32 { id: BOB, network: None }.into(),
+			},
+		]);
+
+		// Act
+		assert!(PolkadotXcm::execute(
+			RuntimeOrigin::signed(BOB.into()),
+			Box::new(VersionedXcm::from(message)),
+			Weight::from_parts(100_000_000_000, 1_000_000),
+		)
+		.is_err());
+
+		// Assert
+		assert_eq!(
+			Tokens::free_balance(AUSD, &AccountId::from(BOB)),
+			BOB_INITIAL_AUSD_BALANCE
+		);
+		assert_eq!(Balances::free_balance(AccountId::from(BOB)), BOB_INITIAL_BSX_BALANCE);
+	});
+}
+
+#[test]
+fn exchange_asset_should_use_route_hint_when_provided() {
+	init();
+
+	Basilisk::execute_with(|| {
+		let route = vec![
+			Trade {
+				pool: PoolType::XYK,
+				asset_in: AUSD,
+				asset_out: BSX,
+			},
+			Trade {
+				pool: PoolType::XYK,
+				asset_in: BSX,
+				asset_out: MOVR,
+			},
+		];
+		let route_hint =
+			RouteHint::<AssetId>::encode(basilisk_runtime::Router::index() as u8, &route).expect("route is valid");
+
+		// Act
+		exchange(
+			vec![asset(AUSD, 10 * UNITS)],
+			vec![asset(MOVR, UNITS), route_hint],
+			true,
+		);
+
+		// Assert
+		assert_eq!(
+			Tokens::free_balance(AUSD, &AccountId::from(BOB)),
+			BOB_INITIAL_AUSD_BALANCE - 10 * UNITS
+		);
+		assert!(Tokens::free_balance(MOVR, &AccountId::from(BOB)) > BOB_INITIAL_MOVR_BALANCE + UNITS);
+	});
+}
+
+#[test]
+fn route_hint_should_be_decoded_when_encoded() {
+	let route = vec![
+		Trade {
+			pool: PoolType::XYK,
+			asset_in: AUSD,
+			asset_out: BSX,
+		},
+		Trade {
+			pool: PoolType::LBP,
+			asset_in: BSX,
+			asset_out: NEW_BOOTSTRAPPED_TOKEN,
+		},
+	];
+
+	let route_hint = RouteHint::<AssetId>::encode(111, &route).unwrap();
+
+	assert_eq!(RouteHint::<AssetId>::decode(111, &route_hint), Some(route));
+	assert_eq!(RouteHint::<AssetId>::decode(112, &route_hint), None);
+	assert_eq!(RouteHint::<AssetId>::encode(111, &[]), None);
+}
@@ -0,0 +1,166 @@
+#![cfg(test)]
+
+use crate::kusama_test_net::*;
+
+use basilisk_runtime::{AccountId, AssetId, AssetRegistry, Balance, BlockNumber, Runtime, Tokens, LBP, XYK};
+use basilisk_traits::router::{AssetPair as RouterAssetPair, PoolType, Trade};
+use basilisk_traits::AMM;
+use frame_support::BoundedVec;
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+
+fn genesis(
+	xyk_pools: Vec<(AccountId, AssetId, Balance, AssetId, Balance)>,
+	lbp_pools: Vec<pallet_lbp::GenesisPool<AccountId, BlockNumber>>,
+	routes: Vec<(RouterAssetPair<AssetId>, Vec<Trade<AssetId>>)>,
+) -> sp_io::TestExternalities {
+	let genesis_config = basilisk_runtime::RuntimeGenesisConfig {
+		balances: basilisk_runtime::BalancesConfig {
+			balances: vec![
+				(AccountId::from(ALICE), ALICE_INITIAL_BSX_BALANCE),
+				(AccountId::from(BOB), BOB_INITIAL_BSX_BALANCE),
+			],
+			dev_accounts: Default::default(),
+		},
+		collator_selection: basilisk_runtime::CollatorSelectionConfig {
+			invulnerables: basilisk::invulnerables().iter().cloned().map(|(acc, _)| acc).collect(),
+			candidacy_bond: 2 * UNITS,
+			..Default::default()
+		},
+		session: basilisk_runtime::SessionConfig {
+			keys: basilisk::invulnerables()
+				.into_iter()
+				.map(|(acc, aura)| (acc.clone(), acc, basilisk_runtime::opaque::SessionKeys { aura }))
+				.collect(),
+			non_authority_keys: Default::default(),
+		},
+		asset_registry: basilisk_runtime::AssetRegistryConfig {
+			registered_assets: vec![
+				(b"aUSD".to_vec(), 1_000_000u128, Some(AUSD)),
+				(b"MOVR".to_vec(), 1_000u128, Some(MOVR)),
+				(b"KSMN".to_vec(), 1_000u128, Some(KSM)),
+			],
+			native_asset_name: b"BSX".to_vec(),
+			native_existential_deposit: basilisk_runtime::NativeExistentialDeposit::get(),
+		},
+		parachain_info: basilisk_runtime::ParachainInfoConfig {
+			parachain_id: BASILISK_PARA_ID.into(),
+			..Default::default()
+		},
+		tokens: basilisk_runtime::TokensConfig {
+			balances: vec![
+				(AccountId::from(ALICE), AUSD, ALICE_INITIAL_AUSD_BALANCE),
+				(AccountId::from(ALICE), MOVR, ALICE_INITIAL_MOVR_BALANCE),
+				(AccountId::from(ALICE), KSM, ALICE_INITIAL_KSM_BALANCE),
+			],
+		},
+		xyk: basilisk_runtime::XYKConfig { pools: xyk_pools },
+		lbp: basilisk_runtime::LBPConfig { pools: lbp_pools },
+		router: basilisk_runtime::RouterConfig { routes },
+		..Default::default()
+	};
+
+	let mut ext = sp_io::TestExternalities::new_empty();
+	ext.execute_with(|| basilisk_runtime::genesis::build(genesis_config));
+	ext
+}
+
+fn bsx_movr_route() -> Vec<Trade<AssetId>> {
+	vec![
+		Trade {
+			pool: PoolType::XYK,
+			asset_in: BSX,
+			asset_out: AUSD,
+		},
+		Trade {
+			pool: PoolType::XYK,
+			asset_in: AUSD,
+			asset_out: MOVR,
+		},
+	]
+}
+
+#[test]
+fn xyk_pools_should_be_created_in_genesis() {
+	genesis(
+		vec![
+			(ALICE.into(), BSX, 100 * UNITS, AUSD, 200 * UNITS),
+			(ALICE.into(), AUSD, 100 * UNITS, MOVR, 50 * UNITS),
+		],
+		vec![],
+		vec![],
+	)
+	.execute_with(|| {
+		let pool = XYK::get_pair_id(AssetPair {
+			asset_in: BSX,
+			asset_out: AUSD,
+		});
+		assert!(XYK::exists(AssetPair {
+			asset_in: AUSD,
+			asset_out: MOVR,
+		}));
+		assert_eq!(Tokens::free_balance(AUSD, &pool), 200 * UNITS);
+		assert_eq!(
+			Tokens::free_balance(AUSD, &AccountId::from(ALICE)),
+			ALICE_INITIAL_AUSD_BALANCE - 300 * UNITS
+		);
+
+		let share_token = XYK::share_token(&pool);
+		assert!(AssetRegistry::assets(share_token).is_some());
+		assert_eq!(Tokens::free_balance(share_token, &AccountId::from(ALICE)), 100 * UNITS);
+	});
+}
+
+#[test]
+fn lbp_pool_should_be_created_with_schedule_in_genesis() {
+	genesis(
+		vec![],
+		vec![(
+			ALICE.into(),
+			(AUSD, 100 * UNITS),
+			(KSM, 200 * UNITS),
+			(20_000_000, 80_000_000),
+			(10, 20),
+			(2, 1_000),
+			BOB.into(),
+			0,
+		)],
+		vec![],
+	)
+	.execute_with(|| {
+		let pool_id = LBP::pair_account_from_assets(AUSD, KSM);
+		let pool = pallet_lbp::PoolData::<Runtime>::get(&pool_id).unwrap();
+		assert_eq!(pool.owner, AccountId::from(ALICE));
+		assert_eq!(pool.start, Some(10));
+		assert_eq!(pool.end, Some(20));
+		assert_eq!(Tokens::free_balance(KSM, &pool_id), 200 * UNITS);
+	});
+}
+
+#[test]
+fn route_should_be_stored_in_genesis() {
+	genesis(
+		vec![
+			(ALICE.into(), BSX, 100 * UNITS, AUSD, 200 * UNITS),
+			(ALICE.into(), AUSD, 100 * UNITS, MOVR, 50 * UNITS),
+		],
+		vec![],
+		vec![(RouterAssetPair::new(BSX, MOVR), bsx_movr_route())],
+	)
+	.execute_with(|| {
+		assert_eq!(
+			pallet_route_executor::Routes::<Runtime>::get(RouterAssetPair::new(BSX, MOVR)),
+			Some(BoundedVec::truncate_from(bsx_movr_route()))
+		);
+	});
+}
+
+#[test]
+#[should_panic(expected = "Route in genesis is not tradable")]
+fn genesis_should_fail_when_route_pool_does_not_exist() {
+	genesis(
+		vec![],
+		vec![],
+		vec![(RouterAssetPair::new(BSX, MOVR), bsx_movr_route())],
+	);
+}
@@ -0,0 +1,288 @@
+#![cfg(test)]
+
+use crate::kusama_test_net::*;
+
+use basilisk_runtime::{Balances, BlockNumber, Router, Runtime, RuntimeOrigin, Tokens, Treasury, LBP, XYK};
+use basilisk_traits::{router::PoolType, AMM};
+use frame_support::{
+	assert_ok,
+	traits::{Get, Hooks},
+	weights::Weight,
+};
+use orml_traits::MultiCurrency;
+use orml_vesting::VestingSchedule;
+use pallet_lbp::{SaleVesting, SharesLock, WeightCurveType, XYKMigration};
+use pallet_route_executor::Trade;
+use primitives::AssetId;
+use sp_runtime::Permill;
+use xcm_emulator::TestExt;
+
+const SALE_START: Option<BlockNumber> = Some(10);
+const SALE_END: Option<BlockNumber> = Some(40);
+
+fn create_lbp_pool(accumulated_asset: AssetId, distributed_asset: AssetId) -> AccountId {
+	assert_ok!(LBP::create_pool(
+		RuntimeOrigin::root(),
+		ALICE.into(),
+		accumulated_asset,
+		100 * UNITS,
+		distributed_asset,
+		200 * UNITS,
+		20_000_000,
+		80_000_000,
+		WeightCurveType::Linear,
+		(2, 1_000),
+		CHARLIE.into(),
+		0,
+	));
+
+	let pool_id = LBP::pair_account_from_assets(accumulated_asset, distributed_asset);
+
+	assert_ok!(LBP::update_pool_data(
+		RuntimeOrigin::signed(ALICE.into()),
+		pool_id.clone(),
+		None,
+		SALE_START,
+		SALE_END,
+		None,
+		None,
+		None,
+		None,
+		None,
+	));
+
+	pool_id
+}
+
+#[test]
+fn lbp_pool_should_be_migrated_to_xyk_pool_when_sale_ends() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		let pool_id = create_lbp_pool(BSX, NEW_BOOTSTRAPPED_TOKEN);
+
+		assert_ok!(LBP::set_xyk_migration(
+			RuntimeOrigin::signed(ALICE.into()),
+			pool_id.clone(),
+			Some(XYKMigration {
+				liquidity_share: Permill::from_percent(10),
+				shares_lock: Some(SharesLock::Until(100)),
+			}),
+		));
+
+		//Act
+		go_to_block(SALE_END.unwrap() + 1);
+		LBP::on_idle(SALE_END.unwrap() + 1, Weight::MAX);
+
+		//Assert
+		assert!(pallet_lbp::PoolData::<basilisk_runtime::Runtime>::get(&pool_id).is_none());
+
+		let asset_pair = AssetPair {
+			asset_in: BSX,
+			asset_out: NEW_BOOTSTRAPPED_TOKEN,
+		};
+		assert!(XYK::exists(asset_pair));
+
+		// final weights are 80% BSX / 20% NEW_BOOTSTRAPPED_TOKEN
+		let xyk_pool = XYK::get_pair_id(asset_pair);
+		assert_eq!(Tokens::free_balance(NEW_BOOTSTRAPPED_TOKEN, &xyk_pool), 80 * UNITS);
+
+		let share_token = XYK::get_share_token(asset_pair);
+		let shares = Tokens::free_balance(share_token, &AccountId::from(ALICE));
+		assert_eq!(shares, 10 * UNITS);
+		assert_eq!(
+			Tokens::locks(AccountId::from(ALICE), share_token)
+				.iter()
+				.find(|lock| lock.id == pallet_lbp::XYK_SHARES_LOCK_ID)
+				.map(|lock| lock.amount),
+			Some(shares)
+		);
+	});
+}
+
+#[test]
+fn bsx_bought_in_lbp_should_be_vested_when_sale_has_vesting() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		let pool_id = create_lbp_pool(AUSD, BSX);
+
+		assert_ok!(LBP::set_sale_vesting(
+			RuntimeOrigin::signed(ALICE.into()),
+			pool_id,
+			Some(SaleVesting {
+				cliff: 10,
+				duration: 100,
+			}),
+		));
+
+		go_to_block(SALE_START.unwrap() + 1);
+
+		//Act
+		assert_ok!(LBP::buy(
+			RuntimeOrigin::signed(BOB.into()),
+			BSX,
+			AUSD,
+			UNITS,
+			100 * UNITS,
+		));
+
+		//Assert
+		let schedules = orml_vesting::VestingSchedules::<Runtime>::get(AccountId::from(BOB));
+		assert_eq!(
+			schedules.into_inner(),
+			vec![VestingSchedule {
+				start: SALE_END.unwrap() + 10,
+				period: 1,
+				period_count: 100,
+				per_period: UNITS / 100,
+			}]
+		);
+	});
+}
+
+#[test]
+fn bsx_bought_repeatedly_in_lbp_should_be_vested_by_single_schedule() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		let pool_id = create_lbp_pool(AUSD, BSX);
+
+		assert_ok!(LBP::set_sale_vesting(
+			RuntimeOrigin::signed(ALICE.into()),
+			pool_id,
+			Some(SaleVesting {
+				cliff: 10,
+				duration: 100,
+			}),
+		));
+
+		go_to_block(SALE_START.unwrap() + 1);
+
+		//Act
+		for _ in 0..2 {
+			assert_ok!(LBP::buy(
+				RuntimeOrigin::signed(BOB.into()),
+				BSX,
+				AUSD,
+				UNITS,
+				100 * UNITS,
+			));
+		}
+
+		//Assert
+		let schedules = orml_vesting::VestingSchedules::<Runtime>::get(AccountId::from(BOB));
+		assert_eq!(
+			schedules.into_inner(),
+			vec![VestingSchedule {
+				start: SALE_END.unwrap() + 10,
+				period: 1,
+				period_count: 100,
+				per_period: 2 * UNITS / 100,
+			}]
+		);
+	});
+}
+
+#[test]
+fn bsx_bought_by_router_in_lbp_should_be_vested_when_sale_has_vesting() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		let pool_id = create_lbp_pool(AUSD, BSX);
+
+		assert_ok!(LBP::set_sale_vesting(
+			RuntimeOrigin::signed(ALICE.into()),
+			pool_id,
+			Some(SaleVesting {
+				cliff: 10,
+				duration: 100,
+			}),
+		));
+
+		go_to_block(SALE_START.unwrap() + 1);
+
+		let bsx_balance_before = basilisk_runtime::Balances::free_balance(AccountId::from(BOB));
+
+		//Act
+		assert_ok!(Router::sell(
+			RuntimeOrigin::signed(BOB.into()),
+			AUSD,
+			BSX,
+			UNITS,
+			0,
+			vec![Trade {
+				pool: PoolType::LBP,
+				asset_in: AUSD,
+				asset_out: BSX,
+			}],
+		));
+
+		//Assert
+		let amount_out = basilisk_runtime::Balances::free_balance(AccountId::from(BOB)) - bsx_balance_before;
+		assert!(amount_out > 0);
+
+		let schedules = orml_vesting::VestingSchedules::<Runtime>::get(AccountId::from(BOB));
+		assert_eq!(schedules.len(), 1);
+		assert_eq!(schedules[0].start, SALE_END.unwrap() + 10);
+		assert_eq!(schedules[0].period_count, 100);
+		assert_eq!(schedules[0].per_period, amount_out / 100);
+	});
+}
+
+#[test]
+fn deposit_of_cancelled_lbp_pool_should_be_slashed_to_treasury() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		//Arrange
+		let deposit = <Runtime as pallet_lbp::Config>::PoolDeposit::get();
+		assert_ok!(Balances::force_set_balance(
+			RuntimeOrigin::root(),
+			ALICE.into(),
+			deposit + ALICE_INITIAL_BSX_BALANCE,
+		));
+
+		assert_ok!(LBP::create_pool_with_deposit(
+			RuntimeOrigin::signed(ALICE.into()),
+			AUSD,
+			100 * UNITS,
+			NEW_BOOTSTRAPPED_TOKEN,
+			200 * UNITS,
+			20_000_000,
+			80_000_000,
+			WeightCurveType::Linear,
+			(2, 1_000),
+			CHARLIE.into(),
+			10 * UNITS,
+		));
+		let pool_id = LBP::pair_account_from_assets(AUSD, NEW_BOOTSTRAPPED_TOKEN);
+
+		assert_eq!(Balances::reserved_balance(AccountId::from(ALICE)), deposit);
+
+		let treasury_balance = Balances::free_balance(Treasury::account_id());
+
+		//Act
+		assert_ok!(LBP::cancel_pool(RuntimeOrigin::root(), pool_id.clone()));
+
+		//Assert
+		assert!(pallet_lbp::PoolData::<Runtime>::get(&pool_id).is_none());
+		assert_eq!(Balances::reserved_balance(AccountId::from(ALICE)), 0);
+		assert_eq!(
+			Balances::free_balance(AccountId::from(ALICE)),
+			ALICE_INITIAL_BSX_BALANCE
+		);
+		assert_eq!(
+			Balances::free_balance(Treasury::account_id()),
+			treasury_balance + deposit
+		);
+		assert_eq!(
+			Tokens::free_balance(AUSD, &AccountId::from(ALICE)),
+			ALICE_INITIAL_AUSD_BALANCE
+		);
+	});
+}
@@ -2,12 +2,17 @@
 // Disable the integration tests when this feature is enabled.
 #![cfg(not(feature = "runtime-benchmarks"))]
 mod call_filter;
+mod delivery_fees;
+mod exchange_asset;
 mod fees;
+mod genesis;
 mod kusama_test_net;
+mod lbp;
 mod nft;
 mod nft_marketplace;
 mod non_native_fee;
 mod oracle;
+mod remote_swap;
 mod router;
 mod sessions;
 mod transact_call_filter;
@@ -0,0 +1,215 @@
+#![cfg(test)]
+
+use crate::kusama_test_net::*;
+
+use basilisk_adapters::xcm_exchange::SwapAndForward;
+use basilisk_runtime::{
+	AssetRegistry, CurrencyIdConvert, MultiTransactionPayment, PolkadotXcm, Runtime, RuntimeEvent, RuntimeOrigin,
+	Tokens, TreasuryAccount, UniversalLocation, XYK,
+};
+use frame_support::assert_ok;
+use orml_traits::MultiCurrency;
+use pallet_transaction_multi_payment::Price;
+use polkadot_xcm::{v5::prelude::*, VersionedAssetId, VersionedXcm};
+use primitives::AssetId;
+use sp_runtime::traits::Convert;
+use xcm_emulator::TestExt;
+use xcm_runtime_apis::fees::runtime_decl_for_xcm_payment_api::XcmPaymentApiV1;
+
+// KSM of the sibling's users held in its sovereign account on Basilisk
+const SIBLING_KSM_BALANCE: u128 = 100 * UNITS;
+const KSM_TO_SWAP: u128 = 50 * UNITS;
+
+/// Asset representing BSX on the sibling chain
+const SIBLING_BSX: AssetId = AUSD;
+
+fn init() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		assert_ok!(AssetRegistry::set_location(
+			RuntimeOrigin::root(),
+			KSM,
+			basilisk_runtime::AssetLocation(Location::parent())
+		));
+		assert_ok!(MultiTransactionPayment::add_currency(
+			RuntimeOrigin::root(),
+			KSM,
+			Price::from_rational(1, 10),
+		));
+
+		assert_ok!(XYK::create_pool(
+			RuntimeOrigin::signed(ALICE.into()),
+			KSM,
+			100 * UNITS,
+			BSX,
+			200 * UNITS,
+		));
+
+		assert_ok!(Tokens::transfer(
+			RuntimeOrigin::signed(ALICE.into()),
+			parachain_reserve_account(),
+			KSM,
+			SIBLING_KSM_BALANCE,
+		));
+	});
+
+	OtherParachain::execute_with(|| {
+		assert_ok!(AssetRegistry::set_location(
+			RuntimeOrigin::root(),
+			SIBLING_BSX,
+			basilisk_runtime::AssetLocation(Location::new(1, [Parachain(BASILISK_PARA_ID), GeneralIndex(0)]))
+		));
+	});
+}
+
+fn ksm(amount: u128) -> Asset {
+	(Location::parent(), amount).into()
+}
+
+fn swap_and_forward(min_bsx_out: u128) -> SwapAndForward {
+	SwapAndForward {
+		fees: ksm(UNITS),
+		want: (CurrencyIdConvert::convert(BSX).unwrap(), min_bsx_out).into(),
+		route_hint: None,
+		dest: Location::new(1, [Parachain(OTHER_PARA_ID)]),
+		dest_fee: UNITS,
+		beneficiary: Junction::AccountId32 { id: BOB, network: None }.into(),
+		refund: Junction::AccountId32 { id: BOB, network: None }.into(),
+	}
+}
+
+/// Program withdrawing KSM from the sovereign account of the sibling, swapping it for BSX and
+/// forwarding the BSX back to BOB on the sibling.
+fn swap_and_forward_program(give: Instruction<()>, min_bsx_out: u128) -> Xcm<()> {
+	let mut program = vec![give];
+	program.extend(
+		swap_and_forward(min_bsx_out)
+			.instructions(&UniversalLocation::get())
+			.expect("BSX is reserved on Basilisk"),
+	);
+	Xcm(program)
+}
+
+fn estimate_fee_in_ksm(program: &Xcm<()>) -> u128 {
+	let weight = Runtime::query_xcm_weight(VersionedXcm::from(program.clone())).unwrap();
+	Runtime::query_weight_to_asset_fee(
+		weight,
+		VersionedAssetId::from(polkadot_xcm::v5::AssetId(Location::parent())),
+	)
+	.unwrap()
+}
+
+fn send_to_basilisk(program: Xcm<()>) {
+	assert_ok!(PolkadotXcm::send_xcm(
+		Here,
+		Location::new(1, [Parachain(BASILISK_PARA_ID)]),
+		program
+	));
+}
+
+#[test]
+fn ksm_should_be_swapped_and_forwarded_to_sibling() {
+	// Arrange
+	init();
+
+	let mut program = Xcm(vec![]);
+	let mut fee = 0;
+	Basilisk::execute_with(|| {
+		program = swap_and_forward_program(WithdrawAsset(ksm(KSM_TO_SWAP).into()), UNITS);
+		fee = estimate_fee_in_ksm(&program);
+		assert!(fee > 0 && fee < UNITS);
+	});
+
+	// Act
+	OtherParachain::execute_with(|| {
+		send_to_basilisk(program);
+	});
+
+	// Assert
+	Basilisk::execute_with(|| {
+		assert_xcm_message_processing_passed();
+
+		// execution paid in KSM
+		assert_eq!(Tokens::free_balance(KSM, &TreasuryAccount::get()), fee);
+		assert_eq!(
+			Tokens::free_balance(KSM, &parachain_reserve_account()),
+			SIBLING_KSM_BALANCE - KSM_TO_SWAP
+		);
+		// all of the incoming KSM is swapped or paid as fee
+		assert_eq!(Tokens::free_balance(KSM, &AccountId::from(BOB)), 0);
+	});
+
+	OtherParachain::execute_with(|| {
+		assert!(Tokens::free_balance(SIBLING_BSX, &AccountId::from(BOB)) > UNITS);
+	});
+}
+
+#[test]
+fn trapped_ksm_should_be_swapped_and_forwarded_when_claimed() {
+	// Arrange
+	init();
+
+	let mut failing_program = Xcm(vec![]);
+	Basilisk::execute_with(|| {
+		// swap fails as the pool has only 200 BSX
+		failing_program = swap_and_forward_program(WithdrawAsset(ksm(KSM_TO_SWAP).into()), 1_000 * UNITS);
+	});
+
+	OtherParachain::execute_with(|| {
+		send_to_basilisk(failing_program);
+	});
+
+	let mut recovery_program = Xcm(vec![]);
+	Basilisk::execute_with(|| {
+		assert_xcm_message_processing_failed();
+
+		let trapped = basilisk_runtime::System::events()
+			.into_iter()
+			.find_map(|r| match r.event {
+				RuntimeEvent::PolkadotXcm(pallet_xcm::Event::AssetsTrapped { origin, assets, .. }) => {
+					assert_eq!(origin, Location::new(1, [Parachain(OTHER_PARA_ID)]));
+					Some(assets)
+				}
+				_ => None,
+			})
+			.expect("assets should be trapped");
+		assert_eq!(
+			Tokens::free_balance(KSM, &parachain_reserve_account()),
+			SIBLING_KSM_BALANCE - KSM_TO_SWAP
+		);
+
+		recovery_program = swap_and_forward_program(
+			ClaimAsset {
+				assets: trapped.try_into().unwrap(),
+				ticket: Here.into(),
+			},
+			UNITS,
+		);
+	});
+
+	// Act
+	OtherParachain::execute_with(|| {
+		send_to_basilisk(recovery_program);
+	});
+
+	// Assert
+	Basilisk::execute_with(|| {
+		assert_xcm_message_processing_passed();
+		assert_eq!(Tokens::free_balance(KSM, &AccountId::from(BOB)), 0);
+	});
+
+	OtherParachain::execute_with(|| {
+		assert!(Tokens::free_balance(SIBLING_BSX, &AccountId::from(BOB)) > UNITS);
+	});
+}
+
+#[test]
+fn swap_and_forward_should_not_be_built_when_proceeds_are_reserved_on_other_chain() {
+	Basilisk::execute_with(|| {
+		let mut swap = swap_and_forward(UNITS);
+		swap.want = ksm(UNITS);
+
+		assert_eq!(swap.instructions::<()>(&UniversalLocation::get()), None);
+	});
+}
@@ -1,7 +1,13 @@
 #![cfg(test)]
 use crate::kusama_test_net::*;
 
-use frame_support::{assert_ok, dispatch::GetDispatchInfo};
+use basilisk_runtime::{BlockNumber, RuntimeCall, RuntimeOrigin, SafeCallFilter, LBP, XYK};
+use basilisk_traits::router::PoolType;
+use frame_support::{assert_ok, dispatch::GetDispatchInfo, traits::Contains};
+use orml_traits::MultiCurrency;
+use pallet_lbp::WeightCurveType;
+use pallet_route_executor::Trade;
+use primitives::AssetId;
 use sp_runtime::codec::Encode;
 
 use polkadot_xcm::v5::prelude::*;
@@ -251,6 +257,294 @@ fn safe_call_filter_should_respect_runtime_call_filter() {
 	Basilisk::execute_with(assert_xcm_message_processing_failed);
 }
 
+#[test]
+fn router_sell_should_be_executed_when_sent_from_sibling() {
+	// Arrange
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		fund_sibling_sovereign_account();
+		create_xyk_pool(AUSD, BSX);
+	});
+
+	OtherParachain::execute_with(|| {
+		let call = RuntimeCall::Router(pallet_route_executor::Call::sell {
+			asset_in: AUSD,
+			asset_out: BSX,
+			amount_in: 10 * UNITS,
+			min_amount_out: 0,
+			route: vec![Trade {
+				pool: PoolType::XYK,
+				asset_in: AUSD,
+				asset_out: BSX,
+			}],
+		});
+
+		// Act
+		assert_ok!(basilisk_runtime::PolkadotXcm::send_xcm(
+			Here,
+			basilisk_location(),
+			transact_message(call)
+		));
+	});
+
+	// Assert
+	Basilisk::execute_with(|| {
+		assert_xcm_message_processing_passed();
+		assert_eq!(
+			basilisk_runtime::Tokens::free_balance(AUSD, &parachain_reserve_account()),
+			SIBLING_SOVEREIGN_AUSD_BALANCE - 10 * UNITS
+		);
+	});
+}
+
+#[test]
+fn lbp_buy_should_be_executed_when_sent_from_sibling() {
+	// Arrange
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		fund_sibling_sovereign_account();
+		create_lbp_pool(AUSD, NEW_BOOTSTRAPPED_TOKEN);
+		go_to_block(SALE_START.unwrap() + 1);
+	});
+
+	OtherParachain::execute_with(|| {
+		let call = RuntimeCall::LBP(pallet_lbp::Call::buy {
+			asset_out: NEW_BOOTSTRAPPED_TOKEN,
+			asset_in: AUSD,
+			amount: 10 * UNITS,
+			max_limit: 100 * UNITS,
+		});
+
+		// Act
+		assert_ok!(basilisk_runtime::PolkadotXcm::send_xcm(
+			Here,
+			basilisk_location(),
+			transact_message(call)
+		));
+	});
+
+	// Assert
+	Basilisk::execute_with(|| {
+		assert_xcm_message_processing_passed();
+		assert_eq!(
+			basilisk_runtime::Tokens::free_balance(NEW_BOOTSTRAPPED_TOKEN, &parachain_reserve_account()),
+			10 * UNITS
+		);
+	});
+}
+
+#[test]
+fn marketplace_buy_should_be_executed_when_sent_from_sibling() {
+	// Arrange
+	TestNet::reset();
+
+	let price = 100 * UNITS;
+
+	Basilisk::execute_with(|| {
+		// marketplace trades in the asset with the relay chain location
+		assert_ok!(basilisk_runtime::AssetRegistry::set_location(
+			RuntimeOrigin::root(),
+			AUSD,
+			basilisk_runtime::AssetLocation(basilisk_runtime::RELAY_CHAIN_ASSET_LOCATION.0)
+		));
+		fund_sibling_sovereign_account();
+
+		assert_ok!(basilisk_runtime::NFT::create_collection(
+			RuntimeOrigin::signed(ALICE.into()),
+			NFT_COLLECTION,
+			pallet_nft::CollectionType::Marketplace,
+			b"ipfs://QmZn9GFNrNyaTXNdCLWEPtjYHGG9yajgw9JzxpMoDZ2Ziq"
+				.to_vec()
+				.try_into()
+				.unwrap(),
+		));
+		assert_ok!(basilisk_runtime::NFT::mint(
+			RuntimeOrigin::signed(ALICE.into()),
+			NFT_COLLECTION,
+			0,
+			b"ipfs://QmQu2jUmtFNPd86tEHFs6hmAArKYyjEC3xuwVWpFGjcMgm"
+				.to_vec()
+				.try_into()
+				.unwrap(),
+		));
+		assert_ok!(basilisk_runtime::Marketplace::set_price(
+			RuntimeOrigin::signed(ALICE.into()),
+			NFT_COLLECTION,
+			0,
+			Some(price),
+		));
+	});
+
+	OtherParachain::execute_with(|| {
+		let call = RuntimeCall::Marketplace(pallet_marketplace::Call::buy {
+			collection_id: NFT_COLLECTION,
+			item_id: 0,
+		});
+
+		// Act
+		assert_ok!(basilisk_runtime::PolkadotXcm::send_xcm(
+			Here,
+			basilisk_location(),
+			transact_message(call)
+		));
+	});
+
+	// Assert
+	Basilisk::execute_with(|| {
+		assert_xcm_message_processing_passed();
+		assert_eq!(
+			basilisk_runtime::Uniques::owner(NFT_COLLECTION, 0),
+			Some(parachain_reserve_account())
+		);
+		assert_eq!(
+			basilisk_runtime::Tokens::free_balance(AUSD, &parachain_reserve_account()),
+			SIBLING_SOVEREIGN_AUSD_BALANCE - price
+		);
+	});
+}
+
+#[test]
+fn safe_call_filter_should_not_allow_management_calls_of_trading_pallets() {
+	TestNet::reset();
+
+	Basilisk::execute_with(|| {
+		assert!(SafeCallFilter::contains(&RuntimeCall::LBP(pallet_lbp::Call::sell {
+			asset_in: AUSD,
+			asset_out: NEW_BOOTSTRAPPED_TOKEN,
+			amount: UNITS,
+			max_limit: 0,
+		})));
+
+		assert!(!SafeCallFilter::contains(&RuntimeCall::LBP(
+			pallet_lbp::Call::remove_liquidity {
+				pool_id: parachain_reserve_account(),
+			}
+		)));
+		assert!(!SafeCallFilter::contains(&RuntimeCall::Router(
+			pallet_route_executor::Call::force_insert_route {
+				asset_pair: basilisk_traits::router::AssetPair::new(AUSD, BSX),
+				new_route: vec![],
+			}
+		)));
+		assert!(!SafeCallFilter::contains(&RuntimeCall::Marketplace(
+			pallet_marketplace::Call::add_royalty {
+				collection_id: NFT_COLLECTION,
+				item_id: 0,
+				author: ALICE.into(),
+				royalty: 10,
+			}
+		)));
+	});
+}
+
+const SIBLING_SOVEREIGN_AUSD_BALANCE: u128 = 500 * UNITS;
+const NFT_COLLECTION: primitives::CollectionId = 13370000;
+const SALE_START: Option<BlockNumber> = Some(10);
+const SALE_END: Option<BlockNumber> = Some(40);
+
+fn fund_sibling_sovereign_account() {
+	assert_ok!(basilisk_runtime::Balances::force_set_balance(
+		RuntimeOrigin::root(),
+		parachain_reserve_account(),
+		1_000 * UNITS,
+	));
+	assert_ok!(basilisk_runtime::Currencies::transfer(
+		RuntimeOrigin::signed(BOB.into()),
+		parachain_reserve_account(),
+		AUSD,
+		SIBLING_SOVEREIGN_AUSD_BALANCE,
+	));
+}
+
+fn create_xyk_pool(asset_a: AssetId, asset_b: AssetId) {
+	assert_ok!(XYK::create_pool(
+		RuntimeOrigin::signed(ALICE.into()),
+		asset_a,
+		100 * UNITS,
+		asset_b,
+		50 * UNITS,
+	));
+}
+
+fn create_lbp_pool(accumulated_asset: AssetId, distributed_asset: AssetId) {
+	assert_ok!(LBP::create_pool(
+		RuntimeOrigin::root(),
+		ALICE.into(),
+		accumulated_asset,
+		100 * UNITS,
+		distributed_asset,
+		200 * UNITS,
+		20_000_000,
+		80_000_000,
+		WeightCurveType::Linear,
+		(2, 1_000),
+		CHARLIE.into(),
+		0,
+	));
+
+	assert_ok!(LBP::update_pool_data(
+		RuntimeOrigin::signed(ALICE.into()),
+		LBP::pair_account_from_assets(accumulated_asset, distributed_asset),
+		None,
+		SALE_START,
+		SALE_END,
+		None,
+		None,
+		None,
+		None,
+		None,
+	));
+}
+
+/// Transact `call` as the sovereign account of the sibling, paying the execution in BSX.
+fn transact_message(call: RuntimeCall) -> Xcm<()> {
+	let bsx_loc = Location::new(
+		1,
+		cumulus_primitives_core::Junctions::X2(Arc::new(
+			vec![
+				cumulus_primitives_core::Junction::Parachain(BASILISK_PARA_ID),
+				cumulus_primitives_core::Junction::GeneralIndex(0),
+			]
+			.try_into()
+			.unwrap(),
+		)),
+	);
+
+	Xcm(vec![
+		WithdrawAsset(
+			Asset {
+				id: cumulus_primitives_core::AssetId(bsx_loc.clone()),
+				fun: Fungible(900 * UNITS),
+			}
+			.into(),
+		),
+		BuyExecution {
+			fees: Asset {
+				id: cumulus_primitives_core::AssetId(bsx_loc),
+				fun: Fungible(800 * UNITS),
+			},
+			weight_limit: Unlimited,
+		},
+		Transact {
+			fallback_max_weight: Some(call.get_dispatch_info().call_weight),
+			origin_kind: OriginKind::SovereignAccount,
+			call: call.encode().into(),
+		},
+		ExpectTransactStatus(MaybeErrorCode::Success),
+		RefundSurplus,
+		DepositAsset {
+			assets: All.into(),
+			beneficiary: cumulus_primitives_core::Junction::AccountId32 {
+				id: parachain_reserve_account().into(),
+				network: None,
+			}
+			.into(),
+		},
+	])
+}
+
 fn basilisk_location() -> Location {
 	Location::new(
 		1,
@@ -10,5 +10,6 @@ extern crate std;
 
 pub mod ema;
 pub mod ratio;
+pub mod stableswap;
 #[cfg(test)]
 pub mod test_utils;
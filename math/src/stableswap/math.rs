@@ -0,0 +1,487 @@
+use crate::stableswap::types::AssetReserve;
+use hydra_dx_math::support::rational::{round_u512_to_rational, Rounding};
+use hydra_dx_math::types::Balance;
+use num_traits::{One, Zero};
+use primitive_types::{U256, U512};
+use sp_arithmetic::Permill;
+use sp_std::vec::Vec;
+
+/// Max number of iterations of the Newton's method when calculating D.
+pub const MAX_D_ITERATIONS: u8 = 64;
+
+/// Max number of iterations of the Newton's method when calculating Y.
+pub const MAX_Y_ITERATIONS: u8 = 64;
+
+/// Precision all reserves are normalized to before the invariant is calculated.
+pub const TARGET_PRECISION: u8 = 18;
+
+/// Max difference of two consecutive iterations for the Newton's method to be considered converged.
+const CONVERGENCE_LIMIT: u128 = 1;
+
+/// Calculate the amount of `asset_out` received for selling `amount_in` of `asset_in`.
+///
+/// `idx_in` and `idx_out` are positions of the assets in `reserves`.
+pub fn calculate_out_given_in<const D: u8, const Y: u8>(
+	reserves: &[AssetReserve],
+	idx_in: usize,
+	idx_out: usize,
+	amount_in: Balance,
+	amplification: Balance,
+) -> Option<Balance> {
+	if idx_in == idx_out || idx_in >= reserves.len() || idx_out >= reserves.len() {
+		return None;
+	}
+
+	let xp = normalize_reserves(reserves)?;
+	let d = calculate_d_internal::<D>(&xp, amplification)?;
+
+	let mut updated_xp = xp.clone();
+	updated_xp[idx_in] = updated_xp[idx_in].checked_add(normalize_value(amount_in, reserves[idx_in].decimals)?)?;
+
+	let y = calculate_y_internal::<Y>(&without_index(&updated_xp, idx_out), d, amplification)?;
+
+	// Round in favour of the pool.
+	let amount_out = xp[idx_out].checked_sub(y)?.saturating_sub(One::one());
+
+	denormalize_value(amount_out, reserves[idx_out].decimals, false)
+}
+
+/// Calculate the amount of `asset_in` required to buy `amount_out` of `asset_out`.
+///
+/// `idx_in` and `idx_out` are positions of the assets in `reserves`.
+pub fn calculate_in_given_out<const D: u8, const Y: u8>(
+	reserves: &[AssetReserve],
+	idx_in: usize,
+	idx_out: usize,
+	amount_out: Balance,
+	amplification: Balance,
+) -> Option<Balance> {
+	if idx_in == idx_out || idx_in >= reserves.len() || idx_out >= reserves.len() {
+		return None;
+	}
+
+	let xp = normalize_reserves(reserves)?;
+	let d = calculate_d_internal::<D>(&xp, amplification)?;
+
+	let mut updated_xp = xp.clone();
+	updated_xp[idx_out] = updated_xp[idx_out].checked_sub(normalize_value(amount_out, reserves[idx_out].decimals)?)?;
+
+	let y = calculate_y_internal::<Y>(&without_index(&updated_xp, idx_in), d, amplification)?;
+
+	// Round in favour of the pool.
+	let amount_in = y.checked_sub(xp[idx_in])?.checked_add(One::one())?;
+
+	denormalize_value(amount_in, reserves[idx_in].decimals, true)
+}
+
+/// Calculate the amount of `asset_out` received for selling `amount_in` of `asset_in` with the fee applied.
+///
+/// Returns the amount out without the fee and the fee amount, both in `asset_out`.
+pub fn calculate_out_given_in_with_fee<const D: u8, const Y: u8>(
+	reserves: &[AssetReserve],
+	idx_in: usize,
+	idx_out: usize,
+	amount_in: Balance,
+	amplification: Balance,
+	fee: Permill,
+) -> Option<(Balance, Balance)> {
+	let amount_out = calculate_out_given_in::<D, Y>(reserves, idx_in, idx_out, amount_in, amplification)?;
+	let fee_amount = fee.mul_ceil(amount_out);
+
+	Some((amount_out.checked_sub(fee_amount)?, fee_amount))
+}
+
+/// Calculate the amount of `asset_in` required to buy `amount_out` of `asset_out` with the fee applied.
+///
+/// Returns the amount in including the fee and the fee amount, both in `asset_in`.
+pub fn calculate_in_given_out_with_fee<const D: u8, const Y: u8>(
+	reserves: &[AssetReserve],
+	idx_in: usize,
+	idx_out: usize,
+	amount_out: Balance,
+	amplification: Balance,
+	fee: Permill,
+) -> Option<(Balance, Balance)> {
+	let amount_in = calculate_in_given_out::<D, Y>(reserves, idx_in, idx_out, amount_out, amplification)?;
+	let fee_amount = fee.mul_ceil(amount_in);
+
+	Some((amount_in.checked_add(fee_amount)?, fee_amount))
+}
+
+/// Calculate the amount of shares to mint when the pool reserves change from `initial_reserves` to `updated_reserves`.
+///
+/// If `share_issuance` is zero, the initial liquidity is being provided and the amount of shares equals to D.
+/// Otherwise, a fee is charged on the imbalance of the provided liquidity.
+pub fn calculate_shares<const D: u8>(
+	initial_reserves: &[AssetReserve],
+	updated_reserves: &[AssetReserve],
+	amplification: Balance,
+	share_issuance: Balance,
+	fee: Permill,
+) -> Option<Balance> {
+	if initial_reserves.len() != updated_reserves.len() {
+		return None;
+	}
+
+	let updated_xp = normalize_reserves(updated_reserves)?;
+	let d1 = calculate_d_internal::<D>(&updated_xp, amplification)?;
+
+	if share_issuance.is_zero() {
+		return Some(d1);
+	}
+
+	let initial_xp = normalize_reserves(initial_reserves)?;
+	let d0 = calculate_d_internal::<D>(&initial_xp, amplification)?;
+
+	if d1 <= d0 {
+		return None;
+	}
+
+	let n = updated_xp.len();
+	let adjusted_xp = initial_xp
+		.iter()
+		.zip(updated_xp.iter())
+		.map(|(initial, updated)| {
+			let ideal = mul_div(*initial, d1, d0)?;
+			let difference = if *updated > ideal {
+				updated - ideal
+			} else {
+				ideal - updated
+			};
+			updated.checked_sub(calculate_imbalance_fee(difference, fee, n)?)
+		})
+		.collect::<Option<Vec<Balance>>>()?;
+
+	let d2 = calculate_d_internal::<D>(&adjusted_xp, amplification)?;
+
+	mul_div(share_issuance, d2.checked_sub(d0)?, d0)
+}
+
+/// Calculate the amount of asset at `asset_index` received for withdrawing `shares` from the pool.
+///
+/// Returns the amount out without the fee and the fee amount.
+pub fn calculate_withdraw_one_asset<const D: u8, const Y: u8>(
+	reserves: &[AssetReserve],
+	shares: Balance,
+	asset_index: usize,
+	share_issuance: Balance,
+	amplification: Balance,
+	fee: Permill,
+) -> Option<(Balance, Balance)> {
+	if share_issuance.is_zero() || shares >= share_issuance || asset_index >= reserves.len() {
+		return None;
+	}
+
+	let xp = normalize_reserves(reserves)?;
+	let d0 = calculate_d_internal::<D>(&xp, amplification)?;
+	let d1 = d0.checked_sub(mul_div(shares, d0, share_issuance)?)?;
+
+	let y = calculate_y_internal::<Y>(&without_index(&xp, asset_index), d1, amplification)?;
+
+	let n = xp.len();
+	let reduced_xp = xp
+		.iter()
+		.enumerate()
+		.map(|(idx, reserve)| {
+			let expected = if idx == asset_index {
+				mul_div(*reserve, d1, d0)?.checked_sub(y)?
+			} else {
+				reserve.checked_sub(mul_div(*reserve, d1, d0)?)?
+			};
+			reserve.checked_sub(calculate_imbalance_fee(expected, fee, n)?)
+		})
+		.collect::<Option<Vec<Balance>>>()?;
+
+	let y_reduced = calculate_y_internal::<Y>(&without_index(&reduced_xp, asset_index), d1, amplification)?;
+
+	// Round in favour of the pool.
+	let amount_out = reduced_xp[asset_index]
+		.checked_sub(y_reduced)?
+		.saturating_sub(One::one());
+	let fee_amount = xp[asset_index].checked_sub(y)?.saturating_sub(amount_out);
+
+	let decimals = reserves[asset_index].decimals;
+
+	Some((
+		denormalize_value(amount_out, decimals, false)?,
+		denormalize_value(fee_amount, decimals, false)?,
+	))
+}
+
+/// Calculate the amount of an asset received for withdrawing `shares` proportionally from the pool.
+pub fn calculate_liquidity_out(reserve: Balance, shares: Balance, share_issuance: Balance) -> Option<Balance> {
+	if shares > share_issuance {
+		return None;
+	}
+
+	mul_div(reserve, shares, share_issuance)
+}
+
+/// Calculate the value of `shares` denominated in an asset with given `decimals`,
+/// assuming all assets of the pool are at peg.
+pub fn calculate_shares_value<const D: u8>(
+	reserves: &[AssetReserve],
+	shares: Balance,
+	share_issuance: Balance,
+	amplification: Balance,
+	decimals: u8,
+) -> Option<Balance> {
+	if shares > share_issuance {
+		return None;
+	}
+
+	let xp = normalize_reserves(reserves)?;
+	let d = calculate_d_internal::<D>(&xp, amplification)?;
+
+	denormalize_value(mul_div(d, shares, share_issuance)?, decimals, false)
+}
+
+/// Calculate the spot price of the asset at `idx_out` denominated in the asset at `idx_in`,
+/// i.e. the amount of `asset_in` per one unit of `asset_out`.
+///
+/// Returns the price as a rational number `(n, d)`.
+pub fn calculate_spot_price<const D: u8>(
+	reserves: &[AssetReserve],
+	idx_in: usize,
+	idx_out: usize,
+	amplification: Balance,
+) -> Option<(Balance, Balance)> {
+	if idx_in >= reserves.len() || idx_out >= reserves.len() {
+		return None;
+	}
+
+	let xp = normalize_reserves(reserves)?;
+	let d = U256::from(calculate_d_internal::<D>(&xp, amplification)?);
+	let n = U256::from(xp.len());
+	let ann = U256::from(amplification).checked_mul(n.checked_pow(n)?)?;
+
+	// D^(n+1) / (n^n * prod(x))
+	let mut d_p = d;
+	for reserve in xp.iter() {
+		d_p = d_p.checked_mul(d)?.checked_div(U256::from(*reserve).checked_mul(n)?)?;
+	}
+
+	let x_in = U256::from(xp[idx_in]);
+	let x_out = U256::from(xp[idx_out]);
+
+	// The price is the ratio of the partial derivatives of the invariant.
+	let mut numerator = U512::from(ann.checked_mul(x_out)?.checked_add(d_p)?).checked_mul(U512::from(x_in))?;
+	let mut denominator = U512::from(ann.checked_mul(x_in)?.checked_add(d_p)?).checked_mul(U512::from(x_out))?;
+
+	// Convert the normalized price back to the asset precisions.
+	let (out_up, out_down) = precision_factors(reserves[idx_out].decimals)?;
+	let (in_up, in_down) = precision_factors(reserves[idx_in].decimals)?;
+	numerator = numerator
+		.checked_mul(U512::from(out_up))?
+		.checked_mul(U512::from(in_down))?;
+	denominator = denominator
+		.checked_mul(U512::from(in_up))?
+		.checked_mul(U512::from(out_down))?;
+
+	Some(round_u512_to_rational((numerator, denominator), Rounding::Nearest))
+}
+
+/// Calculate the current amplification, which changes linearly from `initial_amplification`
+/// at `initial_block` to `final_amplification` at `final_block`.
+pub fn calculate_amplification(
+	initial_amplification: Balance,
+	final_amplification: Balance,
+	initial_block: Balance,
+	final_block: Balance,
+	current_block: Balance,
+) -> Balance {
+	if current_block >= final_block || final_block <= initial_block {
+		return final_amplification;
+	}
+
+	if current_block <= initial_block {
+		return initial_amplification;
+	}
+
+	let step = current_block.saturating_sub(initial_block);
+	let duration = final_block.saturating_sub(initial_block);
+
+	if final_amplification >= initial_amplification {
+		let change = mul_div(
+			final_amplification.saturating_sub(initial_amplification),
+			step,
+			duration,
+		)
+		.unwrap_or_default();
+		initial_amplification.saturating_add(change)
+	} else {
+		let change = mul_div(
+			initial_amplification.saturating_sub(final_amplification),
+			step,
+			duration,
+		)
+		.unwrap_or_default();
+		initial_amplification.saturating_sub(change)
+	}
+}
+
+/// Calculate the invariant D of the pool.
+pub fn calculate_d<const D: u8>(reserves: &[AssetReserve], amplification: Balance) -> Option<Balance> {
+	let xp = normalize_reserves(reserves)?;
+	calculate_d_internal::<D>(&xp, amplification)
+}
+
+pub(crate) fn calculate_d_internal<const D: u8>(xp: &[Balance], amplification: Balance) -> Option<Balance> {
+	let sum = xp
+		.iter()
+		.try_fold(U256::zero(), |acc, reserve| acc.checked_add(U256::from(*reserve)))?;
+
+	if sum.is_zero() {
+		return Some(Balance::zero());
+	}
+
+	// The invariant is not defined for a pool with an empty reserve.
+	if xp.iter().any(|reserve| reserve.is_zero()) {
+		return None;
+	}
+
+	let n = U256::from(xp.len());
+	let ann = U256::from(amplification).checked_mul(n.checked_pow(n)?)?;
+
+	let mut d = sum;
+
+	for _ in 0..D {
+		let mut d_p = d;
+		for reserve in xp.iter() {
+			d_p = d_p.checked_mul(d)?.checked_div(U256::from(*reserve).checked_mul(n)?)?;
+		}
+
+		let d_prev = d;
+
+		// d = (ann * sum + d_p * n) * d / ((ann - 1) * d + (n + 1) * d_p)
+		let numerator = ann.checked_mul(sum)?.checked_add(d_p.checked_mul(n)?)?.checked_mul(d)?;
+		let denominator = ann
+			.checked_sub(U256::one())?
+			.checked_mul(d)?
+			.checked_add(n.checked_add(U256::one())?.checked_mul(d_p)?)?;
+
+		d = numerator.checked_div(denominator)?;
+
+		if has_converged(d_prev, d) {
+			return d.try_into().ok();
+		}
+	}
+
+	None
+}
+
+/// Calculate the reserve of an asset so that the invariant D holds, given the reserves of all other assets `xp`.
+pub(crate) fn calculate_y_internal<const Y: u8>(xp: &[Balance], d: Balance, amplification: Balance) -> Option<Balance> {
+	let n = U256::from(xp.len().checked_add(1)?);
+	let ann = U256::from(amplification).checked_mul(n.checked_pow(n)?)?;
+	let d = U256::from(d);
+
+	let mut c = d;
+	let mut sum = U256::zero();
+	for reserve in xp.iter() {
+		let reserve = U256::from(*reserve);
+		sum = sum.checked_add(reserve)?;
+		c = c.checked_mul(d)?.checked_div(reserve.checked_mul(n)?)?;
+	}
+	c = c.checked_mul(d)?.checked_div(ann.checked_mul(n)?)?;
+
+	let b = sum.checked_add(d.checked_div(ann)?)?;
+
+	let mut y = d;
+
+	for _ in 0..Y {
+		let y_prev = y;
+
+		// y = (y^2 + c) / (2 * y + b - d)
+		let numerator = y.checked_mul(y)?.checked_add(c)?;
+		let denominator = y.checked_mul(U256::from(2u8))?.checked_add(b)?.checked_sub(d)?;
+
+		y = numerator.checked_div(denominator)?;
+
+		if has_converged(y_prev, y) {
+			return y.try_into().ok();
+		}
+	}
+
+	None
+}
+
+/// Imbalance fee is adjusted the same way as in Curve, so that a swap done via liquidity provision
+/// costs roughly the same as a regular trade: fee * n / (4 * (n - 1)).
+fn calculate_imbalance_fee(amount: Balance, fee: Permill, n: usize) -> Option<Balance> {
+	let n = n as u128;
+	let numerator = U256::from(amount)
+		.checked_mul(U256::from(fee.deconstruct()))?
+		.checked_mul(U256::from(n))?;
+	let denominator = U256::from(1_000_000u128)
+		.checked_mul(U256::from(4u8))?
+		.checked_mul(U256::from(n.checked_sub(1)?))?;
+
+	if denominator.is_zero() {
+		return None;
+	}
+
+	let fee_amount = numerator
+		.checked_add(denominator.checked_sub(U256::one())?)?
+		.checked_div(denominator)?;
+
+	fee_amount.try_into().ok()
+}
+
+fn has_converged(prev: U256, current: U256) -> bool {
+	let difference = if current > prev { current - prev } else { prev - current };
+
+	difference <= U256::from(CONVERGENCE_LIMIT)
+}
+
+fn mul_div(a: Balance, b: Balance, c: Balance) -> Option<Balance> {
+	if c.is_zero() {
+		return None;
+	}
+
+	U256::from(a)
+		.checked_mul(U256::from(b))?
+		.checked_div(U256::from(c))?
+		.try_into()
+		.ok()
+}
+
+fn without_index(xp: &[Balance], index: usize) -> Vec<Balance> {
+	xp.iter()
+		.enumerate()
+		.filter(|(idx, _)| *idx != index)
+		.map(|(_, reserve)| *reserve)
+		.collect()
+}
+
+/// Returns factors `(up, down)` which convert a value with given decimals to the target precision: `value * up / down`.
+fn precision_factors(decimals: u8) -> Option<(Balance, Balance)> {
+	if decimals <= TARGET_PRECISION {
+		Some((10u128.checked_pow((TARGET_PRECISION - decimals) as u32)?, 1))
+	} else {
+		Some((1, 10u128.checked_pow((decimals - TARGET_PRECISION) as u32)?))
+	}
+}
+
+pub(crate) fn normalize_reserves(reserves: &[AssetReserve]) -> Option<Vec<Balance>> {
+	reserves
+		.iter()
+		.map(|reserve| normalize_value(reserve.amount, reserve.decimals))
+		.collect()
+}
+
+pub(crate) fn normalize_value(amount: Balance, decimals: u8) -> Option<Balance> {
+	let (up, down) = precision_factors(decimals)?;
+	amount.checked_mul(up)?.checked_div(down)
+}
+
+pub(crate) fn denormalize_value(amount: Balance, decimals: u8, round_up: bool) -> Option<Balance> {
+	let (up, down) = precision_factors(decimals)?;
+	let amount = amount.checked_mul(down)?;
+
+	if round_up {
+		amount.checked_add(up.checked_sub(1)?)?.checked_div(up)
+	} else {
+		amount.checked_div(up)
+	}
+}
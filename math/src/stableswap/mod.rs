@@ -0,0 +1,12 @@
+//! Curve-style stableswap invariant calculations.
+//!
+//! All calculations are performed on reserves normalized to the same precision,
+//! so that assets with different decimals can be mixed in one pool.
+
+mod math;
+pub mod types;
+
+#[cfg(test)]
+mod tests;
+
+pub use math::*;
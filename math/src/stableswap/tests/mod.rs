@@ -0,0 +1,255 @@
+use super::types::AssetReserve;
+use super::*;
+
+use sp_arithmetic::Permill;
+use test_case::test_case;
+
+const D_ITERATIONS: u8 = MAX_D_ITERATIONS;
+const Y_ITERATIONS: u8 = MAX_Y_ITERATIONS;
+
+const ONE: u128 = 1_000_000_000_000;
+
+fn balanced_reserves(amount: u128, count: usize) -> Vec<AssetReserve> {
+	(0..count).map(|_| AssetReserve::new(amount, 12)).collect()
+}
+
+#[test]
+fn calculate_d_should_be_sum_of_reserves_when_pool_is_balanced() {
+	let reserves = balanced_reserves(1_000 * ONE, 3);
+
+	let d = calculate_d::<D_ITERATIONS>(&reserves, 100).unwrap();
+
+	// normalized to 18 decimals
+	assert_eq!(d, 3_000 * 1_000_000_000_000_000_000);
+}
+
+#[test]
+fn calculate_d_should_be_zero_when_pool_is_empty() {
+	let reserves = balanced_reserves(0, 3);
+
+	assert_eq!(calculate_d::<D_ITERATIONS>(&reserves, 100), Some(0));
+}
+
+#[test]
+fn calculate_d_should_fail_when_one_of_reserves_is_empty() {
+	let reserves = vec![AssetReserve::new(1_000 * ONE, 12), AssetReserve::new(0, 12)];
+
+	assert_eq!(calculate_d::<D_ITERATIONS>(&reserves, 100), None);
+}
+
+#[test_case(2)]
+#[test_case(3)]
+#[test_case(5)]
+fn calculate_out_given_in_should_be_close_to_amount_in_when_pool_is_balanced(count: usize) {
+	let reserves = balanced_reserves(1_000_000 * ONE, count);
+	let amount_in = 100 * ONE;
+
+	let amount_out = calculate_out_given_in::<D_ITERATIONS, Y_ITERATIONS>(&reserves, 0, 1, amount_in, 1_000).unwrap();
+
+	assert!(amount_out < amount_in);
+	assert!(amount_out > amount_in - amount_in / 10_000);
+}
+
+#[test]
+fn calculate_out_given_in_should_fail_when_assets_are_same() {
+	let reserves = balanced_reserves(1_000 * ONE, 2);
+
+	assert_eq!(
+		calculate_out_given_in::<D_ITERATIONS, Y_ITERATIONS>(&reserves, 0, 0, ONE, 100),
+		None
+	);
+}
+
+#[test]
+fn calculate_out_given_in_should_have_higher_slippage_when_amplification_is_lower() {
+	let reserves = balanced_reserves(1_000 * ONE, 2);
+	let amount_in = 100 * ONE;
+
+	let high_amp_out = calculate_out_given_in::<D_ITERATIONS, Y_ITERATIONS>(&reserves, 0, 1, amount_in, 1_000).unwrap();
+	let low_amp_out = calculate_out_given_in::<D_ITERATIONS, Y_ITERATIONS>(&reserves, 0, 1, amount_in, 1).unwrap();
+
+	assert!(low_amp_out < high_amp_out);
+}
+
+#[test]
+fn calculate_in_given_out_should_be_inverse_of_out_given_in() {
+	let reserves = vec![
+		AssetReserve::new(1_000_000 * ONE, 12),
+		AssetReserve::new(800_000 * ONE, 12),
+		AssetReserve::new(1_200_000 * ONE, 12),
+	];
+	let amount_in = 1_000 * ONE;
+
+	let amount_out = calculate_out_given_in::<D_ITERATIONS, Y_ITERATIONS>(&reserves, 0, 2, amount_in, 500).unwrap();
+	let required_in = calculate_in_given_out::<D_ITERATIONS, Y_ITERATIONS>(&reserves, 0, 2, amount_out, 500).unwrap();
+
+	assert!(required_in <= amount_in);
+	assert!(amount_in - required_in < 10);
+}
+
+#[test]
+fn calculate_out_given_in_should_work_when_assets_have_different_decimals() {
+	let reserves = vec![
+		AssetReserve::new(1_000_000 * 1_000_000, 6),
+		AssetReserve::new(1_000_000 * 1_000_000_000_000_000_000, 18),
+	];
+
+	let amount_out =
+		calculate_out_given_in::<D_ITERATIONS, Y_ITERATIONS>(&reserves, 0, 1, 100 * 1_000_000, 1_000).unwrap();
+
+	assert!(amount_out < 100 * 1_000_000_000_000_000_000);
+	assert!(amount_out > 99 * 1_000_000_000_000_000_000);
+}
+
+#[test]
+fn calculate_out_given_in_with_fee_should_deduct_fee_from_amount_out() {
+	let reserves = balanced_reserves(1_000_000 * ONE, 2);
+	let amount_in = 100 * ONE;
+
+	let amount_out = calculate_out_given_in::<D_ITERATIONS, Y_ITERATIONS>(&reserves, 0, 1, amount_in, 1_000).unwrap();
+	let (amount_out_with_fee, fee) = calculate_out_given_in_with_fee::<D_ITERATIONS, Y_ITERATIONS>(
+		&reserves,
+		0,
+		1,
+		amount_in,
+		1_000,
+		Permill::from_percent(1),
+	)
+	.unwrap();
+
+	assert_eq!(fee, Permill::from_percent(1).mul_ceil(amount_out));
+	assert_eq!(amount_out_with_fee + fee, amount_out);
+}
+
+#[test]
+fn calculate_shares_should_be_d_when_initial_liquidity_is_provided() {
+	let initial = balanced_reserves(0, 2);
+	let updated = balanced_reserves(1_000 * ONE, 2);
+
+	let shares = calculate_shares::<D_ITERATIONS>(&initial, &updated, 100, 0, Permill::from_percent(1)).unwrap();
+
+	assert_eq!(shares, calculate_d::<D_ITERATIONS>(&updated, 100).unwrap());
+}
+
+#[test]
+fn calculate_shares_should_be_proportional_when_balanced_liquidity_is_provided() {
+	let initial = balanced_reserves(1_000 * ONE, 2);
+	let updated = balanced_reserves(1_100 * ONE, 2);
+	let issuance = 2_000 * ONE;
+
+	let shares = calculate_shares::<D_ITERATIONS>(&initial, &updated, 100, issuance, Permill::from_percent(1)).unwrap();
+
+	assert_eq!(shares, 200 * ONE);
+}
+
+#[test]
+fn calculate_shares_should_charge_fee_when_imbalanced_liquidity_is_provided() {
+	let initial = balanced_reserves(1_000 * ONE, 2);
+	let updated = vec![AssetReserve::new(1_200 * ONE, 12), AssetReserve::new(1_000 * ONE, 12)];
+	let issuance = 2_000 * ONE;
+
+	let without_fee = calculate_shares::<D_ITERATIONS>(&initial, &updated, 100, issuance, Permill::zero()).unwrap();
+	let with_fee =
+		calculate_shares::<D_ITERATIONS>(&initial, &updated, 100, issuance, Permill::from_percent(1)).unwrap();
+
+	assert!(with_fee < without_fee);
+}
+
+#[test]
+fn calculate_withdraw_one_asset_should_not_return_more_than_provided() {
+	let initial = balanced_reserves(1_000 * ONE, 2);
+	let updated = vec![AssetReserve::new(1_100 * ONE, 12), AssetReserve::new(1_000 * ONE, 12)];
+	let issuance = 2_000 * ONE;
+	let fee = Permill::from_percent(1);
+
+	let shares = calculate_shares::<D_ITERATIONS>(&initial, &updated, 100, issuance, fee).unwrap();
+
+	let (amount_out, fee_amount) =
+		calculate_withdraw_one_asset::<D_ITERATIONS, Y_ITERATIONS>(&updated, shares, 0, issuance + shares, 100, fee)
+			.unwrap();
+
+	assert!(amount_out < 100 * ONE);
+	assert!(fee_amount > 0);
+}
+
+#[test]
+fn calculate_withdraw_one_asset_should_fail_when_all_shares_are_withdrawn() {
+	let reserves = balanced_reserves(1_000 * ONE, 2);
+
+	assert_eq!(
+		calculate_withdraw_one_asset::<D_ITERATIONS, Y_ITERATIONS>(
+			&reserves,
+			2_000 * ONE,
+			0,
+			2_000 * ONE,
+			100,
+			Permill::zero()
+		),
+		None
+	);
+}
+
+#[test]
+fn calculate_liquidity_out_should_be_proportional() {
+	assert_eq!(calculate_liquidity_out(1_000 * ONE, 250, 1_000), Some(250 * ONE));
+	assert_eq!(calculate_liquidity_out(1_000 * ONE, 1_001, 1_000), None);
+}
+
+#[test]
+fn calculate_shares_value_should_be_proportional_part_of_d() {
+	let reserves = balanced_reserves(1_000 * ONE, 2);
+	let issuance = 2_000 * ONE;
+
+	let value = calculate_shares_value::<D_ITERATIONS>(&reserves, 500 * ONE, issuance, 100, 12).unwrap();
+
+	assert_eq!(value, 500 * ONE);
+}
+
+#[test]
+fn calculate_spot_price_should_be_one_when_pool_is_balanced() {
+	let reserves = balanced_reserves(1_000 * ONE, 3);
+
+	let (n, d) = calculate_spot_price::<D_ITERATIONS>(&reserves, 0, 1, 100).unwrap();
+
+	assert_eq!(n, d);
+}
+
+#[test]
+fn calculate_spot_price_should_account_for_decimals() {
+	let reserves = vec![
+		AssetReserve::new(1_000 * 1_000_000, 6),
+		AssetReserve::new(1_000 * 1_000_000_000_000_000_000, 18),
+	];
+
+	let (n, d) = calculate_spot_price::<D_ITERATIONS>(&reserves, 0, 1, 100).unwrap();
+
+	// 1 unit of the 18 decimals asset is worth 10^-12 units of the 6 decimals asset
+	assert!((d / n).abs_diff(1_000_000_000_000) <= 1);
+}
+
+#[test]
+fn calculate_spot_price_should_increase_when_asset_out_is_scarce() {
+	let reserves = vec![AssetReserve::new(1_500 * ONE, 12), AssetReserve::new(500 * ONE, 12)];
+
+	let (n, d) = calculate_spot_price::<D_ITERATIONS>(&reserves, 0, 1, 10).unwrap();
+
+	assert!(n > d);
+}
+
+#[test_case(100, 200, 0, 100, 50, 150 ; "increasing")]
+#[test_case(200, 100, 0, 100, 50, 150 ; "decreasing")]
+#[test_case(100, 200, 10, 100, 5, 100 ; "before start")]
+#[test_case(100, 200, 10, 100, 500, 200 ; "after end")]
+fn calculate_amplification_should_change_linearly(
+	initial: u128,
+	r#final: u128,
+	initial_block: u128,
+	final_block: u128,
+	current_block: u128,
+	expected: u128,
+) {
+	assert_eq!(
+		calculate_amplification(initial, r#final, initial_block, final_block, current_block),
+		expected
+	);
+}
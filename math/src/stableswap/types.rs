@@ -0,0 +1,18 @@
+use hydra_dx_math::types::Balance;
+
+/// Reserve of an asset in a stableswap pool together with the decimals of the asset.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AssetReserve {
+	pub amount: Balance,
+	pub decimals: u8,
+}
+
+impl AssetReserve {
+	pub fn new(amount: Balance, decimals: u8) -> Self {
+		Self { amount, decimals }
+	}
+
+	pub fn is_zero(&self) -> bool {
+		self.amount == 0
+	}
+}
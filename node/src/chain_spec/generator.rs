@@ -0,0 +1,401 @@
+// This file is part of Basilisk-node
+
+// Copyright (C) 2020-2026  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Chain spec of a custom testnet built from a TOML or JSON description.
+//!
+//! Accounts and keys are given either as a dev seed (`//Alice`) or in SS58 format. Balances, token
+//! amounts, pool liquidity and the candidacy bond are in whole units, existential deposits in the
+//! smallest units.
+//!
+//! XYK pools are whitelisted in the oracle automatically. LBP sales are scheduled in relay chain
+//! blocks. Routes are validated at genesis, so they can go through XYK pools and running LBP sales only.
+//!
+//! ```toml
+//! name = "Basilisk Team Testnet"
+//! id = "team_testnet"
+//! relay_chain = "rococo-local"
+//!
+//! [collators]
+//! candidacy_bond = 10000
+//! members = [{ account = "//Alice", aura = "//Alice" }]
+//!
+//! [[assets]]
+//! id = 1
+//! name = "KSM"
+//! existential_deposit = 1000
+//! fee_price = 0.0000212
+//!
+//! [[accounts]]
+//! account = "//Alice"
+//! balance = 1000000000
+//! tokens = [{ asset = 1, amount = 1000000 }]
+//!
+//! [[oracle_whitelist]]
+//! source = "snek/xyk"
+//! assets = [0, 1]
+//!
+//! [[xyk_pools]]
+//! owner = "//Alice"
+//! asset_a = 0
+//! amount_a = 1000000
+//! asset_b = 1
+//! amount_b = 100
+//!
+//! [[lbp_pools]]
+//! owner = "//Alice"
+//! asset_a = 1
+//! amount_a = 1000
+//! asset_b = 2
+//! amount_b = 1000000
+//! initial_weight = 10000000
+//! final_weight = 90000000
+//! start = 1000
+//! end = 2000
+//! fee = [2, 1000]
+//! fee_collector = "//Bob"
+//!
+//! [[routes]]
+//! asset_in = 0
+//! asset_out = 2
+//! trades = [
+//!     { pool = "XYK", asset_in = 0, asset_out = 1 },
+//!     { pool = "LBP", asset_in = 1, asset_out = 2 },
+//! ]
+//! ```
+
+use super::*;
+use basilisk_runtime::{BlockNumber, XYKOracleSourceIdentifier};
+use basilisk_traits::router::{AssetPair, Trade};
+use sp_core::{crypto::Ss58Codec, Get};
+use std::path::Path;
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TestnetDescription {
+	pub name: String,
+	pub id: String,
+	#[serde(default = "default_chain_type")]
+	pub chain_type: ChainType,
+	pub relay_chain: String,
+	#[serde(default = "default_para_id")]
+	pub para_id: u32,
+	#[serde(default)]
+	pub boot_nodes: Vec<String>,
+	#[serde(default = "default_is_testnet")]
+	pub is_testnet: bool,
+	pub collators: Collators,
+	#[serde(default)]
+	pub tech_committee: Vec<String>,
+	#[serde(default)]
+	pub assets: Vec<RegistryAsset>,
+	#[serde(default)]
+	pub accounts: Vec<Account>,
+	#[serde(default)]
+	pub oracle_whitelist: Vec<WhitelistedOracle>,
+	#[serde(default)]
+	pub xyk_pools: Vec<XykPool>,
+	#[serde(default)]
+	pub lbp_pools: Vec<LbpPool>,
+	#[serde(default)]
+	pub routes: Vec<Route>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Collators {
+	pub candidacy_bond: Balance,
+	pub members: Vec<Collator>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Collator {
+	pub account: String,
+	pub aura: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RegistryAsset {
+	pub id: AssetId,
+	pub name: String,
+	pub existential_deposit: Balance,
+	/// Fallback price of the asset in native currency. Fees can be paid in the asset if it is set.
+	#[serde(default)]
+	pub fee_price: Option<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Account {
+	pub account: String,
+	#[serde(default)]
+	pub balance: Balance,
+	#[serde(default)]
+	pub tokens: Vec<TokenBalance>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TokenBalance {
+	pub asset: AssetId,
+	pub amount: Balance,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WhitelistedOracle {
+	/// Oracle source identifier, exactly 8 bytes, e.g. `snek/xyk`.
+	pub source: String,
+	pub assets: (AssetId, AssetId),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct XykPool {
+	pub owner: String,
+	pub asset_a: AssetId,
+	pub amount_a: Balance,
+	pub asset_b: AssetId,
+	pub amount_b: Balance,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LbpPool {
+	pub owner: String,
+	pub asset_a: AssetId,
+	pub amount_a: Balance,
+	pub asset_b: AssetId,
+	pub amount_b: Balance,
+	pub initial_weight: u32,
+	pub final_weight: u32,
+	/// Relay chain block number of the start of the sale.
+	pub start: BlockNumber,
+	/// Relay chain block number of the end of the sale.
+	pub end: BlockNumber,
+	pub fee: (u32, u32),
+	pub fee_collector: String,
+	#[serde(default)]
+	pub repay_target: Balance,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Route {
+	pub asset_in: AssetId,
+	pub asset_out: AssetId,
+	pub trades: Vec<Trade<AssetId>>,
+}
+
+fn default_chain_type() -> ChainType {
+	ChainType::Local
+}
+
+fn default_para_id() -> u32 {
+	PARA_ID
+}
+
+fn default_is_testnet() -> bool {
+	true
+}
+
+fn parse_account(account: &str) -> Result<AccountId, String> {
+	match account.strip_prefix("//") {
+		Some(seed) => Ok(get_account_id_from_seed::<sr25519::Public>(seed)),
+		None => AccountId::from_ss58check(account).map_err(|e| format!("Invalid account {account}: {e:?}")),
+	}
+}
+
+fn parse_aura(key: &str) -> Result<AuraId, String> {
+	match key.strip_prefix("//") {
+		Some(seed) => Ok(get_from_seed::<AuraId>(seed)),
+		None => sr25519::Public::from_ss58check(key)
+			.map(Into::into)
+			.map_err(|e| format!("Invalid aura key {key}: {e:?}")),
+	}
+}
+
+/// Oracle sources are 8 byte identifiers.
+fn parse_source(source: &str) -> Result<[u8; 8], String> {
+	source
+		.as_bytes()
+		.try_into()
+		.map_err(|_| format!("Oracle source {source} has to be exactly 8 bytes long"))
+}
+
+impl TestnetDescription {
+	/// Load the description from a `.toml` file, any other file is parsed as JSON.
+	pub fn from_file(path: &Path) -> Result<Self, String> {
+		let content = std::fs::read_to_string(path).map_err(|e| format!("Unable to read {}: {e}", path.display()))?;
+
+		if path.extension().is_some_and(|ext| ext == "toml") {
+			toml::from_str(&content).map_err(|e| format!("Invalid description {}: {e}", path.display()))
+		} else {
+			serde_json::from_str(&content).map_err(|e| format!("Invalid description {}: {e}", path.display()))
+		}
+	}
+
+	pub fn genesis(&self) -> Result<serde_json::Value, String> {
+		let collators = self
+			.collators
+			.members
+			.iter()
+			.map(|collator| Ok((parse_account(&collator.account)?, parse_aura(&collator.aura)?)))
+			.collect::<Result<Vec<_>, String>>()?;
+
+		let tech_committee = self
+			.tech_committee
+			.iter()
+			.map(|account| parse_account(account))
+			.collect::<Result<Vec<_>, String>>()?;
+
+		let registered_assets = self
+			.assets
+			.iter()
+			.map(|asset| {
+				(
+					asset.name.as_bytes().to_vec(),
+					asset.existential_deposit,
+					Some(asset.id),
+				)
+			})
+			.collect();
+
+		let accepted_assets = self
+			.assets
+			.iter()
+			.filter_map(|asset| asset.fee_price.map(|price| (asset.id, Price::from_float(price))))
+			.collect();
+
+		let mut endowed_accounts = Vec::new();
+		let mut token_balances = Vec::new();
+		for account in self.accounts.iter() {
+			let id = parse_account(&account.account)?;
+			if account.balance > 0 {
+				endowed_accounts.push((id.clone(), account.balance));
+			}
+			if !account.tokens.is_empty() {
+				let tokens = account
+					.tokens
+					.iter()
+					.map(|token| (token.asset, token.amount.saturating_mul(UNITS)))
+					.collect();
+				token_balances.push((id, tokens));
+			}
+		}
+
+		let mut whitelisted_oracles = self
+			.oracle_whitelist
+			.iter()
+			.map(|oracle| Ok((parse_source(&oracle.source)?, oracle.assets)))
+			.collect::<Result<Vec<_>, String>>()?;
+
+		let xyk_pools = self
+			.xyk_pools
+			.iter()
+			.map(|pool| {
+				Ok((
+					parse_account(&pool.owner)?,
+					pool.asset_a,
+					pool.amount_a.saturating_mul(UNITS),
+					pool.asset_b,
+					pool.amount_b.saturating_mul(UNITS),
+				))
+			})
+			.collect::<Result<Vec<_>, String>>()?;
+
+		for pool in self.xyk_pools.iter() {
+			let oracle = (XYKOracleSourceIdentifier::get(), (pool.asset_a, pool.asset_b));
+			if !whitelisted_oracles.contains(&oracle) {
+				whitelisted_oracles.push(oracle);
+			}
+		}
+
+		let lbp_pools = self
+			.lbp_pools
+			.iter()
+			.map(|pool| {
+				Ok((
+					parse_account(&pool.owner)?,
+					(pool.asset_a, pool.amount_a.saturating_mul(UNITS)),
+					(pool.asset_b, pool.amount_b.saturating_mul(UNITS)),
+					(pool.initial_weight, pool.final_weight),
+					(pool.start, pool.end),
+					pool.fee,
+					parse_account(&pool.fee_collector)?,
+					pool.repay_target.saturating_mul(UNITS),
+				))
+			})
+			.collect::<Result<Vec<_>, String>>()?;
+
+		let routes: Vec<_> = self
+			.routes
+			.iter()
+			.map(|route| (AssetPair::new(route.asset_in, route.asset_out), route.trades.clone()))
+			.collect();
+
+		let mut genesis = parachain_genesis(
+			(collators, self.collators.candidacy_bond.saturating_mul(UNITS)),
+			endowed_accounts,
+			tech_committee,
+			registered_assets,
+			accepted_assets,
+			token_balances,
+			self.para_id.into(),
+			self.is_testnet,
+		);
+		genesis["emaOracle"]["whitelistedAssets"] = serde_json::json!(whitelisted_oracles);
+		genesis["xyk"]["pools"] = serde_json::json!(xyk_pools);
+		genesis["lbp"]["pools"] = serde_json::json!(lbp_pools);
+		genesis["router"]["routes"] = serde_json::json!(routes);
+
+		Ok(genesis)
+	}
+}
+
+pub fn parachain_config(description: &TestnetDescription) -> Result<ChainSpec, String> {
+	let wasm_binary = WASM_BINARY.ok_or("Development wasm binary not available".to_string())?;
+
+	let mut properties = Map::new();
+	properties.insert("tokenDecimals".into(), TOKEN_DECIMALS.into());
+	properties.insert("tokenSymbol".into(), TOKEN_SYMBOL.into());
+
+	let boot_nodes = description
+		.boot_nodes
+		.iter()
+		.map(|node| node.parse().map_err(|e| format!("Invalid boot node {node}: {e:?}")))
+		.collect::<Result<Vec<_>, String>>()?;
+
+	let chain_spec = ChainSpec::builder(
+		wasm_binary,
+		Extensions {
+			relay_chain: description.relay_chain.clone(),
+			para_id: description.para_id,
+		},
+	)
+	.with_name(&description.name)
+	.with_id(&description.id)
+	.with_chain_type(description.chain_type.clone())
+	.with_boot_nodes(boot_nodes)
+	.with_properties(properties)
+	.with_protocol_id(PROTOCOL_ID)
+	.with_genesis_config_patch(description.genesis()?)
+	.build();
+
+	Ok(chain_spec)
+}
@@ -20,6 +20,7 @@
 #![allow(clippy::derive_partial_eq_without_eq)] //Needed due to bug 'https://github.com/rust-lang/rust-clippy/issues/8867'
 
 pub mod basilisk;
+pub mod generator;
 pub mod local;
 pub mod paseo;
 pub mod rococo;
@@ -164,5 +165,7 @@ pub fn parachain_genesis(
 		"vesting": {},
 		"xykWarehouseLm": {},
 		"xykLiquidityMining": {},
+		"stableswapWarehouseLm": {},
+		"stableswapLiquidityMining": {},
 	})
 }
@@ -47,11 +47,48 @@ pub struct Cli {
 	#[arg(long)]
 	pub no_hardware_benchmarks: bool,
 
+	/// Run the node standalone, without a relay chain, and seal blocks with manual seal.
+	///
+	/// `instant` seals a block as soon as a transaction enters the pool, `manual` seals blocks
+	/// on `engine_createBlock` RPC calls only. Parachain inherents are mocked. For development only.
+	#[arg(long, value_enum)]
+	pub dev_seal: Option<Sealing>,
+
+	/// Index trades of finalized blocks in the offchain database and serve them through
+	/// the `trades_byAccount` and `trades_byPair` RPC methods.
+	#[arg(long)]
+	pub enable_trade_indexer: bool,
+
 	/// Relaychain arguments
 	#[clap(raw = true)]
 	pub relaychain_args: Vec<String>,
 }
 
+/// Block sealing of the standalone development node.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, clap::ValueEnum)]
+pub enum Sealing {
+	/// Seal a block for every transaction.
+	Instant,
+	/// Seal blocks on `engine_createBlock` RPC calls.
+	Manual,
+}
+
+#[derive(Debug, Parser)]
+pub struct GenerateSpecCmd {
+	/// Path to the description of the testnet. Files with `.toml` extension are parsed as TOML,
+	/// any other as JSON.
+	#[arg(long)]
+	pub config: PathBuf,
+
+	/// Output the genesis storage instead of the genesis config patch.
+	#[arg(long)]
+	pub raw: bool,
+
+	/// Output file name, stdout if omitted.
+	#[arg(long)]
+	pub output: Option<PathBuf>,
+}
+
 #[derive(Debug)]
 pub struct RelayChainCli {
 	/// The actual relay chain cli object.
@@ -86,6 +123,9 @@ pub enum Subcommand {
 	/// Build a chain specification.
 	BuildSpec(sc_cli::BuildSpecCmd),
 
+	/// Generate a chain specification of a custom testnet from a TOML or JSON description.
+	GenerateSpec(GenerateSpecCmd),
+
 	/// Validate blocks.
 	CheckBlock(sc_cli::CheckBlockCmd),
 
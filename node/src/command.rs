@@ -136,6 +136,19 @@ pub fn run() -> sc_cli::Result<()> {
 			let runner = cli.create_runner(cmd)?;
 			runner.sync_run(|config| cmd.run(config.chain_spec, config.network))
 		}
+		Some(Subcommand::GenerateSpec(cmd)) => {
+			let description = chain_spec::generator::TestnetDescription::from_file(&cmd.config)?;
+			let spec = chain_spec::generator::parachain_config(&description)?;
+			let json = spec.as_json(cmd.raw)?;
+
+			if let Some(output) = &cmd.output {
+				std::fs::write(output, json)?;
+			} else {
+				std::io::stdout().write_all(json.as_bytes())?;
+			}
+
+			Ok(())
+		}
 		Some(Subcommand::CheckBlock(cmd)) => {
 			let runner = cli.create_runner(cmd)?;
 			runner.async_run(|config| {
@@ -290,6 +303,12 @@ pub fn run() -> sc_cli::Result<()> {
 					.map(|e| e.para_id)
 					.expect("Could not find parachain ID in chain-spec.");
 
+				if let Some(sealing) = cli.dev_seal {
+					info!("Starting standalone development node with {sealing:?} sealing");
+					return crate::service::start_dev_node(config, sealing, ParaId::from(para_id), cli.enable_trade_indexer)
+						.map_err(Into::into);
+				}
+
 				let polkadot_cli = RelayChainCli::new(
 					&config,
 					[RelayChainCli::executable_name()]
@@ -322,7 +341,7 @@ pub fn run() -> sc_cli::Result<()> {
 					if config.role.is_authority() { "yes" } else { "no" }
 				);
 
-				crate::service::start_node(config, polkadot_config, collator_options, id, hwbench)
+				crate::service::start_node(config, polkadot_config, collator_options, id, hwbench, cli.enable_trade_indexer)
 					.await
 					.map(|r| r.0)
 					.map_err(Into::into)
@@ -18,3 +18,4 @@
 pub mod chain_spec;
 pub mod rpc;
 pub mod service;
+pub mod trade_indexer;
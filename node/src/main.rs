@@ -24,6 +24,7 @@ mod service;
 mod cli;
 mod command;
 mod rpc;
+mod trade_indexer;
 
 #[allow(clippy::result_large_err)]
 fn main() -> sc_cli::Result<()> {
@@ -7,7 +7,7 @@
 
 use std::sync::Arc;
 
-use basilisk_runtime::{opaque::Block, AccountId, Balance, Index};
+use basilisk_runtime::{opaque::Block, AccountId, AssetId, AssetLocation, Balance, Index};
 use sc_transaction_pool_api::TransactionPool;
 use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
@@ -21,6 +21,8 @@ pub struct FullDeps<C, P, B> {
 	pub pool: Arc<P>,
 	/// Backend used by the node.
 	pub backend: Arc<B>,
+	/// Whether to serve trades indexed by the trade indexer.
+	pub trade_indexer: bool,
 }
 
 /// RPC Extension Builder
@@ -35,19 +37,34 @@ where
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: BlockBuilder<Block>,
+	C::Api: pallet_asset_registry_rpc::AssetRegistryRuntimeApi<Block, AssetId, Balance, AssetLocation>,
 	P: TransactionPool + Sync + Send + 'static,
 	B: sc_client_api::Backend<Block> + Send + Sync + 'static,
 	B::State: sc_client_api::StateBackend<sp_runtime::traits::HashingFor<Block>>,
 {
+	use crate::trade_indexer::{TradeStore, Trades, TradesApiServer};
+	use pallet_asset_registry_rpc::{AssetRegistry, AssetRegistryApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 	use substrate_state_trie_migration_rpc::{StateMigration, StateMigrationApiServer};
 
 	let mut module = RpcExtension::new(());
-	let FullDeps { client, pool, backend } = deps;
+	let FullDeps {
+		client,
+		pool,
+		backend,
+		trade_indexer,
+	} = deps;
 
 	module.merge(System::new(client.clone(), pool).into_rpc())?;
 	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	module.merge(AssetRegistry::new(client.clone()).into_rpc())?;
+	if trade_indexer {
+		let storage = backend
+			.offchain_storage()
+			.ok_or("Trade indexer requires the offchain storage.")?;
+		module.merge(Trades::new(TradeStore::new(storage)).into_rpc())?;
+	}
 	module.merge(StateMigration::new(client, backend).into_rpc())?;
 
 	// Extend this RPC with a custom API by using the following syntax.
@@ -22,7 +22,10 @@
 // std
 use std::{sync::Arc, time::Duration};
 
+use crate::cli::Sealing;
+use codec::Encode;
 use cumulus_client_cli::CollatorOptions;
+use cumulus_client_parachain_inherent::{MockValidationDataInherentDataProvider, MockXcmConfig};
 // Local Runtime Types
 use basilisk_runtime::{
 	apis::RuntimeApi,
@@ -39,7 +42,7 @@ use cumulus_client_service::{
 	CollatorSybilResistance, DARecoveryProfile, StartRelayChainTasksParams,
 };
 use cumulus_primitives_core::{
-	relay_chain::{CollatorPair, ValidationCode},
+	relay_chain::{CollatorPair, HeadData, ValidationCode},
 	ParaId,
 };
 use cumulus_relay_chain_interface::RelayChainInterface;
@@ -66,28 +69,22 @@ type ParachainClient = TFullClient<
 
 type ParachainBackend = TFullBackend<Block>;
 
+type ParachainTransactionPool = sc_transaction_pool::TransactionPoolHandle<Block, ParachainClient>;
+
 type ParachainBlockImport =
 	TParachainBlockImport<Block, SlotBasedBlockImport<Block, Arc<ParachainClient>, ParachainClient>, ParachainBackend>;
 
-/// Starts a `ServiceBuilder` for a full service.
-///
-/// Use this macro if you don't actually need the full service, but just the builder in order to
-/// be able to perform chain operations.
-pub fn new_partial(
+/// Build the client, the backend and the transaction pool shared by the parachain and the development node.
+fn new_client(
 	config: &Configuration,
 ) -> Result<
 	PartialComponents<
 		ParachainClient,
 		ParachainBackend,
 		(),
-		sc_consensus::DefaultImportQueue<Block>,
-		sc_transaction_pool::TransactionPoolHandle<Block, ParachainClient>,
-		(
-			ParachainBlockImport,
-			SlotBasedBlockImportHandle<Block>,
-			Option<Telemetry>,
-			Option<TelemetryWorkerHandle>,
-		),
+		(),
+		ParachainTransactionPool,
+		(Option<Telemetry>, Option<TelemetryWorkerHandle>),
 	>,
 	sc_service::Error,
 > {
@@ -145,6 +142,50 @@ pub fn new_partial(
 		.build(),
 	);
 
+	Ok(PartialComponents {
+		backend,
+		client,
+		import_queue: (),
+		keystore_container,
+		task_manager,
+		transaction_pool,
+		select_chain: (),
+		other: (telemetry, telemetry_worker_handle),
+	})
+}
+
+/// Starts a `ServiceBuilder` for a full service.
+///
+/// Use this macro if you don't actually need the full service, but just the builder in order to
+/// be able to perform chain operations.
+pub fn new_partial(
+	config: &Configuration,
+) -> Result<
+	PartialComponents<
+		ParachainClient,
+		ParachainBackend,
+		(),
+		sc_consensus::DefaultImportQueue<Block>,
+		ParachainTransactionPool,
+		(
+			ParachainBlockImport,
+			SlotBasedBlockImportHandle<Block>,
+			Option<Telemetry>,
+			Option<TelemetryWorkerHandle>,
+		),
+	>,
+	sc_service::Error,
+> {
+	let PartialComponents {
+		backend,
+		client,
+		keystore_container,
+		task_manager,
+		transaction_pool,
+		other: (telemetry, telemetry_worker_handle),
+		..
+	} = new_client(config)?;
+
 	let (slot_based_block_import, block_import_handle) = SlotBasedBlockImport::new(client.clone(), client.clone());
 	let block_import = ParachainBlockImport::new(slot_based_block_import, backend.clone());
 
@@ -178,6 +219,7 @@ async fn start_node_impl(
 	collator_options: CollatorOptions,
 	para_id: ParaId,
 	hwbench: Option<sc_sysinfo::HwBench>,
+	trade_indexer: bool,
 ) -> sc_service::error::Result<(TaskManager, Arc<ParachainClient>)> {
 	let parachain_config = prepare_node_config(parachain_config);
 
@@ -256,12 +298,21 @@ async fn start_node_impl(
 				client: client.clone(),
 				pool: transaction_pool.clone(),
 				backend: backend.clone(),
+				trade_indexer,
 			};
 
 			crate::rpc::create_full(deps).map_err(Into::into)
 		})
 	};
 
+	if trade_indexer {
+		task_manager.spawn_handle().spawn_blocking(
+			"trade-indexer",
+			None,
+			crate::trade_indexer::run(client.clone(), backend.clone()),
+		);
+	}
+
 	sc_service::spawn_tasks(sc_service::SpawnTasksParams {
 		rpc_builder,
 		client: client.clone(),
@@ -392,7 +443,7 @@ fn start_consensus(
 	telemetry: Option<TelemetryHandle>,
 	task_manager: &TaskManager,
 	relay_chain_interface: Arc<dyn RelayChainInterface>,
-	transaction_pool: Arc<sc_transaction_pool::TransactionPoolHandle<Block, ParachainClient>>,
+	transaction_pool: Arc<ParachainTransactionPool>,
 	keystore: KeystorePtr,
 	relay_chain_slot_duration: Duration,
 	para_id: ParaId,
@@ -451,6 +502,184 @@ fn start_consensus(
 	Ok(())
 }
 
+/// Start a standalone development node which seals blocks with manual seal.
+///
+/// The node does not connect to a relay chain. Parachain inherents are mocked, every block advances the
+/// relay chain by one block and the timestamp and the Aura and relay chain slots by one slot.
+pub fn start_dev_node(
+	config: Configuration,
+	sealing: Sealing,
+	para_id: ParaId,
+	trade_indexer: bool,
+) -> sc_service::error::Result<TaskManager> {
+	use futures::{channel::mpsc, Stream, StreamExt};
+	use sc_consensus_manual_seal::{
+		consensus::aura::AuraConsensusDataProvider,
+		rpc::{ManualSeal, ManualSealApiServer},
+		EngineCommand, ManualSealParams,
+	};
+	use sc_transaction_pool_api::TransactionPool;
+	use sp_blockchain::HeaderBackend;
+
+	let PartialComponents {
+		backend,
+		client,
+		keystore_container,
+		mut task_manager,
+		transaction_pool,
+		other: (mut telemetry, _),
+		..
+	} = new_client(&config)?;
+
+	let select_chain = sc_consensus::LongestChain::new(backend.clone());
+	let import_queue = sc_consensus_manual_seal::import_queue(
+		Box::new(client.clone()),
+		&task_manager.spawn_essential_handle(),
+		config.prometheus_registry(),
+	);
+
+	let prometheus_registry = config.prometheus_registry().cloned();
+	let net_config = sc_network::config::FullNetworkConfiguration::<_, _, sc_network::NetworkWorker<Block, Hash>>::new(
+		&config.network,
+		prometheus_registry.clone(),
+	);
+
+	let (network, system_rpc_tx, tx_handler_controller, sync_service) =
+		sc_service::build_network(sc_service::BuildNetworkParams {
+			config: &config,
+			net_config,
+			client: client.clone(),
+			transaction_pool: transaction_pool.clone(),
+			spawn_handle: task_manager.spawn_handle(),
+			import_queue,
+			block_announce_validator_builder: None,
+			warp_sync_config: None,
+			block_relay: None,
+			metrics: sc_network::service::NotificationMetrics::new(prometheus_registry.as_ref()),
+		})?;
+
+	let proposer = sc_basic_authorship::ProposerFactory::new(
+		task_manager.spawn_handle(),
+		client.clone(),
+		transaction_pool.clone(),
+		prometheus_registry.as_ref(),
+		telemetry.as_ref().map(|t| t.handle()),
+	);
+
+	let (manual_seal_sink, manual_seal_stream) = mpsc::channel(1024);
+	let commands_stream: Box<dyn Stream<Item = EngineCommand<Hash>> + Send + Sync + Unpin> = match sealing {
+		Sealing::Instant => {
+			Box::new(
+				transaction_pool
+					.import_notification_stream()
+					.map(|_| EngineCommand::SealNewBlock {
+						create_empty: false,
+						finalize: true,
+						parent_hash: None,
+						sender: None,
+					}),
+			)
+		}
+		Sealing::Manual => Box::new(manual_seal_stream),
+	};
+
+	let client_for_cidp = client.clone();
+	let create_inherent_data_providers = move |parent: Hash, ()| {
+		let client = client_for_cidp.clone();
+		async move {
+			let parent_header = client
+				.header(parent)?
+				.ok_or("Parent header of the sealed block should be present in the backend.")?;
+			let current_para_block = parent_header.number + 1;
+			// the relay chain and the parachain have the same slot duration
+			let slot = sp_consensus_aura::Slot::from(u64::from(current_para_block));
+
+			let timestamp = sp_timestamp::InherentDataProvider::new(sp_timestamp::Timestamp::new(
+				basilisk_runtime::SLOT_DURATION * u64::from(current_para_block),
+			));
+
+			let mocked_parachain = MockValidationDataInherentDataProvider {
+				current_para_block,
+				para_id,
+				current_para_block_head: Some(HeadData(parent_header.encode())),
+				relay_offset: 0,
+				relay_blocks_per_para_block: 1,
+				para_blocks_per_relay_epoch: 10,
+				relay_randomness_config: (),
+				xcm_config: MockXcmConfig::new(&*client, parent, Default::default()),
+				raw_downward_messages: vec![],
+				raw_horizontal_messages: vec![],
+				additional_key_values: Some(vec![(
+					cumulus_primitives_core::relay_chain::well_known_keys::CURRENT_SLOT.to_vec(),
+					slot.encode(),
+				)]),
+				upgrade_go_ahead: None,
+			};
+
+			Ok::<_, Box<dyn std::error::Error + Send + Sync>>((timestamp, mocked_parachain))
+		}
+	};
+
+	task_manager.spawn_essential_handle().spawn_blocking(
+		"manual-seal",
+		None,
+		sc_consensus_manual_seal::run_manual_seal(ManualSealParams {
+			block_import: client.clone(),
+			env: proposer,
+			client: client.clone(),
+			pool: transaction_pool.clone(),
+			select_chain,
+			commands_stream,
+			consensus_data_provider: Some(Box::new(AuraConsensusDataProvider::new(client.clone()))),
+			create_inherent_data_providers,
+		}),
+	);
+
+	let rpc_builder = {
+		let client = client.clone();
+		let transaction_pool = transaction_pool.clone();
+		let backend = backend.clone();
+
+		Box::new(move |_| {
+			let deps = crate::rpc::FullDeps {
+				client: client.clone(),
+				pool: transaction_pool.clone(),
+				backend: backend.clone(),
+				trade_indexer,
+			};
+
+			let mut module = crate::rpc::create_full(deps)?;
+			module.merge(ManualSeal::new(manual_seal_sink.clone()).into_rpc())?;
+			Ok(module)
+		})
+	};
+
+	if trade_indexer {
+		task_manager.spawn_handle().spawn_blocking(
+			"trade-indexer",
+			None,
+			crate::trade_indexer::run(client.clone(), backend.clone()),
+		);
+	}
+
+	sc_service::spawn_tasks(sc_service::SpawnTasksParams {
+		rpc_builder,
+		client,
+		transaction_pool,
+		task_manager: &mut task_manager,
+		config,
+		keystore: keystore_container.keystore(),
+		backend,
+		network,
+		sync_service,
+		system_rpc_tx,
+		tx_handler_controller,
+		telemetry: telemetry.as_mut(),
+	})?;
+
+	Ok(task_manager)
+}
+
 /// Start a parachain node.
 pub async fn start_node(
 	parachain_config: Configuration,
@@ -458,6 +687,15 @@ pub async fn start_node(
 	collator_options: CollatorOptions,
 	para_id: ParaId,
 	hwbench: Option<sc_sysinfo::HwBench>,
+	trade_indexer: bool,
 ) -> sc_service::error::Result<(TaskManager, Arc<ParachainClient>)> {
-	start_node_impl(parachain_config, polkadot_config, collator_options, para_id, hwbench).await
+	start_node_impl(
+		parachain_config,
+		polkadot_config,
+		collator_options,
+		para_id,
+		hwbench,
+		trade_indexer,
+	)
+	.await
 }
@@ -0,0 +1,529 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2026  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Node-side index of trades.
+//!
+//! The indexer decodes `pallet_broadcast::Swapped3` events of finalized blocks and stores them in the
+//! persistent offchain database, together with per-account and per-asset-pair lists of trades. The trades
+//! are served by the `trades_byAccount` and `trades_byPair` RPC methods.
+//!
+//! Indexing starts at the finalized block when the indexer is enabled for the first time, earlier trades are
+//! not indexed. Trades of each block are committed together with the indexed block number, so an interrupted
+//! node neither skips nor duplicates trades. Events of blocks whose state is pruned can't be read, so nodes
+//! which are stopped for a long time should run with `--state-pruning archive`.
+
+use std::{collections::BTreeMap, sync::Arc};
+
+use basilisk_runtime::{opaque::Block, AccountId, AssetId, Balance, BlockNumber, Hash, RuntimeEvent};
+use codec::{Decode, Encode};
+use futures::StreamExt;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::{error::ErrorObject, ErrorObjectOwned},
+};
+use sc_client_api::{BlockchainEvents, StorageProvider};
+use serde::{Deserialize, Serialize};
+use sp_blockchain::HeaderBackend;
+use sp_core::offchain::{OffchainStorage, STORAGE_PREFIX};
+use sp_runtime::traits::Header as HeaderT;
+use sp_storage::StorageKey;
+
+const LOG_TARGET: &str = "trade-indexer";
+
+/// Max number of trades returned by one RPC call.
+const MAX_PAGE_SIZE: u32 = 1_000;
+
+/// Error code returned when the limit of a page is invalid.
+const INVALID_LIMIT_ERROR: i32 = 1;
+
+/// Position of a trade in the chain: block number and index of the event in the block.
+type TradeId = (BlockNumber, u32);
+
+#[derive(Clone, Debug, Encode, Decode, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeAsset {
+	pub asset_id: AssetId,
+	pub amount: Balance,
+}
+
+#[derive(Clone, Debug, Encode, Decode, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeFee {
+	pub asset_id: AssetId,
+	pub amount: Balance,
+	pub destination: String,
+}
+
+/// Decoded `Swapped3` event.
+#[derive(Clone, Debug, Encode, Decode, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Trade {
+	pub block_number: BlockNumber,
+	pub block_hash: Hash,
+	pub event_index: u32,
+	pub swapper: AccountId,
+	pub filler: AccountId,
+	pub filler_type: String,
+	pub operation: String,
+	pub inputs: Vec<TradeAsset>,
+	pub outputs: Vec<TradeAsset>,
+	pub fees: Vec<TradeFee>,
+	pub operation_stack: Vec<String>,
+}
+
+/// Page of trades ordered from the oldest to the newest.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TradesPage {
+	pub trades: Vec<Trade>,
+	/// Position of the next page, `None` if there are no more trades.
+	pub next: Option<u64>,
+}
+
+/// List of trades maintained by the indexer.
+#[derive(Clone, Debug, Encode, PartialEq, Eq)]
+enum TradeList {
+	Account(AccountId),
+	/// Pair of assets ordered by asset id, so both trade directions share one list.
+	Pair(AssetId, AssetId),
+}
+
+impl TradeList {
+	fn pair(asset_a: AssetId, asset_b: AssetId) -> Self {
+		TradeList::Pair(asset_a.min(asset_b), asset_a.max(asset_b))
+	}
+}
+
+#[derive(Encode)]
+enum Key<'a> {
+	LastIndexed,
+	Trade(TradeId),
+	Len(&'a TradeList),
+	Entry(&'a TradeList, u64),
+}
+
+impl Key<'_> {
+	fn to_bytes(&self) -> Vec<u8> {
+		(b"basilisk::trades", self).encode()
+	}
+}
+
+/// Last indexed block together with its writes, committed with a single write of the cursor.
+///
+/// The writes set absolute values, so they are applied again on start in case the node was stopped
+/// before all of them were applied.
+#[derive(Encode, Decode)]
+struct IndexedBlock {
+	number: BlockNumber,
+	writes: Vec<(Vec<u8>, Vec<u8>)>,
+}
+
+/// Trades stored in the persistent offchain database.
+#[derive(Clone)]
+pub struct TradeStore<S> {
+	storage: S,
+}
+
+impl<S: OffchainStorage> TradeStore<S> {
+	pub fn new(storage: S) -> Self {
+		Self { storage }
+	}
+
+	fn get<T: Decode>(&self, key: Key) -> Option<T> {
+		self.storage
+			.get(STORAGE_PREFIX, &key.to_bytes())
+			.and_then(|value| T::decode(&mut &value[..]).ok())
+	}
+
+	fn set(&mut self, key: Key, value: impl Encode) {
+		self.storage.set(STORAGE_PREFIX, &key.to_bytes(), &value.encode());
+	}
+
+	fn last_indexed(&self) -> Option<BlockNumber> {
+		self.get::<IndexedBlock>(Key::LastIndexed).map(|block| block.number)
+	}
+
+	/// Apply the writes of the last indexed block again.
+	fn recover(&mut self) {
+		if let Some(block) = self.get::<IndexedBlock>(Key::LastIndexed) {
+			self.apply(&block.writes);
+		}
+	}
+
+	fn apply(&mut self, writes: &[(Vec<u8>, Vec<u8>)]) {
+		for (key, value) in writes.iter() {
+			self.storage.set(STORAGE_PREFIX, key, value);
+		}
+	}
+
+	/// Store `trades` of the block `number` and mark the block as indexed.
+	fn insert_block(&mut self, number: BlockNumber, trades: &[Trade]) {
+		let mut writes = Vec::new();
+		let mut lens = BTreeMap::new();
+
+		for trade in trades.iter() {
+			let id = (trade.block_number, trade.event_index);
+			writes.push((Key::Trade(id).to_bytes(), trade.encode()));
+
+			let mut lists = vec![TradeList::Account(trade.swapper.clone())];
+			for input in trade.inputs.iter() {
+				for output in trade.outputs.iter().filter(|output| output.asset_id != input.asset_id) {
+					let pair = TradeList::pair(input.asset_id, output.asset_id);
+					if !lists.contains(&pair) {
+						lists.push(pair);
+					}
+				}
+			}
+
+			for list in lists.iter() {
+				let len_key = Key::Len(list).to_bytes();
+				let len: u64 = match lens.get(&len_key) {
+					Some(len) => *len,
+					None => self.get(Key::Len(list)).unwrap_or_default(),
+				};
+				writes.push((Key::Entry(list, len).to_bytes(), id.encode()));
+				lens.insert(len_key, len.saturating_add(1));
+			}
+		}
+
+		writes.extend(lens.into_iter().map(|(key, len)| (key, len.encode())));
+
+		let block = IndexedBlock { number, writes };
+		self.set(Key::LastIndexed, &block);
+		self.apply(&block.writes);
+	}
+
+	fn page(&self, list: &TradeList, from: u64, limit: u32) -> TradesPage {
+		let len: u64 = self.get(Key::Len(list)).unwrap_or_default();
+		let to = from.saturating_add(limit.into()).min(len);
+
+		let trades = (from..to)
+			.filter_map(|position| self.get::<TradeId>(Key::Entry(list, position)))
+			.filter_map(|id| self.get(Key::Trade(id)))
+			.collect();
+
+		TradesPage {
+			trades,
+			next: (to < len).then_some(to),
+		}
+	}
+}
+
+fn events_key() -> StorageKey {
+	StorageKey([sp_core::twox_128(b"System"), sp_core::twox_128(b"Events")].concat())
+}
+
+/// Decode trades of the block `hash`. Returns `None` if the events of the block are not available.
+fn block_trades<B, C>(client: &C, number: BlockNumber, hash: Hash) -> Option<Vec<Trade>>
+where
+	B: sc_client_api::Backend<Block>,
+	C: StorageProvider<Block, B>,
+{
+	let events = client.storage(hash, &events_key()).ok()??;
+	let records = Vec::<frame_system::EventRecord<RuntimeEvent, Hash>>::decode(&mut &events.0[..])
+		.map_err(|e| log::warn!(target: LOG_TARGET, "Unable to decode events of block {number}: {e:?}"))
+		.ok()?;
+
+	let trades = records
+		.into_iter()
+		.enumerate()
+		.filter_map(|(index, record)| match record.event {
+			RuntimeEvent::Broadcast(pallet_broadcast::Event::Swapped3 {
+				swapper,
+				filler,
+				filler_type,
+				operation,
+				inputs,
+				outputs,
+				fees,
+				operation_stack,
+			}) => Some(Trade {
+				block_number: number,
+				block_hash: hash,
+				event_index: index as u32,
+				swapper,
+				filler,
+				filler_type: format!("{filler_type:?}"),
+				operation: format!("{operation:?}"),
+				inputs: inputs
+					.into_iter()
+					.map(|asset| TradeAsset {
+						asset_id: asset.asset,
+						amount: asset.amount,
+					})
+					.collect(),
+				outputs: outputs
+					.into_iter()
+					.map(|asset| TradeAsset {
+						asset_id: asset.asset,
+						amount: asset.amount,
+					})
+					.collect(),
+				fees: fees
+					.into_iter()
+					.map(|fee| TradeFee {
+						asset_id: fee.asset,
+						amount: fee.amount,
+						destination: format!("{:?}", fee.destination),
+					})
+					.collect(),
+				operation_stack: operation_stack.iter().map(|ctx| format!("{ctx:?}")).collect(),
+			}),
+			_ => None,
+		})
+		.collect();
+
+	Some(trades)
+}
+
+/// Index the trades of all finalized blocks which are not indexed yet, starting at `finalized` if no block
+/// was indexed before.
+fn index_finalized<B, C, S>(client: &C, store: &mut TradeStore<S>, finalized: BlockNumber)
+where
+	B: sc_client_api::Backend<Block>,
+	C: HeaderBackend<Block> + StorageProvider<Block, B>,
+	S: OffchainStorage,
+{
+	let from = store.last_indexed().map_or(finalized, |n| n.saturating_add(1));
+	for number in from..=finalized {
+		let Ok(Some(hash)) = client.hash(number) else {
+			log::warn!(target: LOG_TARGET, "Unable to find hash of finalized block {number}");
+			return;
+		};
+
+		let trades = block_trades::<B, C>(client, number, hash).unwrap_or_else(|| {
+			log::debug!(target: LOG_TARGET, "Events of block {number} are not available, skipping");
+			Vec::new()
+		});
+		store.insert_block(number, &trades);
+	}
+}
+
+/// Keep indexing trades of newly finalized blocks.
+///
+/// Indexing reads the storage of the client synchronously, so the task has to be spawned as blocking.
+pub async fn run<B, C>(client: Arc<C>, backend: Arc<B>)
+where
+	B: sc_client_api::Backend<Block>,
+	C: BlockchainEvents<Block> + HeaderBackend<Block> + StorageProvider<Block, B>,
+{
+	let Some(storage) = backend.offchain_storage() else {
+		log::error!(target: LOG_TARGET, "Offchain storage is not available, trades are not indexed");
+		return;
+	};
+	let mut store = TradeStore::new(storage);
+	store.recover();
+
+	let mut finality_notifications = client.finality_notification_stream();
+	index_finalized::<B, C, _>(&*client, &mut store, client.info().finalized_number);
+
+	while let Some(notification) = finality_notifications.next().await {
+		index_finalized::<B, C, _>(&*client, &mut store, *notification.header.number());
+	}
+}
+
+#[rpc(server)]
+pub trait TradesApi {
+	/// Returns up to `limit` trades of `account` starting at position `from`, ordered from the oldest.
+	#[method(name = "trades_byAccount")]
+	fn by_account(&self, account: AccountId, from: Option<u64>, limit: u32) -> RpcResult<TradesPage>;
+
+	/// Returns up to `limit` trades between `asset_a` and `asset_b` in both directions starting at position
+	/// `from`, ordered from the oldest.
+	#[method(name = "trades_byPair")]
+	fn by_pair(&self, asset_a: AssetId, asset_b: AssetId, from: Option<u64>, limit: u32) -> RpcResult<TradesPage>;
+}
+
+/// Provides RPC methods to query indexed trades.
+pub struct Trades<S> {
+	store: TradeStore<S>,
+}
+
+impl<S> Trades<S> {
+	pub fn new(store: TradeStore<S>) -> Self {
+		Self { store }
+	}
+}
+
+fn ensure_limit(limit: u32) -> Result<(), ErrorObjectOwned> {
+	if limit == 0 || limit > MAX_PAGE_SIZE {
+		return Err(ErrorObject::owned(
+			INVALID_LIMIT_ERROR,
+			format!("Limit has to be between 1 and {MAX_PAGE_SIZE}."),
+			None::<()>,
+		));
+	}
+	Ok(())
+}
+
+impl<S: OffchainStorage + 'static> TradesApiServer for Trades<S> {
+	fn by_account(&self, account: AccountId, from: Option<u64>, limit: u32) -> RpcResult<TradesPage> {
+		ensure_limit(limit)?;
+		Ok(self
+			.store
+			.page(&TradeList::Account(account), from.unwrap_or_default(), limit))
+	}
+
+	fn by_pair(&self, asset_a: AssetId, asset_b: AssetId, from: Option<u64>, limit: u32) -> RpcResult<TradesPage> {
+		ensure_limit(limit)?;
+		Ok(self
+			.store
+			.page(&TradeList::pair(asset_a, asset_b), from.unwrap_or_default(), limit))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_core::offchain::storage::InMemOffchainStorage;
+
+	const ALICE: [u8; 32] = [1; 32];
+	const BOB: [u8; 32] = [2; 32];
+
+	fn trade(
+		block_number: BlockNumber,
+		event_index: u32,
+		swapper: [u8; 32],
+		asset_in: AssetId,
+		asset_out: AssetId,
+	) -> Trade {
+		Trade {
+			block_number,
+			block_hash: Hash::zero(),
+			event_index,
+			swapper: swapper.into(),
+			filler: BOB.into(),
+			filler_type: "XYK".into(),
+			operation: "ExactIn".into(),
+			inputs: vec![TradeAsset {
+				asset_id: asset_in,
+				amount: 1_000,
+			}],
+			outputs: vec![TradeAsset {
+				asset_id: asset_out,
+				amount: 500,
+			}],
+			fees: vec![],
+			operation_stack: vec![],
+		}
+	}
+
+	fn store() -> TradeStore<InMemOffchainStorage> {
+		TradeStore::new(InMemOffchainStorage::default())
+	}
+
+	#[test]
+	fn insert_block_should_add_trades_to_account_and_pair_lists() {
+		let mut store = store();
+		let trades = vec![trade(1, 0, ALICE, 0, 1), trade(1, 3, BOB, 1, 0)];
+
+		store.insert_block(1, &trades);
+
+		assert_eq!(store.last_indexed(), Some(1));
+		assert_eq!(
+			store.page(&TradeList::Account(ALICE.into()), 0, 10),
+			TradesPage {
+				trades: vec![trades[0].clone()],
+				next: None,
+			}
+		);
+		assert_eq!(
+			store.page(&TradeList::Account(BOB.into()), 0, 10),
+			TradesPage {
+				trades: vec![trades[1].clone()],
+				next: None,
+			}
+		);
+		// both directions share one list
+		assert_eq!(
+			store.page(&TradeList::pair(1, 0), 0, 10),
+			TradesPage {
+				trades: trades.clone(),
+				next: None,
+			}
+		);
+		assert_eq!(store.page(&TradeList::pair(0, 2), 0, 10).trades, vec![]);
+	}
+
+	#[test]
+	fn page_should_return_trades_from_position_up_to_limit() {
+		let mut store = store();
+		let trades: Vec<Trade> = (1..=5).map(|number| trade(number, 0, ALICE, 0, 1)).collect();
+		for trade in trades.iter() {
+			store.insert_block(trade.block_number, &[trade.clone()]);
+		}
+
+		let list = TradeList::Account(ALICE.into());
+		assert_eq!(
+			store.page(&list, 0, 2),
+			TradesPage {
+				trades: trades[0..2].to_vec(),
+				next: Some(2),
+			}
+		);
+		assert_eq!(
+			store.page(&list, 2, 2),
+			TradesPage {
+				trades: trades[2..4].to_vec(),
+				next: Some(4),
+			}
+		);
+		assert_eq!(
+			store.page(&list, 4, 2),
+			TradesPage {
+				trades: trades[4..].to_vec(),
+				next: None,
+			}
+		);
+		assert_eq!(
+			store.page(&list, 10, 2),
+			TradesPage {
+				trades: vec![],
+				next: None,
+			}
+		);
+	}
+
+	#[test]
+	fn recover_should_complete_writes_of_interrupted_block() {
+		let mut store = store();
+		store.insert_block(1, &[trade(1, 0, ALICE, 0, 1)]);
+		let trade = trade(2, 0, ALICE, 0, 1);
+		store.insert_block(2, &[trade.clone()]);
+
+		// node stopped after the cursor was written but before the length of the list was updated
+		let list = TradeList::Account(ALICE.into());
+		store.set(Key::Len(&list), 1u64);
+		assert_eq!(store.page(&list, 1, 10).trades, vec![]);
+
+		store.recover();
+
+		assert_eq!(store.last_indexed(), Some(2));
+		assert_eq!(
+			store.page(&list, 1, 10),
+			TradesPage {
+				trades: vec![trade],
+				next: None,
+			}
+		);
+
+		// recovery is idempotent
+		store.recover();
+		assert_eq!(store.page(&list, 0, 10).trades.len(), 2);
+	}
+}
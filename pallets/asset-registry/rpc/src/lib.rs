@@ -0,0 +1,166 @@
+// This file is part of pallet-asset-registry.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RPC interface for the asset registry.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::{error::ErrorObject, ErrorObjectOwned},
+};
+use pallet_asset_registry_runtime_api::{AssetInfo, AssetMetadataInfo};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::{ApiError, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_asset_registry_runtime_api::AssetRegistryApi as AssetRegistryRuntimeApi;
+
+/// Error code returned when a runtime API call fails.
+const RUNTIME_ERROR: i32 = 1;
+
+#[rpc(server)]
+pub trait AssetRegistryApi<BlockHash, AssetId, Balance, Location> {
+	/// Returns symbol, decimals and extended metadata of an asset.
+	#[method(name = "assetRegistry_assetMetadata")]
+	fn asset_metadata(&self, asset_id: AssetId, at: Option<BlockHash>) -> RpcResult<Option<AssetMetadataInfo>>;
+
+	/// Returns details, metadata and location of an asset.
+	#[method(name = "assetRegistry_asset")]
+	fn asset(
+		&self,
+		asset_id: AssetId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<AssetInfo<AssetId, Balance, Location>>>;
+
+	/// Returns up to `limit` assets registered after `start_after`.
+	#[method(name = "assetRegistry_assets")]
+	fn assets(
+		&self,
+		start_after: Option<AssetId>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<AssetInfo<AssetId, Balance, Location>>>;
+
+	/// Returns all assets with given symbol.
+	#[method(name = "assetRegistry_assetsBySymbol")]
+	fn assets_by_symbol(
+		&self,
+		symbol: String,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<AssetInfo<AssetId, Balance, Location>>>;
+
+	/// Returns asset registered for given location.
+	#[method(name = "assetRegistry_assetByLocation")]
+	fn asset_by_location(
+		&self,
+		location: Location,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<AssetInfo<AssetId, Balance, Location>>>;
+}
+
+/// Provides RPC methods to query registered assets.
+pub struct AssetRegistry<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> AssetRegistry<C, Block> {
+	/// Create new `AssetRegistry` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+fn runtime_error(e: ApiError) -> ErrorObjectOwned {
+	ErrorObject::owned(RUNTIME_ERROR, "Unable to query asset registry.", Some(e.to_string()))
+}
+
+impl<C, Block, AssetId, Balance, Location> AssetRegistryApiServer<<Block as BlockT>::Hash, AssetId, Balance, Location>
+	for AssetRegistry<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: AssetRegistryRuntimeApi<Block, AssetId, Balance, Location>,
+	AssetId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	Balance: Codec + Serialize + Send + Sync + 'static,
+	Location: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+	fn asset_metadata(
+		&self,
+		asset_id: AssetId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<AssetMetadataInfo>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.asset_metadata(at, asset_id)
+			.map_err(runtime_error)
+	}
+
+	fn asset(
+		&self,
+		asset_id: AssetId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<AssetInfo<AssetId, Balance, Location>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().asset(at, asset_id).map_err(runtime_error)
+	}
+
+	fn assets(
+		&self,
+		start_after: Option<AssetId>,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<AssetInfo<AssetId, Balance, Location>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.assets(at, start_after, limit)
+			.map_err(runtime_error)
+	}
+
+	fn assets_by_symbol(
+		&self,
+		symbol: String,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<AssetInfo<AssetId, Balance, Location>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.assets_by_symbol(at, symbol.into_bytes())
+			.map_err(runtime_error)
+	}
+
+	fn asset_by_location(
+		&self,
+		location: Location,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<AssetInfo<AssetId, Balance, Location>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.asset_by_location(at, location)
+			.map_err(runtime_error)
+	}
+}
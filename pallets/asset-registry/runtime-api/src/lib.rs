@@ -0,0 +1,51 @@
+// This file is part of pallet-asset-registry.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the asset registry.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+pub use pallet_asset_registry::{AssetInfo, AssetMetadataInfo};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// Read access to registered assets.
+	pub trait AssetRegistryApi<AssetId, Balance, Location>
+	where
+		AssetId: Codec,
+		Balance: Codec,
+		Location: Codec,
+	{
+		/// Returns symbol, decimals and extended metadata of an asset.
+		fn asset_metadata(asset_id: AssetId) -> Option<AssetMetadataInfo>;
+
+		/// Returns details, metadata and location of an asset.
+		fn asset(asset_id: AssetId) -> Option<AssetInfo<AssetId, Balance, Location>>;
+
+		/// Returns up to `limit` assets registered after `start_after`.
+		///
+		/// Pass the id of the last returned asset as `start_after` to get the next page.
+		fn assets(start_after: Option<AssetId>, limit: u32) -> Vec<AssetInfo<AssetId, Balance, Location>>;
+
+		/// Returns all assets with given symbol.
+		fn assets_by_symbol(symbol: Vec<u8>) -> Vec<AssetInfo<AssetId, Balance, Location>>;
+
+		/// Returns asset registered for given location.
+		fn asset_by_location(location: Location) -> Option<AssetInfo<AssetId, Balance, Location>>;
+	}
+}
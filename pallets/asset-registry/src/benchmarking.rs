@@ -19,8 +19,10 @@
 
 use super::*;
 
-use frame_benchmarking::benchmarks;
+use frame_benchmarking::{account, benchmarks};
+use frame_support::traits::Currency;
 use frame_system::RawOrigin;
+use sp_runtime::traits::Saturating;
 
 use crate::types::Metadata;
 
@@ -98,9 +100,10 @@ benchmarks! {
 
 		let stored = stored.unwrap();
 
-		let expected =AssetMetadata{
+		let expected = AssetMetadataT::<T> {
 			symbol: bsymbol,
-			decimals: 10u8
+			decimals: 10u8,
+			..Default::default()
 		};
 
 		assert_eq!(stored.symbol.to_vec(), expected.symbol.to_vec());
@@ -121,6 +124,92 @@ benchmarks! {
 		assert_eq!(crate::Pallet::<T>::locations(asset_id), Some(Default::default()));
 		assert_eq!(crate::Pallet::<T>::location_assets(T::AssetNativeLocation::default()), Some(asset_id));
 	}
+
+	register_external{
+		let caller: T::AccountId = account("caller", 0, 1);
+		T::Currency::make_free_balance_be(&caller, T::ExternalAssetDeposit::get().saturating_mul(10u32.into()));
+
+		let name = vec![1; T::StringLimit::get() as usize];
+		let ed = T::Balance::from(1_000_000u32);
+
+		let metadata = Metadata {
+			symbol: vec![1; T::StringLimit::get() as usize],
+			decimals: 12,
+		};
+
+	}: _(RawOrigin::Signed(caller.clone()), name.clone(), Default::default(), ed, Some(metadata))
+	verify {
+		let bname = crate::Pallet::<T>::to_bounded_name(name).unwrap();
+		let asset_id = crate::Pallet::<T>::asset_ids(bname).unwrap();
+		assert_eq!(crate::Pallet::<T>::external_asset_deposit(asset_id).map(|d| d.owner), Some(caller));
+	}
+
+	approve_external{
+		let caller: T::AccountId = account("caller", 0, 1);
+		T::Currency::make_free_balance_be(&caller, T::ExternalAssetDeposit::get().saturating_mul(10u32.into()));
+
+		let name = b"NAME".to_vec();
+		let ed = T::Balance::from(1_000_000u32);
+		crate::Pallet::<T>::register_external(RawOrigin::Signed(caller).into(), name.clone(), Default::default(), ed, None)?;
+
+		let bname = crate::Pallet::<T>::to_bounded_name(name).unwrap();
+		let asset_id = crate::Pallet::<T>::asset_ids(bname).unwrap();
+
+	}: _(RawOrigin::Root, asset_id)
+	verify {
+		assert!(crate::Pallet::<T>::is_sufficient(asset_id));
+	}
+
+	reject_external{
+		let caller: T::AccountId = account("caller", 0, 1);
+		T::Currency::make_free_balance_be(&caller, T::ExternalAssetDeposit::get().saturating_mul(10u32.into()));
+
+		let name = b"NAME".to_vec();
+		let ed = T::Balance::from(1_000_000u32);
+		crate::Pallet::<T>::register_external(RawOrigin::Signed(caller).into(), name.clone(), Default::default(), ed, None)?;
+
+		let bname = crate::Pallet::<T>::to_bounded_name(name).unwrap();
+		let asset_id = crate::Pallet::<T>::asset_ids(bname).unwrap();
+
+	}: _(RawOrigin::Root, asset_id, true)
+	verify {
+		assert!(crate::Pallet::<T>::external_asset_deposit(asset_id).is_none());
+		assert!(!crate::Pallet::<T>::is_sufficient(asset_id));
+	}
+
+	set_extended_metadata{
+		let name = b"NAME".to_vec();
+		let ed = T::Balance::from(1_000_000u32);
+		let asset_id = T::AssetId::from(10u8);
+		let metadata = Metadata {
+			symbol: b"SYMBOL".to_vec(),
+			decimals: 12,
+		};
+		crate::Pallet::<T>::register(RawOrigin::Root.into(), name, AssetType::Token, ed, Some(asset_id), Some(metadata), None, None)?;
+
+		let max_url = vec![1; T::MetadataUrlLimit::get() as usize];
+		let max_id = vec![1; T::StringLimit::get() as usize];
+
+	}: _(RawOrigin::Root, asset_id, Some(max_url.clone()), Some(max_url.clone()), Some(max_id))
+	verify {
+		let stored = crate::Pallet::<T>::asset_metadata(asset_id).unwrap();
+		assert_eq!(stored.icon_uri.map(|v| v.to_vec()), Some(max_url));
+	}
+
+	set_verified{
+		let name = b"NAME".to_vec();
+		let ed = T::Balance::from(1_000_000u32);
+		let asset_id = T::AssetId::from(10u8);
+		let metadata = Metadata {
+			symbol: b"SYMBOL".to_vec(),
+			decimals: 12,
+		};
+		crate::Pallet::<T>::register(RawOrigin::Root.into(), name, AssetType::Token, ed, Some(asset_id), Some(metadata), None, None)?;
+
+	}: _(RawOrigin::Root, asset_id, true)
+	verify {
+		assert!(crate::Pallet::<T>::asset_metadata(asset_id).unwrap().verified);
+	}
 }
 
 #[cfg(test)]
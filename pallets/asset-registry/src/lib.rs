@@ -20,6 +20,7 @@
 
 use frame_support::pallet_prelude::*;
 use frame_support::sp_runtime::traits::CheckedAdd;
+use frame_support::traits::{Currency, ReservableCurrency};
 use frame_system::pallet_prelude::*;
 use scale_info::TypeInfo;
 use sp_arithmetic::traits::BaseArithmetic;
@@ -46,22 +47,30 @@ pub use types::AssetType;
 // Re-export pallet items so that they can be accessed from the crate namespace.
 pub use pallet::*;
 
-pub use crate::types::{AssetDetails, AssetMetadata};
+pub use crate::types::{AssetDetails, AssetInfo, AssetMetadata, AssetMetadataInfo, ExternalAssetDeposit};
 use frame_support::BoundedVec;
 use hydradx_traits::{AssetKind, Create, Inspect};
 use traits::{CreateRegistry, InspectRegistry, Registry, ShareTokenRegistry};
 
+pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use crate::types::Metadata;
-	use frame_support::sp_runtime::traits::AtLeast32BitUnsigned;
+	use frame_support::sp_runtime::traits::{AtLeast32BitUnsigned, Zero};
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	pub type AssetDetailsT<T> =
 		AssetDetails<<T as Config>::AssetId, <T as Config>::Balance, BoundedVec<u8, <T as Config>::StringLimit>>;
 
+	pub type AssetMetadataT<T> =
+		AssetMetadata<BoundedVec<u8, <T as Config>::StringLimit>, BoundedVec<u8, <T as Config>::MetadataUrlLimit>>;
+
+	pub type AssetInfoT<T> =
+		AssetInfo<<T as Config>::AssetId, <T as Config>::Balance, <T as Config>::AssetNativeLocation>;
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		/// The origin which can work with asset-registry.
@@ -92,6 +101,10 @@ pub mod pallet {
 		/// The maximum length of a name or symbol stored on-chain.
 		type StringLimit: Get<u32>;
 
+		/// The maximum length of an icon URI or website stored on-chain.
+		#[pallet::constant]
+		type MetadataUrlLimit: Get<u32>;
+
 		#[pallet::constant]
 		type SequentialIdStartAt: Get<Self::AssetId>;
 
@@ -99,6 +112,17 @@ pub mod pallet {
 		#[pallet::constant]
 		type NativeAssetId: Get<Self::AssetId>;
 
+		/// Currency used to reserve the deposit of permissionlessly registered external assets.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// Amount reserved from the account registering an external asset.
+		#[pallet::constant]
+		type ExternalAssetDeposit: Get<BalanceOf<Self>>;
+
+		/// Maximum number of external assets which can be registered in a single block.
+		#[pallet::constant]
+		type MaxExternalRegistrationsPerBlock: Get<u32>;
+
 		/// Weight information for the extrinsics
 		type WeightInfo: WeightInfo;
 	}
@@ -138,6 +162,18 @@ pub mod pallet {
 
 		/// Location already registered with different asset
 		LocationAlreadyRegistered,
+
+		/// Maximum number of external asset registrations in this block has been reached.
+		ExternalRegistrationLimitReached,
+
+		/// Existential deposit of an asset cannot be zero.
+		InvalidExistentialDeposit,
+
+		/// Asset is not an external asset waiting for approval.
+		NotPendingExternalAsset,
+
+		/// Metadata of an asset has not been set.
+		AssetMetadataNotFound,
 	}
 
 	#[pallet::storage]
@@ -169,8 +205,23 @@ pub mod pallet {
 	#[pallet::storage]
 	#[pallet::getter(fn asset_metadata)]
 	/// Metadata of an asset.
-	pub type AssetMetadataMap<T: Config> =
-		StorageMap<_, Twox64Concat, T::AssetId, AssetMetadata<BoundedVec<u8, T::StringLimit>>, OptionQuery>;
+	pub type AssetMetadataMap<T: Config> = StorageMap<_, Twox64Concat, T::AssetId, AssetMetadataT<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn external_asset_deposit)]
+	/// Deposits of external assets registered via `register_external`.
+	///
+	/// An asset is considered insufficient while it has an entry here.
+	pub type ExternalAssetDeposits<T: Config> =
+		StorageMap<_, Twox64Concat, T::AssetId, ExternalAssetDeposit<T::AccountId, BalanceOf<T>>, OptionQuery>;
+
+	#[pallet::storage]
+	/// External assets rejected by `reject_external`. Rejected assets stay insufficient.
+	pub type RejectedExternalAssets<T: Config> = StorageMap<_, Twox64Concat, T::AssetId, (), OptionQuery>;
+
+	#[pallet::storage]
+	/// Number of external assets registered in the current block.
+	pub type ExternalRegistrationsInBlock<T: Config> = StorageValue<_, (BlockNumberFor<T>, u32), ValueQuery>;
 
 	#[allow(clippy::type_complexity)]
 	#[pallet::genesis_config]
@@ -250,6 +301,39 @@ pub mod pallet {
 			asset_id: T::AssetId,
 			location: T::AssetNativeLocation,
 		},
+
+		/// External asset was registered and deposit was reserved.
+		ExternalRegistered {
+			asset_id: T::AssetId,
+			owner: T::AccountId,
+			deposit: BalanceOf<T>,
+		},
+
+		/// External asset was approved as sufficient and deposit was returned.
+		ExternalApproved {
+			asset_id: T::AssetId,
+			owner: T::AccountId,
+			deposit: BalanceOf<T>,
+		},
+
+		/// External asset was rejected. The deposit was slashed or returned.
+		ExternalRejected {
+			asset_id: T::AssetId,
+			owner: T::AccountId,
+			deposit: BalanceOf<T>,
+			slashed: bool,
+		},
+
+		/// Icon URI, website and coingecko id set for an asset.
+		ExtendedMetadataSet {
+			asset_id: T::AssetId,
+			icon_uri: Option<BoundedVec<u8, T::MetadataUrlLimit>>,
+			website: Option<BoundedVec<u8, T::MetadataUrlLimit>>,
+			coingecko_id: Option<BoundedVec<u8, T::StringLimit>>,
+		},
+
+		/// Verification status of an asset changed.
+		VerificationSet { asset_id: T::AssetId, verified: bool },
 	}
 
 	#[pallet::call]
@@ -289,20 +373,7 @@ pub mod pallet {
 				Self::register_asset(bounded_name, asset_type, existential_deposit, asset_id, xcm_rate_limit)?;
 
 			if let Some(meta) = metadata {
-				let symbol = Self::to_bounded_name(meta.symbol)?;
-				AssetMetadataMap::<T>::insert(
-					asset_id,
-					AssetMetadata {
-						symbol: symbol.clone(),
-						decimals: meta.decimals,
-					},
-				);
-
-				Self::deposit_event(Event::MetadataSet {
-					asset_id,
-					symbol,
-					decimals: meta.decimals,
-				});
+				Self::do_set_metadata(asset_id, meta.symbol, meta.decimals)?;
 			}
 
 			if let Some(loc) = location {
@@ -395,22 +466,7 @@ pub mod pallet {
 
 			ensure!(Self::assets(asset_id).is_some(), Error::<T>::AssetNotFound);
 
-			let b_symbol = Self::to_bounded_name(symbol)?;
-
-			let metadata = AssetMetadata::<BoundedVec<u8, T::StringLimit>> {
-				symbol: b_symbol.clone(),
-				decimals,
-			};
-
-			AssetMetadataMap::<T>::insert(asset_id, metadata);
-
-			Self::deposit_event(Event::MetadataSet {
-				asset_id,
-				symbol: b_symbol,
-				decimals,
-			});
-
-			Ok(())
+			Self::do_set_metadata(asset_id, symbol, decimals)
 		}
 
 		/// Set asset native location.
@@ -446,6 +502,210 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Register a new external asset.
+		///
+		/// Permissionless version of `register` for assets of `AssetType::External` identified by
+		/// their native location.
+		///
+		/// `ExternalAssetDeposit` is reserved from the caller and the asset is registered as insufficient.
+		/// The deposit is returned when the asset is approved by `RegistryOrigin` via `approve_external`.
+		///
+		/// Number of external registrations per block is limited by `MaxExternalRegistrationsPerBlock`.
+		///
+		/// Emits `Registered`, `LocationSet` and `ExternalRegistered` events when successful.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::register_external())]
+		pub fn register_external(
+			origin: OriginFor<T>,
+			name: Vec<u8>,
+			location: T::AssetNativeLocation,
+			existential_deposit: T::Balance,
+			metadata: Option<Metadata>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!existential_deposit.is_zero(), Error::<T>::InvalidExistentialDeposit);
+
+			let bounded_name = Self::to_bounded_name(name)?;
+
+			ensure!(
+				Self::asset_ids(&bounded_name).is_none(),
+				Error::<T>::AssetAlreadyRegistered
+			);
+			ensure!(
+				Self::location_assets(&location).is_none(),
+				Error::<T>::LocationAlreadyRegistered
+			);
+
+			let current_block = frame_system::Pallet::<T>::block_number();
+			ExternalRegistrationsInBlock::<T>::try_mutate(|(block, count)| -> DispatchResult {
+				if *block != current_block {
+					*block = current_block;
+					*count = 0;
+				}
+				ensure!(
+					*count < T::MaxExternalRegistrationsPerBlock::get(),
+					Error::<T>::ExternalRegistrationLimitReached
+				);
+				*count = count.saturating_add(1);
+				Ok(())
+			})?;
+
+			let deposit = T::ExternalAssetDeposit::get();
+			T::Currency::reserve(&who, deposit)?;
+
+			let asset_id = Self::register_asset(bounded_name, AssetType::External, existential_deposit, None, None)?;
+
+			if let Some(meta) = metadata {
+				Self::do_set_metadata(asset_id, meta.symbol, meta.decimals)?;
+			}
+
+			AssetLocations::<T>::insert(asset_id, &location);
+			LocationAssets::<T>::insert(&location, asset_id);
+
+			Self::deposit_event(Event::LocationSet { asset_id, location });
+
+			ExternalAssetDeposits::<T>::insert(
+				asset_id,
+				ExternalAssetDeposit {
+					owner: who.clone(),
+					amount: deposit,
+				},
+			);
+
+			Self::deposit_event(Event::ExternalRegistered {
+				asset_id,
+				owner: who,
+				deposit,
+			});
+
+			Ok(())
+		}
+
+		/// Approve external asset registered via `register_external`.
+		///
+		/// Asset becomes sufficient and the deposit is returned to the account which registered it.
+		///
+		/// Emits `ExternalApproved` event when successful.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::approve_external())]
+		pub fn approve_external(origin: OriginFor<T>, asset_id: T::AssetId) -> DispatchResult {
+			T::RegistryOrigin::ensure_origin(origin)?;
+
+			let ExternalAssetDeposit { owner, amount } =
+				ExternalAssetDeposits::<T>::take(asset_id).ok_or(Error::<T>::NotPendingExternalAsset)?;
+
+			T::Currency::unreserve(&owner, amount);
+
+			Self::deposit_event(Event::ExternalApproved {
+				asset_id,
+				owner,
+				deposit: amount,
+			});
+
+			Ok(())
+		}
+
+		/// Set icon URI, website and coingecko id of an asset.
+		///
+		/// Metadata of the asset must be set already. Passing `None` clears the value.
+		///
+		/// Emits `ExtendedMetadataSet` event when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_extended_metadata())]
+		pub fn set_extended_metadata(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			icon_uri: Option<Vec<u8>>,
+			website: Option<Vec<u8>>,
+			coingecko_id: Option<Vec<u8>>,
+		) -> DispatchResult {
+			T::RegistryOrigin::ensure_origin(origin)?;
+
+			let icon_uri = icon_uri.map(Self::to_bounded_url).transpose()?;
+			let website = website.map(Self::to_bounded_url).transpose()?;
+			let coingecko_id = coingecko_id.map(Self::to_bounded_name).transpose()?;
+
+			AssetMetadataMap::<T>::try_mutate(asset_id, |maybe_metadata| -> DispatchResult {
+				let metadata = maybe_metadata.as_mut().ok_or(Error::<T>::AssetMetadataNotFound)?;
+
+				metadata.icon_uri = icon_uri.clone();
+				metadata.website = website.clone();
+				metadata.coingecko_id = coingecko_id.clone();
+
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::ExtendedMetadataSet {
+				asset_id,
+				icon_uri,
+				website,
+				coingecko_id,
+			});
+
+			Ok(())
+		}
+
+		/// Set verification status of an asset.
+		///
+		/// Metadata of the asset must be set already.
+		///
+		/// Emits `VerificationSet` event when successful.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_verified())]
+		pub fn set_verified(origin: OriginFor<T>, asset_id: T::AssetId, verified: bool) -> DispatchResult {
+			T::RegistryOrigin::ensure_origin(origin)?;
+
+			AssetMetadataMap::<T>::try_mutate(asset_id, |maybe_metadata| -> DispatchResult {
+				let metadata = maybe_metadata.as_mut().ok_or(Error::<T>::AssetMetadataNotFound)?;
+				metadata.verified = verified;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::VerificationSet { asset_id, verified });
+
+			Ok(())
+		}
+
+		/// Reject external asset registered via `register_external`.
+		///
+		/// Location of the asset is removed, so the asset can't be received from other chains anymore.
+		/// The asset itself stays registered as insufficient and banned, as accounts may already hold it.
+		///
+		/// The deposit is returned to the account which registered the asset, or slashed and burned
+		/// if `slash` is true.
+		///
+		/// Emits `ExternalRejected` event when successful.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::reject_external())]
+		pub fn reject_external(origin: OriginFor<T>, asset_id: T::AssetId, slash: bool) -> DispatchResult {
+			T::RegistryOrigin::ensure_origin(origin)?;
+
+			let ExternalAssetDeposit { owner, amount } =
+				ExternalAssetDeposits::<T>::take(asset_id).ok_or(Error::<T>::NotPendingExternalAsset)?;
+			RejectedExternalAssets::<T>::insert(asset_id, ());
+
+			if let Some(location) = AssetLocations::<T>::take(asset_id) {
+				LocationAssets::<T>::remove(&location);
+			}
+
+			if slash {
+				// Dropping the imbalance burns the slashed amount.
+				let _ = T::Currency::slash_reserved(&owner, amount);
+			} else {
+				T::Currency::unreserve(&owner, amount);
+			}
+
+			Self::deposit_event(Event::ExternalRejected {
+				asset_id,
+				owner,
+				deposit: amount,
+				slashed: slash,
+			});
+
+			Ok(())
+		}
 	}
 }
 
@@ -459,6 +719,99 @@ impl<T: Config> Pallet<T> {
 		name.try_into().map_err(|_| Error::<T>::TooLong)
 	}
 
+	/// Convert Vec<u8> to BoundedVec so it respects the max url limit, otherwise return TooLong error
+	pub fn to_bounded_url(url: Vec<u8>) -> Result<BoundedVec<u8, T::MetadataUrlLimit>, Error<T>> {
+		url.try_into().map_err(|_| Error::<T>::TooLong)
+	}
+
+	/// Set symbol and decimals of an asset.
+	///
+	/// Icon URI, website, coingecko id and verification status are kept if metadata was set before.
+	fn do_set_metadata(asset_id: T::AssetId, symbol: Vec<u8>, decimals: u8) -> DispatchResult {
+		let b_symbol = Self::to_bounded_name(symbol)?;
+
+		AssetMetadataMap::<T>::mutate(asset_id, |maybe_metadata| {
+			let metadata = maybe_metadata.get_or_insert_with(Default::default);
+			metadata.symbol = b_symbol.clone();
+			metadata.decimals = decimals;
+		});
+
+		Self::deposit_event(Event::MetadataSet {
+			asset_id,
+			symbol: b_symbol,
+			decimals,
+		});
+
+		Ok(())
+	}
+
+	/// Set metadata of an asset registered via `Create` if both symbol and decimals are provided.
+	fn set_created_asset_metadata(
+		asset_id: T::AssetId,
+		symbol: Option<BoundedVec<u8, T::StringLimit>>,
+		decimals: Option<u8>,
+	) -> DispatchResult {
+		match (symbol, decimals) {
+			(Some(symbol), Some(decimals)) => Self::do_set_metadata(asset_id, symbol.into_inner(), decimals),
+			_ => Ok(()),
+		}
+	}
+
+	/// Return metadata of an asset in a form suitable for the runtime API.
+	pub fn metadata_info(asset_id: T::AssetId) -> Option<AssetMetadataInfo> {
+		AssetMetadataMap::<T>::get(asset_id).map(Into::into)
+	}
+
+	/// Return details, metadata and location of an asset.
+	pub fn asset_info(asset_id: T::AssetId) -> Option<AssetInfoT<T>> {
+		Assets::<T>::get(asset_id).map(|details| Self::build_asset_info(asset_id, details))
+	}
+
+	/// Return up to `limit` assets, starting after `start_after` asset id.
+	///
+	/// Assets are returned in storage order, so the id of the last returned asset should be
+	/// used as `start_after` to fetch the next page. `limit` is capped by `MAX_ASSETS_PAGE_SIZE`.
+	pub fn assets_info(start_after: Option<T::AssetId>, limit: u32) -> Vec<AssetInfoT<T>> {
+		let limit = limit.min(MAX_ASSETS_PAGE_SIZE) as usize;
+
+		let iter = match start_after {
+			Some(asset_id) => Assets::<T>::iter_from(Assets::<T>::hashed_key_for(asset_id)),
+			None => Assets::<T>::iter(),
+		};
+
+		iter.take(limit)
+			.map(|(asset_id, details)| Self::build_asset_info(asset_id, details))
+			.collect()
+	}
+
+	/// Return all assets with given symbol. Symbols are not unique.
+	pub fn assets_info_by_symbol(symbol: Vec<u8>) -> Vec<AssetInfoT<T>> {
+		AssetMetadataMap::<T>::iter()
+			.filter(|(_, metadata)| metadata.symbol.as_slice() == symbol.as_slice())
+			.filter_map(|(asset_id, _)| Self::asset_info(asset_id))
+			.collect()
+	}
+
+	/// Return asset registered for given location.
+	pub fn asset_info_by_location(location: T::AssetNativeLocation) -> Option<AssetInfoT<T>> {
+		Self::location_to_asset(location).and_then(Self::asset_info)
+	}
+
+	fn build_asset_info(asset_id: T::AssetId, details: AssetDetailsT<T>) -> AssetInfoT<T> {
+		let metadata = AssetMetadataMap::<T>::get(asset_id);
+
+		AssetInfo {
+			asset_id,
+			name: details.name.into(),
+			symbol: metadata.as_ref().map(|m| m.symbol.clone().into()),
+			decimals: metadata.map(|m| m.decimals),
+			existential_deposit: details.existential_deposit,
+			asset_type: details.asset_type,
+			location: AssetLocations::<T>::get(asset_id),
+			xcm_rate_limit: details.xcm_rate_limit,
+		}
+	}
+
 	/// Register new asset.
 	///
 	/// Does not perform any  check whether an asset for given name already exists. This has to be prior to calling this function.
@@ -552,6 +905,11 @@ impl<T: Config> Pallet<T> {
 	pub fn location_to_asset(location: T::AssetNativeLocation) -> Option<T::AssetId> {
 		Self::location_assets(location)
 	}
+
+	/// Return true if asset is not an external asset waiting for approval or rejected.
+	pub fn is_sufficient(asset_id: T::AssetId) -> bool {
+		!ExternalAssetDeposits::<T>::contains_key(asset_id) && !RejectedExternalAssets::<T>::contains_key(asset_id)
+	}
 }
 
 impl<T: Config> Registry<T::AssetId, Vec<u8>, T::Balance, DispatchError> for Pallet<T> {
@@ -658,8 +1016,8 @@ impl<T: Config<Balance = u128>> Inspect for Pallet<T> {
 	type AssetId = T::AssetId;
 	type Location = T::AssetNativeLocation;
 
-	fn is_sufficient(_id: Self::AssetId) -> bool {
-		true
+	fn is_sufficient(id: Self::AssetId) -> bool {
+		Self::is_sufficient(id)
 	}
 
 	fn exists(id: Self::AssetId) -> bool {
@@ -674,8 +1032,8 @@ impl<T: Config<Balance = u128>> Inspect for Pallet<T> {
 		Self::assets(id).map(|a| a.asset_type.into())
 	}
 
-	fn is_banned(_id: Self::AssetId) -> bool {
-		false
+	fn is_banned(id: Self::AssetId) -> bool {
+		RejectedExternalAssets::<T>::contains_key(id)
 	}
 
 	fn asset_name(id: Self::AssetId) -> Option<Vec<u8>> {
@@ -695,6 +1053,9 @@ impl<T: Config<Balance = u128>> Inspect for Pallet<T> {
 /// provided.
 pub const DEFAULT_ED: u128 = 1;
 
+/// Maximum number of assets returned by a single `assets_info` call.
+pub const MAX_ASSETS_PAGE_SIZE: u32 = 1_000;
+
 // Dev note: this comes from new version of asset registry, but in order to support new xyk, we need to implement it here
 // but we can ignore the new fields for now
 impl<T: Config<Balance = u128>> Create<T::Balance> for Pallet<T> {
@@ -707,8 +1068,8 @@ impl<T: Config<Balance = u128>> Create<T::Balance> for Pallet<T> {
 		name: Option<Self::Name>,
 		kind: AssetKind,
 		existential_deposit: Option<T::Balance>,
-		_symbol: Option<Self::Symbol>,
-		_decimals: Option<u8>,
+		symbol: Option<Self::Symbol>,
+		decimals: Option<u8>,
 		_location: Option<Self::Location>,
 		xcm_rate_limit: Option<T::Balance>,
 		_is_sufficient: bool,
@@ -717,34 +1078,42 @@ impl<T: Config<Balance = u128>> Create<T::Balance> for Pallet<T> {
 			return Err(Error::<T>::TooLong.into());
 		};
 
-		Self::register_asset(
+		let asset_id = Self::register_asset(
 			asset_name,
 			kind.into(),
 			existential_deposit.unwrap_or(DEFAULT_ED),
 			asset_id,
 			xcm_rate_limit,
-		)
+		)?;
+		Self::set_created_asset_metadata(asset_id, symbol, decimals)?;
+
+		Ok(asset_id)
 	}
 
 	fn get_or_register_asset(
 		name: Self::Name,
 		kind: AssetKind,
 		existential_deposit: Option<T::Balance>,
-		_symbol: Option<Self::Symbol>,
-		_decimals: Option<u8>,
+		symbol: Option<Self::Symbol>,
+		decimals: Option<u8>,
 		_location: Option<Self::Location>,
 		xcm_rate_limit: Option<T::Balance>,
 		_is_sufficient: bool,
 	) -> Result<Self::AssetId, Self::Error> {
 		match Self::asset_ids(&name) {
 			Some(id) => Ok(id),
-			None => Self::register_asset(
-				name,
-				kind.into(),
-				existential_deposit.unwrap_or(DEFAULT_ED),
-				None,
-				xcm_rate_limit,
-			),
+			None => {
+				let asset_id = Self::register_asset(
+					name,
+					kind.into(),
+					existential_deposit.unwrap_or(DEFAULT_ED),
+					None,
+					xcm_rate_limit,
+				)?;
+				Self::set_created_asset_metadata(asset_id, symbol, decimals)?;
+
+				Ok(asset_id)
+			}
 		}
 	}
 }
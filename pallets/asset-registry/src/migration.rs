@@ -15,11 +15,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License..
 
-use crate::{AssetDetails, AssetType, Assets, Config, Pallet};
+use crate::{AssetDetails, AssetMetadata, AssetMetadataMap, AssetType, Assets, Config, Pallet};
 use frame_support::{
-	traits::{Get, StorageVersion},
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
 	weights::Weight,
 };
+use sp_std::marker::PhantomData;
 
 pub mod v1 {
 	use super::*;
@@ -87,3 +88,90 @@ pub mod v1 {
 		);
 	}
 }
+
+pub mod v2 {
+	use super::*;
+	use codec::{Decode, Encode};
+	use frame_support::BoundedVec;
+	use scale_info::TypeInfo;
+	use sp_core::RuntimeDebug;
+
+	#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo)]
+	pub struct OldAssetMetadata<BoundedString> {
+		pub symbol: BoundedString,
+		pub decimals: u8,
+	}
+
+	pub fn pre_migrate<T: Config>() {
+		assert_eq!(StorageVersion::get::<Pallet<T>>(), 1, "Storage version too high.");
+
+		log::info!(
+			target: "runtime::asset-registry",
+			"Asset Registry migration to v2: PRE checks successful!"
+		);
+	}
+
+	pub fn migrate<T: Config>() -> Weight {
+		log::info!(
+			target: "runtime::asset-registry",
+			"Running migration to v2 for Asset Registry"
+		);
+
+		let mut i = 0;
+		AssetMetadataMap::<T>::translate(
+			|_key, OldAssetMetadata::<BoundedVec<u8, T::StringLimit>> { symbol, decimals }| {
+				i += 1;
+				Some(AssetMetadata {
+					symbol,
+					decimals,
+					icon_uri: None,
+					website: None,
+					coingecko_id: None,
+					verified: false,
+				})
+			},
+		);
+
+		StorageVersion::new(2).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(i, i.saturating_add(1))
+	}
+
+	pub fn post_migrate<T: Config>() {
+		assert_eq!(StorageVersion::get::<Pallet<T>>(), 2, "Unexpected storage version.");
+
+		log::info!(
+			target: "runtime::asset-registry",
+			"Asset Registry migration to v2: POST checks successful!"
+		);
+	}
+
+	/// Migrates `AssetMetadataMap` to the metadata with icon URI, website, coingecko id and verification status.
+	pub struct MigrateV1ToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateV1ToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 1 {
+				log::info!(
+					target: "runtime::asset-registry",
+					"Asset Registry migration to v2 skipped"
+				);
+				return T::DbWeight::get().reads(1);
+			}
+
+			migrate::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<sp_std::vec::Vec<u8>, sp_runtime::TryRuntimeError> {
+			pre_migrate::<T>();
+			Ok(sp_std::vec::Vec::new())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			post_migrate::<T>();
+			Ok(())
+		}
+	}
+}
@@ -32,6 +32,9 @@ use crate::{self as asset_registry, Config};
 pub type AssetId = u32;
 pub type Balance = u128;
 
+pub const ALICE: u64 = 1;
+pub const BOB: u64 = 2;
+
 pub const UNIT: Balance = 1_000_000_000_000;
 
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -40,6 +43,7 @@ frame_support::construct_runtime!(
 	pub enum Test
 	 {
 		 System: frame_system,
+		 Balances: pallet_balances,
 		 Registry: asset_registry,
 	 }
 
@@ -51,6 +55,10 @@ parameter_types! {
 	pub const NativeAssetId: AssetId = 0;
 	pub const RegistryStringLimit: u32 = 10;
 	pub const SequentialIdStart: u32 = 1_000_000;
+	pub const ExistentialDeposit: Balance = 1;
+	pub const ExternalDeposit: Balance = 100 * UNIT;
+	pub const MaxExternalRegistrationsPerBlock: u32 = 2;
+	pub const MetadataUrlLimit: u32 = 32;
 }
 
 impl system::Config for Test {
@@ -71,7 +79,7 @@ impl system::Config for Test {
 	type DbWeight = ();
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<Balance>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -86,6 +94,23 @@ impl system::Config for Test {
 	type ExtensionsWeightInfo = ();
 }
 
+impl pallet_balances::Config for Test {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type RuntimeHoldReason = ();
+	type RuntimeFreezeReason = ();
+	type DoneSlashHandler = ();
+}
+
 use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 
@@ -98,8 +123,12 @@ impl Config for Test {
 	type Balance = Balance;
 	type AssetNativeLocation = AssetLocation;
 	type StringLimit = RegistryStringLimit;
+	type MetadataUrlLimit = MetadataUrlLimit;
 	type SequentialIdStartAt = SequentialIdStart;
 	type NativeAssetId = NativeAssetId;
+	type Currency = Balances;
+	type ExternalAssetDeposit = ExternalDeposit;
+	type MaxExternalRegistrationsPerBlock = MaxExternalRegistrationsPerBlock;
 	type WeightInfo = ();
 }
 pub type AssetRegistryPallet = crate::Pallet<Test>;
@@ -108,6 +137,7 @@ pub type AssetRegistryPallet = crate::Pallet<Test>;
 pub struct ExtBuilder {
 	registered_assets: Vec<(Vec<u8>, Balance, Option<AssetId>)>,
 	native_asset_name: Option<Vec<u8>>,
+	endowed_accounts: Vec<(u64, Balance)>,
 }
 
 impl ExtBuilder {
@@ -121,9 +151,21 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn with_endowed_accounts(mut self, accounts: Vec<(u64, Balance)>) -> Self {
+		self.endowed_accounts = accounts;
+		self
+	}
+
 	pub fn build(self) -> sp_io::TestExternalities {
 		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 
+		pallet_balances::GenesisConfig::<Test> {
+			balances: self.endowed_accounts,
+			..Default::default()
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
 		if let Some(name) = self.native_asset_name {
 			crate::GenesisConfig::<Test> {
 				registered_assets: self.registered_assets,
@@ -17,7 +17,9 @@
 
 use super::Error;
 use crate::mock::AssetId as RegistryAssetId;
-use crate::types::{AssetDetails, AssetMetadata, AssetType, Metadata};
+use crate::types::{
+	AssetDetails, AssetInfo, AssetMetadata, AssetMetadataInfo, AssetType, ExternalAssetDeposit, Metadata,
+};
 use crate::Event;
 use crate::{mock::*, XcmRateLimitsInRegistry};
 use codec::Encode;
@@ -294,6 +296,7 @@ fn set_metadata_works() {
 				AssetMetadata {
 					decimals: 12u8,
 					symbol: b_symbol.clone(),
+					..Default::default()
 				}
 			);
 
@@ -309,6 +312,7 @@ fn set_metadata_works() {
 				AssetMetadata {
 					decimals: 30u8,
 					symbol: b_symbol,
+					..Default::default()
 				}
 			);
 
@@ -683,6 +687,7 @@ fn register_asset_should_work_when_metadata_is_provided() {
 			AssetMetadata {
 				decimals: 18u8,
 				symbol: b_symbol,
+				..Default::default()
 			}
 		);
 	});
@@ -861,7 +866,675 @@ fn register_asset_should_work_when_all_optional_are_provided() {
 			AssetMetadata {
 				decimals: 18u8,
 				symbol: b_symbol,
+				..Default::default()
 			}
 		);
 	});
 }
+
+fn external_location(para_id: u32) -> AssetLocation {
+	let key = Junction::from(BoundedVec::try_from(1_000u32.encode()).unwrap());
+	AssetLocation(Location::new(1, X2(Arc::new([Parachain(para_id), key]))))
+}
+
+#[test]
+fn register_external_should_work_when_signed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, 1_000 * UNIT)])
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			let location = external_location(2000);
+
+			assert_ok!(AssetRegistryPallet::register_external(
+				RuntimeOrigin::signed(ALICE),
+				b"EXT".to_vec(),
+				location.clone(),
+				1_000,
+				Some(Metadata {
+					symbol: b"EXT".to_vec(),
+					decimals: 12,
+				}),
+			));
+
+			let asset_id = 1 + SequentialIdStart::get();
+			let bn = AssetRegistryPallet::to_bounded_name(b"EXT".to_vec()).unwrap();
+
+			assert_eq!(
+				AssetRegistryPallet::assets(asset_id).unwrap(),
+				AssetDetails {
+					name: bn,
+					asset_type: AssetType::External,
+					existential_deposit: 1_000,
+					xcm_rate_limit: None,
+				}
+			);
+			assert_eq!(AssetRegistryPallet::location_to_asset(location), Some(asset_id));
+			assert_eq!(Balances::reserved_balance(ALICE), ExternalDeposit::get());
+			assert_eq!(
+				AssetRegistryPallet::external_asset_deposit(asset_id),
+				Some(ExternalAssetDeposit {
+					owner: ALICE,
+					amount: ExternalDeposit::get(),
+				})
+			);
+			assert!(!AssetRegistryPallet::is_sufficient(asset_id));
+
+			System::assert_last_event(
+				Event::ExternalRegistered {
+					asset_id,
+					owner: ALICE,
+					deposit: ExternalDeposit::get(),
+				}
+				.into(),
+			);
+		});
+}
+
+#[test]
+fn register_external_should_fail_when_deposit_cannot_be_reserved() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, UNIT)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				AssetRegistryPallet::register_external(
+					RuntimeOrigin::signed(ALICE),
+					b"EXT".to_vec(),
+					external_location(2000),
+					1_000,
+					None,
+				),
+				pallet_balances::Error::<Test>::InsufficientBalance
+			);
+		});
+}
+
+#[test]
+fn register_external_should_fail_when_location_is_already_registered() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, 1_000 * UNIT)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(AssetRegistryPallet::register_external(
+				RuntimeOrigin::signed(ALICE),
+				b"EXT".to_vec(),
+				external_location(2000),
+				1_000,
+				None,
+			));
+
+			assert_noop!(
+				AssetRegistryPallet::register_external(
+					RuntimeOrigin::signed(ALICE),
+					b"EXT2".to_vec(),
+					external_location(2000),
+					1_000,
+					None,
+				),
+				Error::<Test>::LocationAlreadyRegistered
+			);
+		});
+}
+
+#[test]
+fn register_external_should_fail_when_existential_deposit_is_zero() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, 1_000 * UNIT)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				AssetRegistryPallet::register_external(
+					RuntimeOrigin::signed(ALICE),
+					b"EXT".to_vec(),
+					external_location(2000),
+					0,
+					None,
+				),
+				Error::<Test>::InvalidExistentialDeposit
+			);
+		});
+}
+
+#[test]
+fn register_external_should_fail_when_block_limit_is_reached() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, 1_000 * UNIT), (BOB, 1_000 * UNIT)])
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			for (i, who) in [ALICE, BOB].into_iter().enumerate() {
+				assert_ok!(AssetRegistryPallet::register_external(
+					RuntimeOrigin::signed(who),
+					vec![b'A' + i as u8],
+					external_location(2000 + i as u32),
+					1_000,
+					None,
+				));
+			}
+
+			assert_noop!(
+				AssetRegistryPallet::register_external(
+					RuntimeOrigin::signed(ALICE),
+					b"EXT".to_vec(),
+					external_location(3000),
+					1_000,
+					None,
+				),
+				Error::<Test>::ExternalRegistrationLimitReached
+			);
+
+			// limit is reset in the next block
+			System::set_block_number(2);
+			assert_ok!(AssetRegistryPallet::register_external(
+				RuntimeOrigin::signed(ALICE),
+				b"EXT".to_vec(),
+				external_location(3000),
+				1_000,
+				None,
+			));
+		});
+}
+
+#[test]
+fn approve_external_should_unreserve_deposit_and_make_asset_sufficient() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, 1_000 * UNIT)])
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(AssetRegistryPallet::register_external(
+				RuntimeOrigin::signed(ALICE),
+				b"EXT".to_vec(),
+				external_location(2000),
+				1_000,
+				None,
+			));
+			let asset_id = 1 + SequentialIdStart::get();
+
+			assert_noop!(
+				AssetRegistryPallet::approve_external(RuntimeOrigin::signed(ALICE), asset_id),
+				sp_runtime::DispatchError::BadOrigin
+			);
+
+			assert_ok!(AssetRegistryPallet::approve_external(RuntimeOrigin::root(), asset_id));
+
+			assert!(AssetRegistryPallet::is_sufficient(asset_id));
+			assert_eq!(Balances::reserved_balance(ALICE), 0);
+			assert_eq!(Balances::free_balance(ALICE), 1_000 * UNIT);
+			assert!(AssetRegistryPallet::external_asset_deposit(asset_id).is_none());
+
+			System::assert_last_event(
+				Event::ExternalApproved {
+					asset_id,
+					owner: ALICE,
+					deposit: ExternalDeposit::get(),
+				}
+				.into(),
+			);
+
+			assert_noop!(
+				AssetRegistryPallet::approve_external(RuntimeOrigin::root(), asset_id),
+				Error::<Test>::NotPendingExternalAsset
+			);
+		});
+}
+
+#[test]
+fn reject_external_should_return_deposit_and_remove_location() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, 1_000 * UNIT)])
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			let location = external_location(2000);
+			assert_ok!(AssetRegistryPallet::register_external(
+				RuntimeOrigin::signed(ALICE),
+				b"EXT".to_vec(),
+				location.clone(),
+				1_000,
+				None,
+			));
+			let asset_id = 1 + SequentialIdStart::get();
+
+			assert_noop!(
+				AssetRegistryPallet::reject_external(RuntimeOrigin::signed(ALICE), asset_id, false),
+				sp_runtime::DispatchError::BadOrigin
+			);
+
+			assert_ok!(AssetRegistryPallet::reject_external(
+				RuntimeOrigin::root(),
+				asset_id,
+				false
+			));
+
+			assert_eq!(Balances::reserved_balance(ALICE), 0);
+			assert_eq!(Balances::free_balance(ALICE), 1_000 * UNIT);
+			assert!(AssetRegistryPallet::external_asset_deposit(asset_id).is_none());
+			assert_eq!(AssetRegistryPallet::location_to_asset(location), None);
+			assert_eq!(AssetRegistryPallet::locations(asset_id), None);
+			assert!(!AssetRegistryPallet::is_sufficient(asset_id));
+			assert!(<AssetRegistryPallet as hydradx_traits::Inspect>::is_banned(asset_id));
+
+			System::assert_last_event(
+				Event::ExternalRejected {
+					asset_id,
+					owner: ALICE,
+					deposit: ExternalDeposit::get(),
+					slashed: false,
+				}
+				.into(),
+			);
+
+			assert_noop!(
+				AssetRegistryPallet::approve_external(RuntimeOrigin::root(), asset_id),
+				Error::<Test>::NotPendingExternalAsset
+			);
+			assert_noop!(
+				AssetRegistryPallet::reject_external(RuntimeOrigin::root(), asset_id, true),
+				Error::<Test>::NotPendingExternalAsset
+			);
+		});
+}
+
+#[test]
+fn reject_external_should_burn_deposit_when_slashed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, 1_000 * UNIT)])
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(AssetRegistryPallet::register_external(
+				RuntimeOrigin::signed(ALICE),
+				b"EXT".to_vec(),
+				external_location(2000),
+				1_000,
+				None,
+			));
+			let asset_id = 1 + SequentialIdStart::get();
+			let issuance = Balances::total_issuance();
+
+			assert_ok!(AssetRegistryPallet::reject_external(
+				RuntimeOrigin::root(),
+				asset_id,
+				true
+			));
+
+			assert_eq!(Balances::reserved_balance(ALICE), 0);
+			assert_eq!(Balances::free_balance(ALICE), 1_000 * UNIT - ExternalDeposit::get());
+			assert_eq!(Balances::total_issuance(), issuance - ExternalDeposit::get());
+			assert!(!AssetRegistryPallet::is_sufficient(asset_id));
+
+			System::assert_last_event(
+				Event::ExternalRejected {
+					asset_id,
+					owner: ALICE,
+					deposit: ExternalDeposit::get(),
+					slashed: true,
+				}
+				.into(),
+			);
+		});
+}
+
+#[test]
+fn set_extended_metadata_should_work() {
+	ExtBuilder::default()
+		.with_assets(vec![(b"DOT".to_vec(), 1_000u128, None)])
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			let dot_id = 1 + SequentialIdStart::get();
+			assert_ok!(AssetRegistryPallet::set_metadata(
+				RuntimeOrigin::root(),
+				dot_id,
+				b"DOT".to_vec(),
+				10u8
+			));
+
+			assert_ok!(AssetRegistryPallet::set_extended_metadata(
+				RuntimeOrigin::root(),
+				dot_id,
+				Some(b"ipfs://dot".to_vec()),
+				Some(b"https://polkadot.com".to_vec()),
+				Some(b"polkadot".to_vec()),
+			));
+
+			let icon_uri = AssetRegistryPallet::to_bounded_url(b"ipfs://dot".to_vec()).unwrap();
+			let website = AssetRegistryPallet::to_bounded_url(b"https://polkadot.com".to_vec()).unwrap();
+			let coingecko_id = AssetRegistryPallet::to_bounded_name(b"polkadot".to_vec()).unwrap();
+
+			assert_eq!(
+				AssetRegistryPallet::asset_metadata(dot_id).unwrap(),
+				AssetMetadata {
+					symbol: AssetRegistryPallet::to_bounded_name(b"DOT".to_vec()).unwrap(),
+					decimals: 10u8,
+					icon_uri: Some(icon_uri.clone()),
+					website: Some(website.clone()),
+					coingecko_id: Some(coingecko_id.clone()),
+					verified: false,
+				}
+			);
+
+			System::assert_last_event(
+				Event::ExtendedMetadataSet {
+					asset_id: dot_id,
+					icon_uri: Some(icon_uri),
+					website: Some(website),
+					coingecko_id: Some(coingecko_id),
+				}
+				.into(),
+			);
+
+			// symbol and decimals update keeps the extended metadata
+			assert_ok!(AssetRegistryPallet::set_metadata(
+				RuntimeOrigin::root(),
+				dot_id,
+				b"xDOT".to_vec(),
+				12u8
+			));
+			let metadata = AssetRegistryPallet::asset_metadata(dot_id).unwrap();
+			assert_eq!(metadata.decimals, 12u8);
+			assert!(metadata.website.is_some());
+
+			// None clears the value
+			assert_ok!(AssetRegistryPallet::set_extended_metadata(
+				RuntimeOrigin::root(),
+				dot_id,
+				None,
+				None,
+				None,
+			));
+			let metadata = AssetRegistryPallet::asset_metadata(dot_id).unwrap();
+			assert!(metadata.icon_uri.is_none());
+			assert!(metadata.website.is_none());
+			assert!(metadata.coingecko_id.is_none());
+		});
+}
+
+#[test]
+fn set_extended_metadata_should_fail_when_metadata_is_not_set() {
+	ExtBuilder::default()
+		.with_assets(vec![(b"DOT".to_vec(), 1_000u128, None)])
+		.build()
+		.execute_with(|| {
+			let dot_id = 1 + SequentialIdStart::get();
+			assert_noop!(
+				AssetRegistryPallet::set_extended_metadata(
+					RuntimeOrigin::root(),
+					dot_id,
+					Some(b"ipfs://dot".to_vec()),
+					None,
+					None,
+				),
+				Error::<Test>::AssetMetadataNotFound
+			);
+		});
+}
+
+#[test]
+fn set_extended_metadata_should_fail_when_url_is_too_long() {
+	ExtBuilder::default()
+		.with_assets(vec![(b"DOT".to_vec(), 1_000u128, None)])
+		.build()
+		.execute_with(|| {
+			let dot_id = 1 + SequentialIdStart::get();
+			assert_ok!(AssetRegistryPallet::set_metadata(
+				RuntimeOrigin::root(),
+				dot_id,
+				b"DOT".to_vec(),
+				10u8
+			));
+
+			let too_long = vec![1u8; MetadataUrlLimit::get() as usize + 1];
+			assert_noop!(
+				AssetRegistryPallet::set_extended_metadata(RuntimeOrigin::root(), dot_id, None, Some(too_long), None),
+				Error::<Test>::TooLong
+			);
+		});
+}
+
+#[test]
+fn set_verified_should_work_only_for_registry_origin() {
+	ExtBuilder::default()
+		.with_assets(vec![(b"DOT".to_vec(), 1_000u128, None)])
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			let dot_id = 1 + SequentialIdStart::get();
+			assert_ok!(AssetRegistryPallet::set_metadata(
+				RuntimeOrigin::root(),
+				dot_id,
+				b"DOT".to_vec(),
+				10u8
+			));
+
+			assert_noop!(
+				AssetRegistryPallet::set_verified(RuntimeOrigin::signed(ALICE), dot_id, true),
+				sp_runtime::DispatchError::BadOrigin
+			);
+
+			assert_ok!(AssetRegistryPallet::set_verified(RuntimeOrigin::root(), dot_id, true));
+			assert!(AssetRegistryPallet::asset_metadata(dot_id).unwrap().verified);
+			System::assert_last_event(
+				Event::VerificationSet {
+					asset_id: dot_id,
+					verified: true,
+				}
+				.into(),
+			);
+
+			assert_ok!(AssetRegistryPallet::set_verified(RuntimeOrigin::root(), dot_id, false));
+			assert!(!AssetRegistryPallet::asset_metadata(dot_id).unwrap().verified);
+
+			assert_noop!(
+				AssetRegistryPallet::set_verified(RuntimeOrigin::root(), 100, true),
+				Error::<Test>::AssetMetadataNotFound
+			);
+		});
+}
+
+#[test]
+fn metadata_info_should_return_all_metadata_fields() {
+	ExtBuilder::default()
+		.with_assets(vec![(b"DOT".to_vec(), 1_000u128, None)])
+		.build()
+		.execute_with(|| {
+			let dot_id = 1 + SequentialIdStart::get();
+			assert!(AssetRegistryPallet::metadata_info(dot_id).is_none());
+
+			assert_ok!(AssetRegistryPallet::set_metadata(
+				RuntimeOrigin::root(),
+				dot_id,
+				b"DOT".to_vec(),
+				10u8
+			));
+			assert_ok!(AssetRegistryPallet::set_extended_metadata(
+				RuntimeOrigin::root(),
+				dot_id,
+				None,
+				Some(b"https://polkadot.com".to_vec()),
+				None,
+			));
+			assert_ok!(AssetRegistryPallet::set_verified(RuntimeOrigin::root(), dot_id, true));
+
+			assert_eq!(
+				AssetRegistryPallet::metadata_info(dot_id),
+				Some(AssetMetadataInfo {
+					symbol: b"DOT".to_vec(),
+					decimals: 10,
+					icon_uri: None,
+					website: Some(b"https://polkadot.com".to_vec()),
+					coingecko_id: None,
+					verified: true,
+				})
+			);
+		});
+}
+
+#[test]
+fn migration_to_v2_should_extend_asset_metadata() {
+	ExtBuilder::default().build().execute_with(|| {
+		use crate::migration::v2::{MigrateV1ToV2, OldAssetMetadata};
+		use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+		StorageVersion::new(1).put::<AssetRegistryPallet>();
+
+		let symbol = AssetRegistryPallet::to_bounded_name(b"DOT".to_vec()).unwrap();
+		frame_support::storage::unhashed::put_raw(
+			&crate::AssetMetadataMap::<Test>::hashed_key_for(5u32),
+			&OldAssetMetadata {
+				symbol: symbol.clone(),
+				decimals: 10u8,
+			}
+			.encode(),
+		);
+
+		MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+		assert_eq!(StorageVersion::get::<AssetRegistryPallet>(), 2);
+		assert_eq!(
+			AssetRegistryPallet::asset_metadata(5u32).unwrap(),
+			AssetMetadata {
+				symbol,
+				decimals: 10u8,
+				icon_uri: None,
+				website: None,
+				coingecko_id: None,
+				verified: false,
+			}
+		);
+	});
+}
+
+#[test]
+fn asset_info_should_contain_details_metadata_and_location() {
+	ExtBuilder::default()
+		.with_assets(vec![(b"DOT".to_vec(), 1_000u128, None)])
+		.build()
+		.execute_with(|| {
+			let dot_id = 1 + SequentialIdStart::get();
+			let location = external_location(2000);
+
+			assert_eq!(
+				AssetRegistryPallet::asset_info(dot_id),
+				Some(AssetInfo {
+					asset_id: dot_id,
+					name: b"DOT".to_vec(),
+					symbol: None,
+					decimals: None,
+					existential_deposit: 1_000u128,
+					asset_type: AssetType::Token,
+					location: None,
+					xcm_rate_limit: None,
+				})
+			);
+
+			assert_ok!(AssetRegistryPallet::set_metadata(
+				RuntimeOrigin::root(),
+				dot_id,
+				b"xDOT".to_vec(),
+				10u8
+			));
+			assert_ok!(AssetRegistryPallet::set_location(
+				RuntimeOrigin::root(),
+				dot_id,
+				location.clone()
+			));
+			assert_ok!(AssetRegistryPallet::update(
+				RuntimeOrigin::root(),
+				dot_id,
+				b"DOT".to_vec(),
+				AssetType::Token,
+				None,
+				Some(5_000u128),
+			));
+
+			assert_eq!(
+				AssetRegistryPallet::asset_info(dot_id),
+				Some(AssetInfo {
+					asset_id: dot_id,
+					name: b"DOT".to_vec(),
+					symbol: Some(b"xDOT".to_vec()),
+					decimals: Some(10u8),
+					existential_deposit: 1_000u128,
+					asset_type: AssetType::Token,
+					location: Some(location.clone()),
+					xcm_rate_limit: Some(5_000u128),
+				})
+			);
+
+			assert_eq!(
+				AssetRegistryPallet::asset_info_by_location(location),
+				AssetRegistryPallet::asset_info(dot_id)
+			);
+			assert_eq!(
+				AssetRegistryPallet::asset_info_by_location(external_location(3000)),
+				None
+			);
+			assert_eq!(AssetRegistryPallet::asset_info(100), None);
+		});
+}
+
+#[test]
+fn assets_info_should_be_paginated() {
+	ExtBuilder::default()
+		.with_assets(vec![
+			(b"ONE".to_vec(), 1_000u128, None),
+			(b"TWO".to_vec(), 1_000u128, None),
+			(b"THREE".to_vec(), 1_000u128, None),
+			(b"FOUR".to_vec(), 1_000u128, None),
+		])
+		.build()
+		.execute_with(|| {
+			// native asset and four registered assets
+			let all = AssetRegistryPallet::assets_info(None, 10);
+			assert_eq!(all.len(), 5);
+
+			let first_page = AssetRegistryPallet::assets_info(None, 2);
+			assert_eq!(first_page, all[..2].to_vec());
+
+			let second_page = AssetRegistryPallet::assets_info(first_page.last().map(|a| a.asset_id), 2);
+			assert_eq!(second_page, all[2..4].to_vec());
+
+			let last_page = AssetRegistryPallet::assets_info(second_page.last().map(|a| a.asset_id), 2);
+			assert_eq!(last_page, all[4..].to_vec());
+
+			assert!(AssetRegistryPallet::assets_info(last_page.last().map(|a| a.asset_id), 2).is_empty());
+			assert!(AssetRegistryPallet::assets_info(None, 0).is_empty());
+		});
+}
+
+#[test]
+fn assets_info_by_symbol_should_return_all_assets_with_the_symbol() {
+	ExtBuilder::default()
+		.with_assets(vec![
+			(b"DOT".to_vec(), 1_000u128, None),
+			(b"Wrapped DOT".to_vec(), 1_000u128, None),
+			(b"KSM".to_vec(), 1_000u128, None),
+		])
+		.build()
+		.execute_with(|| {
+			let dot_id = 1 + SequentialIdStart::get();
+			let wrapped_dot_id = 2 + SequentialIdStart::get();
+			let ksm_id = 3 + SequentialIdStart::get();
+
+			for (asset_id, symbol) in [(dot_id, b"DOT"), (wrapped_dot_id, b"DOT"), (ksm_id, b"KSM")] {
+				assert_ok!(AssetRegistryPallet::set_metadata(
+					RuntimeOrigin::root(),
+					asset_id,
+					symbol.to_vec(),
+					10u8
+				));
+			}
+
+			let mut dots: Vec<RegistryAssetId> = AssetRegistryPallet::assets_info_by_symbol(b"DOT".to_vec())
+				.into_iter()
+				.map(|a| a.asset_id)
+				.collect();
+			dots.sort();
+			assert_eq!(dots, vec![dot_id, wrapped_dot_id]);
+
+			assert!(AssetRegistryPallet::assets_info_by_symbol(b"ACA".to_vec()).is_empty());
+		});
+}
@@ -80,11 +80,48 @@ pub struct AssetDetails<AssetId, Balance, BoundedString> {
 }
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-pub struct AssetMetadata<BoundedString> {
+pub struct AssetMetadata<BoundedString, BoundedUrl> {
 	/// The ticker symbol for this asset. Limited in length by `StringLimit`.
-	pub(super) symbol: BoundedString,
+	pub symbol: BoundedString,
 	/// The number of decimals this asset uses to represent one unit.
-	pub(super) decimals: u8,
+	pub decimals: u8,
+	/// URI of the asset icon. Limited in length by `MetadataUrlLimit`.
+	pub icon_uri: Option<BoundedUrl>,
+	/// Website of the asset project. Limited in length by `MetadataUrlLimit`.
+	pub website: Option<BoundedUrl>,
+	/// Id of the asset in coingecko-style price feeds. Limited in length by `StringLimit`.
+	pub coingecko_id: Option<BoundedString>,
+	/// True if the asset was verified by governance.
+	pub verified: bool,
+}
+
+/// Asset metadata as exposed by the runtime API.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct AssetMetadataInfo {
+	pub symbol: Vec<u8>,
+	pub decimals: u8,
+	pub icon_uri: Option<Vec<u8>>,
+	pub website: Option<Vec<u8>>,
+	pub coingecko_id: Option<Vec<u8>>,
+	pub verified: bool,
+}
+
+impl<BoundedString, BoundedUrl> From<AssetMetadata<BoundedString, BoundedUrl>> for AssetMetadataInfo
+where
+	BoundedString: Into<Vec<u8>>,
+	BoundedUrl: Into<Vec<u8>>,
+{
+	fn from(value: AssetMetadata<BoundedString, BoundedUrl>) -> Self {
+		Self {
+			symbol: value.symbol.into(),
+			decimals: value.decimals,
+			icon_uri: value.icon_uri.map(Into::into),
+			website: value.website.map(Into::into),
+			coingecko_id: value.coingecko_id.map(Into::into),
+			verified: value.verified,
+		}
+	}
 }
 
 #[derive(Clone, Encode, Decode, DecodeWithMemTracking, Eq, PartialEq, RuntimeDebug, TypeInfo)]
@@ -93,3 +130,25 @@ pub struct Metadata {
 	pub(super) symbol: Vec<u8>,
 	pub(super) decimals: u8,
 }
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ExternalAssetDeposit<AccountId, Balance> {
+	/// Account which registered the asset and holds the reserved deposit.
+	pub owner: AccountId,
+	/// Reserved amount.
+	pub amount: Balance,
+}
+
+/// Asset details, metadata and location as exposed by the runtime API.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct AssetInfo<AssetId, Balance, Location> {
+	pub asset_id: AssetId,
+	pub name: Vec<u8>,
+	pub symbol: Option<Vec<u8>>,
+	pub decimals: Option<u8>,
+	pub existential_deposit: Balance,
+	pub asset_type: AssetType<AssetId>,
+	pub location: Option<Location>,
+	pub xcm_rate_limit: Option<Balance>,
+}
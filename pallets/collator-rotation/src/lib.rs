@@ -12,44 +12,158 @@ mod tests;
 
 pub use pallet::*;
 
+use frame_support::{dispatch::DispatchClass, traits::Get, BoundedVec};
+use frame_system::pallet_prelude::BlockNumberFor;
 use pallet_session::SessionManager;
 use sp_staking::SessionIndex;
 use sp_std::vec::Vec;
 
+/// Selects the collator benched in a session.
+pub trait BenchPolicy<AccountId> {
+	/// Returns the index of the collator in `collators` to bench in session `session_index`.
+	///
+	/// `performance` holds the blocks authored in the last completed session by the collators
+	/// which were active in it. It is empty when no data is available.
+	fn select(session_index: SessionIndex, collators: &[AccountId], performance: &[(AccountId, u32)]) -> Option<usize>;
+}
+
+/// Benches the collators in turn, regardless of their performance.
+pub struct RoundRobin;
+
+impl<AccountId> BenchPolicy<AccountId> for RoundRobin {
+	fn select(
+		session_index: SessionIndex,
+		collators: &[AccountId],
+		_performance: &[(AccountId, u32)],
+	) -> Option<usize> {
+		if collators.is_empty() {
+			return None;
+		}
+		Some(((session_index / 2) as usize) % collators.len())
+	}
+}
+
+/// Benches the collator which authored the fewest blocks in the last completed session.
+/// Ties, and sessions without performance data, fall back to round-robin.
+pub struct LeastAuthored;
+
+impl<AccountId: PartialEq> BenchPolicy<AccountId> for LeastAuthored {
+	fn select(session_index: SessionIndex, collators: &[AccountId], performance: &[(AccountId, u32)]) -> Option<usize> {
+		let Some(min) = performance.iter().map(|(_, authored)| *authored).min() else {
+			return RoundRobin::select(session_index, collators, performance);
+		};
+
+		let worst: Vec<usize> = collators
+			.iter()
+			.enumerate()
+			.filter(|(_, who)| performance.iter().any(|(c, authored)| c == *who && *authored == min))
+			.map(|(idx, _)| idx)
+			.collect();
+
+		let tie_idx = RoundRobin::select(session_index, &worst, &[])?;
+		worst.get(tie_idx).copied()
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
+	use frame_support::pallet_prelude::*;
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config<RuntimeEvent: From<Event<Self>>> {
 		type Inner: SessionManager<Self::AccountId>;
+
+		/// Selects the collator benched every odd session.
+		type BenchPolicy: BenchPolicy<Self::AccountId>;
+
+		/// Max number of collators whose performance is tracked in a session.
+		#[pallet::constant]
+		type MaxCollators: Get<u32>;
 	}
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
+	/// Index of the session in progress.
+	#[pallet::storage]
+	pub type CurrentSession<T: Config> = StorageValue<_, SessionIndex, ValueQuery>;
+
+	/// Collators planned for a session.
+	#[pallet::storage]
+	pub type SessionCollators<T: Config> =
+		StorageMap<_, Twox64Concat, SessionIndex, BoundedVec<T::AccountId, T::MaxCollators>, OptionQuery>;
+
+	/// Blocks authored by a collator in a session.
+	#[pallet::storage]
+	pub type AuthoredBlocks<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, SessionIndex, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
+		/// Collator was benched for a session.
 		CollatorBenched {
 			who: T::AccountId,
 			session_index: SessionIndex,
+			/// Session in which the performance was measured.
+			measured_session: Option<SessionIndex>,
+			/// Blocks authored in the measured session by the collators active in it.
+			performance: Vec<(T::AccountId, u32)>,
 		},
 	}
 }
 
+impl<T: Config> Pallet<T> {
+	/// Collect the performance of `collators` in session `measured` and clear the data of the session.
+	fn take_performance(measured: SessionIndex, collators: &[T::AccountId]) -> Vec<(T::AccountId, u32)> {
+		let active = SessionCollators::<T>::take(measured).unwrap_or_default();
+
+		let performance = collators
+			.iter()
+			.filter(|who| active.contains(who))
+			.map(|who| (who.clone(), AuthoredBlocks::<T>::get(measured, who)))
+			.collect();
+
+		let _ = AuthoredBlocks::<T>::clear_prefix(measured, T::MaxCollators::get(), None);
+
+		performance
+	}
+}
+
 impl<T: Config> SessionManager<T::AccountId> for Pallet<T> {
 	fn new_session(new_index: SessionIndex) -> Option<Vec<T::AccountId>> {
-		let mut collators = T::Inner::new_session(new_index)?;
+		// `new_session(n)` is called when session n - 2 ends
+		let measured_session = new_index.checked_sub(2);
+
+		let Some(mut collators) = T::Inner::new_session(new_index) else {
+			if let Some(measured) = measured_session {
+				Self::take_performance(measured, &[]);
+			}
+			return None;
+		};
+
+		let performance = measured_session
+			.map(|measured| Self::take_performance(measured, &collators))
+			.unwrap_or_default();
+
 		// bench 1 collator every odd session rotation
 		if new_index % 2 == 1 && collators.len() > 1 {
-			let bench_idx = ((new_index / 2) as usize) % collators.len();
-			let benched = collators.remove(bench_idx);
-			Self::deposit_event(Event::CollatorBenched {
-				who: benched,
-				session_index: new_index,
-			});
+			if let Some(bench_idx) = T::BenchPolicy::select(new_index, &collators, &performance)
+				.filter(|bench_idx| *bench_idx < collators.len())
+			{
+				let benched = collators.remove(bench_idx);
+				Self::deposit_event(Event::CollatorBenched {
+					who: benched,
+					session_index: new_index,
+					measured_session,
+					performance,
+				});
+			}
 		}
+
+		SessionCollators::<T>::insert(new_index, BoundedVec::truncate_from(collators.clone()));
+
 		Some(collators)
 	}
 
@@ -58,6 +172,19 @@ impl<T: Config> SessionManager<T::AccountId> for Pallet<T> {
 	}
 
 	fn start_session(start_index: SessionIndex) {
+		CurrentSession::<T>::put(start_index);
 		T::Inner::start_session(start_index)
 	}
 }
+
+impl<T: Config> pallet_authorship::EventHandler<T::AccountId, BlockNumberFor<T>> for Pallet<T> {
+	fn note_author(author: T::AccountId) {
+		let session = CurrentSession::<T>::get();
+		AuthoredBlocks::<T>::mutate(session, author, |authored| *authored = authored.saturating_add(1));
+
+		frame_system::Pallet::<T>::register_extra_weight_unchecked(
+			T::DbWeight::get().reads_writes(2, 1),
+			DispatchClass::Mandatory,
+		);
+	}
+}
@@ -48,10 +48,20 @@ pub fn start_calls() -> Vec<SessionIndex> {
 	START_CALLS.with(|c| c.borrow().clone())
 }
 
-parameter_types! {}
+pub fn author_blocks(who: AccountId, blocks: u32) {
+	for _ in 0..blocks {
+		<CollatorRotation as pallet_authorship::EventHandler<AccountId, u64>>::note_author(who);
+	}
+}
+
+parameter_types! {
+	pub const MaxCollators: u32 = 10;
+}
 
 impl pallet_collator_rotation::Config for Test {
 	type Inner = MockInner;
+	type BenchPolicy = pallet_collator_rotation::LeastAuthored;
+	type MaxCollators = MaxCollators;
 }
 
 impl system::Config for Test {
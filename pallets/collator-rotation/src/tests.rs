@@ -4,7 +4,7 @@ use pallet_session::SessionManager;
 
 fn last_bench_event() -> Option<(AccountId, sp_staking::SessionIndex)> {
 	System::<Test>::events().into_iter().rev().find_map(|r| match r.event {
-		RuntimeEvent::CollatorRotation(Event::CollatorBenched { who, session_index }) => Some((who, session_index)),
+		RuntimeEvent::CollatorRotation(Event::CollatorBenched { who, session_index, .. }) => Some((who, session_index)),
 		_ => None,
 	})
 }
@@ -13,7 +13,9 @@ fn bench_events() -> Vec<(AccountId, sp_staking::SessionIndex)> {
 	System::<Test>::events()
 		.into_iter()
 		.filter_map(|r| match r.event {
-			RuntimeEvent::CollatorRotation(Event::CollatorBenched { who, session_index }) => Some((who, session_index)),
+			RuntimeEvent::CollatorRotation(Event::CollatorBenched { who, session_index, .. }) => {
+				Some((who, session_index))
+			}
 			_ => None,
 		})
 		.collect()
@@ -109,3 +111,122 @@ fn end_and_start_session_pass_through() {
 		assert_eq!(start_calls(), vec![7]);
 	});
 }
+
+fn rotate(session: sp_staking::SessionIndex) -> Option<Vec<AccountId>> {
+	<crate::Pallet<Test> as SessionManager<AccountId>>::end_session(session.saturating_sub(2));
+	<crate::Pallet<Test> as SessionManager<AccountId>>::start_session(session - 1);
+	<crate::Pallet<Test> as SessionManager<AccountId>>::new_session(session)
+}
+
+#[test]
+fn note_author_counts_blocks_in_current_session() {
+	ExtBuilder.build().execute_with(|| {
+		<crate::Pallet<Test> as SessionManager<AccountId>>::start_session(4);
+		author_blocks(1, 3);
+		author_blocks(2, 1);
+
+		assert_eq!(crate::AuthoredBlocks::<Test>::get(4, 1), 3);
+		assert_eq!(crate::AuthoredBlocks::<Test>::get(4, 2), 1);
+		assert_eq!(crate::AuthoredBlocks::<Test>::get(3, 1), 0);
+	});
+}
+
+#[test]
+fn benches_worst_performer_of_measured_session() {
+	ExtBuilder.build().execute_with(|| {
+		set_inner(Some(vec![1, 2, 3, 4, 5]));
+
+		crate::SessionCollators::<Test>::insert(1, frame_support::BoundedVec::truncate_from(vec![1, 2, 3, 4, 5]));
+		<crate::Pallet<Test> as SessionManager<AccountId>>::start_session(1);
+		author_blocks(1, 5);
+		author_blocks(2, 5);
+		author_blocks(3, 1);
+		author_blocks(4, 5);
+		author_blocks(5, 5);
+
+		let out = rotate(3).unwrap();
+
+		assert_eq!(out, vec![1, 2, 4, 5]);
+		let events = System::<Test>::events();
+		assert!(events.iter().any(|r| r.event
+			== RuntimeEvent::CollatorRotation(Event::CollatorBenched {
+				who: 3,
+				session_index: 3,
+				measured_session: Some(1),
+				performance: vec![(1, 5), (2, 5), (3, 1), (4, 5), (5, 5)],
+			})));
+
+		// data of the measured session is cleared
+		assert!(crate::SessionCollators::<Test>::get(1).is_none());
+		assert_eq!(crate::AuthoredBlocks::<Test>::get(1, 1), 0);
+	});
+}
+
+#[test]
+fn ties_between_worst_performers_fall_back_to_round_robin() {
+	ExtBuilder.build().execute_with(|| {
+		set_inner(Some(vec![1, 2, 3, 4, 5]));
+		crate::SessionCollators::<Test>::insert(3, frame_support::BoundedVec::truncate_from(vec![1, 2, 3, 4, 5]));
+		<crate::Pallet<Test> as SessionManager<AccountId>>::start_session(3);
+		author_blocks(1, 5);
+		author_blocks(2, 2);
+		author_blocks(3, 5);
+		author_blocks(4, 2);
+		author_blocks(5, 2);
+
+		// session 5 -> 5/2 = 2 -> worst [2, 4, 5][2 % 3 = 2] = 5
+		let out = <crate::Pallet<Test> as SessionManager<AccountId>>::new_session(5).unwrap();
+
+		assert_eq!(out, vec![1, 2, 3, 4]);
+		assert_eq!(last_bench_event(), Some((5, 5)));
+	});
+}
+
+#[test]
+fn collators_inactive_in_measured_session_are_not_benched_for_performance() {
+	ExtBuilder.build().execute_with(|| {
+		set_inner(Some(vec![1, 2, 3, 4, 5]));
+		// collator 3 was benched in session 1 and collator 5 has just joined
+		crate::SessionCollators::<Test>::insert(1, frame_support::BoundedVec::truncate_from(vec![1, 2, 4]));
+		<crate::Pallet<Test> as SessionManager<AccountId>>::start_session(1);
+		author_blocks(1, 4);
+		author_blocks(2, 3);
+		author_blocks(4, 4);
+
+		let out = <crate::Pallet<Test> as SessionManager<AccountId>>::new_session(3).unwrap();
+
+		assert_eq!(out, vec![1, 3, 4, 5]);
+		assert_eq!(last_bench_event(), Some((2, 3)));
+	});
+}
+
+#[test]
+fn round_robin_policy_ignores_performance() {
+	use crate::{BenchPolicy, RoundRobin};
+
+	let collators = vec![10, 20, 30];
+	let performance = vec![(10, 5), (20, 0), (30, 5)];
+	assert_eq!(
+		<RoundRobin as BenchPolicy<AccountId>>::select(7, &collators, &performance),
+		Some(0)
+	);
+	assert_eq!(<RoundRobin as BenchPolicy<AccountId>>::select(1, &[], &[]), None);
+}
+
+#[test]
+fn planned_collators_are_stored_for_every_session() {
+	ExtBuilder.build().execute_with(|| {
+		set_inner(Some(vec![1, 2, 3]));
+		<crate::Pallet<Test> as SessionManager<AccountId>>::new_session(2).unwrap();
+		<crate::Pallet<Test> as SessionManager<AccountId>>::new_session(3).unwrap();
+
+		assert_eq!(
+			crate::SessionCollators::<Test>::get(2).unwrap().into_inner(),
+			vec![1, 2, 3]
+		);
+		assert_eq!(
+			crate::SessionCollators::<Test>::get(3).unwrap().into_inner(),
+			vec![1, 3]
+		);
+	});
+}
@@ -38,6 +38,27 @@ fn fill_whitelist_storage<T: Config>(n: u32) {
 		assert_ok!(EmaOracle::<T>::add_oracle(RawOrigin::Root.into(), SOURCE, (HDX, i)));
 	}
 }
+
+/// Custom periods used in the benchmarks. Chosen to not collide with the named periods.
+fn custom_period(i: u32) -> PeriodBlocks {
+	1_000 + i
+}
+
+fn fill_custom_periods<T: Config>(n: u32) {
+	for i in 0..n {
+		assert_ok!(EmaOracle::<T>::add_period(RawOrigin::Root.into(), custom_period(i)));
+	}
+}
+fn fill_critical_pairs<T: Config>(n: u32) {
+	for i in 0..n {
+		assert_ok!(EmaOracle::<T>::add_critical_pair(
+			RawOrigin::Root.into(),
+			SOURCE,
+			(HDX, i)
+		));
+	}
+}
+
 benchmarks! {
 	add_oracle {
 		let max_entries = <<T as Config>::MaxUniqueEntries as Get<u32>>::get();
@@ -63,6 +84,43 @@ benchmarks! {
 		assert!(!WhitelistedAssets::<T>::get().contains(&(SOURCE, (HDX, DOT))));
 	}
 
+	add_period {
+		let max_periods = <<T as Config>::MaxCustomPeriods as Get<u32>>::get();
+		fill_custom_periods::<T>(max_periods - 1);
+
+		let period = custom_period(max_periods);
+	}: _(RawOrigin::Root, period)
+	verify {
+		assert!(CustomPeriods::<T>::get().contains_key(&period));
+	}
+
+	remove_period {
+		let max_periods = <<T as Config>::MaxCustomPeriods as Get<u32>>::get();
+		fill_custom_periods::<T>(max_periods);
+
+		let period = custom_period(0);
+	}: _(RawOrigin::Root, period)
+	verify {
+		assert!(!CustomPeriods::<T>::get().contains_key(&period));
+	}
+
+	add_critical_pair {
+		let max_entries = <<T as Config>::MaxUniqueEntries as Get<u32>>::get();
+		fill_critical_pairs::<T>(max_entries - 1);
+	}: _(RawOrigin::Root, SOURCE, (HDX, DOT))
+	verify {
+		assert!(CriticalPairs::<T>::get().contains(&(SOURCE, (HDX, DOT))));
+	}
+
+	remove_critical_pair {
+		let max_entries = <<T as Config>::MaxUniqueEntries as Get<u32>>::get();
+		fill_critical_pairs::<T>(max_entries - 1);
+		assert_ok!(EmaOracle::<T>::add_critical_pair(RawOrigin::Root.into(), SOURCE, (HDX, DOT)));
+	}: _(RawOrigin::Root, SOURCE, (HDX, DOT))
+	verify {
+		assert!(!CriticalPairs::<T>::get().contains(&(SOURCE, (HDX, DOT))));
+	}
+
 	on_finalize_no_entry {
 		let block_num: u32 = 5;
 	}: { EmaOracle::<T>::on_finalize(block_num.into()); }
@@ -73,6 +131,7 @@ benchmarks! {
 	on_finalize_insert_one_token {
 		let max_entries = <<T as Config>::MaxUniqueEntries as Get<u32>>::get();
 		fill_whitelist_storage::<T>(max_entries);
+		fill_custom_periods::<T>(<<T as Config>::MaxCustomPeriods as Get<u32>>::get());
 
 		let block_num: BlockNumberFor<T> = 5u32.into();
 		let prev_block = block_num.saturating_sub(One::one());
@@ -105,13 +164,14 @@ benchmarks! {
 	}: { EmaOracle::<T>::on_finalize(block_num); }
 	verify {
 		assert!(Accumulator::<T>::get().is_empty());
-		assert_eq!(Oracles::<T>::get((SOURCE, ordered_pair(HDX, DOT), OraclePeriod::LastBlock)).unwrap(), (entry, block_num));
+		assert_eq!(Oracles::<T>::get((SOURCE, ordered_pair(HDX, DOT), LAST_BLOCK_PERIOD)).unwrap(), (entry, block_num));
 	}
 
 	#[extra]
 	on_finalize_update_one_token {
 		let max_entries = <<T as Config>::MaxUniqueEntries as Get<u32>>::get();
 		fill_whitelist_storage::<T>(max_entries);
+		fill_custom_periods::<T>(<<T as Config>::MaxCustomPeriods as Get<u32>>::get());
 
 		let initial_data_block: BlockNumberFor<T> = 5u32.into();
 		// higher update time difference might make exponentiation more expensive
@@ -148,7 +208,7 @@ benchmarks! {
 	}: { EmaOracle::<T>::on_finalize(block_num); }
 	verify {
 		assert!(Accumulator::<T>::get().is_empty());
-		assert_eq!(Oracles::<T>::get((SOURCE, ordered_pair(HDX, DOT), OraclePeriod::LastBlock)).unwrap(), (entry, initial_data_block));
+		assert_eq!(Oracles::<T>::get((SOURCE, ordered_pair(HDX, DOT), LAST_BLOCK_PERIOD)).unwrap(), (entry, initial_data_block));
 	}
 
 	on_finalize_multiple_tokens {
@@ -156,6 +216,7 @@ benchmarks! {
 
 		let max_entries = <<T as Config>::MaxUniqueEntries as Get<u32>>::get();
 		fill_whitelist_storage::<T>(max_entries);
+		fill_custom_periods::<T>(<<T as Config>::MaxCustomPeriods as Get<u32>>::get());
 
 		let initial_data_block: BlockNumberFor<T> = 5u32.into();
 		let block_num = initial_data_block.saturating_add(1_000_000u32.into());
@@ -198,7 +259,7 @@ benchmarks! {
 		for i in 0 .. b {
 			let asset_a = i * 1_000;
 			let asset_b = asset_a + 500;
-			assert_eq!(Oracles::<T>::get((SOURCE, ordered_pair(asset_a, asset_b), OraclePeriod::LastBlock)).unwrap(), (entry.clone(), initial_data_block));
+			assert_eq!(Oracles::<T>::get((SOURCE, ordered_pair(asset_a, asset_b), LAST_BLOCK_PERIOD)).unwrap(), (entry.clone(), initial_data_block));
 		}
 	}
 
@@ -267,6 +328,64 @@ benchmarks! {
 		assert_eq!(Accumulator::<T>::get().into_inner(), entries.into_iter().collect());
 	}
 
+	on_trade_full_accumulator {
+		let max_entries = <<T as Config>::MaxUniqueEntries as Get<u32>>::get();
+		fill_whitelist_storage::<T>(max_entries);
+
+		let native = T::NativeAssetId::get();
+		let (amount_in, amount_out) = (1_000_000_000_000, 2_000_000_000_000);
+		let (liquidity_asset_in, liquidity_asset_out) = (1_000_000_000_000_000, 2_000_000_000_000_000);
+		// Asset ids are chosen to never collide with the native asset.
+		let pair = |i: u32| (i * 1_000 + 100, i * 1_000 + 600);
+
+		// Worst case: no entry is critical and every entry is valued in the native asset via the
+		// oracle of its second asset, as the first asset has none.
+		// The accumulator can't hold all seeding trades at once, so the incoming pair is seeded in
+		// the following block.
+		for (block, assets) in [(3u32, 0 .. max_entries), (4u32, max_entries .. max_entries + 1)] {
+			let seed_block: BlockNumberFor<T> = block.into();
+			frame_system::Pallet::<T>::set_block_number(seed_block);
+			EmaOracle::<T>::on_initialize(seed_block);
+			for i in assets {
+				let (asset_a, asset_b) = pair(i);
+				T::BenchmarkHelper::register_asset(asset_a)?;
+				T::BenchmarkHelper::register_asset(asset_b)?;
+				assert_ok!(OnActivityHandler::<T>::on_trade(
+					SOURCE, asset_b, native, amount_in, amount_out, liquidity_asset_in, liquidity_asset_out,
+					Price::new(liquidity_asset_in, liquidity_asset_out)));
+			}
+			EmaOracle::<T>::on_finalize(seed_block);
+		}
+
+		let block_num: BlockNumberFor<T> = 5u32.into();
+		frame_system::Pallet::<T>::set_block_number(block_num);
+		EmaOracle::<T>::on_initialize(block_num);
+		for i in 0 .. max_entries {
+			let (asset_a, asset_b) = pair(i);
+			assert_ok!(OnActivityHandler::<T>::on_trade(
+				SOURCE, asset_a, asset_b, amount_in, amount_out, liquidity_asset_in, liquidity_asset_out,
+				Price::new(liquidity_asset_in, liquidity_asset_out)));
+		}
+		assert_eq!(Accumulator::<T>::get().len(), max_entries as usize);
+
+		// The incoming entry is valued the same way and evicts the first entry as it has higher volume.
+		let (asset_a, asset_b) = pair(max_entries);
+		let res = core::cell::RefCell::new(Err(DispatchError::Other("Not initialized")));
+	}: {
+		let _ = res.replace(
+			OnActivityHandler::<T>::on_trade(
+				SOURCE, asset_a, asset_b, 2 * amount_in, 2 * amount_out, liquidity_asset_in, liquidity_asset_out,
+				Price::new(liquidity_asset_in, liquidity_asset_out))
+				.map_err(|(_w, e)| e)
+		);
+	}
+	verify {
+		assert_ok!(*res.borrow());
+		let accumulator = Accumulator::<T>::get();
+		assert!(accumulator.contains_key(&(SOURCE, ordered_pair(asset_a, asset_b))));
+		assert!(!accumulator.contains_key(&(SOURCE, pair(0))));
+	}
+
 	on_liquidity_changed_multiple_tokens {
 		let b in 1 .. (T::MaxUniqueEntries::get() - 1);
 
@@ -41,8 +41,9 @@
 //!   integrate into the average is multiplied with a smoothing factor between 0 and 1.
 //! - *Smoothing Factor*: A factor applied to each value aggregated into the averaging oracle.
 //!   Implicitly determines the oracle period.
-//! - *Period*: The window over which an oracle is averaged. Certain smoothing factors correspond to
-//!   an oracle period. E.g. ten minutes oracle period ≈ 0.0198
+//! - *Period*: The window over which an oracle is averaged, expressed in blocks. The smoothing factor
+//!   is derived from the period as `2 / (period + 1)`. E.g. ten minutes oracle period (100 blocks)
+//!   ≈ 0.0198
 //! - *Source*: The source of the data. E.g. xyk pallet.
 //!
 //! ### Implementation
@@ -53,10 +54,18 @@
 //! this aggregation is NOT based on EMA, yet, it just sums the volume and replaces price and
 //! liquidity with the most recent value.
 //!
+//! The accumulator holds at most `MaxUniqueEntries` entries. When it is full, the entry with the
+//! lowest volume is evicted to make room for an incoming entry with higher volume, otherwise the
+//! incoming entry is dropped. Pairs marked as critical by `AuthorityOrigin` are never evicted and
+//! always get a slot. Every dropped update is reported via the `OracleUpdateDropped` event.
+//!
 //! At the end of the block, all the entries are merged into permanent storage via the exponential
 //! moving average logic defined in the math package this pallet depens on. There is one oracle
 //! entry for each combination of `(source, asset_pair, period)` in storage.
 //!
+//! The tracked periods are the `SupportedPeriods` of the runtime configuration extended by custom
+//! periods added by `AuthorityOrigin` via `add_period`.
+//!
 //! Oracle values are accessed lazily. This means that the storage does not contain the most recent
 //! value, but the value calculated the last time it was updated via trade or liquidity change. On a
 //! read the values are read from storage and then fast-forwarded (assuming the volume to be zero
@@ -73,7 +82,9 @@ use frame_support::pallet_prelude::*;
 use frame_support::sp_runtime::traits::{BlockNumberProvider, One, Zero};
 use frame_support::traits::Contains;
 use frame_system::pallet_prelude::BlockNumberFor;
+use sp_arithmetic::helpers_128bit::multiply_by_rational_with_rounding;
 use sp_arithmetic::traits::Saturating;
+use sp_arithmetic::Rounding;
 use sp_std::marker::PhantomData;
 use sp_std::prelude::*;
 
@@ -88,8 +99,9 @@ pub mod weights;
 pub use weights::WeightInfo;
 
 mod benchmarking;
+pub mod migration;
 
-/// The maximum number of periods that could have corresponding oracles.
+/// The maximum number of named periods that could have corresponding oracles.
 pub const MAX_PERIODS: u32 = OraclePeriod::all_periods().len() as u32;
 
 const LOG_TARGET: &str = "runtime::ema-oracle";
@@ -115,7 +127,10 @@ pub mod pallet {
 	use frame_support::{BoundedBTreeMap, BoundedBTreeSet};
 	use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -130,8 +145,18 @@ pub mod pallet {
 		type BlockNumberProvider: BlockNumberProvider<BlockNumber = BlockNumberFor<Self>>;
 
 		/// The periods supported by the pallet. I.e. which oracles to track.
+		///
+		/// Must contain `OraclePeriod::LastBlock`.
 		type SupportedPeriods: Get<BoundedVec<OraclePeriod, ConstU32<MAX_PERIODS>>>;
 
+		/// Maximum number of custom periods that can be added on top of `SupportedPeriods`.
+		#[pallet::constant]
+		type MaxCustomPeriods: Get<u32>;
+
+		/// Native asset id. Volumes of accumulated entries are valued in it when the accumulator is full.
+		#[pallet::constant]
+		type NativeAssetId: Get<AssetId>;
+
 		/// Whitelist determining what oracles are tracked by the pallet.
 		type OracleWhitelist: Contains<(Source, AssetId, AssetId)>;
 
@@ -148,6 +173,16 @@ pub mod pallet {
 		TooManyUniqueEntries,
 		OnTradeValueZero,
 		OracleNotFound,
+		/// Period must be at least one block long.
+		InvalidPeriod,
+		/// Period is already tracked.
+		PeriodAlreadySupported,
+		/// Period is not a custom period.
+		PeriodNotFound,
+		/// Maximum number of custom periods reached.
+		TooManyPeriods,
+		/// Asset pair is not marked as critical.
+		CriticalPairNotFound,
 	}
 
 	#[pallet::event]
@@ -157,6 +192,16 @@ pub mod pallet {
 		AddedToWhitelist { source: Source, assets: (AssetId, AssetId) },
 		/// Oracle was removed from the whitelist.
 		RemovedFromWhitelist { source: Source, assets: (AssetId, AssetId) },
+		/// Custom oracle period was added.
+		PeriodAdded { period: PeriodBlocks },
+		/// Custom oracle period was removed.
+		PeriodRemoved { period: PeriodBlocks },
+		/// Asset pair was marked as critical.
+		CriticalPairAdded { source: Source, assets: (AssetId, AssetId) },
+		/// Asset pair is no longer marked as critical.
+		CriticalPairRemoved { source: Source, assets: (AssetId, AssetId) },
+		/// Oracle update of an asset pair was dropped in this block because the accumulator was full.
+		OracleUpdateDropped { source: Source, assets: (AssetId, AssetId) },
 	}
 
 	/// Accumulator for oracle data in current block that will be recorded at the end of the block.
@@ -168,7 +213,8 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
-	/// Oracle storage keyed by data source, involved asset ids and the period length of the oracle.
+	/// Oracle storage keyed by data source, involved asset ids and the period length of the oracle
+	/// in blocks.
 	///
 	/// Stores the data entry as well as the block number when the oracle was first initialized.
 	#[pallet::storage]
@@ -178,7 +224,7 @@ pub mod pallet {
 		(
 			NMapKey<Twox64Concat, Source>,
 			NMapKey<Twox64Concat, (AssetId, AssetId)>,
-			NMapKey<Twox64Concat, OraclePeriod>,
+			NMapKey<Twox64Concat, PeriodBlocks>,
 		),
 		(OracleEntry<BlockNumberFor<T>>, BlockNumberFor<T>),
 		OptionQuery,
@@ -189,10 +235,28 @@ pub mod pallet {
 	pub type WhitelistedAssets<T: Config> =
 		StorageValue<_, BoundedBTreeSet<(Source, (AssetId, AssetId)), T::MaxUniqueEntries>, ValueQuery>;
 
+	/// Asset pairs whose oracle updates always get a slot in the accumulator.
+	///
+	/// Bounded by `MaxUniqueEntries` so that a non-critical entry can always be evicted for a
+	/// critical one.
+	#[pallet::storage]
+	pub type CriticalPairs<T: Config> =
+		StorageValue<_, BoundedBTreeSet<(Source, (AssetId, AssetId)), T::MaxUniqueEntries>, ValueQuery>;
+
+	/// Custom periods tracked in addition to `SupportedPeriods`, with the block they were added in.
+	///
+	/// Oracle entries of a custom period initialized before that block are outdated leftovers of
+	/// a previous removal and are ignored.
+	#[pallet::storage]
+	pub type CustomPeriods<T: Config> =
+		StorageValue<_, BoundedBTreeMap<PeriodBlocks, BlockNumberFor<T>, T::MaxCustomPeriods>, ValueQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
 		pub initial_data: Vec<(Source, (AssetId, AssetId), Price, Liquidity<Balance>)>,
+		/// Oracles added to `WhitelistedAssets`.
+		pub whitelisted_assets: Vec<(Source, (AssetId, AssetId))>,
 		#[serde(skip)]
 		pub _marker: PhantomData<T>,
 	}
@@ -215,10 +279,23 @@ pub mod pallet {
 					}
 				};
 
-				for period in T::SupportedPeriods::get() {
-					Pallet::<T>::update_oracle(source, ordered_pair(asset_a, asset_b), period, entry.clone());
+				for (period, valid_since) in Pallet::<T>::supported_periods() {
+					Pallet::<T>::update_oracle(
+						source,
+						ordered_pair(asset_a, asset_b),
+						period,
+						valid_since,
+						entry.clone(),
+					);
 				}
 			}
+
+			WhitelistedAssets::<T>::mutate(|list| {
+				for &(source, (asset_a, asset_b)) in self.whitelisted_assets.iter() {
+					list.try_insert((source, ordered_pair(asset_a, asset_b)))
+						.expect("Too many whitelisted oracles in genesis");
+				}
+			});
 		}
 	}
 
@@ -238,6 +315,10 @@ pub mod pallet {
 				T::MaxUniqueEntries::get() > 0,
 				"At least one trade should be possible per block."
 			);
+			assert!(
+				T::SupportedPeriods::get().contains(&OraclePeriod::LastBlock),
+				"LastBlock oracle is required to update the other oracles."
+			);
 		}
 	}
 
@@ -274,7 +355,7 @@ pub mod pallet {
 			})?;
 
 			// remove oracle from the storage
-			for period in T::SupportedPeriods::get().into_iter() {
+			for (period, _) in Self::supported_periods() {
 				let _ = Accumulator::<T>::mutate(|accumulator| {
 					accumulator.remove(&(source, assets));
 					Ok::<(), ()>(())
@@ -286,33 +367,209 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Start tracking oracles of a custom period of `period` blocks for all oracles.
+		///
+		/// Oracles of the new period are initialized lazily with the next trade or liquidity change.
+		///
+		/// Emits `PeriodAdded` event when successful.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_period())]
+		pub fn add_period(origin: OriginFor<T>, period: PeriodBlocks) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(!period.is_zero(), Error::<T>::InvalidPeriod);
+			ensure!(
+				!T::SupportedPeriods::get().iter().any(|p| p.blocks() == period),
+				Error::<T>::PeriodAlreadySupported
+			);
+
+			CustomPeriods::<T>::try_mutate(|periods| -> DispatchResult {
+				ensure!(!periods.contains_key(&period), Error::<T>::PeriodAlreadySupported);
+				periods
+					.try_insert(period, T::BlockNumberProvider::current_block_number())
+					.map_err(|_| Error::<T>::TooManyPeriods)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::PeriodAdded { period });
+
+			Ok(())
+		}
+
+		/// Stop tracking oracles of a custom period.
+		///
+		/// Stored oracle entries of the period are not removed but are ignored from now on.
+		///
+		/// Emits `PeriodRemoved` event when successful.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_period())]
+		pub fn remove_period(origin: OriginFor<T>, period: PeriodBlocks) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			CustomPeriods::<T>::try_mutate(|periods| -> DispatchResult {
+				periods.remove(&period).ok_or(Error::<T>::PeriodNotFound)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::PeriodRemoved { period });
+
+			Ok(())
+		}
+
+		/// Mark oracle of an asset pair as critical.
+		///
+		/// Updates of critical oracles always get a slot in the accumulator, evicting the entry with
+		/// the lowest volume if it is full. The oracle still has to pass `OracleWhitelist`.
+		///
+		/// Emits `CriticalPairAdded` event when successful.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_critical_pair())]
+		pub fn add_critical_pair(origin: OriginFor<T>, source: Source, assets: (AssetId, AssetId)) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			let assets = ordered_pair(assets.0, assets.1);
+
+			CriticalPairs::<T>::try_mutate(|pairs| {
+				pairs
+					.try_insert((source, assets))
+					.map_err(|_| Error::<T>::TooManyUniqueEntries)
+			})?;
+
+			Self::deposit_event(Event::CriticalPairAdded { source, assets });
+
+			Ok(())
+		}
+
+		/// Remove the critical mark from oracle of an asset pair.
+		///
+		/// Emits `CriticalPairRemoved` event when successful.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_critical_pair())]
+		pub fn remove_critical_pair(
+			origin: OriginFor<T>,
+			source: Source,
+			assets: (AssetId, AssetId),
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			let assets = ordered_pair(assets.0, assets.1);
+
+			CriticalPairs::<T>::try_mutate(|pairs| {
+				ensure!(pairs.remove(&(source, assets)), Error::<T>::CriticalPairNotFound);
+				Ok::<(), DispatchError>(())
+			})?;
+
+			Self::deposit_event(Event::CriticalPairRemoved { source, assets });
+
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// Return all tracked periods in blocks together with the block since which their oracle
+	/// entries are valid. That is zero for `SupportedPeriods` and the block the period was added in
+	/// for custom periods.
+	pub fn supported_periods() -> Vec<(PeriodBlocks, BlockNumberFor<T>)> {
+		let mut periods: Vec<(PeriodBlocks, BlockNumberFor<T>)> = T::SupportedPeriods::get()
+			.into_iter()
+			.map(|p| (p.blocks(), BlockNumberFor::<T>::zero()))
+			.collect();
+		for (period, added_at) in CustomPeriods::<T>::get().into_iter() {
+			if !periods.iter().any(|(p, _)| *p == period) {
+				periods.push((period, added_at));
+			}
+		}
+		periods
+	}
+
+	/// Return the block since which oracle entries of the period are valid, `None` if the period
+	/// is not tracked. `CustomPeriods` is read only if the period is not in `SupportedPeriods`.
+	fn period_valid_since(period: PeriodBlocks) -> Option<BlockNumberFor<T>> {
+		if T::SupportedPeriods::get().iter().any(|p| p.blocks() == period) {
+			return Some(BlockNumberFor::<T>::zero());
+		}
+		CustomPeriods::<T>::get().get(&period).copied()
+	}
+
 	/// Insert or update data in the accumulator from received entry. Aggregates volume and
 	/// takes the most recent data for the rest.
-	pub(crate) fn on_entry(
-		src: Source,
-		assets: (AssetId, AssetId),
-		oracle_entry: OracleEntry<BlockNumberFor<T>>,
-	) -> Result<(), ()> {
+	///
+	/// If the accumulator is full, the entry with the lowest volume valued in the native asset is
+	/// evicted in favour of the incoming one if the incoming entry has higher value or belongs to a
+	/// critical pair. Otherwise the incoming entry is dropped.
+	pub(crate) fn on_entry(src: Source, assets: (AssetId, AssetId), oracle_entry: OracleEntry<BlockNumberFor<T>>) {
 		if !T::OracleWhitelist::contains(&(src, assets.0, assets.1)) {
 			// if we don't track oracle for given asset pair, don't throw error
-			return Ok(());
+			return;
 		}
 
-		Accumulator::<T>::mutate(|accumulator| {
+		let dropped = Accumulator::<T>::mutate(|accumulator| {
 			if let Some(entry) = accumulator.get_mut(&(src, assets)) {
 				entry.accumulate_volume_and_update_from(&oracle_entry);
-				Ok(())
-			} else {
-				accumulator
-					.try_insert((src, assets), oracle_entry)
-					.map(|_| ())
-					.map_err(|_| ())
+				return None;
 			}
-		})
+			if accumulator.len() < T::MaxUniqueEntries::get() as usize {
+				let _ = accumulator.try_insert((src, assets), oracle_entry);
+				return None;
+			}
+
+			let critical_pairs = CriticalPairs::<T>::get();
+			let incoming_is_critical = critical_pairs.contains(&(src, assets));
+			// There is always a non-critical entry for a critical incoming entry as the number of
+			// critical pairs is bounded by the accumulator size.
+			let lowest_volume = accumulator
+				.iter()
+				.filter(|(key, _)| !critical_pairs.contains(key))
+				.map(|((source, pair), entry)| ((*source, *pair), Self::native_volume(*source, *pair, entry)))
+				.min_by_key(|(_, volume)| *volume);
+
+			match lowest_volume {
+				Some((evicted, volume))
+					if incoming_is_critical || volume < Self::native_volume(src, assets, &oracle_entry) =>
+				{
+					accumulator.remove(&evicted);
+					let _ = accumulator.try_insert((src, assets), oracle_entry);
+					Some(evicted)
+				}
+				_ => Some((src, assets)),
+			}
+		});
+
+		if let Some((source, assets)) = dropped {
+			log::debug!(target: LOG_TARGET, "Accumulator full, dropping oracle update ({source:?}, {assets:?}).");
+			Self::deposit_event(Event::OracleUpdateDropped { source, assets });
+		}
+	}
+
+	/// Value the volume of an entry in the native asset so volumes of different pairs are comparable.
+	///
+	/// The volume of the native asset is used for pairs containing it. For other pairs the volume of
+	/// the first asset with a `LastBlock` oracle against the native asset is converted using the
+	/// price of that oracle. Entries which cannot be valued are worth zero.
+	fn native_volume(src: Source, assets: (AssetId, AssetId), entry: &OracleEntry<BlockNumberFor<T>>) -> Balance {
+		let native = T::NativeAssetId::get();
+		let (volume_a, volume_b) = entry.volume.cumulative_volume();
+		if assets.0 == native {
+			return volume_a;
+		}
+		if assets.1 == native {
+			return volume_b;
+		}
+		[(assets.0, volume_a), (assets.1, volume_b)]
+			.into_iter()
+			.find_map(|(asset, volume)| {
+				let (oracle, _) = Self::oracle((src, ordered_pair(asset, native), LAST_BLOCK_PERIOD))?;
+				// The price is the amount of the first asset of the ordered pair per unit of the second.
+				let (n, d) = if asset < native {
+					(oracle.price.d, oracle.price.n)
+				} else {
+					(oracle.price.n, oracle.price.d)
+				};
+				multiply_by_rational_with_rounding(volume, n, d, Rounding::Down)
+			})
+			.unwrap_or_default()
 	}
 
 	/// Insert or update data in the accumulator from received entry. Aggregates volume and
@@ -322,10 +579,8 @@ impl<T: Config> Pallet<T> {
 		assets: (AssetId, AssetId),
 		oracle_entry: OracleEntry<BlockNumberFor<T>>,
 	) -> Result<Weight, (Weight, DispatchError)> {
-		let weight = OnActivityHandler::<T>::on_trade_weight();
-		Self::on_entry(src, assets, oracle_entry)
-			.map(|_| weight)
-			.map_err(|_| (weight, Error::<T>::TooManyUniqueEntries.into()))
+		Self::on_entry(src, assets, oracle_entry);
+		Ok(OnActivityHandler::<T>::on_trade_weight())
 	}
 
 	/// Insert or update data in the accumulator from received entry. Aggregates volume and
@@ -335,10 +590,8 @@ impl<T: Config> Pallet<T> {
 		assets: (AssetId, AssetId),
 		oracle_entry: OracleEntry<BlockNumberFor<T>>,
 	) -> Result<Weight, (Weight, DispatchError)> {
-		let weight = OnActivityHandler::<T>::on_liquidity_changed_weight();
-		Self::on_entry(src, assets, oracle_entry)
-			.map(|_| weight)
-			.map_err(|_| (weight, Error::<T>::TooManyUniqueEntries.into()))
+		Self::on_entry(src, assets, oracle_entry);
+		Ok(OnActivityHandler::<T>::on_liquidity_changed_weight())
 	}
 
 	/// Return the current value of the `LastBlock` oracle for the given `source` and `assets`.
@@ -347,7 +600,7 @@ impl<T: Config> Pallet<T> {
 		assets: (AssetId, AssetId),
 		block: BlockNumberFor<T>,
 	) -> Option<(OracleEntry<BlockNumberFor<T>>, BlockNumberFor<T>)> {
-		Self::oracle((source, assets, OraclePeriod::LastBlock)).map(|(mut last_block, init)| {
+		Self::oracle((source, assets, LAST_BLOCK_PERIOD)).map(|(mut last_block, init)| {
 			// update the `LastBlock` oracle to the last block if it hasn't been updated for a while
 			// price and liquidity stay constant, volume becomes zero
 			if last_block.updated_at != block {
@@ -359,30 +612,41 @@ impl<T: Config> Pallet<T> {
 
 	/// Update oracles based on data accumulated during the block.
 	fn update_oracles_from_accumulator() {
-		for ((src, assets), oracle_entry) in Accumulator::<T>::take().into_iter() {
+		let accumulator = Accumulator::<T>::take();
+		if accumulator.is_empty() {
+			return;
+		}
+		let periods = Self::supported_periods();
+		for ((src, assets), oracle_entry) in accumulator.into_iter() {
 			// First we update the non-immediate oracles with the value of the `LastBlock` oracle.
-			for period in T::SupportedPeriods::get()
-				.into_iter()
-				.filter(|p| *p != OraclePeriod::LastBlock)
-			{
-				Self::update_oracle(src, assets, period, oracle_entry.clone());
+			for (period, valid_since) in periods.iter().filter(|(p, _)| *p != LAST_BLOCK_PERIOD) {
+				Self::update_oracle(src, assets, *period, *valid_since, oracle_entry.clone());
 			}
 			// As we use (the old value of) the `LastBlock` entry to update the other oracles it
 			// gets updated last.
-			Self::update_oracle(src, assets, OraclePeriod::LastBlock, oracle_entry.clone());
+			Self::update_oracle(
+				src,
+				assets,
+				LAST_BLOCK_PERIOD,
+				BlockNumberFor::<T>::zero(),
+				oracle_entry.clone(),
+			);
 		}
 	}
 
 	/// Update the oracle of the given source, assets and period with `oracle_entry`.
+	///
+	/// Entries initialized before `valid_since` are replaced by `incoming_entry`.
 	fn update_oracle(
 		src: Source,
 		assets: (AssetId, AssetId),
-		period: OraclePeriod,
+		period: PeriodBlocks,
+		valid_since: BlockNumberFor<T>,
 		incoming_entry: OracleEntry<BlockNumberFor<T>>,
 	) {
 		Oracles::<T>::mutate((src, assets, period), |oracle| {
-			// initialize the oracle entry if it doesn't exist
-			if oracle.is_none() {
+			// initialize the oracle entry if it doesn't exist or is outdated
+			if oracle.as_ref().is_none_or(|(_, init)| *init < valid_since) {
 				*oracle = Some((incoming_entry.clone(), T::BlockNumberProvider::current_block_number()));
 				return;
 			}
@@ -424,17 +688,21 @@ impl<T: Config> Pallet<T> {
 	fn get_updated_entry(
 		src: Source,
 		assets: (AssetId, AssetId),
-		period: OraclePeriod,
+		period: PeriodBlocks,
 	) -> Option<(OracleEntry<BlockNumberFor<T>>, BlockNumberFor<T>)> {
 		let parent = T::BlockNumberProvider::current_block_number().saturating_sub(One::one());
 		// First get the `LastBlock` oracle to calculate the updated values for the others.
 		let (last_block, last_block_init) = Self::last_block_oracle(src, assets, parent)?;
 		// If it was requested return it directly.
-		if period == OraclePeriod::LastBlock {
+		if period == LAST_BLOCK_PERIOD {
 			return Some((last_block, last_block_init));
 		}
 
+		let valid_since = Self::period_valid_since(period)?;
 		let (entry, init) = Self::oracle((src, assets, period))?;
+		if init < valid_since {
+			return None;
+		}
 		if entry.updated_at < parent {
 			entry.calculate_current_from_outdated(period, &last_block)
 		} else {
@@ -500,6 +768,7 @@ impl<T: Config> OnTradeHandler<AssetId, Balance, Price> for OnActivityHandler<T>
 		let max_entries = T::MaxUniqueEntries::get();
 		// on_trade + on_finalize / max_entries
 		T::WeightInfo::on_trade_multiple_tokens(max_entries)
+			.max(T::WeightInfo::on_trade_full_accumulator())
 			.saturating_add(fractional_on_finalize_weight::<T>(max_entries))
 	}
 }
@@ -539,6 +808,7 @@ impl<T: Config> OnLiquidityChangedHandler<AssetId, Balance, Price> for OnActivit
 		let max_entries = T::MaxUniqueEntries::get();
 		// on_liquidity + on_finalize / max_entries
 		T::WeightInfo::on_liquidity_changed_multiple_tokens(max_entries)
+			.max(T::WeightInfo::on_trade_full_accumulator())
 			.saturating_add(fractional_on_finalize_weight::<T>(max_entries))
 	}
 }
@@ -616,7 +886,7 @@ impl<T: Config> AggregatedOracle<AssetId, Balance, BlockNumberFor<T>, Price> for
 		if asset_a == asset_b {
 			return Err(OracleError::SameAsset);
 		};
-		Self::get_updated_entry(source, ordered_pair(asset_a, asset_b), period)
+		Self::get_updated_entry(source, ordered_pair(asset_a, asset_b), period.blocks())
 			.ok_or(OracleError::NotPresent)
 			.map(|(entry, initialized)| {
 				let entry = if (asset_a, asset_b) != ordered_pair(asset_a, asset_b) {
@@ -629,7 +899,8 @@ impl<T: Config> AggregatedOracle<AssetId, Balance, BlockNumberFor<T>, Price> for
 	}
 
 	fn get_entry_weight() -> Weight {
-		T::WeightInfo::get_entry()
+		// `get_entry` benchmark uses a supported period, custom periods need one more read.
+		T::WeightInfo::get_entry().saturating_add(T::DbWeight::get().reads(1))
 	}
 }
 
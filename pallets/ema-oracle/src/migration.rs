@@ -0,0 +1,105 @@
+// This file is part of pallet-ema-oracle.
+
+// Copyright (C) 2022-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{AssetId, Config, OracleEntry, OraclePeriod, Oracles, Pallet, Source, LOG_TARGET};
+use frame_support::{
+	pallet_prelude::*,
+	storage_alias,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+pub mod v1 {
+	use super::*;
+
+	/// `Oracles` storage keyed by the `OraclePeriod` enum.
+	#[storage_alias]
+	pub type Oracles<T: Config> = StorageNMap<
+		Pallet<T>,
+		(
+			NMapKey<Twox64Concat, Source>,
+			NMapKey<Twox64Concat, (AssetId, AssetId)>,
+			NMapKey<Twox64Concat, OraclePeriod>,
+		),
+		(OracleEntry<BlockNumberFor<T>>, BlockNumberFor<T>),
+		OptionQuery,
+	>;
+
+	pub fn pre_migrate<T: Config>() {
+		assert_eq!(StorageVersion::get::<Pallet<T>>(), 0, "Storage version too high.");
+
+		log::info!(target: LOG_TARGET, "EMA oracle migration to v1: PRE checks successful!");
+	}
+
+	/// Re-key all oracle entries from the `OraclePeriod` enum to the period length in blocks.
+	pub fn migrate<T: Config>() -> Weight {
+		log::info!(target: LOG_TARGET, "Running migration to v1 for EMA oracle");
+
+		// Collect first so that the old and new keys are not mixed while iterating the prefix.
+		let entries: Vec<_> = Oracles::<T>::drain().collect();
+		let count = entries.len() as u64;
+
+		for ((source, assets, period), value) in entries {
+			crate::Oracles::<T>::insert((source, assets, period.blocks()), value);
+		}
+
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		log::info!(target: LOG_TARGET, "EMA oracle migration to v1: {count} entries migrated");
+
+		T::DbWeight::get().reads_writes(count, count.saturating_mul(2).saturating_add(1))
+	}
+
+	pub fn post_migrate<T: Config>() {
+		assert_eq!(StorageVersion::get::<Pallet<T>>(), 1, "Unexpected storage version.");
+
+		log::info!(target: LOG_TARGET, "EMA oracle migration to v1: POST checks successful!");
+	}
+
+	/// Migrates `Oracles` keys from the `OraclePeriod` enum to the period length in blocks.
+	pub struct MigrateV0ToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateV0ToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 0 {
+				log::info!(target: LOG_TARGET, "EMA oracle migration to v1 skipped");
+				return T::DbWeight::get().reads(1);
+			}
+
+			migrate::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			pre_migrate::<T>();
+			Ok((Oracles::<T>::iter_keys().count() as u64).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			post_migrate::<T>();
+			let count = u64::decode(&mut &state[..]).map_err(|_| "Invalid pre-upgrade state")?;
+			ensure!(
+				crate::Oracles::<T>::iter_keys().count() as u64 == count,
+				"Number of oracle entries changed"
+			);
+			Ok(())
+		}
+	}
+}
@@ -180,3 +180,14 @@ fn on_liquidity_changed_should_include_whitelisted_oracle_for_correct_source() {
 		assert!(get_accumulator_entry([0; 8], (HDX, INSUFFICIENT_ASSET)).is_none());
 	});
 }
+
+#[test]
+fn genesis_config_should_whitelist_oracles_in_correct_order() {
+	ExtBuilder::default()
+		.with_whitelisted_assets(vec![(SOURCE, (DOT, HDX))])
+		.build()
+		.execute_with(|| {
+			assert!(WhitelistedAssets::<Test>::get().contains(&(SOURCE, (HDX, DOT))));
+			assert_eq!(WhitelistedAssets::<Test>::get().len(), 1);
+		});
+}
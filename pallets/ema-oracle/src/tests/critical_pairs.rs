@@ -0,0 +1,248 @@
+// This file is part of pallet-ema-oracle.
+
+// Copyright (C) 2022-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+pub use mock::{expect_events, RuntimeOrigin, Test, ACA, DOT, HDX};
+
+use frame_support::{assert_noop, assert_ok};
+use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+
+fn trade(asset_a: AssetId, asset_b: AssetId, amount: Balance) {
+	assert_ok!(OnActivityHandler::<Test>::on_trade(
+		SOURCE,
+		asset_a,
+		asset_b,
+		amount,
+		amount,
+		2_000,
+		2_000,
+		Price::new(2_000, 2_000),
+	));
+}
+
+fn fill_accumulator(amount: Balance) -> u32 {
+	let max_entries = <<Test as crate::Config>::MaxUniqueEntries as Get<u32>>::get();
+	for i in 0..max_entries {
+		trade(100 + 2 * i, 101 + 2 * i, amount + i as Balance);
+	}
+	max_entries
+}
+
+#[test]
+fn add_critical_pair_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(EmaOracle::add_critical_pair(RuntimeOrigin::root(), SOURCE, (DOT, HDX)));
+
+		assert!(CriticalPairs::<Test>::get().contains(&(SOURCE, ordered_pair(HDX, DOT))));
+		expect_events(vec![Event::CriticalPairAdded {
+			source: SOURCE,
+			assets: ordered_pair(HDX, DOT),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn add_critical_pair_should_fail_when_called_by_non_authority() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			EmaOracle::add_critical_pair(RuntimeOrigin::signed(1), SOURCE, (HDX, DOT)),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn add_critical_pair_should_fail_when_too_many_pairs() {
+	new_test_ext().execute_with(|| {
+		let max_entries = <<Test as crate::Config>::MaxUniqueEntries as Get<u32>>::get();
+		for i in 0..max_entries {
+			assert_ok!(EmaOracle::add_critical_pair(
+				RuntimeOrigin::root(),
+				SOURCE,
+				(HDX, 100 + i)
+			));
+		}
+
+		assert_noop!(
+			EmaOracle::add_critical_pair(RuntimeOrigin::root(), SOURCE, (HDX, DOT)),
+			Error::<Test>::TooManyUniqueEntries
+		);
+	});
+}
+
+#[test]
+fn remove_critical_pair_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(EmaOracle::add_critical_pair(RuntimeOrigin::root(), SOURCE, (HDX, DOT)));
+
+		assert_ok!(EmaOracle::remove_critical_pair(
+			RuntimeOrigin::root(),
+			SOURCE,
+			(DOT, HDX)
+		));
+
+		assert!(CriticalPairs::<Test>::get().is_empty());
+		expect_events(vec![
+			Event::CriticalPairAdded {
+				source: SOURCE,
+				assets: ordered_pair(HDX, DOT),
+			}
+			.into(),
+			Event::CriticalPairRemoved {
+				source: SOURCE,
+				assets: ordered_pair(HDX, DOT),
+			}
+			.into(),
+		]);
+	});
+}
+
+#[test]
+fn remove_critical_pair_should_fail_when_pair_is_not_critical() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			EmaOracle::remove_critical_pair(RuntimeOrigin::root(), SOURCE, (HDX, DOT)),
+			Error::<Test>::CriticalPairNotFound
+		);
+	});
+}
+
+#[test]
+fn full_accumulator_should_evict_lowest_volume_entry_for_higher_volume_update() {
+	new_test_ext().execute_with(|| {
+		let max_entries = fill_accumulator(1_000);
+
+		trade(HDX, DOT, 1_000_000);
+
+		let accumulator = Accumulator::<Test>::get();
+		assert_eq!(accumulator.len(), max_entries as usize);
+		assert!(accumulator.contains_key(&(SOURCE, ordered_pair(HDX, DOT))));
+		// the first entry has the lowest volume
+		assert!(!accumulator.contains_key(&(SOURCE, (100, 101))));
+		expect_events(vec![Event::OracleUpdateDropped {
+			source: SOURCE,
+			assets: (100, 101),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn full_accumulator_should_update_existing_entry() {
+	new_test_ext().execute_with(|| {
+		fill_accumulator(1_000);
+		let accumulator = Accumulator::<Test>::get();
+
+		trade(100, 101, 1);
+
+		let (volume_a, volume_b) = Accumulator::<Test>::get()
+			.get(&(SOURCE, (100, 101)))
+			.expect("entry should be updated")
+			.volume
+			.cumulative_volume();
+		assert_eq!(volume_a + volume_b, 2_002);
+		assert_eq!(Accumulator::<Test>::get().len(), accumulator.len());
+	});
+}
+
+#[test]
+fn full_accumulator_should_evict_lowest_volume_entry_for_critical_pair() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(EmaOracle::add_critical_pair(RuntimeOrigin::root(), SOURCE, (HDX, DOT)));
+		fill_accumulator(1_000);
+
+		trade(HDX, DOT, 1);
+
+		let accumulator = Accumulator::<Test>::get();
+		assert!(accumulator.contains_key(&(SOURCE, ordered_pair(HDX, DOT))));
+		assert!(!accumulator.contains_key(&(SOURCE, (100, 101))));
+	});
+}
+
+#[test]
+fn full_accumulator_should_never_evict_critical_pair() {
+	new_test_ext().execute_with(|| {
+		// the lowest volume entry is critical
+		assert_ok!(EmaOracle::add_critical_pair(RuntimeOrigin::root(), SOURCE, (100, 101)));
+		fill_accumulator(1_000);
+
+		trade(HDX, DOT, 1_000_000);
+
+		let accumulator = Accumulator::<Test>::get();
+		assert!(accumulator.contains_key(&(SOURCE, (100, 101))));
+		assert!(accumulator.contains_key(&(SOURCE, ordered_pair(HDX, DOT))));
+		// the second lowest entry is evicted instead
+		assert!(!accumulator.contains_key(&(SOURCE, (102, 103))));
+	});
+}
+
+#[test]
+fn full_accumulator_should_compare_volumes_valued_in_native_asset() {
+	new_test_ext().execute_with(|| {
+		const HDX_UNIT: Balance = 1_000_000_000_000;
+		const DOT_UNIT: Balance = 10_000_000_000;
+		const JUNK_UNIT: Balance = 1_000_000_000_000_000_000;
+		// 1 DOT = 5 HDX
+		assert_ok!(OnActivityHandler::<Test>::on_trade(
+			SOURCE,
+			HDX,
+			DOT,
+			5 * HDX_UNIT,
+			DOT_UNIT,
+			5_000 * HDX_UNIT,
+			1_000 * DOT_UNIT,
+			Price::new(5_000 * HDX_UNIT, 1_000 * DOT_UNIT),
+		));
+		EmaOracle::on_finalize(1);
+		System::set_block_number(2);
+		EmaOracle::on_initialize(2);
+
+		let max_entries = <<Test as crate::Config>::MaxUniqueEntries as Get<u32>>::get();
+		for i in 0..max_entries - 1 {
+			trade(HDX, 100 + i, 100 * HDX_UNIT);
+		}
+		// huge raw volume of an 18 decimals asset without a price in the native asset
+		trade(5_000, 5_001, 1_000_000 * JUNK_UNIT);
+
+		// 50 DOT are worth 250 HDX even though the raw volume is lower than the volume of the HDX pairs
+		trade(DOT, ACA, 50 * DOT_UNIT);
+
+		let accumulator = Accumulator::<Test>::get();
+		assert!(accumulator.contains_key(&(SOURCE, (DOT, ACA))));
+		assert!(!accumulator.contains_key(&(SOURCE, (5_000, 5_001))));
+
+		// 10 DOT are worth 50 HDX, less than any of the remaining entries
+		trade(DOT, 6_000, 10 * DOT_UNIT);
+
+		let accumulator = Accumulator::<Test>::get();
+		assert_eq!(accumulator.len(), max_entries as usize);
+		assert!(!accumulator.contains_key(&(SOURCE, (DOT, 6_000))));
+		expect_events(vec![
+			Event::OracleUpdateDropped {
+				source: SOURCE,
+				assets: (5_000, 5_001),
+			}
+			.into(),
+			Event::OracleUpdateDropped {
+				source: SOURCE,
+				assets: (DOT, 6_000),
+			}
+			.into(),
+		]);
+	});
+}
@@ -0,0 +1,250 @@
+// This file is part of pallet-ema-oracle.
+
+// Copyright (C) 2022-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+pub use mock::{expect_events, EmaOracle, RuntimeOrigin, System, Test, DOT, HDX};
+
+use frame_support::{assert_noop, assert_ok};
+use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+
+const THIRTY_MINUTES: PeriodBlocks = 300;
+
+fn trade_hdx_dot(block: BlockNumber, price: Price) {
+	System::set_block_number(block);
+	assert_ok!(OnActivityHandler::<Test>::on_trade(
+		SOURCE, HDX, DOT, 1_000, 500, 2_000, 1_000, price,
+	));
+	EmaOracle::on_finalize(block);
+}
+
+#[test]
+fn add_period_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(3);
+		assert_ok!(EmaOracle::add_period(RuntimeOrigin::root(), THIRTY_MINUTES));
+
+		assert_eq!(CustomPeriods::<Test>::get().get(&THIRTY_MINUTES), Some(&3));
+		expect_events(vec![Event::PeriodAdded { period: THIRTY_MINUTES }.into()]);
+	});
+}
+
+#[test]
+fn add_period_should_fail_when_called_by_non_authority() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			EmaOracle::add_period(RuntimeOrigin::signed(1), THIRTY_MINUTES),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn add_period_should_fail_when_period_is_invalid_or_already_supported() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			EmaOracle::add_period(RuntimeOrigin::root(), 0),
+			Error::<Test>::InvalidPeriod
+		);
+		// `TenMinutes` is in `SupportedPeriods` of the mock
+		assert_noop!(
+			EmaOracle::add_period(RuntimeOrigin::root(), TenMinutes.blocks()),
+			Error::<Test>::PeriodAlreadySupported
+		);
+
+		assert_ok!(EmaOracle::add_period(RuntimeOrigin::root(), THIRTY_MINUTES));
+		assert_noop!(
+			EmaOracle::add_period(RuntimeOrigin::root(), THIRTY_MINUTES),
+			Error::<Test>::PeriodAlreadySupported
+		);
+	});
+}
+
+#[test]
+fn add_period_should_fail_when_max_periods_reached() {
+	new_test_ext().execute_with(|| {
+		for period in 1_001..=1_004 {
+			assert_ok!(EmaOracle::add_period(RuntimeOrigin::root(), period));
+		}
+		assert_noop!(
+			EmaOracle::add_period(RuntimeOrigin::root(), 1_005),
+			Error::<Test>::TooManyPeriods
+		);
+	});
+}
+
+#[test]
+fn remove_period_should_work_only_for_custom_periods() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(EmaOracle::add_period(RuntimeOrigin::root(), THIRTY_MINUTES));
+
+		assert_noop!(
+			EmaOracle::remove_period(RuntimeOrigin::signed(1), THIRTY_MINUTES),
+			BadOrigin
+		);
+		assert_noop!(
+			EmaOracle::remove_period(RuntimeOrigin::root(), TenMinutes.blocks()),
+			Error::<Test>::PeriodNotFound
+		);
+
+		assert_ok!(EmaOracle::remove_period(RuntimeOrigin::root(), THIRTY_MINUTES));
+
+		assert!(CustomPeriods::<Test>::get().is_empty());
+		expect_events(vec![
+			Event::PeriodAdded { period: THIRTY_MINUTES }.into(),
+			Event::PeriodRemoved { period: THIRTY_MINUTES }.into(),
+		]);
+	});
+}
+
+#[test]
+fn custom_period_oracle_should_be_tracked() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(EmaOracle::add_period(RuntimeOrigin::root(), THIRTY_MINUTES));
+
+		trade_hdx_dot(1, Price::new(2_000, 1_000));
+		trade_hdx_dot(2, Price::new(4_000, 1_000));
+
+		assert!(Oracles::<Test>::get((SOURCE, (HDX, DOT), THIRTY_MINUTES)).is_some());
+
+		System::set_block_number(3);
+		let custom = EmaOracle::get_entry(HDX, DOT, Custom(THIRTY_MINUTES), SOURCE).unwrap();
+		let ten_minutes = EmaOracle::get_entry(HDX, DOT, TenMinutes, SOURCE).unwrap();
+		let last_block = EmaOracle::get_entry(HDX, DOT, LastBlock, SOURCE).unwrap();
+
+		// the longer the period, the slower the oracle follows the price
+		assert!(custom.price < ten_minutes.price);
+		assert!(ten_minutes.price < last_block.price);
+		assert_eq!(custom.oracle_age, 1);
+	});
+}
+
+#[test]
+fn named_period_should_be_available_when_added_as_custom_period() {
+	new_test_ext().execute_with(|| {
+		trade_hdx_dot(1, Price::new(2_000, 1_000));
+		System::set_block_number(2);
+		// `Hour` is not in `SupportedPeriods` of the mock
+		assert_eq!(
+			EmaOracle::get_entry(HDX, DOT, Hour, SOURCE),
+			Err(OracleError::NotPresent)
+		);
+
+		assert_ok!(EmaOracle::add_period(RuntimeOrigin::root(), Hour.blocks()));
+		trade_hdx_dot(2, Price::new(2_000, 1_000));
+
+		System::set_block_number(3);
+		assert_eq!(
+			EmaOracle::get_entry(HDX, DOT, Hour, SOURCE),
+			EmaOracle::get_entry(HDX, DOT, Custom(Hour.blocks()), SOURCE)
+		);
+		assert!(EmaOracle::get_entry(HDX, DOT, Hour, SOURCE).is_ok());
+	});
+}
+
+#[test]
+fn removed_period_should_not_be_tracked() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(EmaOracle::add_period(RuntimeOrigin::root(), THIRTY_MINUTES));
+		trade_hdx_dot(1, Price::new(2_000, 1_000));
+
+		assert_ok!(EmaOracle::remove_period(RuntimeOrigin::root(), THIRTY_MINUTES));
+		trade_hdx_dot(2, Price::new(4_000, 1_000));
+
+		System::set_block_number(3);
+		assert_eq!(
+			EmaOracle::get_entry(HDX, DOT, Custom(THIRTY_MINUTES), SOURCE),
+			Err(OracleError::NotPresent)
+		);
+		// the stored entry was not updated after the removal
+		assert_eq!(
+			Oracles::<Test>::get((SOURCE, (HDX, DOT), THIRTY_MINUTES)).map(|(e, _)| e.updated_at),
+			Some(1)
+		);
+	});
+}
+
+#[test]
+fn readded_period_should_ignore_outdated_entries() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(EmaOracle::add_period(RuntimeOrigin::root(), THIRTY_MINUTES));
+		trade_hdx_dot(1, Price::new(2_000, 1_000));
+		assert_ok!(EmaOracle::remove_period(RuntimeOrigin::root(), THIRTY_MINUTES));
+
+		System::set_block_number(10);
+		assert_ok!(EmaOracle::add_period(RuntimeOrigin::root(), THIRTY_MINUTES));
+
+		System::set_block_number(11);
+		// the entry from before the removal is ignored
+		assert_eq!(
+			EmaOracle::get_entry(HDX, DOT, Custom(THIRTY_MINUTES), SOURCE),
+			Err(OracleError::NotPresent)
+		);
+
+		// and replaced by the next update
+		trade_hdx_dot(11, Price::new(4_000, 1_000));
+		assert_eq!(
+			Oracles::<Test>::get((SOURCE, (HDX, DOT), THIRTY_MINUTES)),
+			Some((
+				OracleEntry {
+					price: Price::new(4_000, 1_000),
+					volume: Volume::from_a_in_b_out(1_000, 500),
+					liquidity: Liquidity::new(2_000, 1_000),
+					updated_at: 11,
+				},
+				11
+			))
+		);
+	});
+}
+
+#[test]
+fn migration_to_v1_should_rekey_oracles_by_period_blocks() {
+	new_test_ext().execute_with(|| {
+		use crate::migration::v1;
+		use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+		StorageVersion::new(0).put::<EmaOracle>();
+
+		let entry = (
+			OracleEntry {
+				price: Price::new(2_000, 1_000),
+				volume: Volume::default(),
+				liquidity: Liquidity::new(2_000, 1_000),
+				updated_at: 5,
+			},
+			1,
+		);
+		for period in [LastBlock, TenMinutes, Day, Week] {
+			v1::Oracles::<Test>::insert((SOURCE, (HDX, DOT), period), entry.clone());
+		}
+
+		v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(StorageVersion::get::<EmaOracle>(), 1);
+		assert_eq!(Oracles::<Test>::iter().count(), 4);
+		for period in [LastBlock, TenMinutes, Day, Week] {
+			assert_eq!(
+				Oracles::<Test>::get((SOURCE, (HDX, DOT), period.blocks())),
+				Some(entry.clone())
+			);
+		}
+	});
+}
@@ -119,10 +119,10 @@ proptest! {
 		start_oracle in oracle_entry_within_updated_at_range((0, 1_000)),
 		incoming_value in oracle_entry_within_updated_at_range((1_001, 100_000)),
 	) {
-		let next_oracle = start_oracle.calculate_current_from_outdated(TenMinutes, &incoming_value);
+		let next_oracle = start_oracle.calculate_current_from_outdated(TenMinutes.blocks(), &incoming_value);
 
 		let mut start_oracle = start_oracle;
-		start_oracle.update_outdated_to_current(TenMinutes, &incoming_value);
+		start_oracle.update_outdated_to_current(TenMinutes.blocks(), &incoming_value);
 		prop_assert_eq!(next_oracle, Some(start_oracle));
 	}
 }
@@ -133,10 +133,10 @@ proptest! {
 		start_oracle in oracle_entry_with_updated_at(10_000),
 		incoming_value in oracle_entry_with_updated_at(10_001),
 	) {
-		let next_oracle = start_oracle.calculate_new_by_integrating_incoming(TenMinutes, &incoming_value);
+		let next_oracle = start_oracle.calculate_new_by_integrating_incoming(TenMinutes.blocks(), &incoming_value);
 
 		let mut start_oracle = start_oracle;
-		start_oracle.update_to_new_by_integrating_incoming(TenMinutes, &incoming_value);
+		start_oracle.update_to_new_by_integrating_incoming(TenMinutes.blocks(), &incoming_value);
 		prop_assert_eq!(next_oracle, Some(start_oracle));
 	}
 }
@@ -155,7 +155,7 @@ proptest! {
 			EmaOracle::on_finalize(1);
 			let oracle_age: u32 = 98;
 			System::set_block_number(u64::from(oracle_age) + 2);
-			let smoothing = into_smoothing(LastBlock);
+			let smoothing = into_smoothing(LastBlock.blocks());
 			let price = Price::new(liquidity_hdx, liquidity_dot);
 			let volume = (amount_hdx, amount_dot, 0, 0);
 			let expected = AggregatedEntry {
@@ -167,7 +167,7 @@ proptest! {
 			};
 			prop_assert_eq!(EmaOracle::get_entry(HDX, DOT, LastBlock, SOURCE), Ok(expected));
 
-			let smoothing = into_smoothing(TenMinutes);
+			let smoothing = into_smoothing(TenMinutes.blocks());
 			let expected_ten_min = AggregatedEntry {
 				price: iterated_price_ema(oracle_age, price, price, smoothing),
 				volume: iterated_volume_ema(oracle_age, volume, smoothing).into(),
@@ -121,6 +121,7 @@ impl frame_system::Config for Test {
 
 parameter_types! {
 	pub SupportedPeriods: BoundedVec<OraclePeriod, ConstU32<MAX_PERIODS>> = bounded_vec![LastBlock, TenMinutes, Day, Week];
+	pub const NativeAssetId: AssetId = HDX;
 }
 
 pub struct OracleWhitelist;
@@ -134,6 +135,8 @@ impl Config for Test {
 	type AuthorityOrigin = EnsureRoot<AccountId>;
 	type BlockNumberProvider = System;
 	type SupportedPeriods = SupportedPeriods;
+	type MaxCustomPeriods = ConstU32<4>;
+	type NativeAssetId = NativeAssetId;
 	type OracleWhitelist = OracleWhitelist;
 	type MaxUniqueEntries = ConstU32<45>;
 	#[cfg(feature = "runtime-benchmarks")]
@@ -146,6 +149,7 @@ pub type InitialDataEntry = (Source, (AssetId, AssetId), Price, Liquidity<Balanc
 #[derive(Default)]
 pub struct ExtBuilder {
 	pub initial_data: Vec<InitialDataEntry>,
+	pub whitelisted_assets: Vec<(Source, (AssetId, AssetId))>,
 }
 
 impl ExtBuilder {
@@ -154,10 +158,16 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn with_whitelisted_assets(mut self, assets: Vec<(Source, (AssetId, AssetId))>) -> Self {
+		self.whitelisted_assets = assets;
+		self
+	}
+
 	pub fn build(self) -> sp_io::TestExternalities {
 		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 		crate::GenesisConfig::<Test> {
 			initial_data: self.initial_data,
+			whitelisted_assets: self.whitelisted_assets,
 			..Default::default()
 		}
 		.assimilate_storage(&mut t)
@@ -16,13 +16,16 @@
 // limitations under the License.
 
 mod add_and_remove_oracle;
+mod critical_pairs;
+mod custom_periods;
 mod invariants;
 mod mock;
 
 use super::*;
 use crate::OraclePeriod::*;
 pub use mock::{
-	BlockNumber, EmaOracle, ExtBuilder, System, Test, ACA, DOT, HDX, INSUFFICIENT_ASSET, ORACLE_ENTRY_1, ORACLE_ENTRY_2,
+	expect_events, BlockNumber, EmaOracle, ExtBuilder, System, Test, ACA, DOT, HDX, INSUFFICIENT_ASSET, ORACLE_ENTRY_1,
+	ORACLE_ENTRY_2,
 };
 
 use frame_support::{assert_noop, assert_ok, assert_storage_noop};
@@ -72,7 +75,7 @@ fn get_accumulator_entry(src: Source, (a, b): (AssetId, AssetId)) -> Option<Orac
 }
 
 fn get_oracle_entry(a: AssetId, b: AssetId, period: OraclePeriod) -> Option<OracleEntry<BlockNumber>> {
-	Oracles::<Test>::get((SOURCE, ordered_pair(a, b), period)).map(|(e, _)| e)
+	Oracles::<Test>::get((SOURCE, ordered_pair(a, b), period.blocks())).map(|(e, _)| e)
 }
 
 #[test]
@@ -393,7 +396,7 @@ fn on_trade_should_exclude_insufficient_assets() {
 }
 
 #[test]
-fn on_entry_should_error_on_accumulator_overflow() {
+fn on_entry_should_drop_update_with_lower_volume_on_accumulator_overflow() {
 	new_test_ext().execute_with(|| {
 		let max_entries = <<Test as crate::Config>::MaxUniqueEntries as Get<u32>>::get();
 		// let's fill the accumulator
@@ -409,21 +412,24 @@ fn on_entry_should_error_on_accumulator_overflow() {
 				Price::new(2_000, 2_000),
 			));
 		}
-		// on_trade should fail once the accumulator is full
-		assert_noop!(
-			OnActivityHandler::<Test>::on_trade(
-				SOURCE,
-				2 * max_entries,
-				2 * max_entries + 1,
-				1_000,
-				1_000,
-				2_000,
-				2_000,
-				Price::new(2_000, 2_000),
-			)
-			.map_err(|(_w, e)| e),
-			Error::<Test>::TooManyUniqueEntries
-		);
+		let accumulator = Accumulator::<Test>::get();
+		// on_trade should not fail once the accumulator is full but drop the update
+		assert_ok!(OnActivityHandler::<Test>::on_trade(
+			SOURCE,
+			2 * max_entries,
+			2 * max_entries + 1,
+			1_000,
+			1_000,
+			2_000,
+			2_000,
+			Price::new(2_000, 2_000),
+		));
+		assert_eq!(Accumulator::<Test>::get(), accumulator);
+		expect_events(vec![Event::OracleUpdateDropped {
+			source: SOURCE,
+			assets: (2 * max_entries, 2 * max_entries + 1),
+		}
+		.into()]);
 	});
 }
 
@@ -553,11 +559,11 @@ fn update_data_should_use_old_last_block_oracle_to_update_to_parent() {
 			};
 			let mut expected = ORACLE_ENTRY_1.clone();
 			expected
-				.update_to_new_by_integrating_incoming(period, &second_entry)
+				.update_to_new_by_integrating_incoming(period.blocks(), &second_entry)
 				.unwrap()
-				.update_outdated_to_current(period, &second_at_50)
+				.update_outdated_to_current(period.blocks(), &second_at_50)
 				.unwrap()
-				.update_to_new_by_integrating_incoming(period, &third_entry)
+				.update_to_new_by_integrating_incoming(period.blocks(), &third_entry)
 				.unwrap();
 			assert_eq!(
 				get_oracle_entry(HDX, DOT, period).unwrap(),
@@ -582,7 +588,7 @@ fn calculate_new_by_integrating_incoming_only_updates_updated_at_on_stable_value
 		updated_at: 6,
 		..start_oracle.clone()
 	};
-	let next_oracle = start_oracle.calculate_new_by_integrating_incoming(period, &next_value);
+	let next_oracle = start_oracle.calculate_new_by_integrating_incoming(period.blocks(), &next_value);
 	assert_eq!(next_oracle, Some(next_value));
 }
 
@@ -602,7 +608,7 @@ fn calculate_new_by_integrating_incoming_with_works() {
 		updated_at: 6,
 	};
 	let next_oracle = start_oracle
-		.calculate_new_by_integrating_incoming(TenMinutes, &next_value)
+		.calculate_new_by_integrating_incoming(TenMinutes.blocks(), &next_value)
 		.unwrap();
 	// ten minutes corresponds to 100 blocks which corresponds to a smoothing factor of
 	// `2 / 101 ≈ 1 / 50` which means that for an update from 50 to 151 we expect an update of
@@ -635,7 +641,7 @@ fn calculate_new_by_integrating_incoming_last_block_period_returns_new_value() {
 		liquidity: Liquidity::new(8_u128, 1_u128),
 		updated_at: 6,
 	};
-	let next_oracle = start_oracle.calculate_new_by_integrating_incoming(LastBlock, &next_value);
+	let next_oracle = start_oracle.calculate_new_by_integrating_incoming(LastBlock.blocks(), &next_value);
 	let expected_oracle = next_value;
 	assert_eq!(next_oracle, Some(expected_oracle));
 }
@@ -656,7 +662,7 @@ fn calculate_current_from_outdated_should_incorporate_longer_time_deltas() {
 		updated_at: 1_000,
 	};
 	let next_oracle = start_oracle
-		.calculate_current_from_outdated(period, &next_value)
+		.calculate_current_from_outdated(period.blocks(), &next_value)
 		.unwrap();
 	assert_price_approx_eq!(
 		next_oracle.price,
@@ -829,14 +835,20 @@ fn ema_update_should_return_none_if_new_entry_is_older() {
 		updated_at: 9,
 		..ORACLE_ENTRY_2
 	};
-	assert_eq!(entry.calculate_current_from_outdated(TenMinutes, &outdated_entry), None);
-	assert_eq!(entry.calculate_current_from_outdated(LastBlock, &outdated_entry), None);
 	assert_eq!(
-		entry.calculate_new_by_integrating_incoming(TenMinutes, &outdated_entry),
+		entry.calculate_current_from_outdated(TenMinutes.blocks(), &outdated_entry),
+		None
+	);
+	assert_eq!(
+		entry.calculate_current_from_outdated(LastBlock.blocks(), &outdated_entry),
 		None
 	);
 	assert_eq!(
-		entry.calculate_new_by_integrating_incoming(LastBlock, &outdated_entry),
+		entry.calculate_new_by_integrating_incoming(TenMinutes.blocks(), &outdated_entry),
+		None
+	);
+	assert_eq!(
+		entry.calculate_new_by_integrating_incoming(LastBlock.blocks(), &outdated_entry),
 		None
 	);
 	// same updated_at as current
@@ -844,29 +856,38 @@ fn ema_update_should_return_none_if_new_entry_is_older() {
 		updated_at: 10,
 		..ORACLE_ENTRY_2
 	};
-	assert_eq!(entry.calculate_current_from_outdated(TenMinutes, &outdated_entry), None);
-	assert_eq!(entry.calculate_current_from_outdated(LastBlock, &outdated_entry), None);
 	assert_eq!(
-		entry.calculate_new_by_integrating_incoming(TenMinutes, &outdated_entry),
+		entry.calculate_current_from_outdated(TenMinutes.blocks(), &outdated_entry),
+		None
+	);
+	assert_eq!(
+		entry.calculate_current_from_outdated(LastBlock.blocks(), &outdated_entry),
+		None
+	);
+	assert_eq!(
+		entry.calculate_new_by_integrating_incoming(TenMinutes.blocks(), &outdated_entry),
 		None
 	);
 	assert_eq!(
-		entry.calculate_new_by_integrating_incoming(LastBlock, &outdated_entry),
+		entry.calculate_new_by_integrating_incoming(LastBlock.blocks(), &outdated_entry),
 		None
 	);
 
 	assert_eq!(
-		entry.update_to_new_by_integrating_incoming(TenMinutes, &outdated_entry),
+		entry.update_to_new_by_integrating_incoming(TenMinutes.blocks(), &outdated_entry),
 		None
 	);
 	assert_eq!(entry, original);
-	assert_eq!(entry.update_outdated_to_current(TenMinutes, &outdated_entry), None);
+	assert_eq!(
+		entry.update_outdated_to_current(TenMinutes.blocks(), &outdated_entry),
+		None
+	);
 	assert_eq!(entry, original);
 }
 
 #[test]
 fn check_period_smoothing_factors() {
-	use hydra_dx_math::ema::smoothing_from_period;
+	use hydra_dx_math::types::Fraction;
 
 	// We assume a 6 second block time.
 	let secs_per_block = 6;
@@ -874,27 +895,24 @@ fn check_period_smoothing_factors() {
 	let hours = 60 * minutes;
 	let days = 24 * hours;
 
-	let last_block = smoothing_from_period(1);
-	println!("Last Block: {} (bits: {})", last_block, last_block.to_bits());
-	assert_eq!(into_smoothing(LastBlock), last_block);
-
-	let short = smoothing_from_period(9);
-	println!("Short: {} (bits: {})", short, short.to_bits());
-	assert_eq!(into_smoothing(Short), short);
-
-	let ten_minutes = smoothing_from_period(10 * minutes);
-	println!("Ten Minutes: {} (bits: {})", ten_minutes, ten_minutes.to_bits());
-	assert_eq!(into_smoothing(TenMinutes), ten_minutes);
-
-	let hour = smoothing_from_period(hours);
-	println!("Hour: {} (bits: {})", hour, hour.to_bits());
-	assert_eq!(into_smoothing(Hour), hour);
-
-	let day = smoothing_from_period(days);
-	println!("Day: {} (bits: {})", day, day.to_bits());
-	assert_eq!(into_smoothing(Day), day);
-
-	let week = smoothing_from_period(7 * days);
-	println!("Week: {} (bits: {})", week, week.to_bits());
-	assert_eq!(into_smoothing(Week), week);
+	assert_eq!(LastBlock.blocks(), 1);
+	assert_eq!(Short.blocks(), 9);
+	assert_eq!(TenMinutes.blocks(), 10 * minutes);
+	assert_eq!(Hour.blocks(), hours);
+	assert_eq!(Day.blocks(), days);
+	assert_eq!(Week.blocks(), 7 * days);
+	assert_eq!(Custom(300).blocks(), 300);
+
+	// smoothing factors of the named periods used before periods were expressed in blocks
+	let expected = [
+		(LastBlock, 170141183460469231731687303715884105728),
+		(Short, 34028236692093846346337460743176821146),
+		(TenMinutes, 3369132345751865974884897103284833777),
+		(Hour, 566193622164623067326746434994622648),
+		(Day, 23629079016800115510268356880200556),
+		(Week, 3375783642235081630771268215908257),
+	];
+	for (period, bits) in expected {
+		assert_eq!(into_smoothing(period.blocks()), Fraction::from_bits(bits), "{period:?}");
+	}
 }
@@ -19,6 +19,7 @@ use basilisk_math::ema::{calculate_new_by_integrating_incoming, update_outdated_
 use basilisk_traits::oracle::{AggregatedEntry, Liquidity, Volume};
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::sp_runtime::RuntimeDebug;
+use hydra_dx_math::ema::smoothing_from_period;
 use hydra_dx_math::types::Fraction;
 use scale_info::TypeInfo;
 use sp_arithmetic::traits::{AtLeast32BitUnsigned, SaturatedConversion, UniqueSaturatedInto};
@@ -32,6 +33,10 @@ use serde::{Deserialize, Serialize};
 
 pub type AssetId = u32;
 pub type Balance = u128;
+/// Length of an oracle period in blocks.
+pub type PeriodBlocks = u32;
+/// Period length of the `LastBlock` oracle.
+pub const LAST_BLOCK_PERIOD: PeriodBlocks = OraclePeriod::LastBlock.blocks();
 /// A price is a tuple of two `u128`s representing the numerator and denominator of a rational number.
 pub type Price = EmaPrice;
 
@@ -130,13 +135,13 @@ where
 	/// Returns `None` if any of the calculations fail (including the `incoming` entry not being
 	/// one iteration (block) more recent than `self`).
 	///
-	/// The period is used to determine the smoothing factor alpha for an exponential moving average.
-	pub fn calculate_new_by_integrating_incoming(&self, period: OraclePeriod, incoming: &Self) -> Option<Self> {
+	/// The period (in blocks) is used to determine the smoothing factor alpha for an exponential moving average.
+	pub fn calculate_new_by_integrating_incoming(&self, period: PeriodBlocks, incoming: &Self) -> Option<Self> {
 		// incoming should be one step ahead of the previous value
 		if !incoming.updated_at.checked_sub(&self.updated_at)?.is_one() {
 			return None;
 		}
-		if period == OraclePeriod::LastBlock {
+		if period == LAST_BLOCK_PERIOD {
 			return Some(incoming.clone());
 		}
 		// determine smoothing factor
@@ -155,7 +160,7 @@ where
 	/// Update `self` based on a previous (`self`) and an `incoming` oracle entry as well as  a `period`.
 	pub fn update_to_new_by_integrating_incoming(
 		&mut self,
-		period: OraclePeriod,
+		period: PeriodBlocks,
 		incoming: &Self,
 	) -> Option<&mut Self> {
 		*self = self.calculate_new_by_integrating_incoming(period, incoming)?;
@@ -168,15 +173,15 @@ where
 	/// Returns `None` if any of the calculations fail (including the `update_with` entry not being
 	/// more recent than `self`).
 	///
-	/// The period is used to determine the smoothing factor alpha for an exponential moving average.
+	/// The period (in blocks) is used to determine the smoothing factor alpha for an exponential moving average.
 	///
 	/// Uses the difference between `updated_at` to determine the time (i.e. iterations) to cover.
-	pub fn calculate_current_from_outdated(&self, period: OraclePeriod, update_with: &Self) -> Option<Self> {
+	pub fn calculate_current_from_outdated(&self, period: PeriodBlocks, update_with: &Self) -> Option<Self> {
 		let iterations = update_with.updated_at.checked_sub(&self.updated_at)?;
 		if iterations.is_zero() {
 			return None;
 		}
-		if period == OraclePeriod::LastBlock {
+		if period == LAST_BLOCK_PERIOD {
 			return Some(update_with.clone());
 		}
 		// determine smoothing factor
@@ -198,23 +203,15 @@ where
 
 	/// Update `self` based on a previous (`self`) and an `update_with` entry as well as a `period`.
 	/// See [`calculate_current_from_outdated`].
-	pub fn update_outdated_to_current(&mut self, period: OraclePeriod, update_with: &Self) -> Option<&mut Self> {
+	pub fn update_outdated_to_current(&mut self, period: PeriodBlocks, update_with: &Self) -> Option<&mut Self> {
 		*self = self.calculate_current_from_outdated(period, update_with)?;
 		Some(self)
 	}
 }
 
-/// Convert a given `period` into the smoothing factor used in the weighted average.
-/// See [`check_period_smoothing_factors`] for how the values are generated.
-pub fn into_smoothing(period: OraclePeriod) -> Fraction {
-	match period {
-		OraclePeriod::LastBlock => Fraction::from_bits(170141183460469231731687303715884105728),
-		OraclePeriod::Short => Fraction::from_bits(34028236692093846346337460743176821146),
-		OraclePeriod::TenMinutes => Fraction::from_bits(3369132345751865974884897103284833777),
-		OraclePeriod::Hour => Fraction::from_bits(566193622164623067326746434994622648),
-		OraclePeriod::Day => Fraction::from_bits(23629079016800115510268356880200556),
-		OraclePeriod::Week => Fraction::from_bits(3375783642235081630771268215908257),
-	}
+/// Convert a given `period` in blocks into the smoothing factor used in the weighted average.
+pub fn into_smoothing(period: PeriodBlocks) -> Fraction {
+	smoothing_from_period(period.into())
 }
 
 impl<BlockNumber> From<(Price, Volume<Balance>, Liquidity<Balance>, BlockNumber)> for OracleEntry<BlockNumber> {
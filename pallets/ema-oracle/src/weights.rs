@@ -13,9 +13,14 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn add_oracle() -> Weight;
 	fn remove_oracle() -> Weight;
+	fn add_period() -> Weight;
+	fn remove_period() -> Weight;
+	fn add_critical_pair() -> Weight;
+	fn remove_critical_pair() -> Weight;
 	fn on_finalize_no_entry() -> Weight;
 	fn on_finalize_multiple_tokens(b: u32) -> Weight;
 	fn on_trade_multiple_tokens(b: u32) -> Weight;
+	fn on_trade_full_accumulator() -> Weight;
 	fn on_liquidity_changed_multiple_tokens(b: u32) -> Weight;
 	fn get_entry() -> Weight;
 }
@@ -48,6 +53,38 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
+	/// Storage: `EmaOracle::CustomPeriods` (r:1 w:1)
+	/// Proof: `EmaOracle::CustomPeriods` (`max_values`: Some(1), `max_size`: Some(41), added: 536, mode: `MaxEncodedLen`)
+	fn add_period() -> Weight {
+		// Not benchmarked yet, estimated from add_oracle.
+		Weight::from_parts(12_170_000, 1526)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EmaOracle::CustomPeriods` (r:1 w:1)
+	/// Proof: `EmaOracle::CustomPeriods` (`max_values`: Some(1), `max_size`: Some(41), added: 536, mode: `MaxEncodedLen`)
+	fn remove_period() -> Weight {
+		// Not benchmarked yet, estimated from add_oracle.
+		Weight::from_parts(11_782_000, 1526)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EmaOracle::CriticalPairs` (r:1 w:1)
+	/// Proof: `EmaOracle::CriticalPairs` (`max_values`: Some(1), `max_size`: Some(641), added: 1136, mode: `MaxEncodedLen`)
+	fn add_critical_pair() -> Weight {
+		// Not benchmarked yet, estimated from add_oracle.
+		Weight::from_parts(18_602_000, 2126)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EmaOracle::CriticalPairs` (r:1 w:1)
+	/// Proof: `EmaOracle::CriticalPairs` (`max_values`: Some(1), `max_size`: Some(641), added: 1136, mode: `MaxEncodedLen`)
+	fn remove_critical_pair() -> Weight {
+		// Not benchmarked yet, estimated from remove_oracle.
+		Weight::from_parts(19_045_000, 2126)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 	/// Storage: `EmaOracle::Accumulator` (r:1 w:0)
 	/// Proof: `EmaOracle::Accumulator` (`max_values`: Some(1), `max_size`: Some(5921), added: 6416, mode: `MaxEncodedLen`)
 	fn on_finalize_no_entry() -> Weight {
@@ -93,6 +130,22 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `EmaOracle::WhitelistedAssets` (r:1 w:0)
+	/// Proof: `EmaOracle::WhitelistedAssets` (`max_values`: Some(1), `max_size`: Some(641), added: 1136, mode: `MaxEncodedLen`)
+	/// Storage: `EmaOracle::Accumulator` (r:1 w:1)
+	/// Proof: `EmaOracle::Accumulator` (`max_values`: Some(1), `max_size`: Some(5921), added: 6416, mode: `MaxEncodedLen`)
+	/// Storage: `EmaOracle::CriticalPairs` (r:1 w:0)
+	/// Proof: `EmaOracle::CriticalPairs` (`max_values`: Some(1), `max_size`: Some(641), added: 1136, mode: `MaxEncodedLen`)
+	/// Storage: `EmaOracle::Oracles` (r:92 w:0)
+	/// Proof: `EmaOracle::Oracles` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn on_trade_full_accumulator() -> Weight {
+		// Not benchmarked yet, estimated from on_trade_multiple_tokens plus two `Oracles` reads per valued entry.
+		Weight::from_parts(168_104_000, 251390)
+			.saturating_add(RocksDbWeight::get().reads(96_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
 	/// Storage: `EmaOracle::Accumulator` (r:1 w:1)
@@ -21,7 +21,8 @@ use crate::Pallet as LBP;
 
 use basilisk_traits::router::{PoolType, TradeExecution};
 
-use frame_benchmarking::{account, benchmarks};
+use frame_benchmarking::{account, benchmarks, BenchmarkError};
+use frame_support::{traits::ConstU32, BoundedVec};
 use frame_system::RawOrigin;
 
 const SEED: u32 = 1;
@@ -36,6 +37,84 @@ const FINAL_WEIGHT: LBPWeight = 90_000_000;
 
 const DEFAULT_FEE: (u32, u32) = (2, 1_000);
 
+fn xyk_migration<T: Config>() -> XYKMigration<BlockNumberFor<T>> {
+	XYKMigration {
+		liquidity_share: Permill::from_percent(50),
+		shares_lock: Some(SharesLock::VestedUntil(BlockNumberFor::<T>::from(100u32))),
+	}
+}
+
+fn sale_restrictions<T: Config>() -> SaleRestrictions<BlockNumberFor<T>> {
+	SaleRestrictions {
+		max_purchase_per_account: Some(ASSET_A_AMOUNT),
+		allowlist: Some(Allowlist::Origin),
+		min_blocks_between_trades: Some(BlockNumberFor::<T>::from(1u32)),
+	}
+}
+
+fn sale_caps() -> SaleCaps {
+	SaleCaps {
+		soft_cap: Some(ASSET_A_AMOUNT),
+		hard_cap: Some(ASSET_A_AMOUNT * 10),
+	}
+}
+
+fn sale_vesting<T: Config>() -> SaleVesting<BlockNumberFor<T>> {
+	SaleVesting {
+		cliff: BlockNumberFor::<T>::from(10u32),
+		duration: BlockNumberFor::<T>::from(100u32),
+	}
+}
+
+fn ended_pool_with_migration<T: Config>(caller: T::AccountId) -> Result<PoolId<T>, BenchmarkError> {
+	T::BenchmarkHelper::register_asset(ASSET_A_ID)?;
+	T::BenchmarkHelper::register_asset(ASSET_B_ID)?;
+
+	let fee_collector = funded_account::<T>("fee_collector", 0);
+	let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+
+	LBP::<T>::create_pool(
+		RawOrigin::Root.into(),
+		caller.clone(),
+		ASSET_A_ID,
+		ASSET_A_AMOUNT,
+		ASSET_B_ID,
+		ASSET_B_AMOUNT,
+		INITIAL_WEIGHT,
+		FINAL_WEIGHT,
+		WeightCurveType::Linear,
+		DEFAULT_FEE,
+		fee_collector,
+		0,
+	)?;
+	ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
+
+	let start = BlockNumberFor::<T>::from(1u32);
+	let end = BlockNumberFor::<T>::from(11u32);
+
+	LBP::<T>::update_pool_data(
+		RawOrigin::Signed(caller.clone()).into(),
+		pool_id.clone(),
+		None,
+		Some(start),
+		Some(end),
+		None,
+		None,
+		None,
+		None,
+		None,
+	)?;
+	LBP::<T>::set_xyk_migration(
+		RawOrigin::Signed(caller).into(),
+		pool_id.clone(),
+		Some(xyk_migration::<T>()),
+	)?;
+
+	frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(12u32));
+
+	Ok(pool_id)
+}
+
 fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
 	let caller: T::AccountId = account(name, index, SEED);
 	T::MultiCurrency::update_balance(ASSET_HDX, &caller, 1_000_000_000_000_000).unwrap();
@@ -107,19 +186,28 @@ benchmarks! {
 		let caller = funded_account::<T>("caller", 0);
 		let fee_collector = funded_account::<T>("fee_collector", 0);
 		let asset_in: AssetId = ASSET_A_ID;
-		let asset_out: AssetId = ASSET_B_ID;
+		let asset_out: AssetId = ASSET_HDX;
 		let amount : Balance = 100_000_000;
 		let max_limit: Balance = 10_000_000;
 
-		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_HDX);
 
-		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, fee_collector, 0)?;
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_HDX, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, fee_collector, 0)?;
 		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
 
 		let start = BlockNumberFor::<T>::from(1u32);
 		let end = BlockNumberFor::<T>::from(11u32);
 
-		LBP::<T>::update_pool_data(RawOrigin::Signed(caller.clone()).into(), pool_id, None, Some(start), Some(end), None, None, None, None, None)?;
+		LBP::<T>::update_pool_data(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), None, Some(start), Some(end), None, None, None, None, None)?;
+
+		// worst case: trade is checked against all restrictions of the sale
+		LBP::<T>::set_sale_restrictions(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), Some(sale_restrictions::<T>()))?;
+		let (sale_id, _) = PoolRestrictions::<T>::get(&pool_id).ok_or(BenchmarkError::Stop("Restrictions not set."))?;
+		Allowlisted::<T>::insert(sale_id, &caller, true);
+		// soft cap can not be combined with vesting, contributions are still recorded for the hard cap
+		LBP::<T>::set_sale_caps(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), Some(SaleCaps { soft_cap: None, ..sale_caps() }))?;
+		// worst case: bought native asset is transferred with a new vesting schedule
+		LBP::<T>::set_sale_vesting(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), Some(sale_vesting::<T>()))?;
 
 		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(2u32));
 
@@ -133,18 +221,27 @@ benchmarks! {
 		let caller = funded_account::<T>("caller", 0);
 		let fee_collector = funded_account::<T>("fee_collector", 0);
 		let asset_in: AssetId = ASSET_A_ID;
-		let asset_out: AssetId = ASSET_B_ID;
+		let asset_out: AssetId = ASSET_HDX;
 		let amount : Balance = 100_000_000;
 		let max_limit: Balance = 1_000_000_000;
-		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_HDX);
 
-		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, fee_collector, 0)?;
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_HDX, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, fee_collector, 0)?;
 		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
 
 		let start = BlockNumberFor::<T>::from(1u32);
 		let end = BlockNumberFor::<T>::from(11u32);
 
-		LBP::<T>::update_pool_data(RawOrigin::Signed(caller.clone()).into(), pool_id, None, Some(start), Some(end), None, None, None, None, None)?;
+		LBP::<T>::update_pool_data(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), None, Some(start), Some(end), None, None, None, None, None)?;
+
+		// worst case: trade is checked against all restrictions of the sale
+		LBP::<T>::set_sale_restrictions(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), Some(sale_restrictions::<T>()))?;
+		let (sale_id, _) = PoolRestrictions::<T>::get(&pool_id).ok_or(BenchmarkError::Stop("Restrictions not set."))?;
+		Allowlisted::<T>::insert(sale_id, &caller, true);
+		// soft cap can not be combined with vesting, contributions are still recorded for the hard cap
+		LBP::<T>::set_sale_caps(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), Some(SaleCaps { soft_cap: None, ..sale_caps() }))?;
+		// worst case: bought native asset is transferred with a new vesting schedule
+		LBP::<T>::set_sale_vesting(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), Some(sale_vesting::<T>()))?;
 
 		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(2u32));
 
@@ -163,19 +260,22 @@ benchmarks! {
 		let caller = funded_account::<T>("caller", 0);
 		let fee_collector = funded_account::<T>("fee_collector", 0);
 		let asset_in: AssetId = ASSET_A_ID;
-		let asset_out: AssetId = ASSET_B_ID;
+		let asset_out: AssetId = ASSET_HDX;
 		let amount : Balance = 100_000_000;
 		let max_limit: Balance = 10_000_000;
 
-		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_HDX);
 
-		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, fee_collector, 0)?;
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_HDX, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, fee_collector, 0)?;
 		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
 
 		let start = BlockNumberFor::<T>::from(1u32);
 		let end = BlockNumberFor::<T>::from(11u32);
 
-		LBP::<T>::update_pool_data(RawOrigin::Signed(caller.clone()).into(), pool_id, None, Some(start), Some(end), None, None, None, None, None)?;
+		LBP::<T>::update_pool_data(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), None, Some(start), Some(end), None, None, None, None, None)?;
+
+		// worst case: bought native asset is transferred with a new vesting schedule
+		LBP::<T>::set_sale_vesting(RawOrigin::Signed(caller.clone()).into(), pool_id, Some(sale_vesting::<T>()))?;
 
 		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(2u32));
 
@@ -201,19 +301,22 @@ benchmarks! {
 		let caller = funded_account::<T>("caller", 0);
 		let fee_collector = funded_account::<T>("fee_collector", 0);
 		let asset_in: AssetId = ASSET_A_ID;
-		let asset_out: AssetId = ASSET_B_ID;
+		let asset_out: AssetId = ASSET_HDX;
 		let amount : Balance = 100_000_000;
 		let max_limit: Balance = 1_000_000_000;
 
-		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_HDX);
 
-		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, fee_collector, 0)?;
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_HDX, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, fee_collector, 0)?;
 		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
 
 		let start = BlockNumberFor::<T>::from(1u32);
 		let end = BlockNumberFor::<T>::from(11u32);
 
-		LBP::<T>::update_pool_data(RawOrigin::Signed(caller.clone()).into(), pool_id, None, Some(start), Some(end), None, None, None, None, None)?;
+		LBP::<T>::update_pool_data(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), None, Some(start), Some(end), None, None, None, None, None)?;
+
+		// worst case: bought native asset is transferred with a new vesting schedule
+		LBP::<T>::set_sale_vesting(RawOrigin::Signed(caller.clone()).into(), pool_id, Some(sale_vesting::<T>()))?;
 
 		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(2u32));
 
@@ -281,6 +384,176 @@ benchmarks! {
 	}: {
 		assert!(<LBP::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance>>::calculate_spot_price_with_fee(PoolType::LBP, asset_in, asset_out).is_ok());
 	}
+
+	set_xyk_migration {
+		let caller = funded_account::<T>("caller", 0);
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, caller.clone(), 0)?;
+		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
+
+	}: _(RawOrigin::Signed(caller), pool_id.clone(), Some(xyk_migration::<T>()))
+	verify {
+		assert_eq!(XYKMigrations::<T>::get(&pool_id), Some(xyk_migration::<T>()));
+	}
+
+	unlock_xyk_shares {
+		let caller = funded_account::<T>("caller", 0);
+		let pool_id = ended_pool_with_migration::<T>(caller.clone())?;
+		let migration = XYKMigrations::<T>::take(&pool_id).ok_or(BenchmarkError::Stop("Migration not set."))?;
+		LBP::<T>::migrate_to_xyk(&pool_id, migration)?;
+
+		let (share_token, locked_shares) = LockedXYKShares::<T>::iter_prefix(&caller).next().ok_or(BenchmarkError::Stop("Shares not locked."))?;
+
+		// partial unlock updates the lock
+		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(50u32));
+
+	}: _(RawOrigin::Signed(caller.clone()), share_token)
+	verify {
+		assert!(LockedXYKShares::<T>::get(&caller, share_token).unwrap().locked_at(BlockNumberFor::<T>::from(50u32)) < locked_shares.amount);
+	}
+
+	set_sale_restrictions {
+		let caller = funded_account::<T>("caller", 0);
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, caller.clone(), 0)?;
+		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
+
+	}: _(RawOrigin::Signed(caller), pool_id.clone(), Some(sale_restrictions::<T>()))
+	verify {
+		assert_eq!(PoolRestrictions::<T>::get(&pool_id).map(|(_, restrictions)| restrictions), Some(sale_restrictions::<T>()));
+	}
+
+	add_to_allowlist {
+		let n in 1..T::MaxAllowlistUpdate::get();
+
+		let caller = funded_account::<T>("caller", 0);
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, caller.clone(), 0)?;
+		LBP::<T>::set_sale_restrictions(RawOrigin::Signed(caller).into(), pool_id.clone(), Some(sale_restrictions::<T>()))?;
+
+		let accounts: Vec<T::AccountId> = (0..n).map(|i| account("participant", i, SEED)).collect();
+		let accounts: BoundedVec<T::AccountId, T::MaxAllowlistUpdate> = accounts.try_into().map_err(|_| BenchmarkError::Stop("Too many accounts."))?;
+		let last = accounts.last().cloned().ok_or(BenchmarkError::Stop("No accounts."))?;
+
+		let origin = T::AllowlistOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+	}: _<T::RuntimeOrigin>(origin, pool_id.clone(), accounts)
+	verify {
+		let (sale_id, _) = PoolRestrictions::<T>::get(&pool_id).unwrap();
+		assert!(Allowlisted::<T>::get(sale_id, last));
+	}
+
+	prove_allowlisted {
+		let p in 0..MAX_MERKLE_PROOF_LENGTH;
+
+		let caller = funded_account::<T>("caller", 0);
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+
+		let proof: Vec<H256> = (0..p).map(|i| H256::repeat_byte(i as u8)).collect();
+		let root = LBP::<T>::compute_merkle_root(H256::from(blake2_256(&caller.encode())), &proof);
+		let proof: BoundedVec<H256, ConstU32<MAX_MERKLE_PROOF_LENGTH>> = proof.try_into().map_err(|_| BenchmarkError::Stop("Proof is too long."))?;
+
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, caller.clone(), 0)?;
+		LBP::<T>::set_sale_restrictions(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), Some(SaleRestrictions {
+			allowlist: Some(Allowlist::MerkleRoot(root)),
+			..Default::default()
+		}))?;
+
+	}: _(RawOrigin::Signed(caller.clone()), pool_id.clone(), proof)
+	verify {
+		let (sale_id, _) = PoolRestrictions::<T>::get(&pool_id).unwrap();
+		assert!(Allowlisted::<T>::get(sale_id, caller));
+	}
+
+	set_sale_vesting {
+		let caller = funded_account::<T>("caller", 0);
+		// native asset is sold so that the vesting is supported
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_HDX);
+		let vesting = sale_vesting::<T>();
+
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_HDX, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, caller.clone(), 0)?;
+		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
+
+	}: _(RawOrigin::Signed(caller), pool_id.clone(), Some(vesting))
+	verify {
+		assert_eq!(PoolVesting::<T>::get(&pool_id), Some(vesting));
+	}
+
+	set_sale_caps {
+		let caller = funded_account::<T>("caller", 0);
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, caller.clone(), 0)?;
+		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
+
+	}: _(RawOrigin::Signed(caller), pool_id.clone(), Some(sale_caps()))
+	verify {
+		assert_eq!(PoolCaps::<T>::get(&pool_id).map(|(_, caps)| caps), Some(sale_caps()));
+	}
+
+	claim_refund {
+		let caller = funded_account::<T>("caller", 0);
+		let buyer = funded_account::<T>("buyer", 1);
+		let fee_collector = funded_account::<T>("fee_collector", 0);
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, fee_collector, 0)?;
+		LBP::<T>::update_pool_data(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), None, Some(BlockNumberFor::<T>::from(1u32)), Some(BlockNumberFor::<T>::from(11u32)), None, None, None, None, None)?;
+		LBP::<T>::set_sale_caps(RawOrigin::Signed(caller).into(), pool_id.clone(), Some(sale_caps()))?;
+
+		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(2u32));
+		LBP::<T>::sell(RawOrigin::Signed(buyer.clone()).into(), ASSET_A_ID, ASSET_B_ID, 100_000_000, 0)?;
+
+		// worst case: the soft cap was not reached and refunds start with the first claim
+		frame_system::Pallet::<T>::set_block_number(BlockNumberFor::<T>::from(12u32));
+
+	}: _(RawOrigin::Signed(buyer.clone()), pool_id.clone())
+	verify {
+		let (sale_id, _) = PoolCaps::<T>::get(&pool_id).unwrap();
+		assert_eq!(Contributions::<T>::get(sale_id, &buyer), Contribution::default());
+		assert_eq!(T::MultiCurrency::free_balance(ASSET_B_ID, &buyer), 1_000_000_000_000_000);
+	}
+
+	create_pool_with_deposit {
+		let caller = funded_account::<T>("caller", 0);
+		let fee_collector = funded_account::<T>("fee_collector", 0);
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+
+	}: _(RawOrigin::Signed(caller.clone()), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, fee_collector, 1)
+	verify {
+		assert!(PoolData::<T>::contains_key(&pool_id));
+		assert_eq!(PoolDeposits::<T>::get(&pool_id), Some((caller, T::PoolDeposit::get())));
+	}
+
+	cancel_pool {
+		let caller = funded_account::<T>("caller", 0);
+		let fee_collector = funded_account::<T>("fee_collector", 0);
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+
+		LBP::<T>::create_pool_with_deposit(RawOrigin::Signed(caller).into(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, fee_collector, 1)?;
+		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
+
+	}: _(RawOrigin::Root, pool_id.clone())
+	verify {
+		assert!(!PoolData::<T>::contains_key(&pool_id));
+		assert!(!PoolDeposits::<T>::contains_key(&pool_id));
+	}
+
+	migrate_to_xyk {
+		let caller = funded_account::<T>("caller", 0);
+		let pool_id = ended_pool_with_migration::<T>(caller.clone())?;
+		let migration = XYKMigrations::<T>::take(&pool_id).ok_or(BenchmarkError::Stop("Migration not set."))?;
+
+	}: {
+		LBP::<T>::migrate_to_xyk(&pool_id, migration)?;
+	}
+	verify {
+		assert!(!PoolData::<T>::contains_key(&pool_id));
+		assert!(LockedXYKShares::<T>::iter_prefix(&caller).next().is_some());
+	}
 }
 
 #[cfg(test)]
@@ -300,6 +573,17 @@ mod tests {
 			assert_ok!(Pallet::<Test>::test_benchmark_buy());
 			assert_ok!(Pallet::<Test>::test_benchmark_router_execution_sell());
 			assert_ok!(Pallet::<Test>::test_benchmark_router_execution_buy());
+			assert_ok!(Pallet::<Test>::test_benchmark_set_xyk_migration());
+			assert_ok!(Pallet::<Test>::test_benchmark_unlock_xyk_shares());
+			assert_ok!(Pallet::<Test>::test_benchmark_migrate_to_xyk());
+			assert_ok!(Pallet::<Test>::test_benchmark_set_sale_restrictions());
+			assert_ok!(Pallet::<Test>::test_benchmark_add_to_allowlist());
+			assert_ok!(Pallet::<Test>::test_benchmark_prove_allowlisted());
+			assert_ok!(Pallet::<Test>::test_benchmark_set_sale_vesting());
+			assert_ok!(Pallet::<Test>::test_benchmark_set_sale_caps());
+			assert_ok!(Pallet::<Test>::test_benchmark_claim_refund());
+			assert_ok!(Pallet::<Test>::test_benchmark_create_pool_with_deposit());
+			assert_ok!(Pallet::<Test>::test_benchmark_cancel_pool());
 		});
 	}
 }
@@ -23,17 +23,20 @@
 #![allow(clippy::manual_inspect)]
 
 pub use crate::types::{Amount, AssetId, AssetPair, Balance};
-use basilisk_traits::{AMMTransfer, AMM};
+use basilisk_traits::{AMMTransfer, CreateXYKPool, VestedTransfer, AMM};
 use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use frame_support::sp_runtime::{
-	traits::{AtLeast32BitUnsigned, BlockNumberProvider, Saturating, Zero},
-	DispatchError, RuntimeDebug,
+	traits::{AtLeast32BitUnsigned, BlockNumberProvider, SaturatedConversion, Saturating, Zero},
+	DispatchError, Permill, RuntimeDebug,
 };
 use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
-	traits::{EnsureOrigin, ExistenceRequirement, Get, LockIdentifier},
+	traits::{
+		Currency, EnsureOrigin, ExistenceRequirement, Get, Imbalance, LockIdentifier, OnUnbalanced, ReservableCurrency,
+	},
 	transactional,
+	weights::Weight,
 };
 use frame_system::ensure_signed;
 use frame_system::pallet_prelude::BlockNumberFor;
@@ -42,8 +45,10 @@ use hydradx_traits::{AssetPairAccountIdFor, CanCreatePool, LockedBalance};
 use pallet_broadcast::types::{Asset, Destination, Fee};
 
 use orml_traits::{MultiCurrency, MultiCurrencyExtended, MultiLockableCurrency};
+use primitive_types::U256;
 
 use scale_info::TypeInfo;
+use sp_core::{hashing::blake2_256, H256};
 
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
@@ -74,6 +79,8 @@ pub use pallet::*;
 
 type BalanceOf<T> = <<T as Config>::MultiCurrency as MultiCurrency<<T as frame_system::Config>::AccountId>>::Balance;
 type PoolId<T> = <T as frame_system::Config>::AccountId;
+type NegativeImbalanceOf<T> =
+	<<T as Config>::DepositCurrency as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
 
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(
@@ -93,6 +100,28 @@ pub const MAX_SALE_DURATION: u32 = (60 * 60 * 24 / 6) * 14;
 /// Lock Identifier for the collected fees
 pub const COLLECTOR_LOCK_ID: LockIdentifier = *b"lbpcllct";
 
+/// Max length of a merkle proof of an allowlisted account
+pub const MAX_MERKLE_PROOF_LENGTH: u32 = 32;
+
+/// Identifier of a sale with participation restrictions
+pub type SaleId = u32;
+
+/// Lock Identifier for the XYK shares issued to the pool owner by the migration into an XYK pool
+pub const XYK_SHARES_LOCK_ID: LockIdentifier = *b"lbpxyksh";
+
+/// Pool created at genesis: (owner, (asset a, amount a), (asset b, amount b), (initial weight, final weight),
+/// (start, end), fee, fee collector, repay target)
+pub type GenesisPool<AccountId, BlockNumber> = (
+	AccountId,
+	(AssetId, Balance),
+	(AssetId, Balance),
+	(LBPWeight, LBPWeight),
+	(BlockNumber, BlockNumber),
+	(u32, u32),
+	AccountId,
+	Balance,
+);
+
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(RuntimeDebug, Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub struct Pool<AccountId, BlockNumber: AtLeast32BitUnsigned + Copy> {
@@ -154,6 +183,175 @@ impl<AccountId, BlockNumber: AtLeast32BitUnsigned + Copy> Pool<AccountId, BlockN
 	}
 }
 
+/// Lock of the XYK shares issued to the pool owner when the pool liquidity is migrated into an XYK pool.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(RuntimeDebug, Encode, Decode, DecodeWithMemTracking, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum SharesLock<BlockNumber> {
+	/// All shares are locked until the given block.
+	Until(BlockNumber),
+
+	/// Shares are unlocked linearly from the migration block until the given block.
+	VestedUntil(BlockNumber),
+}
+
+/// Settings of the migration of the pool liquidity into an XYK pool at the end of the sale.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(RuntimeDebug, Encode, Decode, DecodeWithMemTracking, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct XYKMigration<BlockNumber> {
+	/// share of the accumulated asset used to seed the XYK pool, the sold asset is added at the final spot price
+	pub liquidity_share: Permill,
+
+	/// optional lock of the XYK shares issued to the pool owner
+	pub shares_lock: Option<SharesLock<BlockNumber>>,
+}
+
+/// XYK shares locked in the account of the pool owner after the migration.
+#[derive(RuntimeDebug, Encode, Decode, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct LockedShares<BlockNumber> {
+	/// amount of shares issued by the migration
+	pub amount: Balance,
+
+	/// block of the migration
+	pub start: BlockNumber,
+
+	/// lock of the shares
+	pub lock: SharesLock<BlockNumber>,
+}
+
+impl<BlockNumber: AtLeast32BitUnsigned + Copy> LockedShares<BlockNumber> {
+	/// Amount of shares which remain locked at the block `now`.
+	pub fn locked_at(&self, now: BlockNumber) -> Balance {
+		match self.lock {
+			SharesLock::Until(until) if now < until => self.amount,
+			SharesLock::VestedUntil(until) if now < until => {
+				let remaining: u128 = until.saturating_sub(now).saturated_into();
+				let duration: u128 = until.saturating_sub(self.start).saturated_into();
+				if duration.is_zero() {
+					return Zero::zero();
+				}
+				U256::from(self.amount)
+					.saturating_mul(U256::from(remaining))
+					.checked_div(U256::from(duration))
+					.and_then(|locked| locked.try_into().ok())
+					.unwrap_or(self.amount)
+			}
+			_ => Zero::zero(),
+		}
+	}
+}
+
+/// Allowlist of accounts which can participate in a sale.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(RuntimeDebug, Encode, Decode, DecodeWithMemTracking, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum Allowlist {
+	/// Accounts are added to the allowlist by `AllowlistOrigin`.
+	Origin,
+
+	/// Accounts add themselves to the allowlist by proving inclusion in the merkle tree with the given root.
+	/// Leaves of the tree are `blake2_256` hashes of SCALE encoded account ids, pairs of nodes are sorted before hashing.
+	MerkleRoot(H256),
+}
+
+/// Participation restrictions of a sale.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(
+	Default, RuntimeDebug, Encode, Decode, DecodeWithMemTracking, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen,
+)]
+pub struct SaleRestrictions<BlockNumber> {
+	/// max amount of the accumulated asset an account can spend on purchases of the sold asset
+	pub max_purchase_per_account: Option<Balance>,
+
+	/// allowlist of accounts which can trade in the pool
+	pub allowlist: Option<Allowlist>,
+
+	/// min number of blocks between two trades of an account
+	pub min_blocks_between_trades: Option<BlockNumber>,
+}
+
+/// Participation of an account in a sale with restrictions.
+#[derive(Default, RuntimeDebug, Encode, Decode, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct Participation<BlockNumber> {
+	/// amount of the accumulated asset spent on purchases of the sold asset
+	pub purchased: Balance,
+
+	/// block of the last trade
+	pub last_trade: Option<BlockNumber>,
+}
+
+/// Caps on the amount of the accumulated asset raised in a sale.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(
+	Default, RuntimeDebug, Encode, Decode, DecodeWithMemTracking, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen,
+)]
+pub struct SaleCaps {
+	/// min amount raised for the sale to succeed, participants are refunded if it is not reached by the end of the sale
+	pub soft_cap: Option<Balance>,
+
+	/// max amount raised, the sale ends when it is reached
+	pub hard_cap: Option<Balance>,
+}
+
+/// Contribution of an account to a sale with caps.
+#[derive(Default, RuntimeDebug, Encode, Decode, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct Contribution {
+	/// amount of the accumulated asset added to the pool
+	pub contributed: Balance,
+
+	/// amount of the sold asset bought from the pool
+	pub bought: Balance,
+}
+
+/// Refunds of a sale which has not reached its soft cap.
+#[derive(RuntimeDebug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct SaleRefund<AccountId, BlockNumber> {
+	/// sale of the refunded contributions
+	pub sale_id: SaleId,
+
+	/// block at which refunds started
+	pub started: BlockNumber,
+
+	/// owner of the pool, receives the sold asset returned by participants
+	pub owner: AccountId,
+
+	/// accumulated asset and sold asset of the pool
+	pub assets: (AssetId, AssetId),
+
+	/// total contributions to the sale when refunds started
+	pub total_contributed: Balance,
+
+	/// amount of the accumulated asset reserved for refunds when refunds started
+	pub refundable: Balance,
+
+	/// amount of the accumulated asset which has not been refunded yet
+	pub remaining: Balance,
+
+	/// contributions which have not been refunded yet
+	pub unclaimed: Balance,
+}
+
+/// Vesting of the sold asset bought in a sale.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(RuntimeDebug, Encode, Decode, DecodeWithMemTracking, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct SaleVesting<BlockNumber> {
+	/// number of blocks after the end of the sale during which bought tokens remain fully locked
+	pub cliff: BlockNumber,
+
+	/// number of blocks after the cliff over which bought tokens are released linearly
+	pub duration: BlockNumber,
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetId> {
+	/// Register `asset_id` in the asset registry, so that XYK pools with the asset can be created.
+	fn register_asset(asset_id: AssetId) -> DispatchResult;
+}
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper<AssetId> for () {
+	fn register_asset(_asset_id: AssetId) -> DispatchResult {
+		Ok(())
+	}
+}
+
 pub trait LBPWeightCalculation<BlockNumber: AtLeast32BitUnsigned> {
 	fn calculate_weight(
 		weight_curve: WeightCurveType,
@@ -227,10 +425,57 @@ pub mod pallet {
 
 		/// The block number provider
 		type BlockNumberProvider: BlockNumberProvider<BlockNumber = BlockNumberFor<Self>>;
+
+		/// XYK pools used to migrate the pool liquidity into at the end of the sale
+		type XYKPool: CreateXYKPool<Self::AccountId, AssetId, Balance>;
+
+		/// The origin which can add accounts to the allowlist of a sale
+		type AllowlistOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Max number of accounts added to the allowlist in a single call
+		#[pallet::constant]
+		type MaxAllowlistUpdate: Get<u32>;
+
+		/// Vesting of the sold asset bought in sales with vesting.
+		/// Sales can be configured with vesting only if the sold asset is supported.
+		type Vesting: VestedTransfer<Self::AccountId, AssetId, BalanceOf<Self>, BlockNumberFor<Self>>;
+
+		/// Currency in which the deposit of permissionlessly created pools is reserved
+		type DepositCurrency: ReservableCurrency<Self::AccountId, Balance = Balance>;
+
+		/// Deposit reserved from the creator of a pool created without the `CreatePoolOrigin`
+		#[pallet::constant]
+		type PoolDeposit: Get<Balance>;
+
+		/// Handler of the deposits slashed when a pool is cancelled
+		type SlashedDeposit: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// The origin which can cancel a pool before the start of the sale and slash its deposit
+		type CancelPoolOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Min duration of sales in pools created with a deposit
+		#[pallet::constant]
+		type MinSaleDuration: Get<BlockNumberFor<Self>>;
+
+		/// Max initial weight of the accumulated asset in pools created with a deposit
+		#[pallet::constant]
+		type MaxInitialWeight: Get<LBPWeight>;
+
+		/// Number of blocks after the start of refunds during which contributions can be refunded.
+		/// Unclaimed refunds are transferred to the pool owner when a new pool of the pair is created afterwards.
+		#[pallet::constant]
+		type RefundPeriod: Get<BlockNumberFor<Self>>;
+
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<AssetId>;
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::migrate_ended_pools(remaining_weight)
+		}
+
 		fn integrity_test() {
 			// The exponentiation used in the math can overflow for values smaller than 3
 			assert!(T::MaxInRatio::get() >= 3, "LBP: MaxInRatio is set to invalid value.");
@@ -309,6 +554,66 @@ pub mod pallet {
 
 		/// Not more than one fee collector per asset id
 		FeeCollectorWithAssetAlreadyUsed,
+
+		/// Share of the liquidity migrated into an XYK pool can not be zero
+		ZeroLiquidityShare,
+
+		/// XYK shares of the account are already locked
+		XYKSharesAlreadyLocked,
+
+		/// There are no locked XYK shares
+		XYKSharesNotLocked,
+
+		/// Account is not allowlisted to trade in the pool
+		NotAllowlisted,
+
+		/// Pool does not use allowlist of this kind
+		AllowlistNotEnabled,
+
+		/// Merkle proof of the allowlisted account is invalid
+		InvalidMerkleProof,
+
+		/// Purchase exceeds the max purchase per account
+		MaxPurchaseExceeded,
+
+		/// Account traded in the pool too recently
+		TradeTooSoon,
+
+		/// Vesting duration can not be zero
+		ZeroVestingDuration,
+
+		/// Sold asset of the pool can not be vested
+		VestingNotSupported,
+
+		/// Soft cap can not be zero or higher than the hard cap
+		InvalidCaps,
+
+		/// Trade exceeds the hard cap of the sale
+		HardCapExceeded,
+
+		/// Sale has not reached its soft cap
+		SoftCapNotReached,
+
+		/// Sale has not ended without reaching its soft cap
+		SaleNotFailed,
+
+		/// Account has no contribution to refund
+		NothingToRefund,
+
+		/// Refunds of the previous sale in the pool are pending and have not expired yet
+		RefundsPending,
+
+		/// Sale of a pool created with a deposit is shorter than the min sale duration
+		SaleDurationTooShort,
+
+		/// Initial weight of a pool created with a deposit is higher than the max initial weight
+		InitialWeightTooHigh,
+
+		/// Pool created with a deposit requires a non-zero repay target
+		RepayTargetRequired,
+
+		/// Sale with vesting can not have a soft cap, vested tokens could not be returned for refunds
+		VestingWithSoftCap,
 	}
 
 	#[pallet::event]
@@ -367,6 +672,79 @@ pub mod pallet {
 			fee_asset: AssetId,
 			fee_amount: BalanceOf<T>,
 		},
+
+		/// Migration of the pool liquidity into an XYK pool was set or cleared.
+		XYKMigrationSet {
+			pool: PoolId<T>,
+			migration: Option<XYKMigration<BlockNumberFor<T>>>,
+		},
+
+		/// Pool liquidity was migrated into a new XYK pool.
+		PoolMigrated {
+			pool: PoolId<T>,
+			who: T::AccountId,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			amount_a: BalanceOf<T>,
+			amount_b: BalanceOf<T>,
+			share_token: AssetId,
+			shares: BalanceOf<T>,
+		},
+
+		/// Migration of the pool liquidity into an XYK pool failed. The pool is left untouched.
+		XYKMigrationFailed { pool: PoolId<T>, error: DispatchError },
+
+		/// Participation restrictions of a sale were set or cleared.
+		SaleRestrictionsSet {
+			pool: PoolId<T>,
+			restrictions: Option<SaleRestrictions<BlockNumberFor<T>>>,
+		},
+
+		/// Account was added to the allowlist of a sale.
+		Allowlisted { pool: PoolId<T>, who: T::AccountId },
+
+		/// Vesting of the sold asset bought in a sale was set or cleared.
+		SaleVestingSet {
+			pool: PoolId<T>,
+			vesting: Option<SaleVesting<BlockNumberFor<T>>>,
+		},
+
+		/// Caps of a sale were set or cleared.
+		SaleCapsSet { pool: PoolId<T>, caps: Option<SaleCaps> },
+
+		/// Sale reached its hard cap and ended.
+		HardCapReached { pool: PoolId<T>, raised: BalanceOf<T> },
+
+		/// Sale ended without reaching its soft cap, contributions can be refunded.
+		RefundsStarted { pool: PoolId<T>, refundable: BalanceOf<T> },
+
+		/// Refund period ended, unclaimed refunds were transferred to the pool owner.
+		RefundsExpired { pool: PoolId<T>, unclaimed: BalanceOf<T> },
+
+		/// Contribution was refunded and the bought sold asset was returned to the pool owner.
+		Refunded {
+			pool: PoolId<T>,
+			who: T::AccountId,
+			amount: BalanceOf<T>,
+			returned: BalanceOf<T>,
+		},
+
+		/// Pool was created with a deposit reserved from its creator.
+		PoolDepositReserved {
+			pool: PoolId<T>,
+			who: T::AccountId,
+			amount: Balance,
+		},
+
+		/// Pool was cancelled before the start of the sale and its deposit was slashed.
+		PoolCancelled { pool: PoolId<T>, slashed: Balance },
+
+		/// Locked XYK shares were partially or fully unlocked.
+		XYKSharesUnlocked {
+			who: T::AccountId,
+			share_token: AssetId,
+			remaining_locked: BalanceOf<T>,
+		},
 	}
 
 	/// Details of a pool.
@@ -381,6 +759,149 @@ pub mod pallet {
 	pub type FeeCollectorWithAsset<T: Config> =
 		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, AssetId, bool, ValueQuery>;
 
+	/// Pools which liquidity is migrated into an XYK pool at the end of the sale.
+	#[pallet::storage]
+	#[pallet::getter(fn xyk_migration)]
+	pub type XYKMigrations<T: Config> =
+		StorageMap<_, Blake2_128Concat, PoolId<T>, XYKMigration<BlockNumberFor<T>>, OptionQuery>;
+
+	/// Last pool visited by the XYK migration sweep in `on_idle`. The next sweep continues after it.
+	#[pallet::storage]
+	pub type XYKMigrationCursor<T: Config> = StorageValue<_, PoolId<T>, OptionQuery>;
+
+	/// XYK shares locked in the account of the pool owner after the migration, keyed by account and share token.
+	#[pallet::storage]
+	#[pallet::getter(fn locked_xyk_shares)]
+	pub type LockedXYKShares<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		AssetId,
+		LockedShares<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// Participation restrictions of sales, keyed by pool.
+	#[pallet::storage]
+	#[pallet::getter(fn sale_restrictions)]
+	pub type PoolRestrictions<T: Config> =
+		StorageMap<_, Blake2_128Concat, PoolId<T>, (SaleId, SaleRestrictions<BlockNumberFor<T>>), OptionQuery>;
+
+	/// Identifier of the next sale with participation restrictions.
+	#[pallet::storage]
+	pub type NextSaleId<T: Config> = StorageValue<_, SaleId, ValueQuery>;
+
+	/// Accounts allowlisted to trade in a sale.
+	#[pallet::storage]
+	pub type Allowlisted<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, SaleId, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
+
+	/// Participation of accounts in sales with restrictions.
+	#[pallet::storage]
+	#[pallet::getter(fn participation)]
+	pub type Participants<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		SaleId,
+		Blake2_128Concat,
+		T::AccountId,
+		Participation<BlockNumberFor<T>>,
+		ValueQuery,
+	>;
+
+	/// Vesting of the sold asset bought in sales, keyed by pool.
+	#[pallet::storage]
+	#[pallet::getter(fn sale_vesting)]
+	pub type PoolVesting<T: Config> =
+		StorageMap<_, Blake2_128Concat, PoolId<T>, SaleVesting<BlockNumberFor<T>>, OptionQuery>;
+
+	/// Caps of sales, keyed by pool.
+	#[pallet::storage]
+	#[pallet::getter(fn sale_caps)]
+	pub type PoolCaps<T: Config> = StorageMap<_, Blake2_128Concat, PoolId<T>, (SaleId, SaleCaps), OptionQuery>;
+
+	/// Total contributions to sales with caps.
+	#[pallet::storage]
+	#[pallet::getter(fn raised)]
+	pub type SaleRaised<T: Config> = StorageMap<_, Blake2_128Concat, SaleId, BalanceOf<T>, ValueQuery>;
+
+	/// Contributions of accounts to sales with caps.
+	#[pallet::storage]
+	#[pallet::getter(fn contribution)]
+	pub type Contributions<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, SaleId, Blake2_128Concat, T::AccountId, Contribution, ValueQuery>;
+
+	/// Refunds of sales which have not reached their soft cap, keyed by pool.
+	#[pallet::storage]
+	#[pallet::getter(fn sale_refund)]
+	pub type SaleRefunds<T: Config> =
+		StorageMap<_, Blake2_128Concat, PoolId<T>, SaleRefund<T::AccountId, BlockNumberFor<T>>, OptionQuery>;
+
+	/// Block at which a sale was closed by reaching its hard cap before its end, keyed by pool.
+	#[pallet::storage]
+	#[pallet::getter(fn sale_closed_at)]
+	pub type SaleClosedAt<T: Config> = StorageMap<_, Blake2_128Concat, PoolId<T>, BlockNumberFor<T>, OptionQuery>;
+
+	/// Deposits reserved for pools created without the `CreatePoolOrigin`, keyed by pool.
+	#[pallet::storage]
+	#[pallet::getter(fn pool_deposit)]
+	pub type PoolDeposits<T: Config> = StorageMap<_, Blake2_128Concat, PoolId<T>, (T::AccountId, Balance), OptionQuery>;
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// Pools created at genesis with a linear weight curve.
+		///
+		/// The initial liquidity is provided by the owner. The sale is scheduled in blocks of
+		/// `BlockNumberProvider`.
+		pub pools: Vec<GenesisPool<T::AccountId, BlockNumberFor<T>>>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			for (
+				owner,
+				(asset_a, amount_a),
+				(asset_b, amount_b),
+				(initial_weight, final_weight),
+				(start, end),
+				fee,
+				fee_collector,
+				repay_target,
+			) in self.pools.iter()
+			{
+				Pallet::<T>::do_create_pool(
+					owner.clone(),
+					*asset_a,
+					*amount_a,
+					*asset_b,
+					*amount_b,
+					*initial_weight,
+					*final_weight,
+					WeightCurveType::Linear,
+					*fee,
+					fee_collector.clone(),
+					*repay_target,
+					None,
+				)
+				.expect("Failed to create LBP pool in genesis");
+
+				let pool_id = Pallet::<T>::get_pair_id(AssetPair {
+					asset_in: *asset_a,
+					asset_out: *asset_b,
+				});
+				<PoolData<T>>::mutate(&pool_id, |maybe_pool| {
+					let pool = maybe_pool.as_mut().expect("Pool was created; qed");
+					pool.start = Some(*start);
+					pool.end = Some(*end);
+					Pallet::<T>::validate_pool_data(pool).expect("Invalid schedule of LBP pool in genesis");
+				});
+			}
+		}
+	}
+
 	#[pallet::extra_constants]
 	impl<T: Config> Pallet<T> {
 		pub fn repay_fee() -> (u32, u32) {
@@ -441,83 +962,20 @@ pub mod pallet {
 		) -> DispatchResult {
 			T::CreatePoolOrigin::ensure_origin(origin)?;
 
-			ensure!(
-				asset_a_amount >= T::MinPoolLiquidity::get() && asset_b_amount >= T::MinPoolLiquidity::get(),
-				Error::<T>::InsufficientLiquidity
-			);
-
-			ensure!(asset_a != asset_b, Error::<T>::CannotCreatePoolWithSameAssets);
-
-			let asset_pair = AssetPair {
-				asset_in: asset_a,
-				asset_out: asset_b,
-			};
-
-			ensure!(!Self::exists(asset_pair), Error::<T>::PoolAlreadyExists);
-
-			ensure!(
-				!<FeeCollectorWithAsset<T>>::contains_key(fee_collector.clone(), asset_a),
-				Error::<T>::FeeCollectorWithAssetAlreadyUsed
-			);
-
-			ensure!(
-				T::MultiCurrency::free_balance(asset_a, &pool_owner) >= asset_a_amount,
-				Error::<T>::InsufficientAssetBalance
-			);
-
-			ensure!(
-				T::MultiCurrency::free_balance(asset_b, &pool_owner) >= asset_b_amount,
-				Error::<T>::InsufficientAssetBalance
-			);
-
-			let pool_data = Pool::new(
-				pool_owner.clone(),
+			Self::do_create_pool(
+				pool_owner,
 				asset_a,
+				asset_a_amount,
 				asset_b,
+				asset_b_amount,
 				initial_weight,
 				final_weight,
 				weight_curve,
 				fee,
-				fee_collector.clone(),
+				fee_collector,
 				repay_target,
-			);
-
-			Self::validate_pool_data(&pool_data)?;
-
-			let pool_id = Self::get_pair_id(asset_pair);
-
-			<PoolData<T>>::insert(&pool_id, &pool_data);
-			<FeeCollectorWithAsset<T>>::insert(fee_collector, asset_a, true);
-
-			Self::deposit_event(Event::PoolCreated {
-				pool: pool_id.clone(),
-				data: pool_data,
-			});
-
-			T::MultiCurrency::transfer(
-				asset_a,
-				&pool_owner,
-				&pool_id,
-				asset_a_amount,
-				ExistenceRequirement::AllowDeath,
-			)?;
-			T::MultiCurrency::transfer(
-				asset_b,
-				&pool_owner,
-				&pool_id,
-				asset_b_amount,
-				ExistenceRequirement::AllowDeath,
-			)?;
-
-			Self::deposit_event(Event::LiquidityAdded {
-				who: pool_id,
-				asset_a,
-				asset_b,
-				amount_a: asset_a_amount,
-				amount_b: asset_b_amount,
-			});
-
-			Ok(())
+				None,
+			)
 		}
 
 		/// Update pool data of a pool.
@@ -604,6 +1062,10 @@ pub mod pallet {
 
 				Self::validate_pool_data(pool)?;
 
+				if <PoolDeposits<T>>::contains_key(&pool_id) {
+					Self::validate_deposit_pool_data(pool)?;
+				}
+
 				Self::deposit_event(Event::PoolUpdated {
 					pool: pool_id,
 					data: (*pool).clone(),
@@ -677,7 +1139,9 @@ pub mod pallet {
 		/// Transfer all the liquidity from a pool back to the pool owner and destroy the pool.
 		/// The pool data are also removed from the storage.
 		///
-		/// The pool can't be destroyed during the sale.
+		/// The pool can't be destroyed during the sale. A pool created with a deposit can't be destroyed
+		/// once the sale is scheduled until the end of the sale, so that the deposit can't be unreserved
+		/// ahead of `cancel_pool`. A pool created with a deposit that was never scheduled can be destroyed.
 		///
 		/// The dispatch origin for this call must be signed by the pool owner.
 		///
@@ -694,30 +1158,18 @@ pub mod pallet {
 
 			ensure!(who == pool_data.owner, Error::<T>::NotOwner);
 
-			ensure!(!Self::is_pool_running(&pool_data), Error::<T>::SaleNotEnded);
-
-			let (asset_a, asset_b) = pool_data.assets;
-
-			let amount_a = T::MultiCurrency::free_balance(asset_a, &pool_id);
-			let amount_b = T::MultiCurrency::free_balance(asset_b, &pool_id);
-
-			T::MultiCurrency::transfer(asset_a, &pool_id, &who, amount_a, ExistenceRequirement::AllowDeath)?;
-			T::MultiCurrency::transfer(asset_b, &pool_id, &who, amount_b, ExistenceRequirement::AllowDeath)?;
+			ensure!(!Self::is_pool_running(&pool_id, &pool_data), Error::<T>::SaleNotEnded);
 
-			if Self::collected_fees(&pool_data) > 0 {
-				T::MultiCurrency::remove_lock(COLLECTOR_LOCK_ID, asset_a, &pool_data.fee_collector)?;
-			}
+			ensure!(
+				!<PoolDeposits<T>>::contains_key(&pool_id)
+					|| pool_data.start.is_none()
+					|| Self::has_pool_ended(&pool_id, &pool_data),
+				Error::<T>::SaleNotEnded
+			);
 
-			<FeeCollectorWithAsset<T>>::remove(pool_data.fee_collector, pool_data.assets.0);
-			<PoolData<T>>::remove(&pool_id);
+			Self::start_refunds(&pool_id, &pool_data)?;
 
-			Self::deposit_event(Event::LiquidityRemoved {
-				who: pool_id,
-				asset_a,
-				asset_b,
-				amount_a,
-				amount_b,
-			});
+			Self::do_remove_liquidity(&pool_id, &pool_data)?;
 
 			Ok(())
 		}
@@ -783,67 +1235,687 @@ pub mod pallet {
 
 			Ok(())
 		}
-	}
-}
 
-impl<T: Config> Pallet<T> {
-	fn calculate_weights(
-		pool_data: &Pool<T::AccountId, BlockNumberFor<T>>,
-		at: BlockNumberFor<T>,
-	) -> Result<(LBPWeight, LBPWeight), DispatchError> {
-		let weight_a = T::LBPWeightFunction::calculate_weight(
-			pool_data.weight_curve,
-			pool_data.start.unwrap_or_else(Zero::zero),
-			pool_data.end.unwrap_or_else(Zero::zero),
-			pool_data.initial_weight,
-			pool_data.final_weight,
-			at,
-		)
+		/// Set or clear the migration of the pool liquidity into an XYK pool at the end of the sale.
+		///
+		/// When the sale ends, the pool liquidity is returned to the pool owner and the owner seeds a new XYK pool
+		/// with `liquidity_share` of the accumulated asset and the amount of the sold asset corresponding to
+		/// the final spot price of the sale. The rest of the liquidity stays in the owner's account.
+		/// XYK shares issued to the owner are optionally locked by `shares_lock`.
+		///
+		/// If the migration fails, e.g. because the XYK pool already exists, `XYKMigrationFailed` is emitted
+		/// and the pool liquidity has to be removed by the owner.
+		///
+		/// The dispatch origin for this call must be signed by the pool owner.
+		///
+		/// The migration can be set only if the sale has not already started.
+		///
+		/// Parameters:
+		/// - `pool_id`: The identifier of the pool.
+		/// - `migration`: Migration settings. `None` clears the migration.
+		///
+		/// Emits `XYKMigrationSet` event when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_xyk_migration())]
+		pub fn set_xyk_migration(
+			origin: OriginFor<T>,
+			pool_id: PoolId<T>,
+			migration: Option<XYKMigration<BlockNumberFor<T>>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let pool_data = <PoolData<T>>::try_get(&pool_id).map_err(|_| Error::<T>::PoolNotFound)?;
+
+			ensure!(who == pool_data.owner, Error::<T>::NotOwner);
+
+			ensure!(!Self::has_pool_started(&pool_data), Error::<T>::SaleStarted);
+
+			if let Some(migration) = migration {
+				ensure!(!migration.liquidity_share.is_zero(), Error::<T>::ZeroLiquidityShare);
+			}
+
+			<XYKMigrations<T>>::set(&pool_id, migration);
+
+			Self::deposit_event(Event::XYKMigrationSet {
+				pool: pool_id,
+				migration,
+			});
+
+			Ok(())
+		}
+
+		/// Unlock XYK shares issued by the migration of the pool liquidity whose lock has expired.
+		///
+		/// The dispatch origin for this call must be signed by the owner of the locked shares.
+		///
+		/// Parameters:
+		/// - `share_token`: The share token of the XYK pool.
+		///
+		/// Emits `XYKSharesUnlocked` event when successful.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::unlock_xyk_shares())]
+		pub fn unlock_xyk_shares(origin: OriginFor<T>, share_token: AssetId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let locked_shares =
+				<LockedXYKShares<T>>::try_get(&who, share_token).map_err(|_| Error::<T>::XYKSharesNotLocked)?;
+
+			let remaining_locked = locked_shares.locked_at(T::BlockNumberProvider::current_block_number());
+
+			if remaining_locked.is_zero() {
+				T::MultiCurrency::remove_lock(XYK_SHARES_LOCK_ID, share_token, &who)?;
+				<LockedXYKShares<T>>::remove(&who, share_token);
+			} else {
+				T::MultiCurrency::set_lock(XYK_SHARES_LOCK_ID, share_token, &who, remaining_locked)?;
+			}
+
+			Self::deposit_event(Event::XYKSharesUnlocked {
+				who,
+				share_token,
+				remaining_locked,
+			});
+
+			Ok(())
+		}
+
+		/// Set or clear participation restrictions of a sale.
+		///
+		/// Restrictions are enforced on every trade in the pool, including trades executed by the router.
+		///
+		/// The dispatch origin for this call must be signed by the pool owner.
+		///
+		/// The restrictions can be set only if the sale has not already started.
+		///
+		/// Parameters:
+		/// - `pool_id`: The identifier of the pool.
+		/// - `restrictions`: Restrictions of the sale. `None` clears the restrictions.
+		///
+		/// Emits `SaleRestrictionsSet` event when successful.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_sale_restrictions())]
+		pub fn set_sale_restrictions(
+			origin: OriginFor<T>,
+			pool_id: PoolId<T>,
+			restrictions: Option<SaleRestrictions<BlockNumberFor<T>>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let pool_data = <PoolData<T>>::try_get(&pool_id).map_err(|_| Error::<T>::PoolNotFound)?;
+
+			ensure!(who == pool_data.owner, Error::<T>::NotOwner);
+
+			ensure!(!Self::has_pool_started(&pool_data), Error::<T>::SaleStarted);
+
+			<PoolRestrictions<T>>::try_mutate_exists(&pool_id, |maybe_restrictions| -> DispatchResult {
+				*maybe_restrictions = match (maybe_restrictions.take(), restrictions) {
+					(_, None) => None,
+					(Some((sale_id, _)), Some(restrictions)) => Some((sale_id, restrictions)),
+					(None, Some(restrictions)) => Some((Self::next_sale_id()?, restrictions)),
+				};
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::SaleRestrictionsSet {
+				pool: pool_id,
+				restrictions,
+			});
+
+			Ok(())
+		}
+
+		/// Add accounts to the allowlist of a sale.
+		///
+		/// The dispatch origin for this call must be `T::AllowlistOrigin`.
+		///
+		/// The sale must use `Allowlist::Origin`.
+		///
+		/// Parameters:
+		/// - `pool_id`: The identifier of the pool.
+		/// - `accounts`: Accounts to add to the allowlist.
+		///
+		/// Emits `Allowlisted` event for every account when successful.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_to_allowlist(accounts.len() as u32))]
+		pub fn add_to_allowlist(
+			origin: OriginFor<T>,
+			pool_id: PoolId<T>,
+			accounts: BoundedVec<T::AccountId, T::MaxAllowlistUpdate>,
+		) -> DispatchResult {
+			T::AllowlistOrigin::ensure_origin(origin)?;
+
+			let (sale_id, restrictions) =
+				<PoolRestrictions<T>>::try_get(&pool_id).map_err(|_| Error::<T>::AllowlistNotEnabled)?;
+
+			ensure!(
+				restrictions.allowlist == Some(Allowlist::Origin),
+				Error::<T>::AllowlistNotEnabled
+			);
+
+			for who in accounts {
+				<Allowlisted<T>>::insert(sale_id, &who, true);
+
+				Self::deposit_event(Event::Allowlisted {
+					pool: pool_id.clone(),
+					who,
+				});
+			}
+
+			Ok(())
+		}
+
+		/// Add the origin to the allowlist of a sale by proving inclusion in the allowlist merkle tree.
+		///
+		/// The dispatch origin for this call must be signed by the allowlisted account.
+		///
+		/// The sale must use `Allowlist::MerkleRoot`.
+		///
+		/// Parameters:
+		/// - `pool_id`: The identifier of the pool.
+		/// - `proof`: Sibling nodes on the path from the leaf of the account to the merkle root.
+		///
+		/// Emits `Allowlisted` event when successful.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::prove_allowlisted(proof.len() as u32))]
+		pub fn prove_allowlisted(
+			origin: OriginFor<T>,
+			pool_id: PoolId<T>,
+			proof: BoundedVec<H256, ConstU32<MAX_MERKLE_PROOF_LENGTH>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (sale_id, restrictions) =
+				<PoolRestrictions<T>>::try_get(&pool_id).map_err(|_| Error::<T>::AllowlistNotEnabled)?;
+
+			let Some(Allowlist::MerkleRoot(root)) = restrictions.allowlist else {
+				return Err(Error::<T>::AllowlistNotEnabled.into());
+			};
+
+			let leaf = H256::from(blake2_256(&who.encode()));
+			ensure!(
+				Self::compute_merkle_root(leaf, &proof) == root,
+				Error::<T>::InvalidMerkleProof
+			);
+
+			<Allowlisted<T>>::insert(sale_id, &who, true);
+
+			Self::deposit_event(Event::Allowlisted { pool: pool_id, who });
+
+			Ok(())
+		}
+
+		/// Set or clear vesting of the sold asset bought in the sale.
+		///
+		/// Bought tokens are transferred to the buyer locked by a vesting schedule. Tokens remain fully locked
+		/// until `cliff` blocks after the end of the sale and are then released linearly over `duration` blocks.
+		///
+		/// The sold asset must be supported by `T::Vesting`. Purchases of an account share one vesting schedule
+		/// if `T::Vesting` merges schedules with the same start and duration.
+		/// Sales with a soft cap can not be vested because vested tokens can not be returned for refunds.
+		///
+		/// The dispatch origin for this call must be signed by the pool owner.
+		///
+		/// The vesting can be set only before the sale starts.
+		///
+		/// Parameters:
+		/// - `pool_id`: The identifier of the pool.
+		/// - `vesting`: Vesting of bought tokens. `None` clears the vesting.
+		///
+		/// Emits `SaleVestingSet` event when successful.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_sale_vesting())]
+		pub fn set_sale_vesting(
+			origin: OriginFor<T>,
+			pool_id: PoolId<T>,
+			vesting: Option<SaleVesting<BlockNumberFor<T>>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let pool_data = <PoolData<T>>::try_get(&pool_id).map_err(|_| Error::<T>::PoolNotFound)?;
+
+			ensure!(who == pool_data.owner, Error::<T>::NotOwner);
+
+			ensure!(!Self::has_pool_started(&pool_data), Error::<T>::SaleStarted);
+
+			if let Some(vesting) = vesting {
+				ensure!(!vesting.duration.is_zero(), Error::<T>::ZeroVestingDuration);
+				ensure!(
+					T::Vesting::is_supported(pool_data.assets.1),
+					Error::<T>::VestingNotSupported
+				);
+				ensure!(
+					!<PoolCaps<T>>::get(&pool_id).is_some_and(|(_, caps)| caps.soft_cap.is_some()),
+					Error::<T>::VestingWithSoftCap
+				);
+			}
+
+			<PoolVesting<T>>::set(&pool_id, vesting);
+
+			Self::deposit_event(Event::SaleVestingSet { pool: pool_id, vesting });
+
+			Ok(())
+		}
+
+		/// Set or clear caps on the amount of the accumulated asset raised in the sale.
+		///
+		/// The sale ends when the hard cap is reached. If the soft cap is not reached by the end of the sale,
+		/// participants can claim refunds of their contributions.
+		/// The soft cap can not be set for sales with vesting.
+		///
+		/// The dispatch origin for this call must be signed by the pool owner.
+		///
+		/// The caps can be set only before the sale starts.
+		///
+		/// Parameters:
+		/// - `pool_id`: The identifier of the pool.
+		/// - `caps`: Caps of the sale. `None` clears the caps.
+		///
+		/// Emits `SaleCapsSet` event when successful.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_sale_caps())]
+		pub fn set_sale_caps(origin: OriginFor<T>, pool_id: PoolId<T>, caps: Option<SaleCaps>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let pool_data = <PoolData<T>>::try_get(&pool_id).map_err(|_| Error::<T>::PoolNotFound)?;
+
+			ensure!(who == pool_data.owner, Error::<T>::NotOwner);
+
+			ensure!(!Self::has_pool_started(&pool_data), Error::<T>::SaleStarted);
+
+			if let Some(caps) = caps {
+				ensure!(caps.soft_cap != Some(0), Error::<T>::InvalidCaps);
+				if let (Some(soft_cap), Some(hard_cap)) = (caps.soft_cap, caps.hard_cap) {
+					ensure!(soft_cap <= hard_cap, Error::<T>::InvalidCaps);
+				}
+				ensure!(
+					caps.soft_cap.is_none() || !<PoolVesting<T>>::contains_key(&pool_id),
+					Error::<T>::VestingWithSoftCap
+				);
+			}
+
+			<PoolCaps<T>>::try_mutate_exists(&pool_id, |maybe_caps| -> DispatchResult {
+				*maybe_caps = match (maybe_caps.take(), caps) {
+					(_, None) => None,
+					(Some((sale_id, _)), Some(caps)) => Some((sale_id, caps)),
+					(None, Some(caps)) => Some((Self::next_sale_id()?, caps)),
+				};
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::SaleCapsSet { pool: pool_id, caps });
+
+			Ok(())
+		}
+
+		/// Claim refund of the contribution to a sale which has not reached its soft cap.
+		///
+		/// Contributions are refunded proportionally from the accumulated asset reserved for refunds.
+		/// The sold asset bought in the sale is returned to the pool owner and must be transferable.
+		/// If the contributor holds less than the bought amount, all of it is returned and the contribution
+		/// is refunded partially, in proportion to the returned amount. The rest can be claimed later.
+		///
+		/// Refunds can be claimed for `T::RefundPeriod` blocks. Afterwards, unclaimed refunds are transferred
+		/// to the pool owner when a new pool of the pair is created.
+		///
+		/// The dispatch origin for this call must be signed by the contributor.
+		///
+		/// Parameters:
+		/// - `pool_id`: The identifier of the pool.
+		///
+		/// Emits `Refunded` event when successful.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_refund())]
+		pub fn claim_refund(origin: OriginFor<T>, pool_id: PoolId<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut refund = match <SaleRefunds<T>>::get(&pool_id) {
+				Some(refund) => refund,
+				None => {
+					let pool_data = <PoolData<T>>::try_get(&pool_id).map_err(|_| Error::<T>::PoolNotFound)?;
+					Self::start_refunds(&pool_id, &pool_data)?.ok_or(Error::<T>::SaleNotFailed)?
+				}
+			};
+
+			let mut contribution = <Contributions<T>>::get(refund.sale_id, &who);
+			ensure!(!contribution.contributed.is_zero(), Error::<T>::NothingToRefund);
+
+			let (asset_a, asset_b) = refund.assets;
+
+			// contribution is refunded in proportion to the part of the bought asset which is returned
+			let returned = contribution.bought.min(T::MultiCurrency::free_balance(asset_b, &who));
+			let refunded: BalanceOf<T> = if returned == contribution.bought {
+				contribution.contributed
+			} else {
+				U256::from(contribution.contributed)
+					.saturating_mul(U256::from(returned))
+					.checked_div(U256::from(contribution.bought))
+					.and_then(|refunded| refunded.try_into().ok())
+					.ok_or(Error::<T>::Overflow)?
+			};
+			ensure!(!refunded.is_zero(), Error::<T>::NothingToRefund);
+
+			let amount: BalanceOf<T> = U256::from(refunded)
+				.saturating_mul(U256::from(refund.refundable))
+				.checked_div(U256::from(refund.total_contributed))
+				.and_then(|amount| amount.try_into().ok())
+				.ok_or(Error::<T>::Overflow)?;
+			let amount = amount.min(refund.remaining);
+
+			if !returned.is_zero() {
+				T::MultiCurrency::transfer(asset_b, &who, &refund.owner, returned, ExistenceRequirement::AllowDeath)?;
+			}
+
+			T::MultiCurrency::transfer(asset_a, &pool_id, &who, amount, ExistenceRequirement::AllowDeath)?;
+
+			contribution.contributed = contribution.contributed.saturating_sub(refunded);
+			contribution.bought = contribution.bought.saturating_sub(returned);
+			if contribution.contributed.is_zero() {
+				<Contributions<T>>::remove(refund.sale_id, &who);
+			} else {
+				<Contributions<T>>::insert(refund.sale_id, &who, contribution);
+			}
+
+			<SaleRaised<T>>::mutate(refund.sale_id, |raised| *raised = raised.saturating_sub(refunded));
+
+			refund.remaining = refund.remaining.saturating_sub(amount);
+			refund.unclaimed = refund.unclaimed.saturating_sub(refunded);
+
+			if refund.unclaimed.is_zero() {
+				// rounding leftovers go to the owner once the pool liquidity was removed
+				if !<PoolData<T>>::contains_key(&pool_id) && !refund.remaining.is_zero() {
+					T::MultiCurrency::transfer(
+						asset_a,
+						&pool_id,
+						&refund.owner,
+						refund.remaining,
+						ExistenceRequirement::AllowDeath,
+					)?;
+				}
+				<SaleRefunds<T>>::remove(&pool_id);
+			} else {
+				<SaleRefunds<T>>::insert(&pool_id, refund);
+			}
+
+			Self::deposit_event(Event::Refunded {
+				pool: pool_id,
+				who,
+				amount,
+				returned,
+			});
+
+			Ok(())
+		}
+
+		/// Create a new liquidity bootstrapping pool for given asset pair without the `CreatePoolOrigin`.
+		///
+		/// The dispatch origin for this call must be signed. The caller becomes the pool owner and
+		/// provides the initial liquidity. `T::PoolDeposit` is reserved from the caller until
+		/// the liquidity of the pool is removed.
+		///
+		/// On top of the bounds of `create_pool`, the sale must last at least `T::MinSaleDuration` blocks,
+		/// the initial weight can not exceed `T::MaxInitialWeight` and `repay_target` must be non-zero.
+		///
+		/// Parameters are the same as in `create_pool`, without the `pool_owner`.
+		///
+		/// Emits `PoolCreated` and `PoolDepositReserved` events when successful.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_pool_with_deposit())]
+		pub fn create_pool_with_deposit(
+			origin: OriginFor<T>,
+			asset_a: AssetId,
+			asset_a_amount: Balance,
+			asset_b: AssetId,
+			asset_b_amount: Balance,
+			initial_weight: LBPWeight,
+			final_weight: LBPWeight,
+			weight_curve: WeightCurveType,
+			fee: (u32, u32),
+			fee_collector: T::AccountId,
+			repay_target: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_create_pool(
+				who,
+				asset_a,
+				asset_a_amount,
+				asset_b,
+				asset_b_amount,
+				initial_weight,
+				final_weight,
+				weight_curve,
+				fee,
+				fee_collector,
+				repay_target,
+				Some(T::PoolDeposit::get()),
+			)
+		}
+
+		/// Cancel a pool before the start of the sale.
+		///
+		/// The dispatch origin for this call must be `T::CancelPoolOrigin`.
+		///
+		/// The liquidity of the pool is returned to the pool owner and the deposit of the pool,
+		/// if any, is slashed.
+		///
+		/// Parameters:
+		/// - `pool_id`: The identifier of the pool to be cancelled.
+		///
+		/// Emits `PoolCancelled` event when successful.
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_pool())]
+		pub fn cancel_pool(origin: OriginFor<T>, pool_id: PoolId<T>) -> DispatchResult {
+			T::CancelPoolOrigin::ensure_origin(origin)?;
+
+			let pool_data = <PoolData<T>>::try_get(&pool_id).map_err(|_| Error::<T>::PoolNotFound)?;
+
+			ensure!(!Self::has_pool_started(&pool_data), Error::<T>::SaleStarted);
+
+			let slashed = <PoolDeposits<T>>::take(&pool_id)
+				.map(|(depositor, deposit)| {
+					let (imbalance, _) = T::DepositCurrency::slash_reserved(&depositor, deposit);
+					let slashed = imbalance.peek();
+					T::SlashedDeposit::on_unbalanced(imbalance);
+					slashed
+				})
+				.unwrap_or_default();
+
+			Self::do_remove_liquidity(&pool_id, &pool_data)?;
+
+			Self::deposit_event(Event::PoolCancelled { pool: pool_id, slashed });
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	fn calculate_weights(
+		pool_data: &Pool<T::AccountId, BlockNumberFor<T>>,
+		at: BlockNumberFor<T>,
+	) -> Result<(LBPWeight, LBPWeight), DispatchError> {
+		let weight_a = T::LBPWeightFunction::calculate_weight(
+			pool_data.weight_curve,
+			pool_data.start.unwrap_or_else(Zero::zero),
+			pool_data.end.unwrap_or_else(Zero::zero),
+			pool_data.initial_weight,
+			pool_data.final_weight,
+			at,
+		)
 		.ok_or(Error::<T>::WeightCalculationError)?;
 
-		let weight_b = MAX_WEIGHT.saturating_sub(weight_a);
+		let weight_b = MAX_WEIGHT.saturating_sub(weight_a);
+
+		Ok((weight_a, weight_b))
+	}
+
+	fn validate_pool_data(pool_data: &Pool<T::AccountId, BlockNumberFor<T>>) -> DispatchResult {
+		let now = T::BlockNumberProvider::current_block_number();
+
+		ensure!(
+			match (pool_data.start, pool_data.end) {
+				(Some(start), Some(end)) => now < start && start < end,
+				(None, None) => true,
+				_ => false,
+			},
+			Error::<T>::InvalidBlockRange
+		);
+
+		// duration of the LBP sale should not exceed 2 weeks (assuming 6 sec blocks)
+		ensure!(
+			pool_data
+				.end
+				.unwrap_or_default()
+				.saturating_sub(pool_data.start.unwrap_or_default())
+				< MAX_SALE_DURATION.into(),
+			Error::<T>::MaxSaleDurationExceeded
+		);
+
+		// zero weight at the beginning or at the end of a sale may cause a problem in the price calculation
+		// Minimum allowed weight is 2%. The exponentiation used in the math can overflow when the ration between the weights is higher than 98/2.
+		ensure!(
+			!pool_data.initial_weight.is_zero()
+				&& pool_data.initial_weight < MAX_WEIGHT
+				&& pool_data.initial_weight >= MAX_WEIGHT / 50	// 2%
+				&& !pool_data.final_weight.is_zero()
+				&& pool_data.final_weight < MAX_WEIGHT
+				// when initial and final weights are >= 2%, then the weights are also <= 98%
+				&& pool_data.final_weight >= MAX_WEIGHT / 50, // 2%,
+			// TODO people could leak value out the pool if initial weight is < final weight due to fee structure
+			// && pool_data.initial_weight > pool_data.final_weight,
+			Error::<T>::InvalidWeight
+		);
+
+		ensure!(!pool_data.fee.1.is_zero(), Error::<T>::FeeAmountInvalid);
+
+		Ok(())
+	}
+
+	/// Create a pool with the initial liquidity provided by the `pool_owner`.
+	/// When `deposit` is set, the stricter bounds of pools created with a deposit are enforced
+	/// and the deposit is reserved from the `pool_owner`.
+	fn do_create_pool(
+		pool_owner: T::AccountId,
+		asset_a: AssetId,
+		asset_a_amount: Balance,
+		asset_b: AssetId,
+		asset_b_amount: Balance,
+		initial_weight: LBPWeight,
+		final_weight: LBPWeight,
+		weight_curve: WeightCurveType,
+		fee: (u32, u32),
+		fee_collector: T::AccountId,
+		repay_target: Balance,
+		deposit: Option<Balance>,
+	) -> DispatchResult {
+		ensure!(
+			asset_a_amount >= T::MinPoolLiquidity::get() && asset_b_amount >= T::MinPoolLiquidity::get(),
+			Error::<T>::InsufficientLiquidity
+		);
+
+		ensure!(asset_a != asset_b, Error::<T>::CannotCreatePoolWithSameAssets);
+
+		let asset_pair = AssetPair {
+			asset_in: asset_a,
+			asset_out: asset_b,
+		};
+
+		ensure!(!Self::exists(asset_pair), Error::<T>::PoolAlreadyExists);
+
+		Self::close_expired_refunds(&Self::get_pair_id(asset_pair))?;
+
+		ensure!(
+			!<FeeCollectorWithAsset<T>>::contains_key(fee_collector.clone(), asset_a),
+			Error::<T>::FeeCollectorWithAssetAlreadyUsed
+		);
+
+		ensure!(
+			T::MultiCurrency::free_balance(asset_a, &pool_owner) >= asset_a_amount,
+			Error::<T>::InsufficientAssetBalance
+		);
+
+		ensure!(
+			T::MultiCurrency::free_balance(asset_b, &pool_owner) >= asset_b_amount,
+			Error::<T>::InsufficientAssetBalance
+		);
+
+		let pool_data = Pool::new(
+			pool_owner.clone(),
+			asset_a,
+			asset_b,
+			initial_weight,
+			final_weight,
+			weight_curve,
+			fee,
+			fee_collector.clone(),
+			repay_target,
+		);
+
+		Self::validate_pool_data(&pool_data)?;
+
+		let pool_id = Self::get_pair_id(asset_pair);
 
-		Ok((weight_a, weight_b))
-	}
+		if let Some(deposit) = deposit {
+			Self::validate_deposit_pool_data(&pool_data)?;
 
-	fn validate_pool_data(pool_data: &Pool<T::AccountId, BlockNumberFor<T>>) -> DispatchResult {
-		let now = T::BlockNumberProvider::current_block_number();
+			T::DepositCurrency::reserve(&pool_owner, deposit)?;
+			<PoolDeposits<T>>::insert(&pool_id, (pool_owner.clone(), deposit));
 
-		ensure!(
-			match (pool_data.start, pool_data.end) {
-				(Some(start), Some(end)) => now < start && start < end,
-				(None, None) => true,
-				_ => false,
-			},
-			Error::<T>::InvalidBlockRange
-		);
+			Self::deposit_event(Event::PoolDepositReserved {
+				pool: pool_id.clone(),
+				who: pool_owner.clone(),
+				amount: deposit,
+			});
+		}
 
-		// duration of the LBP sale should not exceed 2 weeks (assuming 6 sec blocks)
-		ensure!(
-			pool_data
-				.end
-				.unwrap_or_default()
-				.saturating_sub(pool_data.start.unwrap_or_default())
-				< MAX_SALE_DURATION.into(),
-			Error::<T>::MaxSaleDurationExceeded
-		);
+		<PoolData<T>>::insert(&pool_id, &pool_data);
+		<FeeCollectorWithAsset<T>>::insert(fee_collector, asset_a, true);
+
+		Self::deposit_event(Event::PoolCreated {
+			pool: pool_id.clone(),
+			data: pool_data,
+		});
+
+		T::MultiCurrency::transfer(
+			asset_a,
+			&pool_owner,
+			&pool_id,
+			asset_a_amount,
+			ExistenceRequirement::AllowDeath,
+		)?;
+		T::MultiCurrency::transfer(
+			asset_b,
+			&pool_owner,
+			&pool_id,
+			asset_b_amount,
+			ExistenceRequirement::AllowDeath,
+		)?;
+
+		Self::deposit_event(Event::LiquidityAdded {
+			who: pool_id,
+			asset_a,
+			asset_b,
+			amount_a: asset_a_amount,
+			amount_b: asset_b_amount,
+		});
+
+		Ok(())
+	}
+
+	/// Stricter bounds of pools created with a deposit, on top of `validate_pool_data`.
+	fn validate_deposit_pool_data(pool_data: &Pool<T::AccountId, BlockNumberFor<T>>) -> DispatchResult {
+		ensure!(!pool_data.repay_target.is_zero(), Error::<T>::RepayTargetRequired);
 
-		// zero weight at the beginning or at the end of a sale may cause a problem in the price calculation
-		// Minimum allowed weight is 2%. The exponentiation used in the math can overflow when the ration between the weights is higher than 98/2.
 		ensure!(
-			!pool_data.initial_weight.is_zero()
-				&& pool_data.initial_weight < MAX_WEIGHT
-				&& pool_data.initial_weight >= MAX_WEIGHT / 50	// 2%
-				&& !pool_data.final_weight.is_zero()
-				&& pool_data.final_weight < MAX_WEIGHT
-				// when initial and final weights are >= 2%, then the weights are also <= 98%
-				&& pool_data.final_weight >= MAX_WEIGHT / 50, // 2%,
-			// TODO people could leak value out the pool if initial weight is < final weight due to fee structure
-			// && pool_data.initial_weight > pool_data.final_weight,
-			Error::<T>::InvalidWeight
+			pool_data.initial_weight <= T::MaxInitialWeight::get(),
+			Error::<T>::InitialWeightTooHigh
 		);
 
-		ensure!(!pool_data.fee.1.is_zero(), Error::<T>::FeeAmountInvalid);
+		if let (Some(start), Some(end)) = (pool_data.start, pool_data.end) {
+			ensure!(
+				end.saturating_sub(start) >= T::MinSaleDuration::get(),
+				Error::<T>::SaleDurationTooShort
+			);
+		}
 
 		Ok(())
 	}
@@ -866,15 +1938,41 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
-	/// return true if now is in interval <pool.start, pool.end>
-	fn is_pool_running(pool_data: &Pool<T::AccountId, BlockNumberFor<T>>) -> bool {
+	/// returns the last block of the sale, the block at which the hard cap was reached if it was reached before pool.end
+	/// note: pool.end is kept when the sale is closed early, so the weights follow the original curve
+	fn sale_end(pool_id: &PoolId<T>, pool_data: &Pool<T::AccountId, BlockNumberFor<T>>) -> Option<BlockNumberFor<T>> {
+		match (<SaleClosedAt<T>>::get(pool_id), pool_data.end) {
+			(Some(closed_at), Some(end)) => Some(closed_at.min(end)),
+			(_, end) => end,
+		}
+	}
+
+	/// return true if now is in interval <pool.start, sale end>
+	fn is_pool_running(pool_id: &PoolId<T>, pool_data: &Pool<T::AccountId, BlockNumberFor<T>>) -> bool {
 		let now = T::BlockNumberProvider::current_block_number();
-		match (pool_data.start, pool_data.end) {
+		match (pool_data.start, Self::sale_end(pool_id, pool_data)) {
 			(Some(start), Some(end)) => start <= now && now <= end,
 			_ => false,
 		}
 	}
 
+	/// return true if now is > sale end and pool has been initialized
+	fn has_pool_ended(pool_id: &PoolId<T>, pool_data: &Pool<T::AccountId, BlockNumberFor<T>>) -> bool {
+		let now = T::BlockNumberProvider::current_block_number();
+		Self::sale_end(pool_id, pool_data).is_some_and(|end| end < now)
+	}
+
+	/// return true if the sale has ended without reaching its soft cap
+	fn has_sale_failed(pool_id: &PoolId<T>, pool_data: &Pool<T::AccountId, BlockNumberFor<T>>) -> bool {
+		let Some((sale_id, caps)) = <PoolCaps<T>>::get(pool_id) else {
+			return false;
+		};
+
+		caps.soft_cap.is_some_and(|soft_cap| {
+			Self::has_pool_ended(pool_id, pool_data) && <SaleRaised<T>>::get(sale_id) < soft_cap
+		})
+	}
+
 	/// return true if now is > pool.start and pool has been initialized
 	fn has_pool_started(pool_data: &Pool<T::AccountId, BlockNumberFor<T>>) -> bool {
 		let now = T::BlockNumberProvider::current_block_number();
@@ -908,13 +2006,7 @@ impl<T: Config> Pallet<T> {
 			transfer.amount,
 			ExistenceRequirement::AllowDeath,
 		)?;
-		T::MultiCurrency::transfer(
-			transfer.assets.asset_out,
-			&pool_account,
-			&transfer.origin,
-			transfer.amount_b,
-			ExistenceRequirement::AllowDeath,
-		)?;
+		Self::transfer_bought_asset(&pool_account, &pool, transfer)?;
 
 		// Fee is deducted from the sent out amount of accumulated asset and transferred to the fee collector
 		let (fee_asset, fee_amount) = transfer.fee;
@@ -936,9 +2028,202 @@ impl<T: Config> Pallet<T> {
 		let collected_fee_total = Self::collected_fees(&pool) + fee_amount;
 		T::MultiCurrency::set_lock(COLLECTOR_LOCK_ID, fee_asset, &pool.fee_collector, collected_fee_total)?;
 
+		Self::record_participation(&pool_account, &pool, transfer)?;
+
+		Self::record_contribution(&pool_account, &pool, transfer)
+	}
+
+	/// Transfer the bought asset from the pool to the trader.
+	/// The sold asset of a sale with vesting is transferred locked by a vesting schedule.
+	fn transfer_bought_asset(
+		pool_account: &T::AccountId,
+		pool: &Pool<T::AccountId, BlockNumberFor<T>>,
+		transfer: &AMMTransfer<T::AccountId, AssetId, AssetPair, Balance>,
+	) -> DispatchResult {
+		let vesting = <PoolVesting<T>>::get(pool_account);
+
+		match (vesting, Self::sale_end(pool_account, pool)) {
+			(Some(vesting), Some(end)) if transfer.assets.asset_out == pool.assets.1 => T::Vesting::vested_transfer(
+				transfer.assets.asset_out,
+				pool_account,
+				&transfer.origin,
+				transfer.amount_b,
+				end.saturating_add(vesting.cliff),
+				vesting.duration,
+			),
+			_ => T::MultiCurrency::transfer(
+				transfer.assets.asset_out,
+				pool_account,
+				&transfer.origin,
+				transfer.amount_b,
+				ExistenceRequirement::AllowDeath,
+			),
+		}
+	}
+
+	/// Ensure that `who` is allowed to trade in the pool with respect to the restrictions of the sale.
+	/// `purchase` is the amount of the accumulated asset spent on the sold asset, zero if the asset is not bought.
+	fn ensure_participation_allowed(
+		who: &T::AccountId,
+		pool_id: &PoolId<T>,
+		purchase: BalanceOf<T>,
+		now: BlockNumberFor<T>,
+	) -> DispatchResult {
+		let Some((sale_id, restrictions)) = <PoolRestrictions<T>>::get(pool_id) else {
+			return Ok(());
+		};
+
+		if restrictions.allowlist.is_some() {
+			ensure!(<Allowlisted<T>>::get(sale_id, who), Error::<T>::NotAllowlisted);
+		}
+
+		let participation = <Participants<T>>::get(sale_id, who);
+
+		if let (Some(min_blocks), Some(last_trade)) = (restrictions.min_blocks_between_trades, participation.last_trade)
+		{
+			ensure!(now >= last_trade.saturating_add(min_blocks), Error::<T>::TradeTooSoon);
+		}
+
+		if let Some(max_purchase) = restrictions.max_purchase_per_account {
+			ensure!(
+				participation
+					.purchased
+					.checked_add(purchase)
+					.ok_or(Error::<T>::Overflow)?
+					<= max_purchase,
+				Error::<T>::MaxPurchaseExceeded
+			);
+		}
+
+		Ok(())
+	}
+
+	/// Track the trade of an account in a sale with restrictions.
+	fn record_participation(
+		pool_id: &PoolId<T>,
+		pool: &Pool<T::AccountId, BlockNumberFor<T>>,
+		transfer: &AMMTransfer<T::AccountId, AssetId, AssetPair, Balance>,
+	) -> DispatchResult {
+		let Some((sale_id, _)) = <PoolRestrictions<T>>::get(pool_id) else {
+			return Ok(());
+		};
+
+		let purchase = Self::accumulated_asset_spent(pool, transfer);
+
+		<Participants<T>>::try_mutate(sale_id, &transfer.origin, |participation| -> DispatchResult {
+			participation.purchased = participation
+				.purchased
+				.checked_add(purchase)
+				.ok_or(Error::<T>::Overflow)?;
+			participation.last_trade = Some(T::BlockNumberProvider::current_block_number());
+			Ok(())
+		})
+	}
+
+	/// Ensure that adding `amount` of the accumulated asset to the pool does not exceed the hard cap of the sale.
+	fn ensure_hard_cap_not_exceeded(pool_id: &PoolId<T>, amount: BalanceOf<T>) -> DispatchResult {
+		let Some((
+			sale_id,
+			SaleCaps {
+				hard_cap: Some(hard_cap),
+				..
+			},
+		)) = <PoolCaps<T>>::get(pool_id)
+		else {
+			return Ok(());
+		};
+
+		ensure!(
+			<SaleRaised<T>>::get(sale_id)
+				.checked_add(amount)
+				.ok_or(Error::<T>::Overflow)?
+				<= hard_cap,
+			Error::<T>::HardCapExceeded
+		);
+
+		Ok(())
+	}
+
+	/// Track the contribution of an account to a sale with caps.
+	/// The sale ends when the remaining amount to the hard cap is lower than the min trading limit.
+	fn record_contribution(
+		pool_id: &PoolId<T>,
+		pool: &Pool<T::AccountId, BlockNumberFor<T>>,
+		transfer: &AMMTransfer<T::AccountId, AssetId, AssetPair, Balance>,
+	) -> DispatchResult {
+		let Some((sale_id, caps)) = <PoolCaps<T>>::get(pool_id) else {
+			return Ok(());
+		};
+
+		let raised = <SaleRaised<T>>::try_mutate(sale_id, |raised| -> Result<BalanceOf<T>, DispatchError> {
+			<Contributions<T>>::try_mutate(sale_id, &transfer.origin, |contribution| -> DispatchResult {
+				if transfer.assets.asset_in == pool.assets.0 {
+					// accumulated asset (in) sans fee is added to the pool
+					contribution.contributed = contribution
+						.contributed
+						.checked_add(transfer.amount)
+						.ok_or(Error::<T>::Overflow)?;
+					contribution.bought = contribution
+						.bought
+						.checked_add(transfer.amount_b)
+						.ok_or(Error::<T>::Overflow)?;
+					*raised = raised.checked_add(transfer.amount).ok_or(Error::<T>::Overflow)?;
+				} else {
+					// accumulated asset (out) and fee are taken from the pool
+					let withdrawn = transfer
+						.amount_b
+						.saturating_add(transfer.fee.1)
+						.min(contribution.contributed);
+					contribution.contributed = contribution.contributed.saturating_sub(withdrawn);
+					contribution.bought = contribution.bought.saturating_sub(transfer.amount);
+					*raised = raised.saturating_sub(withdrawn);
+				}
+				Ok(())
+			})?;
+			Ok(*raised)
+		})?;
+
+		if let Some(hard_cap) = caps.hard_cap {
+			let now = T::BlockNumberProvider::current_block_number();
+			if hard_cap.saturating_sub(raised) < T::MinTradingLimit::get() && !<SaleClosedAt<T>>::contains_key(pool_id)
+			{
+				<SaleClosedAt<T>>::insert(pool_id, now);
+
+				Self::deposit_event(Event::HardCapReached {
+					pool: pool_id.clone(),
+					raised,
+				});
+			}
+		}
+
 		Ok(())
 	}
 
+	/// Amount of the accumulated asset, including the fee, spent by the trader on the sold asset.
+	fn accumulated_asset_spent(
+		pool: &Pool<T::AccountId, BlockNumberFor<T>>,
+		transfer: &AMMTransfer<T::AccountId, AssetId, AssetPair, Balance>,
+	) -> BalanceOf<T> {
+		if transfer.assets.asset_in == pool.assets.0 {
+			transfer.amount.saturating_add(transfer.fee.1)
+		} else {
+			Zero::zero()
+		}
+	}
+
+	/// Compute merkle root from the `leaf` and the sibling nodes on the path to the root.
+	/// Pairs of nodes are sorted before hashing.
+	pub fn compute_merkle_root(leaf: H256, proof: &[H256]) -> H256 {
+		proof.iter().fold(leaf, |node, sibling| {
+			let (left, right) = if node <= *sibling {
+				(node, *sibling)
+			} else {
+				(*sibling, node)
+			};
+			H256::from(blake2_256(&[left.as_bytes(), right.as_bytes()].concat()))
+		})
+	}
+
 	/// determines fee rate and applies it to the amount
 	fn calculate_fees(
 		pool: &Pool<T::AccountId, BlockNumberFor<T>>,
@@ -953,6 +2238,312 @@ impl<T: Config> Pallet<T> {
 			.ok_or::<Error<T>>(Error::<T>::FeeAmountInvalid)?)
 	}
 
+	/// Allocate identifier of a new sale.
+	fn next_sale_id() -> Result<SaleId, DispatchError> {
+		<NextSaleId<T>>::try_mutate(|next_id| -> Result<SaleId, DispatchError> {
+			let current_id = *next_id;
+			*next_id = next_id.checked_add(1).ok_or(Error::<T>::Overflow)?;
+			Ok(current_id)
+		})
+	}
+
+	/// Start refunds of the sale if it has ended without reaching its soft cap.
+	/// Contributions are reserved in the pool account, up to the available balance of the accumulated asset.
+	fn start_refunds(
+		pool_id: &PoolId<T>,
+		pool_data: &Pool<T::AccountId, BlockNumberFor<T>>,
+	) -> Result<Option<SaleRefund<T::AccountId, BlockNumberFor<T>>>, DispatchError> {
+		if let Some(refund) = <SaleRefunds<T>>::get(pool_id) {
+			return Ok(Some(refund));
+		}
+
+		if !Self::has_sale_failed(pool_id, pool_data) {
+			return Ok(None);
+		}
+
+		let Some((sale_id, _)) = <PoolCaps<T>>::get(pool_id) else {
+			return Ok(None);
+		};
+
+		let raised = <SaleRaised<T>>::get(sale_id);
+		if raised.is_zero() {
+			return Ok(None);
+		}
+
+		let refundable = raised.min(T::MultiCurrency::free_balance(pool_data.assets.0, pool_id));
+
+		let refund = SaleRefund {
+			sale_id,
+			started: T::BlockNumberProvider::current_block_number(),
+			owner: pool_data.owner.clone(),
+			assets: pool_data.assets,
+			total_contributed: raised,
+			refundable,
+			remaining: refundable,
+			unclaimed: raised,
+		};
+
+		<SaleRefunds<T>>::insert(pool_id, refund.clone());
+
+		Self::deposit_event(Event::RefundsStarted {
+			pool: pool_id.clone(),
+			refundable,
+		});
+
+		Ok(Some(refund))
+	}
+
+	/// Close refunds in the pool account once `T::RefundPeriod` has passed since they started.
+	/// The accumulated asset which has not been refunded is transferred to the pool owner.
+	fn close_expired_refunds(pool_id: &PoolId<T>) -> DispatchResult {
+		let Some(refund) = <SaleRefunds<T>>::get(pool_id) else {
+			return Ok(());
+		};
+
+		let now = T::BlockNumberProvider::current_block_number();
+		ensure!(
+			refund.started.saturating_add(T::RefundPeriod::get()) < now,
+			Error::<T>::RefundsPending
+		);
+
+		if !refund.remaining.is_zero() {
+			T::MultiCurrency::transfer(
+				refund.assets.0,
+				pool_id,
+				&refund.owner,
+				refund.remaining,
+				ExistenceRequirement::AllowDeath,
+			)?;
+		}
+
+		<SaleRefunds<T>>::remove(pool_id);
+
+		Self::deposit_event(Event::RefundsExpired {
+			pool: pool_id.clone(),
+			unclaimed: refund.remaining,
+		});
+
+		Ok(())
+	}
+
+	/// Transfer all the liquidity of the pool to the pool owner and remove the pool.
+	/// The accumulated asset reserved for refunds is kept in the pool account.
+	fn do_remove_liquidity(
+		pool_id: &PoolId<T>,
+		pool_data: &Pool<T::AccountId, BlockNumberFor<T>>,
+	) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+		let (asset_a, asset_b) = pool_data.assets;
+
+		let reserved_for_refunds = <SaleRefunds<T>>::get(pool_id)
+			.map(|refund| refund.remaining)
+			.unwrap_or_default();
+
+		let amount_a = T::MultiCurrency::free_balance(asset_a, pool_id).saturating_sub(reserved_for_refunds);
+		let amount_b = T::MultiCurrency::free_balance(asset_b, pool_id);
+
+		T::MultiCurrency::transfer(
+			asset_a,
+			pool_id,
+			&pool_data.owner,
+			amount_a,
+			ExistenceRequirement::AllowDeath,
+		)?;
+		T::MultiCurrency::transfer(
+			asset_b,
+			pool_id,
+			&pool_data.owner,
+			amount_b,
+			ExistenceRequirement::AllowDeath,
+		)?;
+
+		if Self::collected_fees(pool_data) > 0 {
+			T::MultiCurrency::remove_lock(COLLECTOR_LOCK_ID, asset_a, &pool_data.fee_collector)?;
+		}
+
+		<FeeCollectorWithAsset<T>>::remove(&pool_data.fee_collector, pool_data.assets.0);
+		<PoolData<T>>::remove(pool_id);
+		<XYKMigrations<T>>::remove(pool_id);
+		<PoolRestrictions<T>>::remove(pool_id);
+		<PoolVesting<T>>::remove(pool_id);
+		<PoolCaps<T>>::remove(pool_id);
+		<SaleClosedAt<T>>::remove(pool_id);
+
+		if let Some((depositor, deposit)) = <PoolDeposits<T>>::take(pool_id) {
+			T::DepositCurrency::unreserve(&depositor, deposit);
+		}
+
+		Self::deposit_event(Event::LiquidityRemoved {
+			who: pool_id.clone(),
+			asset_a,
+			asset_b,
+			amount_a,
+			amount_b,
+		});
+
+		Ok((amount_a, amount_b))
+	}
+
+	/// Migrate liquidity of ended pools into XYK pools within the `remaining_weight`.
+	///
+	/// Pools are visited in storage order starting after `XYKMigrationCursor`, so every pending
+	/// migration is eventually visited even if the weight runs out before the end of the map.
+	fn migrate_ended_pools(remaining_weight: Weight) -> Weight {
+		let now = T::BlockNumberProvider::current_block_number();
+
+		// XYKMigrationCursor
+		let cursor_weight = T::DbWeight::get().reads_writes(1, 1);
+		// XYKMigrations key and PoolData
+		let read_weight = T::DbWeight::get().reads(2);
+		let migration_weight = <T as Config>::WeightInfo::migrate_to_xyk();
+
+		if !remaining_weight.all_gte(cursor_weight.saturating_add(read_weight)) {
+			return Weight::zero();
+		}
+
+		let mut used_weight = cursor_weight;
+		let mut ended_pools = Vec::new();
+		let mut last_visited = None;
+		let mut exhausted = true;
+
+		let pool_ids = match <XYKMigrationCursor<T>>::get() {
+			Some(cursor) => <XYKMigrations<T>>::iter_keys_from(<XYKMigrations<T>>::hashed_key_for(cursor)),
+			None => <XYKMigrations<T>>::iter_keys(),
+		};
+
+		for pool_id in pool_ids {
+			if !remaining_weight.all_gte(used_weight.saturating_add(read_weight).saturating_add(migration_weight)) {
+				exhausted = false;
+				break;
+			}
+			used_weight.saturating_accrue(read_weight);
+
+			let has_ended = <PoolData<T>>::get(&pool_id)
+				.and_then(|pool_data| Self::sale_end(&pool_id, &pool_data))
+				.is_some_and(|end| end < now);
+
+			if has_ended {
+				used_weight.saturating_accrue(migration_weight);
+				ended_pools.push(pool_id.clone());
+			}
+			last_visited = Some(pool_id);
+		}
+
+		match (exhausted, last_visited) {
+			(false, Some(pool_id)) => <XYKMigrationCursor<T>>::put(pool_id),
+			(false, None) => {}
+			(true, _) => <XYKMigrationCursor<T>>::kill(),
+		}
+
+		for pool_id in ended_pools {
+			let Some(migration) = <XYKMigrations<T>>::take(&pool_id) else {
+				continue;
+			};
+
+			if let Err(error) = Self::migrate_to_xyk(&pool_id, migration) {
+				Self::deposit_event(Event::XYKMigrationFailed { pool: pool_id, error });
+			}
+		}
+
+		used_weight
+	}
+
+	/// Remove the pool liquidity and seed a new XYK pool with it at the final spot price of the sale.
+	#[transactional]
+	fn migrate_to_xyk(pool_id: &PoolId<T>, migration: XYKMigration<BlockNumberFor<T>>) -> DispatchResult {
+		let pool_data = <PoolData<T>>::try_get(pool_id).map_err(|_| Error::<T>::PoolNotFound)?;
+		let (asset_a, asset_b) = pool_data.assets;
+		let owner = pool_data.owner.clone();
+
+		ensure!(
+			!Self::has_sale_failed(pool_id, &pool_data),
+			Error::<T>::SoftCapNotReached
+		);
+
+		let (weight_a, weight_b) = Self::calculate_weights(
+			&pool_data,
+			Self::sale_end(pool_id, &pool_data).unwrap_or_else(Zero::zero),
+		)?;
+
+		let (reserve_a, reserve_b) = Self::do_remove_liquidity(pool_id, &pool_data)?;
+
+		let (amount_a, amount_b) =
+			Self::calculate_xyk_liquidity(reserve_a, reserve_b, weight_a, weight_b, migration.liquidity_share)?;
+
+		let (share_token, shares) = T::XYKPool::create_pool(&owner, asset_a, amount_a, asset_b, amount_b)?;
+
+		if let Some(lock) = migration.shares_lock {
+			ensure!(
+				!<LockedXYKShares<T>>::contains_key(&owner, share_token),
+				Error::<T>::XYKSharesAlreadyLocked
+			);
+
+			T::MultiCurrency::set_lock(XYK_SHARES_LOCK_ID, share_token, &owner, shares)?;
+
+			<LockedXYKShares<T>>::insert(
+				&owner,
+				share_token,
+				LockedShares {
+					amount: shares,
+					start: T::BlockNumberProvider::current_block_number(),
+					lock,
+				},
+			);
+		}
+
+		Self::deposit_event(Event::PoolMigrated {
+			pool: pool_id.clone(),
+			who: owner,
+			asset_a,
+			asset_b,
+			amount_a,
+			amount_b,
+			share_token,
+			shares,
+		});
+
+		Ok(())
+	}
+
+	/// Calculate amounts of the accumulated asset `a` and the sold asset `b` that seed the XYK pool.
+	///
+	/// `liquidity_share` of the accumulated asset is used, the amount of the sold asset corresponds to the spot price
+	/// given by the reserves and weights. If the sold asset reserve is insufficient, the amounts are scaled down.
+	fn calculate_xyk_liquidity(
+		reserve_a: Balance,
+		reserve_b: Balance,
+		weight_a: LBPWeight,
+		weight_b: LBPWeight,
+		liquidity_share: Permill,
+	) -> Result<(Balance, Balance), DispatchError> {
+		ensure!(
+			!reserve_a.is_zero() && !reserve_b.is_zero() && !weight_a.is_zero() && !weight_b.is_zero(),
+			Error::<T>::InsufficientLiquidity
+		);
+
+		let amount_a = liquidity_share.mul_floor(reserve_a);
+
+		// spot price of b denominated in a is (reserve_a / weight_a) / (reserve_b / weight_b)
+		let amount_b = U256::from(amount_a)
+			.checked_mul(U256::from(reserve_b))
+			.and_then(|v| v.checked_mul(U256::from(weight_a)))
+			.and_then(|v| v.checked_div(U256::from(reserve_a).checked_mul(U256::from(weight_b))?))
+			.ok_or(Error::<T>::Overflow)?;
+
+		if amount_b <= U256::from(reserve_b) {
+			let amount_b = Balance::try_from(amount_b).map_err(|_| Error::<T>::Overflow)?;
+			return Ok((amount_a, amount_b));
+		}
+
+		// whole sold asset reserve is used, amount of the accumulated asset is given by the spot price
+		let amount_a = U256::from(reserve_a)
+			.checked_mul(U256::from(weight_b))
+			.and_then(|v| v.checked_div(U256::from(weight_a)))
+			.and_then(|v| Balance::try_from(v).ok())
+			.ok_or(Error::<T>::Overflow)?;
+
+		Ok((amount_a, reserve_b))
+	}
+
 	pub fn pair_account_from_assets(asset_a: AssetId, asset_b: AssetId) -> PoolId<T> {
 		T::AssetPairAccountId::from_assets(asset_a, asset_b, "lbp")
 	}
@@ -1029,7 +2620,10 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, BalanceOf<T>> for Pallet<T
 		let pool_id = Self::get_pair_id(assets);
 		let pool_data = <PoolData<T>>::try_get(&pool_id).map_err(|_| Error::<T>::PoolNotFound)?;
 
-		ensure!(Self::is_pool_running(&pool_data), Error::<T>::SaleIsNotRunning);
+		ensure!(
+			Self::is_pool_running(&pool_id, &pool_data),
+			Error::<T>::SaleIsNotRunning
+		);
 
 		let now = T::BlockNumberProvider::current_block_number();
 		let (weight_in, weight_out) = Self::get_sorted_weight(assets.asset_in, now, &pool_data)?;
@@ -1053,6 +2647,8 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, BalanceOf<T>> for Pallet<T
 		// Take fee from the seller and send to fee collector
 		// Pool bears repay fee
 		if fee_asset == assets.asset_in {
+			Self::ensure_participation_allowed(who, &pool_id, amount, now)?;
+
 			let fee = Self::calculate_fees(&pool_data, amount)?;
 
 			let amount_out = hydra_dx_math::lbp::calculate_out_given_in(
@@ -1076,6 +2672,8 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, BalanceOf<T>> for Pallet<T
 
 			let amount_without_fee = amount.checked_sub(fee).ok_or(Error::<T>::Overflow)?;
 
+			Self::ensure_hard_cap_not_exceeded(&pool_id, amount_without_fee)?;
+
 			Ok(AMMTransfer {
 				origin: who.clone(),
 				assets,
@@ -1092,6 +2690,8 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, BalanceOf<T>> for Pallet<T
 		// Take fee from the pool and send to fee collector
 		// Seller bears repay fee
 		} else {
+			Self::ensure_participation_allowed(who, &pool_id, Zero::zero(), now)?;
+
 			let calculated_out = hydra_dx_math::lbp::calculate_out_given_in(
 				asset_in_reserve,
 				asset_out_reserve,
@@ -1172,7 +2772,10 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, BalanceOf<T>> for Pallet<T
 		let pool_id = Self::get_pair_id(assets);
 		let pool_data = <PoolData<T>>::try_get(&pool_id).map_err(|_| Error::<T>::PoolNotFound)?;
 
-		ensure!(Self::is_pool_running(&pool_data), Error::<T>::SaleIsNotRunning);
+		ensure!(
+			Self::is_pool_running(&pool_id, &pool_data),
+			Error::<T>::SaleIsNotRunning
+		);
 
 		let now = T::BlockNumberProvider::current_block_number();
 		let (weight_in, weight_out) = Self::get_sorted_weight(assets.asset_in, now, &pool_data)?;
@@ -1196,6 +2799,8 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, BalanceOf<T>> for Pallet<T
 		// Take fee from the pool and send to fee collector
 		// Buyer bears repay fee
 		if fee_asset == assets.asset_out {
+			Self::ensure_participation_allowed(who, &pool_id, Zero::zero(), now)?;
+
 			let fee = Self::calculate_fees(&pool_data, amount)?;
 			let amount_out_plus_fee = amount.checked_add(fee).ok_or(Error::<T>::Overflow)?;
 
@@ -1248,9 +2853,13 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, BalanceOf<T>> for Pallet<T
 			)
 			.map_err(|_| Error::<T>::Overflow)?;
 
+			Self::ensure_participation_allowed(who, &pool_id, calculated_in, now)?;
+
 			let fee = Self::calculate_fees(&pool_data, calculated_in)?;
 			let calculated_in_without_fee = calculated_in.checked_sub(fee).ok_or(Error::<T>::Overflow)?;
 
+			Self::ensure_hard_cap_not_exceeded(&pool_id, calculated_in_without_fee)?;
+
 			ensure!(
 				calculated_in
 					<= asset_in_reserve
@@ -1345,10 +2954,14 @@ pub struct DisallowWhenLBPPoolRunning<T>(sp_std::marker::PhantomData<T>);
 impl<T: Config> CanCreatePool<AssetId> for DisallowWhenLBPPoolRunning<T> {
 	fn can_create(asset_a: AssetId, asset_b: AssetId) -> bool {
 		let pool_id = Pallet::<T>::pair_account_from_assets(asset_a, asset_b);
+		// the XYK pool is created by the pending migration
+		if <XYKMigrations<T>>::contains_key(&pool_id) {
+			return false;
+		}
 		let now = T::BlockNumberProvider::current_block_number();
 		match <PoolData<T>>::try_get(&pool_id) {
 			// returns true if the pool exists and the sale ended
-			Ok(data) => match data.end {
+			Ok(data) => match Pallet::<T>::sale_end(&pool_id, &data) {
 				Some(end) => end < now,
 				None => false,
 			},
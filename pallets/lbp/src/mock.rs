@@ -1,18 +1,20 @@
 use crate as lbp;
 use crate::{
 	types::{AssetId, AssetPair, Balance},
-	AssetPairAccountIdFor, Config, Pool, WeightCurveType,
+	AssetPairAccountIdFor, Config, GenesisPool, Pool, WeightCurveType,
 };
-use basilisk_traits::AMMTransfer;
+use basilisk_traits::{AMMTransfer, CreateXYKPool, VestedTransfer};
 use frame_support::parameter_types;
-use frame_support::traits::{Everything, LockIdentifier, Nothing};
+use frame_support::traits::{Everything, ExistenceRequirement, LockIdentifier, Nothing};
+use hydra_dx_math::types::LBPWeight;
 use hydradx_traits::LockedBalance;
-use orml_traits::parameter_type_with_key;
+use orml_traits::{parameter_type_with_key, MultiCurrency, MultiCurrencyExtended};
 use sp_core::H256;
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup, Zero},
-	BuildStorage,
+	BuildStorage, DispatchError,
 };
+use std::cell::RefCell;
 use std::collections::BTreeMap;
 
 pub type Amount = i128;
@@ -40,6 +42,7 @@ pub const HDX_BSX_POOL_ID: AccountId = 3_000;
 pub const KUSD_BSX_POOL_ID: AccountId = 2_003_000;
 
 pub const DEFAULT_FEE: (u32, u32) = (2, 1_000);
+pub const POOL_DEPOSIT: Balance = 1_000_000_000;
 
 pub const SAMPLE_POOL_DATA: Pool<AccountId, BlockNumber> = Pool {
 	owner: ALICE,
@@ -158,6 +161,11 @@ parameter_types! {
 	pub const MinPoolLiquidity: Balance = 1_000;
 	pub const MaxInRatio: u128 = 3;
 	pub const MaxOutRatio: u128 = 3;
+	pub const MaxAllowlistUpdate: u32 = 10;
+	pub const PoolDeposit: Balance = POOL_DEPOSIT;
+	pub const MinSaleDuration: BlockNumber = 20;
+	pub const MaxInitialWeight: LBPWeight = 50_000_000;
+	pub const RefundPeriod: BlockNumber = 100;
 }
 
 pub struct MultiLockedBalance();
@@ -174,6 +182,91 @@ impl LockedBalance<AssetId, AccountId, Balance> for MultiLockedBalance {
 	}
 }
 
+thread_local! {
+	pub static XYK_POOLS: RefCell<Vec<(AssetId, AssetId)>> = const { RefCell::new(Vec::new()) };
+}
+
+pub fn xyk_share_token(asset_a: AssetId, asset_b: AssetId) -> AssetId {
+	AssetPairAccountIdTest::from_assets(asset_a, asset_b, "xyk") as AssetId
+}
+
+pub fn xyk_pool_account(asset_a: AssetId, asset_b: AssetId) -> AccountId {
+	AssetPairAccountIdTest::from_assets(asset_a, asset_b, "xyk") + 1_000_000_000
+}
+
+pub struct DummyXYK;
+
+impl CreateXYKPool<AccountId, AssetId, Balance> for DummyXYK {
+	fn create_pool(
+		who: &AccountId,
+		asset_a: AssetId,
+		amount_a: Balance,
+		asset_b: AssetId,
+		amount_b: Balance,
+	) -> Result<(AssetId, Balance), DispatchError> {
+		let pair = (asset_a.min(asset_b), asset_a.max(asset_b));
+		if XYK_POOLS.with(|v| v.borrow().contains(&pair)) {
+			return Err(DispatchError::CannotLookup);
+		}
+
+		let pool_account = xyk_pool_account(asset_a, asset_b);
+		<Currency as MultiCurrency<AccountId>>::transfer(
+			asset_a,
+			who,
+			&pool_account,
+			amount_a,
+			ExistenceRequirement::AllowDeath,
+		)?;
+		<Currency as MultiCurrency<AccountId>>::transfer(
+			asset_b,
+			who,
+			&pool_account,
+			amount_b,
+			ExistenceRequirement::AllowDeath,
+		)?;
+
+		let share_token = xyk_share_token(asset_a, asset_b);
+		let shares = if asset_a < asset_b { amount_a } else { amount_b };
+		<Currency as MultiCurrencyExtended<AccountId>>::update_balance(share_token, who, shares as Amount)?;
+
+		XYK_POOLS.with(|v| v.borrow_mut().push(pair));
+
+		Ok((share_token, shares))
+	}
+}
+
+thread_local! {
+	pub static VESTED_TRANSFERS: RefCell<Vec<(AssetId, AccountId, Balance, BlockNumber, BlockNumber)>> = const { RefCell::new(Vec::new()) };
+}
+
+pub fn vested_transfers() -> Vec<(AssetId, AccountId, Balance, BlockNumber, BlockNumber)> {
+	VESTED_TRANSFERS.with(|v| v.borrow().clone())
+}
+
+/// Records vested transfers, ETH can not be vested.
+pub struct DummyVesting;
+
+impl VestedTransfer<AccountId, AssetId, Balance, BlockNumber> for DummyVesting {
+	fn is_supported(asset: AssetId) -> bool {
+		asset != ETH
+	}
+
+	fn vested_transfer(
+		asset: AssetId,
+		from: &AccountId,
+		to: &AccountId,
+		amount: Balance,
+		start: BlockNumber,
+		duration: BlockNumber,
+	) -> Result<(), DispatchError> {
+		<Currency as MultiCurrency<AccountId>>::transfer(asset, from, to, amount, ExistenceRequirement::AllowDeath)?;
+
+		VESTED_TRANSFERS.with(|v| v.borrow_mut().push((asset, *to, amount, start, duration)));
+
+		Ok(())
+	}
+}
+
 impl pallet_broadcast::Config for Test {}
 
 impl Config for Test {
@@ -188,10 +281,24 @@ impl Config for Test {
 	type MaxInRatio = MaxInRatio;
 	type MaxOutRatio = MaxOutRatio;
 	type BlockNumberProvider = System;
+	type XYKPool = DummyXYK;
+	type AllowlistOrigin = frame_system::EnsureRoot<u64>;
+	type MaxAllowlistUpdate = MaxAllowlistUpdate;
+	type Vesting = DummyVesting;
+	type DepositCurrency = orml_tokens::CurrencyAdapter<Test, NativeAssetId>;
+	type PoolDeposit = PoolDeposit;
+	type SlashedDeposit = ();
+	type CancelPoolOrigin = frame_system::EnsureRoot<u64>;
+	type MinSaleDuration = MinSaleDuration;
+	type MaxInitialWeight = MaxInitialWeight;
+	type RefundPeriod = RefundPeriod;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 }
 
 pub struct ExtBuilder {
 	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+	pools: Vec<GenesisPool<AccountId, BlockNumber>>,
 }
 
 impl Default for ExtBuilder {
@@ -207,6 +314,7 @@ impl Default for ExtBuilder {
 				(BOB, KUSD, INITIAL_BALANCE),
 				(BOB, ETH, INITIAL_ETH_BALANCE),
 			],
+			pools: vec![],
 		}
 	}
 }
@@ -217,7 +325,15 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn with_pools(mut self, pools: Vec<GenesisPool<AccountId, BlockNumber>>) -> Self {
+		self.pools = pools;
+		self
+	}
+
 	pub fn build(self) -> sp_io::TestExternalities {
+		XYK_POOLS.with(|v| v.borrow_mut().clear());
+		VESTED_TRANSFERS.with(|v| v.borrow_mut().clear());
+
 		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 
 		orml_tokens::GenesisConfig::<Test> {
@@ -226,6 +342,10 @@ impl ExtBuilder {
 		.assimilate_storage(&mut t)
 		.unwrap();
 
+		crate::GenesisConfig::<Test> { pools: self.pools }
+			.assimilate_storage(&mut t)
+			.unwrap();
+
 		t.into()
 	}
 }
@@ -21,8 +21,8 @@
 use super::*;
 use crate::mock::{
 	expect_events, generate_trades, run_to_sale_end, run_to_sale_start, AccountId, RuntimeCall as Call, DEFAULT_FEE,
-	EXISTENTIAL_DEPOSIT, HDX_BSX_POOL_ID, INITIAL_BALANCE, INITIAL_ETH_BALANCE, KUSD_BSX_POOL_ID, SALE_END, SALE_START,
-	SAMPLE_AMM_TRANSFER, SAMPLE_POOL_DATA,
+	EXISTENTIAL_DEPOSIT, HDX_BSX_POOL_ID, INITIAL_BALANCE, INITIAL_ETH_BALANCE, KUSD_BSX_POOL_ID, POOL_DEPOSIT,
+	SALE_END, SALE_START, SAMPLE_AMM_TRANSFER, SAMPLE_POOL_DATA,
 };
 pub use crate::mock::{
 	set_block_number, Currency, ExtBuilder, LBPPallet, RuntimeOrigin as Origin, Test, ALICE, BOB, BSX, CHARLIE, ETH,
@@ -413,6 +413,58 @@ fn create_pool_should_work() {
 	});
 }
 
+#[test]
+fn genesis_config_should_create_scheduled_pools() {
+	ExtBuilder::default()
+		.with_pools(vec![(
+			ALICE,
+			(KUSD, 1_000_000_000),
+			(BSX, 2_000_000_000),
+			(20_000_000, 90_000_000),
+			(10, 40),
+			DEFAULT_FEE,
+			CHARLIE,
+			0,
+		)])
+		.build()
+		.execute_with(|| {
+			assert_eq!(Currency::free_balance(KUSD, &KUSD_BSX_POOL_ID), 1_000_000_000);
+			assert_eq!(Currency::free_balance(BSX, &KUSD_BSX_POOL_ID), 2_000_000_000);
+			assert_eq!(
+				Currency::free_balance(KUSD, &ALICE),
+				INITIAL_BALANCE.saturating_sub(1_000_000_000)
+			);
+
+			let pool_data = LBPPallet::pool_data(KUSD_BSX_POOL_ID).unwrap();
+			assert_eq!(pool_data.owner, ALICE);
+			assert_eq!(pool_data.start, Some(10));
+			assert_eq!(pool_data.end, Some(40));
+			assert_eq!(pool_data.assets, (KUSD, BSX));
+			assert_eq!(pool_data.initial_weight, 20_000_000);
+			assert_eq!(pool_data.final_weight, 90_000_000);
+			assert_eq!(pool_data.fee_collector, CHARLIE);
+
+			assert!(<FeeCollectorWithAsset<Test>>::contains_key(CHARLIE, KUSD));
+		});
+}
+
+#[test]
+#[should_panic(expected = "Invalid schedule of LBP pool in genesis")]
+fn genesis_config_should_panic_when_schedule_is_invalid() {
+	ExtBuilder::default()
+		.with_pools(vec![(
+			ALICE,
+			(KUSD, 1_000_000_000),
+			(BSX, 2_000_000_000),
+			(20_000_000, 90_000_000),
+			(40, 10),
+			DEFAULT_FEE,
+			CHARLIE,
+			0,
+		)])
+		.build();
+}
+
 #[test]
 fn create_pool_from_basic_origin_should_not_work() {
 	new_test_ext().execute_with(|| {
@@ -4176,3 +4228,1677 @@ mod spot_price_calculation {
 		})
 	}
 }
+
+fn xyk_migration(
+	liquidity_share: Permill,
+	shares_lock: Option<SharesLock<mock::BlockNumber>>,
+) -> XYKMigration<mock::BlockNumber> {
+	XYKMigration {
+		liquidity_share,
+		shares_lock,
+	}
+}
+
+fn run_on_idle(n: mock::BlockNumber) {
+	set_block_number(n);
+	<LBPPallet as frame_support::traits::Hooks<mock::BlockNumber>>::on_idle(n, Weight::MAX);
+}
+
+#[test]
+fn set_xyk_migration_should_work() {
+	predefined_test_ext().execute_with(|| {
+		let migration = xyk_migration(Permill::from_percent(10), Some(SharesLock::Until(100)));
+
+		assert_ok!(LBPPallet::set_xyk_migration(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(migration)
+		));
+
+		assert_eq!(<XYKMigrations<Test>>::get(KUSD_BSX_POOL_ID), Some(migration));
+
+		expect_events(vec![Event::XYKMigrationSet {
+			pool: KUSD_BSX_POOL_ID,
+			migration: Some(migration),
+		}
+		.into()]);
+
+		assert_ok!(LBPPallet::set_xyk_migration(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			None
+		));
+
+		assert!(!<XYKMigrations<Test>>::contains_key(KUSD_BSX_POOL_ID));
+	});
+}
+
+#[test]
+fn set_xyk_migration_should_not_work_when_invalid() {
+	predefined_test_ext().execute_with(|| {
+		let migration = xyk_migration(Permill::from_percent(10), None);
+
+		assert_noop!(
+			LBPPallet::set_xyk_migration(Origin::signed(BOB), KUSD_BSX_POOL_ID, Some(migration)),
+			Error::<Test>::NotOwner
+		);
+
+		assert_noop!(
+			LBPPallet::set_xyk_migration(Origin::signed(ALICE), HDX_BSX_POOL_ID, Some(migration)),
+			Error::<Test>::PoolNotFound
+		);
+
+		assert_noop!(
+			LBPPallet::set_xyk_migration(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				Some(xyk_migration(Permill::zero(), None))
+			),
+			Error::<Test>::ZeroLiquidityShare
+		);
+
+		run_to_sale_start();
+
+		assert_noop!(
+			LBPPallet::set_xyk_migration(Origin::signed(ALICE), KUSD_BSX_POOL_ID, Some(migration)),
+			Error::<Test>::SaleStarted
+		);
+	});
+}
+
+#[test]
+fn pool_should_be_migrated_to_xyk_when_sale_ends() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_xyk_migration(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(xyk_migration(Permill::from_percent(10), None))
+		));
+
+		let share_token = mock::xyk_share_token(KUSD, BSX);
+		let xyk_pool = mock::xyk_pool_account(KUSD, BSX);
+
+		// sale has not ended yet
+		run_on_idle(SALE_END.unwrap());
+		assert!(<PoolData<Test>>::contains_key(KUSD_BSX_POOL_ID));
+
+		run_on_idle(SALE_END.unwrap() + 1);
+
+		assert!(!<PoolData<Test>>::contains_key(KUSD_BSX_POOL_ID));
+		assert!(!<XYKMigrations<Test>>::contains_key(KUSD_BSX_POOL_ID));
+		assert!(!<FeeCollectorWithAsset<Test>>::contains_key(CHARLIE, KUSD));
+
+		// final weights are 80% KUSD / 20% BSX, spot price is 1 BSX = 2 KUSD
+		assert_eq!(Currency::free_balance(KUSD, &xyk_pool), 100_000_000);
+		assert_eq!(Currency::free_balance(BSX, &xyk_pool), 800_000_000);
+		assert_eq!(Currency::free_balance(KUSD, &ALICE), INITIAL_BALANCE - 100_000_000);
+		assert_eq!(Currency::free_balance(BSX, &ALICE), INITIAL_BALANCE - 800_000_000);
+		assert_eq!(Currency::free_balance(share_token, &ALICE), 100_000_000);
+		assert!(!<LockedXYKShares<Test>>::contains_key(ALICE, share_token));
+
+		expect_events(vec![Event::PoolMigrated {
+			pool: KUSD_BSX_POOL_ID,
+			who: ALICE,
+			asset_a: KUSD,
+			asset_b: BSX,
+			amount_a: 100_000_000,
+			amount_b: 800_000_000,
+			share_token,
+			shares: 100_000_000,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn migration_should_use_whole_sold_asset_reserve_when_insufficient() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_xyk_migration(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(xyk_migration(Permill::from_percent(50), None))
+		));
+
+		run_on_idle(SALE_END.unwrap() + 1);
+
+		let xyk_pool = mock::xyk_pool_account(KUSD, BSX);
+		assert_eq!(Currency::free_balance(KUSD, &xyk_pool), 250_000_000);
+		assert_eq!(Currency::free_balance(BSX, &xyk_pool), 2_000_000_000);
+		assert_eq!(Currency::free_balance(KUSD, &ALICE), INITIAL_BALANCE - 250_000_000);
+		assert_eq!(Currency::free_balance(BSX, &ALICE), INITIAL_BALANCE - 2_000_000_000);
+	});
+}
+
+#[test]
+fn failed_migration_should_leave_pool_untouched() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_xyk_migration(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(xyk_migration(Permill::from_percent(10), None))
+		));
+
+		mock::XYK_POOLS.with(|v| v.borrow_mut().push((KUSD, BSX)));
+
+		run_on_idle(SALE_END.unwrap() + 1);
+
+		assert!(<PoolData<Test>>::contains_key(KUSD_BSX_POOL_ID));
+		assert!(!<XYKMigrations<Test>>::contains_key(KUSD_BSX_POOL_ID));
+		assert_eq!(Currency::free_balance(KUSD, &KUSD_BSX_POOL_ID), 1_000_000_000);
+		assert_eq!(Currency::free_balance(BSX, &KUSD_BSX_POOL_ID), 2_000_000_000);
+
+		expect_events(vec![Event::XYKMigrationFailed {
+			pool: KUSD_BSX_POOL_ID,
+			error: DispatchError::CannotLookup,
+		}
+		.into()]);
+
+		// liquidity can still be removed by the owner
+		assert_ok!(LBPPallet::remove_liquidity(Origin::signed(ALICE), KUSD_BSX_POOL_ID));
+	});
+}
+
+#[test]
+fn migrated_shares_should_be_locked_until_block() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_xyk_migration(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(xyk_migration(Permill::from_percent(10), Some(SharesLock::Until(100))))
+		));
+
+		run_on_idle(SALE_END.unwrap() + 1);
+
+		let share_token = mock::xyk_share_token(KUSD, BSX);
+		assert_eq!(
+			mock::MultiLockedBalance::get_by_lock(XYK_SHARES_LOCK_ID, share_token, ALICE),
+			100_000_000
+		);
+
+		set_block_number(99);
+		assert_ok!(LBPPallet::unlock_xyk_shares(Origin::signed(ALICE), share_token));
+		assert_eq!(
+			mock::MultiLockedBalance::get_by_lock(XYK_SHARES_LOCK_ID, share_token, ALICE),
+			100_000_000
+		);
+
+		set_block_number(100);
+		assert_ok!(LBPPallet::unlock_xyk_shares(Origin::signed(ALICE), share_token));
+		assert_eq!(
+			mock::MultiLockedBalance::get_by_lock(XYK_SHARES_LOCK_ID, share_token, ALICE),
+			0
+		);
+		assert!(!<LockedXYKShares<Test>>::contains_key(ALICE, share_token));
+
+		expect_events(vec![Event::XYKSharesUnlocked {
+			who: ALICE,
+			share_token,
+			remaining_locked: 0,
+		}
+		.into()]);
+
+		assert_noop!(
+			LBPPallet::unlock_xyk_shares(Origin::signed(ALICE), share_token),
+			Error::<Test>::XYKSharesNotLocked
+		);
+	});
+}
+
+#[test]
+fn migrated_shares_should_be_vested_linearly() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_xyk_migration(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(xyk_migration(
+				Permill::from_percent(10),
+				Some(SharesLock::VestedUntil(141))
+			))
+		));
+
+		run_on_idle(41);
+
+		let share_token = mock::xyk_share_token(KUSD, BSX);
+		assert_eq!(
+			<LockedXYKShares<Test>>::get(ALICE, share_token),
+			Some(LockedShares {
+				amount: 100_000_000,
+				start: 41,
+				lock: SharesLock::VestedUntil(141),
+			})
+		);
+
+		set_block_number(91);
+		assert_ok!(LBPPallet::unlock_xyk_shares(Origin::signed(ALICE), share_token));
+		assert_eq!(
+			mock::MultiLockedBalance::get_by_lock(XYK_SHARES_LOCK_ID, share_token, ALICE),
+			50_000_000
+		);
+
+		expect_events(vec![Event::XYKSharesUnlocked {
+			who: ALICE,
+			share_token,
+			remaining_locked: 50_000_000,
+		}
+		.into()]);
+
+		set_block_number(141);
+		assert_ok!(LBPPallet::unlock_xyk_shares(Origin::signed(ALICE), share_token));
+		assert!(!<LockedXYKShares<Test>>::contains_key(ALICE, share_token));
+	});
+}
+
+#[test]
+fn remove_liquidity_should_clear_xyk_migration() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_xyk_migration(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(xyk_migration(Permill::from_percent(10), None))
+		));
+
+		assert_ok!(LBPPallet::remove_liquidity(Origin::signed(ALICE), KUSD_BSX_POOL_ID));
+
+		assert!(!<XYKMigrations<Test>>::contains_key(KUSD_BSX_POOL_ID));
+
+		run_on_idle(SALE_END.unwrap() + 1);
+
+		assert_eq!(Currency::free_balance(mock::xyk_share_token(KUSD, BSX), &ALICE), 0);
+	});
+}
+
+#[test]
+fn can_create_should_not_work_when_xyk_migration_is_pending() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_xyk_migration(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(xyk_migration(Permill::from_percent(10), None))
+		));
+
+		// sale ended but the pool has not been migrated yet
+		set_block_number(SALE_END.unwrap() + 1);
+		assert!(!DisallowWhenLBPPoolRunning::<Test>::can_create(KUSD, BSX));
+
+		run_on_idle(SALE_END.unwrap() + 1);
+
+		assert!(!<XYKMigrations<Test>>::contains_key(KUSD_BSX_POOL_ID));
+		assert!(DisallowWhenLBPPoolRunning::<Test>::can_create(KUSD, BSX));
+	});
+}
+
+#[test]
+fn migration_sweep_should_continue_from_cursor_when_weight_runs_out() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::create_pool(
+			Origin::root(),
+			ALICE,
+			HDX,
+			1_000_000_000,
+			BSX,
+			2_000_000_000,
+			20_000_000,
+			80_000_000,
+			WeightCurveType::Linear,
+			DEFAULT_FEE,
+			BOB,
+			0,
+		));
+		assert_ok!(LBPPallet::update_pool_data(
+			Origin::signed(ALICE),
+			HDX_BSX_POOL_ID,
+			None,
+			SALE_START,
+			SALE_END,
+			None,
+			None,
+			None,
+			None,
+			None,
+		));
+
+		for pool_id in [KUSD_BSX_POOL_ID, HDX_BSX_POOL_ID] {
+			assert_ok!(LBPPallet::set_xyk_migration(
+				Origin::signed(ALICE),
+				pool_id,
+				Some(xyk_migration(Permill::from_percent(10), None))
+			));
+		}
+
+		// enough weight for a single migration per block
+		let weight = <Test as Config>::WeightInfo::migrate_to_xyk();
+		let on_idle = |n: mock::BlockNumber| {
+			set_block_number(n);
+			<LBPPallet as frame_support::traits::Hooks<mock::BlockNumber>>::on_idle(n, weight)
+		};
+
+		on_idle(SALE_END.unwrap() + 1);
+
+		let pending = [KUSD_BSX_POOL_ID, HDX_BSX_POOL_ID]
+			.into_iter()
+			.filter(|pool_id| <XYKMigrations<Test>>::contains_key(pool_id))
+			.count();
+		assert_eq!(pending, 1);
+		assert!(<XYKMigrationCursor<Test>>::get().is_some());
+
+		on_idle(SALE_END.unwrap() + 2);
+
+		assert!(!<XYKMigrations<Test>>::contains_key(KUSD_BSX_POOL_ID));
+		assert!(!<XYKMigrations<Test>>::contains_key(HDX_BSX_POOL_ID));
+		assert!(!<PoolData<Test>>::contains_key(KUSD_BSX_POOL_ID));
+		assert!(!<PoolData<Test>>::contains_key(HDX_BSX_POOL_ID));
+		assert_eq!(<XYKMigrationCursor<Test>>::get(), None);
+	});
+}
+
+fn sale_restrictions(
+	max_purchase_per_account: Option<Balance>,
+	allowlist: Option<Allowlist>,
+	min_blocks_between_trades: Option<mock::BlockNumber>,
+) -> SaleRestrictions<mock::BlockNumber> {
+	SaleRestrictions {
+		max_purchase_per_account,
+		allowlist,
+		min_blocks_between_trades,
+	}
+}
+
+fn allowlist_leaf(who: AccountId) -> H256 {
+	H256::from(blake2_256(&who.encode()))
+}
+
+#[test]
+fn set_sale_restrictions_should_work() {
+	predefined_test_ext().execute_with(|| {
+		let restrictions = sale_restrictions(Some(1_000), Some(Allowlist::Origin), Some(5));
+
+		assert_ok!(LBPPallet::set_sale_restrictions(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(restrictions.clone())
+		));
+
+		assert_eq!(
+			LBPPallet::sale_restrictions(KUSD_BSX_POOL_ID),
+			Some((0, restrictions.clone()))
+		);
+
+		expect_events(vec![Event::SaleRestrictionsSet {
+			pool: KUSD_BSX_POOL_ID,
+			restrictions: Some(restrictions),
+		}
+		.into()]);
+
+		// sale id is kept when restrictions are updated
+		let restrictions = sale_restrictions(Some(2_000), None, None);
+		assert_ok!(LBPPallet::set_sale_restrictions(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(restrictions.clone())
+		));
+		assert_eq!(
+			LBPPallet::sale_restrictions(KUSD_BSX_POOL_ID),
+			Some((0, restrictions.clone()))
+		);
+
+		assert_ok!(LBPPallet::set_sale_restrictions(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			None
+		));
+		assert_eq!(LBPPallet::sale_restrictions(KUSD_BSX_POOL_ID), None);
+
+		// new sale id is allocated once restrictions were removed
+		assert_ok!(LBPPallet::set_sale_restrictions(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(restrictions.clone())
+		));
+		assert_eq!(LBPPallet::sale_restrictions(KUSD_BSX_POOL_ID), Some((1, restrictions)));
+	});
+}
+
+#[test]
+fn set_sale_restrictions_should_not_work_when_invalid() {
+	predefined_test_ext().execute_with(|| {
+		let restrictions = sale_restrictions(Some(1_000), None, None);
+
+		assert_noop!(
+			LBPPallet::set_sale_restrictions(Origin::signed(BOB), KUSD_BSX_POOL_ID, Some(restrictions.clone())),
+			Error::<Test>::NotOwner
+		);
+
+		assert_noop!(
+			LBPPallet::set_sale_restrictions(Origin::signed(ALICE), HDX_BSX_POOL_ID, Some(restrictions.clone())),
+			Error::<Test>::PoolNotFound
+		);
+
+		run_to_sale_start();
+
+		assert_noop!(
+			LBPPallet::set_sale_restrictions(Origin::signed(ALICE), KUSD_BSX_POOL_ID, Some(restrictions)),
+			Error::<Test>::SaleStarted
+		);
+	});
+}
+
+#[test]
+fn max_purchase_per_account_should_be_enforced() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_sale_restrictions(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(sale_restrictions(Some(15_000_000), None, None))
+		));
+
+		set_block_number(11);
+
+		assert_ok!(LBPPallet::sell(
+			Origin::signed(BOB),
+			KUSD,
+			BSX,
+			10_000_000_u128,
+			2_000_u128
+		));
+		assert_eq!(LBPPallet::participation(0, BOB).purchased, 10_000_000);
+
+		assert_noop!(
+			LBPPallet::sell(Origin::signed(BOB), KUSD, BSX, 10_000_000_u128, 2_000_u128),
+			Error::<Test>::MaxPurchaseExceeded
+		);
+
+		assert_noop!(
+			LBPPallet::buy(Origin::signed(BOB), BSX, KUSD, 10_000_000_u128, 2_000_000_000_u128),
+			Error::<Test>::MaxPurchaseExceeded
+		);
+
+		// selling the distributed asset back to the pool does not count as purchase
+		assert_ok!(LBPPallet::sell(
+			Origin::signed(BOB),
+			BSX,
+			KUSD,
+			10_000_000_u128,
+			2_000_u128
+		));
+		assert_eq!(LBPPallet::participation(0, BOB).purchased, 10_000_000);
+	});
+}
+
+#[test]
+fn trade_should_fail_when_account_is_not_allowlisted() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_sale_restrictions(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(sale_restrictions(None, Some(Allowlist::Origin), None))
+		));
+
+		set_block_number(11);
+
+		assert_noop!(
+			LBPPallet::sell(Origin::signed(BOB), KUSD, BSX, 10_000_000_u128, 2_000_u128),
+			Error::<Test>::NotAllowlisted
+		);
+		assert_noop!(
+			LBPPallet::sell(Origin::signed(BOB), BSX, KUSD, 10_000_000_u128, 2_000_u128),
+			Error::<Test>::NotAllowlisted
+		);
+
+		assert_noop!(
+			LBPPallet::add_to_allowlist(Origin::signed(BOB), KUSD_BSX_POOL_ID, vec![BOB].try_into().unwrap()),
+			BadOrigin
+		);
+
+		assert_ok!(LBPPallet::add_to_allowlist(
+			Origin::root(),
+			KUSD_BSX_POOL_ID,
+			vec![BOB].try_into().unwrap()
+		));
+
+		expect_events(vec![Event::Allowlisted {
+			pool: KUSD_BSX_POOL_ID,
+			who: BOB,
+		}
+		.into()]);
+
+		assert_ok!(LBPPallet::sell(
+			Origin::signed(BOB),
+			KUSD,
+			BSX,
+			10_000_000_u128,
+			2_000_u128
+		));
+	});
+}
+
+#[test]
+fn add_to_allowlist_should_not_work_without_origin_allowlist() {
+	predefined_test_ext().execute_with(|| {
+		assert_noop!(
+			LBPPallet::add_to_allowlist(Origin::root(), KUSD_BSX_POOL_ID, vec![BOB].try_into().unwrap()),
+			Error::<Test>::AllowlistNotEnabled
+		);
+
+		assert_ok!(LBPPallet::set_sale_restrictions(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(sale_restrictions(
+				None,
+				Some(Allowlist::MerkleRoot(allowlist_leaf(BOB))),
+				None
+			))
+		));
+
+		assert_noop!(
+			LBPPallet::add_to_allowlist(Origin::root(), KUSD_BSX_POOL_ID, vec![BOB].try_into().unwrap()),
+			Error::<Test>::AllowlistNotEnabled
+		);
+	});
+}
+
+#[test]
+fn prove_allowlisted_should_work_with_valid_proof() {
+	predefined_test_ext().execute_with(|| {
+		let root = LBPPallet::compute_merkle_root(allowlist_leaf(BOB), &[allowlist_leaf(CHARLIE)]);
+
+		assert_ok!(LBPPallet::set_sale_restrictions(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(sale_restrictions(None, Some(Allowlist::MerkleRoot(root)), None))
+		));
+
+		assert_noop!(
+			LBPPallet::prove_allowlisted(
+				Origin::signed(CHARLIE),
+				KUSD_BSX_POOL_ID,
+				vec![allowlist_leaf(ALICE)].try_into().unwrap()
+			),
+			Error::<Test>::InvalidMerkleProof
+		);
+
+		assert_ok!(LBPPallet::prove_allowlisted(
+			Origin::signed(BOB),
+			KUSD_BSX_POOL_ID,
+			vec![allowlist_leaf(CHARLIE)].try_into().unwrap()
+		));
+
+		assert!(<Allowlisted<Test>>::get(0, BOB));
+
+		expect_events(vec![Event::Allowlisted {
+			pool: KUSD_BSX_POOL_ID,
+			who: BOB,
+		}
+		.into()]);
+
+		set_block_number(11);
+
+		assert_ok!(LBPPallet::sell(
+			Origin::signed(BOB),
+			KUSD,
+			BSX,
+			10_000_000_u128,
+			2_000_u128
+		));
+	});
+}
+
+#[test]
+fn trade_should_fail_when_min_blocks_between_trades_not_passed() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_sale_restrictions(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(sale_restrictions(None, None, Some(5)))
+		));
+
+		set_block_number(11);
+
+		assert_ok!(LBPPallet::sell(
+			Origin::signed(BOB),
+			KUSD,
+			BSX,
+			10_000_000_u128,
+			2_000_u128
+		));
+		assert_eq!(LBPPallet::participation(0, BOB).last_trade, Some(11));
+
+		set_block_number(15);
+
+		assert_noop!(
+			LBPPallet::buy(Origin::signed(BOB), BSX, KUSD, 10_000_000_u128, 2_000_000_000_u128),
+			Error::<Test>::TradeTooSoon
+		);
+
+		set_block_number(16);
+
+		assert_ok!(LBPPallet::buy(
+			Origin::signed(BOB),
+			BSX,
+			KUSD,
+			10_000_000_u128,
+			2_000_000_000_u128
+		));
+	});
+}
+
+#[test]
+fn remove_liquidity_should_clear_sale_restrictions() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_sale_restrictions(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(sale_restrictions(Some(1_000), None, None))
+		));
+
+		assert_ok!(LBPPallet::remove_liquidity(Origin::signed(ALICE), KUSD_BSX_POOL_ID));
+
+		assert_eq!(LBPPallet::sale_restrictions(KUSD_BSX_POOL_ID), None);
+	});
+}
+
+fn sale_vesting(cliff: mock::BlockNumber, duration: mock::BlockNumber) -> SaleVesting<mock::BlockNumber> {
+	SaleVesting { cliff, duration }
+}
+
+#[test]
+fn set_sale_vesting_should_work() {
+	predefined_test_ext().execute_with(|| {
+		let vesting = sale_vesting(10, 100);
+
+		assert_ok!(LBPPallet::set_sale_vesting(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(vesting)
+		));
+
+		assert_eq!(LBPPallet::sale_vesting(KUSD_BSX_POOL_ID), Some(vesting));
+
+		expect_events(vec![Event::SaleVestingSet {
+			pool: KUSD_BSX_POOL_ID,
+			vesting: Some(vesting),
+		}
+		.into()]);
+
+		assert_ok!(LBPPallet::set_sale_vesting(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			None
+		));
+
+		assert_eq!(LBPPallet::sale_vesting(KUSD_BSX_POOL_ID), None);
+	});
+}
+
+#[test]
+fn set_sale_vesting_should_not_work_when_invalid() {
+	predefined_test_ext().execute_with(|| {
+		let vesting = sale_vesting(10, 100);
+
+		assert_noop!(
+			LBPPallet::set_sale_vesting(Origin::signed(BOB), KUSD_BSX_POOL_ID, Some(vesting)),
+			Error::<Test>::NotOwner
+		);
+
+		assert_noop!(
+			LBPPallet::set_sale_vesting(Origin::signed(ALICE), HDX_BSX_POOL_ID, Some(vesting)),
+			Error::<Test>::PoolNotFound
+		);
+
+		assert_noop!(
+			LBPPallet::set_sale_vesting(Origin::signed(ALICE), KUSD_BSX_POOL_ID, Some(sale_vesting(10, 0))),
+			Error::<Test>::ZeroVestingDuration
+		);
+
+		assert_ok!(LBPPallet::create_pool(
+			Origin::root(),
+			ALICE,
+			KUSD,
+			1_000_000_000,
+			ETH,
+			2_000_000_000,
+			20_000_000,
+			80_000_000,
+			WeightCurveType::Linear,
+			DEFAULT_FEE,
+			BOB,
+			0,
+		));
+		let kusd_eth_pool_id = LBPPallet::get_pair_id(AssetPair {
+			asset_in: KUSD,
+			asset_out: ETH,
+		});
+
+		assert_noop!(
+			LBPPallet::set_sale_vesting(Origin::signed(ALICE), kusd_eth_pool_id, Some(vesting)),
+			Error::<Test>::VestingNotSupported
+		);
+
+		run_to_sale_start();
+
+		assert_noop!(
+			LBPPallet::set_sale_vesting(Origin::signed(ALICE), KUSD_BSX_POOL_ID, Some(vesting)),
+			Error::<Test>::SaleStarted
+		);
+	});
+}
+
+#[test]
+fn bought_tokens_should_be_vested_when_sale_has_vesting() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_sale_vesting(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(sale_vesting(10, 100))
+		));
+
+		set_block_number(11);
+
+		let bob_bsx_before = Currency::free_balance(BSX, &BOB);
+
+		assert_ok!(LBPPallet::buy(
+			Origin::signed(BOB),
+			BSX,
+			KUSD,
+			10_000_000_u128,
+			2_000_000_000_u128
+		));
+
+		// vesting starts `cliff` blocks after the end of the sale
+		assert_eq!(
+			mock::vested_transfers(),
+			vec![(BSX, BOB, 10_000_000, SALE_END.unwrap() + 10, 100)]
+		);
+		assert_eq!(Currency::free_balance(BSX, &BOB), bob_bsx_before + 10_000_000);
+
+		assert_ok!(LBPPallet::sell(
+			Origin::signed(BOB),
+			KUSD,
+			BSX,
+			10_000_000_u128,
+			2_000_u128
+		));
+
+		let vested = mock::vested_transfers();
+		assert_eq!(vested.len(), 2);
+		assert_eq!(vested[1].0, BSX);
+		assert_eq!(vested[1].1, BOB);
+		assert_eq!(vested[1].3, SALE_END.unwrap() + 10);
+
+		// accumulated asset bought from the pool is not vested
+		assert_ok!(LBPPallet::sell(
+			Origin::signed(BOB),
+			BSX,
+			KUSD,
+			10_000_000_u128,
+			2_000_u128
+		));
+
+		assert_eq!(mock::vested_transfers().len(), 2);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_clear_sale_vesting() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_sale_vesting(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(sale_vesting(10, 100))
+		));
+
+		assert_ok!(LBPPallet::remove_liquidity(Origin::signed(ALICE), KUSD_BSX_POOL_ID));
+
+		assert_eq!(LBPPallet::sale_vesting(KUSD_BSX_POOL_ID), None);
+	});
+}
+
+fn sale_caps(soft_cap: Option<Balance>, hard_cap: Option<Balance>) -> SaleCaps {
+	SaleCaps { soft_cap, hard_cap }
+}
+
+#[test]
+fn set_sale_caps_should_work() {
+	predefined_test_ext().execute_with(|| {
+		let caps = sale_caps(Some(100_000_000), Some(200_000_000));
+
+		assert_ok!(LBPPallet::set_sale_caps(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(caps)
+		));
+
+		assert_eq!(LBPPallet::sale_caps(KUSD_BSX_POOL_ID), Some((0, caps)));
+
+		expect_events(vec![Event::SaleCapsSet {
+			pool: KUSD_BSX_POOL_ID,
+			caps: Some(caps),
+		}
+		.into()]);
+
+		assert_ok!(LBPPallet::set_sale_caps(Origin::signed(ALICE), KUSD_BSX_POOL_ID, None));
+
+		assert_eq!(LBPPallet::sale_caps(KUSD_BSX_POOL_ID), None);
+	});
+}
+
+#[test]
+fn set_sale_caps_should_not_work_when_invalid() {
+	predefined_test_ext().execute_with(|| {
+		let caps = sale_caps(Some(100_000_000), None);
+
+		assert_noop!(
+			LBPPallet::set_sale_caps(Origin::signed(BOB), KUSD_BSX_POOL_ID, Some(caps)),
+			Error::<Test>::NotOwner
+		);
+
+		assert_noop!(
+			LBPPallet::set_sale_caps(Origin::signed(ALICE), HDX_BSX_POOL_ID, Some(caps)),
+			Error::<Test>::PoolNotFound
+		);
+
+		assert_noop!(
+			LBPPallet::set_sale_caps(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				Some(sale_caps(Some(200_000_000), Some(100_000_000)))
+			),
+			Error::<Test>::InvalidCaps
+		);
+
+		assert_noop!(
+			LBPPallet::set_sale_caps(Origin::signed(ALICE), KUSD_BSX_POOL_ID, Some(sale_caps(Some(0), None))),
+			Error::<Test>::InvalidCaps
+		);
+
+		run_to_sale_start();
+
+		assert_noop!(
+			LBPPallet::set_sale_caps(Origin::signed(ALICE), KUSD_BSX_POOL_ID, Some(caps)),
+			Error::<Test>::SaleStarted
+		);
+	});
+}
+
+#[test]
+fn soft_cap_should_not_be_combined_with_vesting() {
+	predefined_test_ext().execute_with(|| {
+		let vesting = sale_vesting(10, 100);
+
+		assert_ok!(LBPPallet::set_sale_vesting(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(vesting)
+		));
+
+		// vested tokens could not be returned for refunds
+		assert_noop!(
+			LBPPallet::set_sale_caps(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				Some(sale_caps(Some(100_000_000), Some(200_000_000)))
+			),
+			Error::<Test>::VestingWithSoftCap
+		);
+
+		// hard cap does not trigger refunds
+		assert_ok!(LBPPallet::set_sale_caps(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(sale_caps(None, Some(200_000_000)))
+		));
+
+		assert_ok!(LBPPallet::set_sale_vesting(Origin::signed(ALICE), KUSD_BSX_POOL_ID, None));
+		assert_ok!(LBPPallet::set_sale_caps(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(sale_caps(Some(100_000_000), Some(200_000_000)))
+		));
+
+		assert_noop!(
+			LBPPallet::set_sale_vesting(Origin::signed(ALICE), KUSD_BSX_POOL_ID, Some(vesting)),
+			Error::<Test>::VestingWithSoftCap
+		);
+	});
+}
+
+#[test]
+fn contributions_should_be_tracked_when_sale_has_caps() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_sale_caps(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(sale_caps(Some(100_000_000), None))
+		));
+
+		set_block_number(11);
+
+		let bsx_before = Currency::free_balance(BSX, &BOB);
+
+		assert_ok!(LBPPallet::sell(
+			Origin::signed(BOB),
+			KUSD,
+			BSX,
+			10_000_000_u128,
+			2_000_u128
+		));
+
+		let bought = Currency::free_balance(BSX, &BOB) - bsx_before;
+
+		// fee is not added to the pool
+		assert_eq!(
+			LBPPallet::contribution(0, BOB),
+			Contribution {
+				contributed: 9_980_000,
+				bought,
+			}
+		);
+		assert_eq!(LBPPallet::raised(0), 9_980_000);
+
+		let pool_kusd_before = Currency::free_balance(KUSD, &KUSD_BSX_POOL_ID);
+
+		assert_ok!(LBPPallet::sell(
+			Origin::signed(BOB),
+			BSX,
+			KUSD,
+			1_000_000_u128,
+			2_000_u128
+		));
+
+		// accumulated asset and fee taken from the pool decrease the contribution
+		let withdrawn = pool_kusd_before - Currency::free_balance(KUSD, &KUSD_BSX_POOL_ID);
+		assert_eq!(
+			LBPPallet::contribution(0, BOB),
+			Contribution {
+				contributed: 9_980_000 - withdrawn,
+				bought: bought - 1_000_000,
+			}
+		);
+		assert_eq!(LBPPallet::raised(0), 9_980_000 - withdrawn);
+	});
+}
+
+#[test]
+fn sale_should_end_when_hard_cap_is_reached() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_sale_caps(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(sale_caps(None, Some(15_000_000)))
+		));
+
+		set_block_number(11);
+
+		assert_ok!(LBPPallet::sell(
+			Origin::signed(BOB),
+			KUSD,
+			BSX,
+			10_000_000_u128,
+			2_000_u128
+		));
+
+		assert_noop!(
+			LBPPallet::sell(Origin::signed(BOB), KUSD, BSX, 10_000_000_u128, 2_000_u128),
+			Error::<Test>::HardCapExceeded
+		);
+		assert_noop!(
+			LBPPallet::buy(Origin::signed(BOB), BSX, KUSD, 10_000_000_u128, 2_000_000_000_u128),
+			Error::<Test>::HardCapExceeded
+		);
+
+		assert_ok!(LBPPallet::sell(
+			Origin::signed(BOB),
+			KUSD,
+			BSX,
+			5_000_000_u128,
+			2_000_u128
+		));
+		assert_eq!(LBPPallet::raised(0), 14_970_000);
+		assert_eq!(LBPPallet::sale_closed_at(KUSD_BSX_POOL_ID), None);
+
+		// remaining amount to the hard cap is lower than the min trading limit
+		assert_ok!(LBPPallet::sell(Origin::signed(BOB), KUSD, BSX, 30_000_u128, 2_000_u128));
+
+		let raised = LBPPallet::raised(0);
+		assert!(15_000_000 - raised < <Test as Config>::MinTradingLimit::get());
+		assert_eq!(LBPPallet::sale_closed_at(KUSD_BSX_POOL_ID), Some(11));
+		// the weight curve is not changed
+		assert_eq!(LBPPallet::pool_data(KUSD_BSX_POOL_ID).unwrap().end, SALE_END);
+
+		let hard_cap_reached: mock::RuntimeEvent = Event::HardCapReached {
+			pool: KUSD_BSX_POOL_ID,
+			raised,
+		}
+		.into();
+		assert!(frame_system::Pallet::<Test>::events()
+			.iter()
+			.any(|record| record.event == hard_cap_reached));
+
+		set_block_number(12);
+
+		assert_noop!(
+			LBPPallet::sell(Origin::signed(BOB), BSX, KUSD, 1_000_000_u128, 2_000_u128),
+			Error::<Test>::SaleIsNotRunning
+		);
+	});
+}
+
+#[test]
+fn contributions_should_be_refunded_when_soft_cap_is_not_reached() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_sale_caps(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(sale_caps(Some(100_000_000), None))
+		));
+
+		set_block_number(11);
+
+		assert_ok!(LBPPallet::sell(
+			Origin::signed(BOB),
+			KUSD,
+			BSX,
+			10_000_000_u128,
+			2_000_u128
+		));
+		assert_ok!(LBPPallet::sell(
+			Origin::signed(ALICE),
+			KUSD,
+			BSX,
+			20_000_000_u128,
+			2_000_u128
+		));
+
+		let bob_bought = LBPPallet::contribution(0, BOB).bought;
+		let alice_bsx = Currency::free_balance(BSX, &ALICE);
+
+		assert_noop!(
+			LBPPallet::claim_refund(Origin::signed(BOB), KUSD_BSX_POOL_ID),
+			Error::<Test>::SaleNotFailed
+		);
+
+		run_to_sale_end();
+
+		assert_ok!(LBPPallet::claim_refund(Origin::signed(BOB), KUSD_BSX_POOL_ID));
+
+		// contribution is refunded, fee is not
+		assert_eq!(Currency::free_balance(KUSD, &BOB), INITIAL_BALANCE - 20_000);
+		assert_eq!(Currency::free_balance(BSX, &BOB), INITIAL_BALANCE);
+		assert_eq!(Currency::free_balance(BSX, &ALICE), alice_bsx + bob_bought);
+		assert_eq!(LBPPallet::contribution(0, BOB), Contribution::default());
+
+		expect_events(vec![Event::Refunded {
+			pool: KUSD_BSX_POOL_ID,
+			who: BOB,
+			amount: 9_980_000,
+			returned: bob_bought,
+		}
+		.into()]);
+
+		assert_eq!(
+			LBPPallet::sale_refund(KUSD_BSX_POOL_ID),
+			Some(SaleRefund {
+				sale_id: 0,
+				started: SALE_END.unwrap() + 1,
+				owner: ALICE,
+				assets: (KUSD, BSX),
+				total_contributed: 29_940_000,
+				refundable: 29_940_000,
+				remaining: 19_960_000,
+				unclaimed: 19_960_000,
+			})
+		);
+
+		assert_noop!(
+			LBPPallet::claim_refund(Origin::signed(CHARLIE), KUSD_BSX_POOL_ID),
+			Error::<Test>::NothingToRefund
+		);
+
+		// unclaimed refunds remain in the pool account
+		assert_ok!(LBPPallet::remove_liquidity(Origin::signed(ALICE), KUSD_BSX_POOL_ID));
+
+		assert_eq!(Currency::free_balance(KUSD, &KUSD_BSX_POOL_ID), 19_960_000);
+		assert_eq!(Currency::free_balance(KUSD, &ALICE), INITIAL_BALANCE - 20_000_000);
+
+		assert_noop!(
+			LBPPallet::create_pool(
+				Origin::root(),
+				ALICE,
+				KUSD,
+				1_000_000_000,
+				BSX,
+				2_000_000_000,
+				20_000_000,
+				80_000_000,
+				WeightCurveType::Linear,
+				DEFAULT_FEE,
+				CHARLIE,
+				0,
+			),
+			Error::<Test>::RefundsPending
+		);
+
+		assert_ok!(LBPPallet::claim_refund(Origin::signed(ALICE), KUSD_BSX_POOL_ID));
+
+		assert_eq!(Currency::free_balance(KUSD, &ALICE), INITIAL_BALANCE - 40_000);
+		assert_eq!(Currency::free_balance(KUSD, &KUSD_BSX_POOL_ID), 0);
+		assert_eq!(LBPPallet::sale_refund(KUSD_BSX_POOL_ID), None);
+	});
+}
+
+#[test]
+fn contribution_should_be_refunded_partially_when_bought_asset_is_partially_returned() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_sale_caps(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(sale_caps(Some(100_000_000), None))
+		));
+
+		set_block_number(11);
+
+		assert_ok!(LBPPallet::sell(
+			Origin::signed(BOB),
+			KUSD,
+			BSX,
+			10_000_000_u128,
+			2_000_u128
+		));
+
+		let contribution = LBPPallet::contribution(0, BOB);
+
+		// BOB keeps only half of the bought asset
+		let kept = contribution.bought / 2;
+		let bob_bsx = Currency::free_balance(BSX, &BOB);
+		assert_ok!(Currency::transfer(Origin::signed(BOB), CHARLIE, BSX, bob_bsx - kept));
+
+		run_to_sale_end();
+
+		assert_ok!(LBPPallet::claim_refund(Origin::signed(BOB), KUSD_BSX_POOL_ID));
+
+		let refunded = contribution.contributed * kept / contribution.bought;
+		assert_eq!(Currency::free_balance(BSX, &BOB), 0);
+		assert_eq!(
+			LBPPallet::contribution(0, BOB),
+			Contribution {
+				contributed: contribution.contributed - refunded,
+				bought: contribution.bought - kept,
+			}
+		);
+
+		expect_events(vec![Event::Refunded {
+			pool: KUSD_BSX_POOL_ID,
+			who: BOB,
+			amount: refunded,
+			returned: kept,
+		}
+		.into()]);
+
+		// the rest is refunded when the rest of the bought asset is returned
+		assert_ok!(Currency::transfer(
+			Origin::signed(CHARLIE),
+			BOB,
+			BSX,
+			contribution.bought - kept
+		));
+		assert_ok!(LBPPallet::claim_refund(Origin::signed(BOB), KUSD_BSX_POOL_ID));
+
+		assert_eq!(LBPPallet::contribution(0, BOB), Contribution::default());
+		assert_eq!(LBPPallet::sale_refund(KUSD_BSX_POOL_ID), None);
+	});
+}
+
+#[test]
+fn unclaimed_refunds_should_be_transferred_to_owner_when_refund_period_expires() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_sale_caps(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(sale_caps(Some(100_000_000), None))
+		));
+
+		set_block_number(11);
+
+		assert_ok!(LBPPallet::sell(
+			Origin::signed(BOB),
+			KUSD,
+			BSX,
+			10_000_000_u128,
+			2_000_u128
+		));
+
+		run_to_sale_end();
+
+		assert_ok!(LBPPallet::remove_liquidity(Origin::signed(ALICE), KUSD_BSX_POOL_ID));
+
+		let unclaimed = Currency::free_balance(KUSD, &KUSD_BSX_POOL_ID);
+		assert!(unclaimed > 0);
+
+		let create_pool = || {
+			LBPPallet::create_pool(
+				Origin::root(),
+				ALICE,
+				KUSD,
+				1_000_000_000,
+				BSX,
+				2_000_000_000,
+				20_000_000,
+				80_000_000,
+				WeightCurveType::Linear,
+				DEFAULT_FEE,
+				CHARLIE,
+				0,
+			)
+		};
+
+		let refund_end = SALE_END.unwrap() + 1 + <Test as Config>::RefundPeriod::get();
+
+		set_block_number(refund_end);
+		assert_noop!(create_pool(), Error::<Test>::RefundsPending);
+
+		set_block_number(refund_end + 1);
+		let alice_kusd = Currency::free_balance(KUSD, &ALICE);
+
+		assert_ok!(create_pool());
+
+		assert_eq!(LBPPallet::sale_refund(KUSD_BSX_POOL_ID), None);
+		assert_eq!(
+			Currency::free_balance(KUSD, &ALICE),
+			alice_kusd + unclaimed - 1_000_000_000
+		);
+		assert_eq!(Currency::free_balance(KUSD, &KUSD_BSX_POOL_ID), 1_000_000_000);
+
+		let refunds_expired: mock::RuntimeEvent = Event::RefundsExpired {
+			pool: KUSD_BSX_POOL_ID,
+			unclaimed,
+		}
+		.into();
+		assert!(frame_system::Pallet::<Test>::events()
+			.iter()
+			.any(|record| record.event == refunds_expired));
+	});
+}
+
+#[test]
+fn migration_should_use_weights_at_close_when_hard_cap_is_reached() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_sale_caps(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(sale_caps(None, Some(10_000_000)))
+		));
+		assert_ok!(LBPPallet::set_xyk_migration(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(xyk_migration(Permill::from_percent(10), None))
+		));
+
+		set_block_number(11);
+
+		assert_ok!(LBPPallet::sell(
+			Origin::signed(BOB),
+			KUSD,
+			BSX,
+			10_020_000_u128,
+			2_000_u128
+		));
+		assert_eq!(LBPPallet::sale_closed_at(KUSD_BSX_POOL_ID), Some(11));
+
+		let pool = LBPPallet::pool_data(KUSD_BSX_POOL_ID).unwrap();
+		let (weight_a, weight_b) = LBPPallet::calculate_weights(&pool, 11).unwrap();
+		let (amount_a, amount_b) = LBPPallet::calculate_xyk_liquidity(
+			Currency::free_balance(KUSD, &KUSD_BSX_POOL_ID),
+			Currency::free_balance(BSX, &KUSD_BSX_POOL_ID),
+			weight_a,
+			weight_b,
+			Permill::from_percent(10),
+		)
+		.unwrap();
+
+		run_on_idle(12);
+
+		assert!(!<PoolData<Test>>::contains_key(KUSD_BSX_POOL_ID));
+		assert_eq!(LBPPallet::sale_closed_at(KUSD_BSX_POOL_ID), None);
+
+		let xyk_pool = mock::xyk_pool_account(KUSD, BSX);
+		assert_eq!(Currency::free_balance(KUSD, &xyk_pool), amount_a);
+		assert_eq!(Currency::free_balance(BSX, &xyk_pool), amount_b);
+	});
+}
+
+#[test]
+fn pool_should_not_be_migrated_to_xyk_when_soft_cap_is_not_reached() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::set_sale_caps(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(sale_caps(Some(100_000_000), None))
+		));
+		assert_ok!(LBPPallet::set_xyk_migration(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			Some(xyk_migration(Permill::from_percent(10), None))
+		));
+
+		run_on_idle(SALE_END.unwrap() + 1);
+
+		assert!(<PoolData<Test>>::contains_key(KUSD_BSX_POOL_ID));
+		assert!(!<XYKMigrations<Test>>::contains_key(KUSD_BSX_POOL_ID));
+
+		expect_events(vec![Event::XYKMigrationFailed {
+			pool: KUSD_BSX_POOL_ID,
+			error: Error::<Test>::SoftCapNotReached.into(),
+		}
+		.into()]);
+	});
+}
+
+fn create_pool_with_deposit(initial_weight: LBPWeight, repay_target: Balance) -> DispatchResult {
+	LBPPallet::create_pool_with_deposit(
+		Origin::signed(ALICE),
+		KUSD,
+		1_000_000_000,
+		BSX,
+		2_000_000_000,
+		initial_weight,
+		90_000_000,
+		WeightCurveType::Linear,
+		DEFAULT_FEE,
+		CHARLIE,
+		repay_target,
+	)
+}
+
+fn reserved_balance(who: AccountId) -> Balance {
+	orml_tokens::Accounts::<Test>::get(who, HDX).reserved
+}
+
+#[test]
+fn create_pool_with_deposit_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_pool_with_deposit(20_000_000, 1_000));
+
+		assert_eq!(Currency::free_balance(KUSD, &KUSD_BSX_POOL_ID), 1_000_000_000);
+		assert_eq!(Currency::free_balance(BSX, &KUSD_BSX_POOL_ID), 2_000_000_000);
+		assert_eq!(Currency::free_balance(HDX, &ALICE), INITIAL_BALANCE - POOL_DEPOSIT);
+		assert_eq!(reserved_balance(ALICE), POOL_DEPOSIT);
+
+		let pool_data = LBPPallet::pool_data(KUSD_BSX_POOL_ID).unwrap();
+		assert_eq!(pool_data.owner, ALICE);
+		assert_eq!(pool_data.repay_target, 1_000);
+		assert_eq!(LBPPallet::pool_deposit(KUSD_BSX_POOL_ID), Some((ALICE, POOL_DEPOSIT)));
+
+		assert!(frame_system::Pallet::<Test>::events().iter().any(|record| record.event
+			== Event::PoolDepositReserved {
+				pool: KUSD_BSX_POOL_ID,
+				who: ALICE,
+				amount: POOL_DEPOSIT,
+			}
+			.into()));
+	});
+}
+
+#[test]
+fn create_pool_with_deposit_should_not_work_when_bounds_are_not_met() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			create_pool_with_deposit(20_000_000, 0),
+			Error::<Test>::RepayTargetRequired
+		);
+
+		assert_noop!(
+			create_pool_with_deposit(<Test as Config>::MaxInitialWeight::get() + 1, 1_000),
+			Error::<Test>::InitialWeightTooHigh
+		);
+	});
+}
+
+#[test]
+fn create_pool_with_deposit_should_not_work_when_deposit_can_not_be_reserved() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(CHARLIE, KUSD, INITIAL_BALANCE), (CHARLIE, BSX, INITIAL_BALANCE)])
+		.build()
+		.execute_with(|| {
+			assert!(LBPPallet::create_pool_with_deposit(
+				Origin::signed(CHARLIE),
+				KUSD,
+				1_000_000_000,
+				BSX,
+				2_000_000_000,
+				20_000_000,
+				90_000_000,
+				WeightCurveType::Linear,
+				DEFAULT_FEE,
+				BOB,
+				1_000,
+			)
+			.is_err());
+
+			assert!(!<PoolData<Test>>::contains_key(KUSD_BSX_POOL_ID));
+		});
+}
+
+#[test]
+fn update_pool_data_should_enforce_deposit_bounds() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_pool_with_deposit(20_000_000, 1_000));
+
+		let min_duration = <Test as Config>::MinSaleDuration::get();
+
+		assert_noop!(
+			LBPPallet::update_pool_data(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				None,
+				Some(10),
+				Some(10 + min_duration - 1),
+				None,
+				None,
+				None,
+				None,
+				None,
+			),
+			Error::<Test>::SaleDurationTooShort
+		);
+
+		assert_noop!(
+			LBPPallet::update_pool_data(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+				Some(0),
+			),
+			Error::<Test>::RepayTargetRequired
+		);
+
+		assert_ok!(LBPPallet::update_pool_data(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			None,
+			Some(10),
+			Some(10 + min_duration),
+			None,
+			None,
+			None,
+			None,
+			None,
+		));
+	});
+}
+
+#[test]
+fn remove_liquidity_should_unreserve_pool_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_pool_with_deposit(20_000_000, 1_000));
+		assert_ok!(LBPPallet::update_pool_data(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			None,
+			SALE_START,
+			SALE_END,
+			None,
+			None,
+			None,
+			None,
+			None,
+		));
+
+		run_to_sale_end();
+
+		assert_ok!(LBPPallet::remove_liquidity(Origin::signed(ALICE), KUSD_BSX_POOL_ID));
+
+		assert_eq!(Currency::free_balance(HDX, &ALICE), INITIAL_BALANCE);
+		assert_eq!(reserved_balance(ALICE), 0);
+		assert_eq!(LBPPallet::pool_deposit(KUSD_BSX_POOL_ID), None);
+	});
+}
+
+#[test]
+fn cancel_pool_should_slash_deposit_and_return_liquidity() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_pool_with_deposit(20_000_000, 1_000));
+		assert_ok!(LBPPallet::update_pool_data(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			None,
+			SALE_START,
+			SALE_END,
+			None,
+			None,
+			None,
+			None,
+			None,
+		));
+
+		assert_ok!(LBPPallet::cancel_pool(Origin::root(), KUSD_BSX_POOL_ID));
+
+		assert_eq!(Currency::free_balance(KUSD, &ALICE), INITIAL_BALANCE);
+		assert_eq!(Currency::free_balance(BSX, &ALICE), INITIAL_BALANCE);
+		assert_eq!(Currency::free_balance(HDX, &ALICE), INITIAL_BALANCE - POOL_DEPOSIT);
+		assert_eq!(reserved_balance(ALICE), 0);
+
+		assert!(!<PoolData<Test>>::contains_key(KUSD_BSX_POOL_ID));
+		assert_eq!(LBPPallet::pool_deposit(KUSD_BSX_POOL_ID), None);
+
+		expect_events(vec![Event::PoolCancelled {
+			pool: KUSD_BSX_POOL_ID,
+			slashed: POOL_DEPOSIT,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_not_front_run_cancel_pool_of_pool_with_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_pool_with_deposit(20_000_000, 1_000));
+
+		assert_ok!(LBPPallet::update_pool_data(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			None,
+			SALE_START,
+			SALE_END,
+			None,
+			None,
+			None,
+			None,
+			None,
+		));
+
+		// the owner tries to get the deposit back before the pool is cancelled
+		assert_noop!(
+			LBPPallet::remove_liquidity(Origin::signed(ALICE), KUSD_BSX_POOL_ID),
+			Error::<Test>::SaleNotEnded
+		);
+
+		assert_ok!(LBPPallet::cancel_pool(Origin::root(), KUSD_BSX_POOL_ID));
+
+		assert_eq!(Currency::free_balance(HDX, &ALICE), INITIAL_BALANCE - POOL_DEPOSIT);
+		assert_eq!(reserved_balance(ALICE), 0);
+
+		expect_events(vec![Event::PoolCancelled {
+			pool: KUSD_BSX_POOL_ID,
+			slashed: POOL_DEPOSIT,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_unreserve_deposit_of_pool_that_was_never_scheduled() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_pool_with_deposit(20_000_000, 1_000));
+		assert_eq!(reserved_balance(ALICE), POOL_DEPOSIT);
+
+		assert_ok!(LBPPallet::remove_liquidity(Origin::signed(ALICE), KUSD_BSX_POOL_ID));
+
+		assert!(!<PoolData<Test>>::contains_key(KUSD_BSX_POOL_ID));
+		assert_eq!(Currency::free_balance(HDX, &ALICE), INITIAL_BALANCE);
+		assert_eq!(reserved_balance(ALICE), 0);
+		assert_eq!(LBPPallet::pool_deposit(KUSD_BSX_POOL_ID), None);
+	});
+}
+
+#[test]
+fn cancel_pool_should_work_for_pool_without_deposit() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::cancel_pool(Origin::root(), KUSD_BSX_POOL_ID));
+
+		assert!(!<PoolData<Test>>::contains_key(KUSD_BSX_POOL_ID));
+
+		expect_events(vec![Event::PoolCancelled {
+			pool: KUSD_BSX_POOL_ID,
+			slashed: 0,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn cancel_pool_should_not_work_after_sale_start() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_pool_with_deposit(20_000_000, 1_000));
+		assert_ok!(LBPPallet::update_pool_data(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			None,
+			SALE_START,
+			SALE_END,
+			None,
+			None,
+			None,
+			None,
+			None,
+		));
+
+		set_block_number(SALE_START.unwrap());
+
+		assert_noop!(
+			LBPPallet::cancel_pool(Origin::root(), KUSD_BSX_POOL_ID),
+			Error::<Test>::SaleStarted
+		);
+	});
+}
+
+#[test]
+fn cancel_pool_should_not_work_from_basic_origin() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_pool_with_deposit(20_000_000, 1_000));
+
+		assert_noop!(
+			LBPPallet::cancel_pool(Origin::signed(ALICE), KUSD_BSX_POOL_ID),
+			BadOrigin
+		);
+
+		assert_noop!(
+			LBPPallet::cancel_pool(Origin::root(), HDX_BSX_POOL_ID),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
@@ -21,6 +21,17 @@ pub trait WeightInfo {
 	fn router_execution_buy(c: u32, e: u32) -> Weight;
 	fn calculate_buy() -> Weight;
 	fn calculate_spot_price_with_fee() -> Weight;
+	fn set_xyk_migration() -> Weight;
+	fn unlock_xyk_shares() -> Weight;
+	fn migrate_to_xyk() -> Weight;
+	fn set_sale_restrictions() -> Weight;
+	fn add_to_allowlist(n: u32) -> Weight;
+	fn prove_allowlisted(p: u32) -> Weight;
+	fn set_sale_vesting() -> Weight;
+	fn set_sale_caps() -> Weight;
+	fn claim_refund() -> Weight;
+	fn create_pool_with_deposit() -> Weight;
+	fn cancel_pool() -> Weight;
 }
 
 /// Weights for pallet_lbp using the hydraDX node and recommended hardware.
@@ -31,7 +42,7 @@ impl WeightInfo for () {
 	/// Proof: `LBP::FeeCollectorWithAsset` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
 	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
-	/// Storage: `Tokens::Accounts` (r:4 w:4)
+	/// Storage: `Tokens::Accounts` (r:5 w:5)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
 	/// Storage: `Router::SkipEd` (r:1 w:0)
 	/// Proof: `Router::SkipEd` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
@@ -45,26 +56,24 @@ impl WeightInfo for () {
 	/// Proof: `MultiTransactionPayment::AccountCurrencyMap` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `MultiTransactionPayment::AcceptedCurrencies` (r:1 w:0)
 	/// Proof: `MultiTransactionPayment::AcceptedCurrencies` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::SaleRefunds` (r:1 w:1)
+	/// Proof: `LBP::SaleRefunds` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
 	fn create_pool() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1586`
-		//  Estimated: `11322`
-		// Minimum execution time: 168_852_000 picoseconds.
-		Weight::from_parts(169_826_000, 11322)
-			.saturating_add(RocksDbWeight::get().reads(16_u64))
-			.saturating_add(RocksDbWeight::get().writes(8_u64))
+		// Not benchmarked yet, the last benchmarked weight with the orml_tokens::transfer of expired refunds added.
+		Weight::from_parts(261_548_000, 11322)
+			.saturating_add(RocksDbWeight::get().reads(18_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
 	}
 	/// Storage: `LBP::PoolData` (r:1 w:1)
 	/// Proof: `LBP::PoolData` (`max_values`: None, `max_size`: Some(163), added: 2638, mode: `MaxEncodedLen`)
 	/// Storage: `LBP::FeeCollectorWithAsset` (r:1 w:2)
 	/// Proof: `LBP::FeeCollectorWithAsset` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::PoolDeposits` (r:1 w:0)
+	/// Proof: `LBP::PoolDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	fn update_pool_data() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `417`
-		//  Estimated: `3628`
-		// Minimum execution time: 30_046_000 picoseconds.
+		// Not benchmarked yet, the last benchmarked weight with the storage of pool deposits added.
 		Weight::from_parts(30_632_000, 3628)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `LBP::PoolData` (r:1 w:0)
@@ -110,20 +119,27 @@ impl WeightInfo for () {
 	/// Proof: `Tokens::Locks` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
 	/// Storage: `LBP::FeeCollectorWithAsset` (r:0 w:1)
 	/// Proof: `LBP::FeeCollectorWithAsset` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::PoolCaps` (r:1 w:1)
+	/// Proof: `LBP::PoolCaps` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::SaleRaised` (r:1 w:0)
+	/// Proof: `LBP::SaleRaised` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::SaleRefunds` (r:1 w:1)
+	/// Proof: `LBP::SaleRefunds` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::PoolDeposits` (r:1 w:1)
+	/// Proof: `LBP::PoolDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::SaleClosedAt` (r:1 w:1)
+	/// Proof: `LBP::SaleClosedAt` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	fn remove_liquidity() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1962`
-		//  Estimated: `11322`
-		// Minimum execution time: 168_745_000 picoseconds.
+		// Not benchmarked yet, the last benchmarked weight with the storage of sale caps, refunds and pool deposits added.
 		Weight::from_parts(169_823_000, 11322)
-			.saturating_add(RocksDbWeight::get().reads(14_u64))
-			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(RocksDbWeight::get().reads(19_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
 	}
 	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
 	/// Storage: `Tokens::Accounts` (r:5 w:5)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
-	/// Storage: `LBP::PoolData` (r:1 w:0)
+	/// Storage: `LBP::PoolData` (r:1 w:1)
 	/// Proof: `LBP::PoolData` (`max_values`: None, `max_size`: Some(163), added: 2638, mode: `MaxEncodedLen`)
 	/// Storage: `Tokens::Locks` (r:1 w:1)
 	/// Proof: `Tokens::Locks` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
@@ -135,16 +151,35 @@ impl WeightInfo for () {
 	/// Proof: `AssetRegistry::BannedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:3 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::PoolRestrictions` (r:1 w:0)
+	/// Proof: `LBP::PoolRestrictions` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::PoolVesting` (r:1 w:0)
+	/// Proof: `LBP::PoolVesting` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::Allowlisted` (r:1 w:0)
+	/// Proof: `LBP::Allowlisted` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::Participants` (r:1 w:1)
+	/// Proof: `LBP::Participants` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::PoolCaps` (r:1 w:0)
+	/// Proof: `LBP::PoolCaps` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::SaleRaised` (r:1 w:1)
+	/// Proof: `LBP::SaleRaised` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::Contributions` (r:1 w:1)
+	/// Proof: `LBP::Contributions` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	/// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(469), added: 2944, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::SaleClosedAt` (r:1 w:1)
+	/// Proof: `LBP::SaleClosedAt` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	fn sell() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2251`
-		//  Estimated: `13905`
-		// Minimum execution time: 278_405_000 picoseconds.
-		Weight::from_parts(280_656_000, 13905)
-			.saturating_add(RocksDbWeight::get().reads(17_u64))
-			.saturating_add(RocksDbWeight::get().writes(7_u64))
+		// Not benchmarked yet, the last benchmarked weight with sale restrictions, orml_vesting::vested_transfer and sale caps added.
+		Weight::from_parts(389_993_000, 20101)
+			.saturating_add(RocksDbWeight::get().reads(30_u64))
+			.saturating_add(RocksDbWeight::get().writes(16_u64))
 	}
-	/// Storage: `LBP::PoolData` (r:1 w:0)
+	/// Storage: `LBP::PoolData` (r:1 w:1)
 	/// Proof: `LBP::PoolData` (`max_values`: None, `max_size`: Some(163), added: 2638, mode: `MaxEncodedLen`)
 	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
@@ -160,16 +195,35 @@ impl WeightInfo for () {
 	/// Proof: `AssetRegistry::BannedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:3 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::PoolRestrictions` (r:1 w:0)
+	/// Proof: `LBP::PoolRestrictions` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::PoolVesting` (r:1 w:0)
+	/// Proof: `LBP::PoolVesting` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::Allowlisted` (r:1 w:0)
+	/// Proof: `LBP::Allowlisted` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::Participants` (r:1 w:1)
+	/// Proof: `LBP::Participants` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::PoolCaps` (r:1 w:0)
+	/// Proof: `LBP::PoolCaps` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::SaleRaised` (r:1 w:1)
+	/// Proof: `LBP::SaleRaised` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::Contributions` (r:1 w:1)
+	/// Proof: `LBP::Contributions` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	/// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(469), added: 2944, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::SaleClosedAt` (r:1 w:1)
+	/// Proof: `LBP::SaleClosedAt` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	fn buy() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2251`
-		//  Estimated: `13905`
-		// Minimum execution time: 278_628_000 picoseconds.
-		Weight::from_parts(280_382_000, 13905)
-			.saturating_add(RocksDbWeight::get().reads(17_u64))
-			.saturating_add(RocksDbWeight::get().writes(7_u64))
+		// Not benchmarked yet, the last benchmarked weight with sale restrictions, orml_vesting::vested_transfer and sale caps added.
+		Weight::from_parts(389_719_000, 20101)
+			.saturating_add(RocksDbWeight::get().reads(30_u64))
+			.saturating_add(RocksDbWeight::get().writes(16_u64))
 	}
-	/// Storage: `LBP::PoolData` (r:1 w:0)
+	/// Storage: `LBP::PoolData` (r:1 w:1)
 	/// Proof: `LBP::PoolData` (`max_values`: None, `max_size`: Some(163), added: 2638, mode: `MaxEncodedLen`)
 	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
@@ -185,24 +239,43 @@ impl WeightInfo for () {
 	/// Proof: `AssetRegistry::BannedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:3 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::PoolRestrictions` (r:1 w:0)
+	/// Proof: `LBP::PoolRestrictions` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::PoolVesting` (r:1 w:0)
+	/// Proof: `LBP::PoolVesting` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::Allowlisted` (r:1 w:0)
+	/// Proof: `LBP::Allowlisted` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::Participants` (r:1 w:1)
+	/// Proof: `LBP::Participants` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	/// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(469), added: 2944, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::PoolCaps` (r:1 w:0)
+	/// Proof: `LBP::PoolCaps` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::SaleRaised` (r:1 w:1)
+	/// Proof: `LBP::SaleRaised` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::Contributions` (r:1 w:1)
+	/// Proof: `LBP::Contributions` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::SaleClosedAt` (r:1 w:1)
+	/// Proof: `LBP::SaleClosedAt` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 2]`.
 	/// The range of component `e` is `[0, 1]`.
 	fn router_execution_sell(c: u32, e: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1052 + e * (1199 ±0)`
-		//  Estimated: `8799 + e * (7749 ±0)`
-		// Minimum execution time: 102_791_000 picoseconds.
+		// Not benchmarked yet, the last benchmarked weight with sale restrictions, orml_vesting::vested_transfer and sale caps added.
 		Weight::from_parts(103_541_000, 8799)
 			// Standard Error: 232_924
 			.saturating_add(Weight::from_parts(979_511, 0).saturating_mul(c.into()))
 			// Standard Error: 518_787
-			.saturating_add(Weight::from_parts(178_064_307, 0).saturating_mul(e.into()))
+			.saturating_add(Weight::from_parts(287_401_307, 0).saturating_mul(e.into()))
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().reads((12_u64).saturating_mul(e.into())))
-			.saturating_add(RocksDbWeight::get().writes((7_u64).saturating_mul(e.into())))
-			.saturating_add(Weight::from_parts(0, 7749).saturating_mul(e.into()))
+			.saturating_add(RocksDbWeight::get().reads((25_u64).saturating_mul(e.into())))
+			.saturating_add(RocksDbWeight::get().writes((16_u64).saturating_mul(e.into())))
+			.saturating_add(Weight::from_parts(0, 13945).saturating_mul(e.into()))
 	}
-	/// Storage: `LBP::PoolData` (r:1 w:0)
+	/// Storage: `LBP::PoolData` (r:1 w:1)
 	/// Proof: `LBP::PoolData` (`max_values`: None, `max_size`: Some(163), added: 2638, mode: `MaxEncodedLen`)
 	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
@@ -218,22 +291,41 @@ impl WeightInfo for () {
 	/// Proof: `AssetRegistry::BannedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:3 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::PoolRestrictions` (r:1 w:0)
+	/// Proof: `LBP::PoolRestrictions` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::PoolVesting` (r:1 w:0)
+	/// Proof: `LBP::PoolVesting` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::Allowlisted` (r:1 w:0)
+	/// Proof: `LBP::Allowlisted` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::Participants` (r:1 w:1)
+	/// Proof: `LBP::Participants` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::VestingSchedules` (r:1 w:1)
+	/// Proof: `Vesting::VestingSchedules` (`max_values`: None, `max_size`: Some(469), added: 2944, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::PoolCaps` (r:1 w:0)
+	/// Proof: `LBP::PoolCaps` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::SaleRaised` (r:1 w:1)
+	/// Proof: `LBP::SaleRaised` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::Contributions` (r:1 w:1)
+	/// Proof: `LBP::Contributions` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::SaleClosedAt` (r:1 w:1)
+	/// Proof: `LBP::SaleClosedAt` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 3]`.
 	/// The range of component `e` is `[0, 1]`.
 	fn router_execution_buy(c: u32, e: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1052 + e * (1199 ±0)`
-		//  Estimated: `6190 + e * (8544 ±14_296_005_195_177_084)`
-		// Minimum execution time: 177_688_000 picoseconds.
+		// Not benchmarked yet, the last benchmarked weight with sale restrictions, orml_vesting::vested_transfer and sale caps added.
 		Weight::from_parts(178_851_000, 6190)
 			// Standard Error: 450_328
 			.saturating_add(Weight::from_parts(2_999_206, 0).saturating_mul(c.into()))
 			// Standard Error: 1_518_653
-			.saturating_add(Weight::from_parts(137_463_885, 0).saturating_mul(e.into()))
+			.saturating_add(Weight::from_parts(246_800_885, 0).saturating_mul(e.into()))
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().reads((12_u64).saturating_mul(e.into())))
-			.saturating_add(RocksDbWeight::get().writes((7_u64).saturating_mul(e.into())))
-			.saturating_add(Weight::from_parts(0, 8544).saturating_mul(e.into()))
+			.saturating_add(RocksDbWeight::get().reads((25_u64).saturating_mul(e.into())))
+			.saturating_add(RocksDbWeight::get().writes((16_u64).saturating_mul(e.into())))
+			.saturating_add(Weight::from_parts(0, 14740).saturating_mul(e.into()))
 	}
 	/// Storage: `LBP::PoolData` (r:1 w:0)
 	/// Proof: `LBP::PoolData` (`max_values`: None, `max_size`: Some(163), added: 2638, mode: `MaxEncodedLen`)
@@ -265,4 +357,226 @@ impl WeightInfo for () {
 		Weight::from_parts(43_263_000, 6190)
 			.saturating_add(RocksDbWeight::get().reads(6_u64))
 	}
+	/// Storage: `LBP::PoolData` (r:1 w:0)
+	/// Proof: `LBP::PoolData` (`max_values`: None, `max_size`: Some(163), added: 2638, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::XYKMigrations` (r:0 w:1)
+	/// Proof: `LBP::XYKMigrations` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+	fn set_xyk_migration() -> Weight {
+		// Not benchmarked yet, estimated from set_sale_vesting.
+		Weight::from_parts(26_413_000, 3628)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LBP::LockedXYKShares` (r:1 w:1)
+	/// Proof: `LBP::LockedXYKShares` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Locks` (r:1 w:1)
+	/// Proof: `Tokens::Locks` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:1 w:1)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	fn unlock_xyk_shares() -> Weight {
+		// Not benchmarked yet, estimated from a transfer that removes a lock.
+		Weight::from_parts(48_305_000, 4726)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `LBP::PoolData` (r:2 w:1)
+	/// Proof: `LBP::PoolData` (`max_values`: None, `max_size`: Some(163), added: 2638, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::Assets` (r:4 w:1)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:8 w:8)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `Router::SkipEd` (r:1 w:0)
+	/// Proof: `Router::SkipEd` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Duster::AccountBlacklist` (r:1 w:0)
+	/// Proof: `Duster::AccountBlacklist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::BannedAssets` (r:2 w:0)
+	/// Proof: `AssetRegistry::BannedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `MultiTransactionPayment::AccountCurrencyMap` (r:2 w:1)
+	/// Proof: `MultiTransactionPayment::AccountCurrencyMap` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `MultiTransactionPayment::AcceptedCurrencies` (r:2 w:0)
+	/// Proof: `MultiTransactionPayment::AcceptedCurrencies` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Locks` (r:2 w:1)
+	/// Proof: `Tokens::Locks` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::ShareToken` (r:1 w:1)
+	/// Proof: `XYK::ShareToken` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetIds` (r:1 w:1)
+	/// Proof: `AssetRegistry::AssetIds` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::NextAssetId` (r:1 w:1)
+	/// Proof: `AssetRegistry::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::TotalIssuance` (r:1 w:1)
+	/// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::LockedXYKShares` (r:1 w:1)
+	/// Proof: `LBP::LockedXYKShares` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::FeeCollectorWithAsset` (r:0 w:1)
+	/// Proof: `LBP::FeeCollectorWithAsset` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::XYKMigrations` (r:0 w:1)
+	/// Proof: `LBP::XYKMigrations` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+	/// Storage: `Duster::AccountWhitelist` (r:0 w:1)
+	/// Proof: `Duster::AccountWhitelist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::TotalLiquidity` (r:0 w:1)
+	/// Proof: `XYK::TotalLiquidity` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `XYK::PoolAssets` (r:0 w:1)
+	/// Proof: `XYK::PoolAssets` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::SaleClosedAt` (r:1 w:1)
+	/// Proof: `LBP::SaleClosedAt` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn migrate_to_xyk() -> Weight {
+		// Not benchmarked yet, estimated from remove_liquidity and pallet_xyk::create_pool.
+		Weight::from_parts(415_291_000, 21666)
+			.saturating_add(RocksDbWeight::get().reads(33_u64))
+			.saturating_add(RocksDbWeight::get().writes(27_u64))
+	}
+	/// Storage: `LBP::PoolData` (r:1 w:0)
+	/// Proof: `LBP::PoolData` (`max_values`: None, `max_size`: Some(163), added: 2638, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::PoolRestrictions` (r:1 w:1)
+	/// Proof: `LBP::PoolRestrictions` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::NextSaleId` (r:1 w:1)
+	/// Proof: `LBP::NextSaleId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_sale_restrictions() -> Weight {
+		// Not benchmarked yet, estimated from update_pool_data.
+		Weight::from_parts(31_794_000, 3628)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `LBP::PoolRestrictions` (r:1 w:0)
+	/// Proof: `LBP::PoolRestrictions` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::Allowlisted` (r:0 w:100)
+	/// Proof: `LBP::Allowlisted` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 100]`.
+	fn add_to_allowlist(n: u32, ) -> Weight {
+		// Not benchmarked yet, estimated from a single storage write per account.
+		Weight::from_parts(15_120_000, 3573)
+			// Standard Error: 2_139
+			.saturating_add(Weight::from_parts(7_914_306, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `LBP::PoolRestrictions` (r:1 w:0)
+	/// Proof: `LBP::PoolRestrictions` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::Allowlisted` (r:0 w:1)
+	/// Proof: `LBP::Allowlisted` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[0, 32]`.
+	fn prove_allowlisted(p: u32, ) -> Weight {
+		// Not benchmarked yet, estimated from one blake2 hash per proof item.
+		Weight::from_parts(19_852_000, 3573)
+			// Standard Error: 1_204
+			.saturating_add(Weight::from_parts(1_436_587, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LBP::PoolData` (r:1 w:0)
+	/// Proof: `LBP::PoolData` (`max_values`: None, `max_size`: Some(163), added: 2638, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::PoolVesting` (r:0 w:1)
+	/// Proof: `LBP::PoolVesting` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::PoolCaps` (r:1 w:0)
+	/// Proof: `LBP::PoolCaps` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	fn set_sale_vesting() -> Weight {
+		// Not benchmarked yet, estimated from update_pool_data.
+		Weight::from_parts(23_590_000, 3628)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `LBP::PoolData` (r:1 w:0)
+	/// Proof: `LBP::PoolData` (`max_values`: None, `max_size`: Some(163), added: 2638, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::PoolCaps` (r:1 w:1)
+	/// Proof: `LBP::PoolCaps` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::NextSaleId` (r:1 w:1)
+	/// Proof: `LBP::NextSaleId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::PoolVesting` (r:1 w:0)
+	/// Proof: `LBP::PoolVesting` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn set_sale_caps() -> Weight {
+		// Not benchmarked yet, estimated from update_pool_data.
+		Weight::from_parts(31_225_000, 3628)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `LBP::SaleRefunds` (r:1 w:1)
+	/// Proof: `LBP::SaleRefunds` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::PoolData` (r:2 w:0)
+	/// Proof: `LBP::PoolData` (`max_values`: None, `max_size`: Some(163), added: 2638, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::PoolCaps` (r:2 w:0)
+	/// Proof: `LBP::PoolCaps` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::SaleRaised` (r:2 w:1)
+	/// Proof: `LBP::SaleRaised` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::Contributions` (r:1 w:1)
+	/// Proof: `LBP::Contributions` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:4 w:4)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::SaleClosedAt` (r:1 w:0)
+	/// Proof: `LBP::SaleClosedAt` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn claim_refund() -> Weight {
+		// Not benchmarked yet, estimated from remove_liquidity.
+		Weight::from_parts(113_902_000, 11322)
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `LBP::PoolData` (r:1 w:1)
+	/// Proof: `LBP::PoolData` (`max_values`: None, `max_size`: Some(163), added: 2638, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::FeeCollectorWithAsset` (r:1 w:1)
+	/// Proof: `LBP::FeeCollectorWithAsset` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:5 w:5)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `Router::SkipEd` (r:1 w:0)
+	/// Proof: `Router::SkipEd` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Duster::AccountBlacklist` (r:1 w:0)
+	/// Proof: `Duster::AccountBlacklist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::BannedAssets` (r:2 w:0)
+	/// Proof: `AssetRegistry::BannedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `MultiTransactionPayment::AccountCurrencyMap` (r:1 w:1)
+	/// Proof: `MultiTransactionPayment::AccountCurrencyMap` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `MultiTransactionPayment::AcceptedCurrencies` (r:1 w:0)
+	/// Proof: `MultiTransactionPayment::AcceptedCurrencies` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::SaleRefunds` (r:1 w:1)
+	/// Proof: `LBP::SaleRefunds` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::PoolDeposits` (r:0 w:1)
+	/// Proof: `LBP::PoolDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	fn create_pool_with_deposit() -> Weight {
+		// Not benchmarked yet, estimated from create_pool with the deposit reserve added.
+		Weight::from_parts(331_212_000, 11322)
+			.saturating_add(RocksDbWeight::get().reads(19_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+	}
+	/// Storage: `LBP::PoolData` (r:1 w:1)
+	/// Proof: `LBP::PoolData` (`max_values`: None, `max_size`: Some(163), added: 2638, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:4 w:4)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `Router::SkipEd` (r:1 w:0)
+	/// Proof: `Router::SkipEd` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Duster::AccountBlacklist` (r:1 w:0)
+	/// Proof: `Duster::AccountBlacklist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::BannedAssets` (r:2 w:0)
+	/// Proof: `AssetRegistry::BannedAssets` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `MultiTransactionPayment::AccountCurrencyMap` (r:1 w:1)
+	/// Proof: `MultiTransactionPayment::AccountCurrencyMap` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Locks` (r:1 w:0)
+	/// Proof: `Tokens::Locks` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::FeeCollectorWithAsset` (r:0 w:1)
+	/// Proof: `LBP::FeeCollectorWithAsset` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::PoolCaps` (r:1 w:1)
+	/// Proof: `LBP::PoolCaps` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::SaleRaised` (r:1 w:0)
+	/// Proof: `LBP::SaleRaised` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::SaleRefunds` (r:1 w:1)
+	/// Proof: `LBP::SaleRefunds` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::PoolDeposits` (r:1 w:1)
+	/// Proof: `LBP::PoolDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `LBP::SaleClosedAt` (r:0 w:1)
+	/// Proof: `LBP::SaleClosedAt` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn cancel_pool() -> Weight {
+		// Not benchmarked yet, estimated from remove_liquidity with the deposit slash added.
+		Weight::from_parts(189_106_000, 11322)
+			.saturating_add(RocksDbWeight::get().reads(19_u64))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
+	}
 }
@@ -0,0 +1,44 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2026  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use frame_benchmarking::benchmarks;
+use frame_system::RawOrigin;
+
+benchmarks! {
+	set_parameter {
+		let (key, value) = T::BenchmarkHelper::parameter();
+	}: _(RawOrigin::Root, key.clone(), Some(value.clone()))
+	verify {
+		assert_eq!(Values::<T>::get(key), Some(value));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::{ExtBuilder, Test};
+	use frame_support::assert_ok;
+
+	#[test]
+	fn test_benchmarks() {
+		ExtBuilder.build().execute_with(|| {
+			assert_ok!(Pallet::<Test>::test_benchmark_set_parameter());
+		});
+	}
+}
@@ -10,17 +10,81 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+
 pub use pallet::*;
+pub use weights::WeightInfo;
 
+use codec::DecodeWithMemTracking;
 use core::marker::PhantomData;
 use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::OriginFor;
+
+/// Per-key rules of the parameter registry, implemented by the runtime parameter key.
+pub trait ParameterRules<Origin, Value> {
+	/// Ensure `origin` is allowed to set the parameter.
+	fn ensure_origin(&self, origin: Origin) -> DispatchResult;
+
+	/// Returns true if `value` has the type of the parameter and is within its bounds.
+	fn validate(&self, value: &Value) -> bool;
+}
+
+/// Typed parameter stored in the parameter registry.
+pub trait RuntimeParameter<Key, Value> {
+	/// Type of the parameter value.
+	type Type;
+
+	/// Key of the parameter in the registry.
+	fn key() -> Key;
+
+	/// Value of the parameter when it is not set in the registry.
+	fn default_value() -> Self::Type;
+
+	/// Typed value of a registry value, `None` if the value has a different type.
+	fn try_from_value(value: Value) -> Option<Self::Type>;
+}
+
+/// `Get` adapter reading the parameter `P` from the parameter registry.
+pub struct ParameterOf<T, P>(PhantomData<(T, P)>);
+
+impl<T: Config, P: RuntimeParameter<T::ParameterKey, T::ParameterValue>> Get<P::Type> for ParameterOf<T, P> {
+	fn get() -> P::Type {
+		Values::<T>::get(P::key())
+			.and_then(P::try_from_value)
+			.unwrap_or_else(P::default_value)
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<Key, Value> {
+	/// Parameter which can be set by the `Root` origin.
+	fn parameter() -> (Key, Value);
+}
 
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 
 	#[pallet::config]
-	pub trait Config: frame_system::Config {}
+	pub trait Config: frame_system::Config<RuntimeEvent: From<Event<Self>>> {
+		/// Key of the runtime parameters, defines the origin and bounds of every parameter.
+		type ParameterKey: Parameter
+			+ DecodeWithMemTracking
+			+ MaxEncodedLen
+			+ ParameterRules<Self::RuntimeOrigin, Self::ParameterValue>;
+
+		/// Value of the runtime parameters.
+		type ParameterValue: Parameter + DecodeWithMemTracking + MaxEncodedLen;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
+
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::ParameterKey, Self::ParameterValue>;
+	}
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
@@ -33,6 +97,28 @@ pub mod pallet {
 	#[pallet::getter(fn relay_parent_offset_override)]
 	pub type RelayParentOffsetOverride<T> = StorageValue<_, bool, ValueQuery>;
 
+	/// Values of the runtime parameters set by governance.
+	#[pallet::storage]
+	#[pallet::getter(fn parameter)]
+	pub type Values<T: Config> = StorageMap<_, Blake2_128Concat, T::ParameterKey, T::ParameterValue, OptionQuery>;
+
+	#[pallet::error]
+	#[cfg_attr(test, derive(PartialEq, Eq))]
+	pub enum Error<T> {
+		/// Value has a different type than the parameter or is out of its bounds
+		InvalidValue,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Parameter was set, or reset to its default value.
+		ParameterSet {
+			key: T::ParameterKey,
+			value: Option<T::ParameterValue>,
+		},
+	}
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub is_testnet: bool,
@@ -57,4 +143,38 @@ pub mod pallet {
 			RelayParentOffsetOverride::<T>::put(self.relay_parent_offset_override);
 		}
 	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the value of a runtime parameter.
+		///
+		/// The dispatch origin for this call must be the origin of the parameter `key`.
+		///
+		/// Parameters:
+		/// - `key`: The parameter to set.
+		/// - `value`: The new value of the parameter. `None` resets the parameter to its default value.
+		///
+		/// Emits `ParameterSet` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_parameter())]
+		pub fn set_parameter(
+			origin: OriginFor<T>,
+			key: T::ParameterKey,
+			value: Option<T::ParameterValue>,
+		) -> DispatchResult {
+			key.ensure_origin(origin)?;
+
+			match &value {
+				Some(value) => {
+					ensure!(key.validate(value), Error::<T>::InvalidValue);
+					Values::<T>::insert(&key, value);
+				}
+				None => Values::<T>::remove(&key),
+			}
+
+			Self::deposit_event(Event::ParameterSet { key, value });
+
+			Ok(())
+		}
+	}
 }
@@ -1,8 +1,14 @@
 use crate as pallet_parameters;
-use frame_support::{construct_runtime, traits::Everything};
+use crate::{ParameterRules, RuntimeParameter};
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::{construct_runtime, dispatch::DispatchResult, traits::Everything};
 use frame_system as system;
+use scale_info::TypeInfo;
 use sp_core::H256;
-use sp_runtime::{traits::BlakeTwo256, BuildStorage};
+use sp_runtime::{
+	traits::{BadOrigin, BlakeTwo256},
+	BuildStorage, RuntimeDebug,
+};
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -13,7 +19,103 @@ construct_runtime!(
 	}
 );
 
-impl pallet_parameters::Config for Test {}
+pub const ALICE: u64 = 1;
+pub const BOB: u64 = 2;
+
+pub const DEFAULT_FEE: (u32, u32) = (3, 1_000);
+pub const DEFAULT_MIN_TRADING_LIMIT: u128 = 1_000;
+
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ParameterKey {
+	ExchangeFee,
+	MinTradingLimit,
+}
+
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ParameterValue {
+	Fee((u32, u32)),
+	Balance(u128),
+}
+
+impl ParameterRules<RuntimeOrigin, ParameterValue> for ParameterKey {
+	fn ensure_origin(&self, origin: RuntimeOrigin) -> DispatchResult {
+		match self {
+			// fee can be set only by root
+			ParameterKey::ExchangeFee => frame_system::ensure_root(origin)?,
+			// trading limit can be set by root or ALICE
+			ParameterKey::MinTradingLimit => match frame_system::ensure_signed_or_root(origin)? {
+				None | Some(ALICE) => {}
+				Some(_) => return Err(BadOrigin.into()),
+			},
+		}
+		Ok(())
+	}
+
+	fn validate(&self, value: &ParameterValue) -> bool {
+		match (self, value) {
+			(ParameterKey::ExchangeFee, ParameterValue::Fee((numerator, denominator))) => {
+				*denominator > 0 && numerator.saturating_mul(10) <= *denominator
+			}
+			(ParameterKey::MinTradingLimit, ParameterValue::Balance(limit)) => *limit > 0,
+			_ => false,
+		}
+	}
+}
+
+pub struct ExchangeFee;
+impl RuntimeParameter<ParameterKey, ParameterValue> for ExchangeFee {
+	type Type = (u32, u32);
+
+	fn key() -> ParameterKey {
+		ParameterKey::ExchangeFee
+	}
+
+	fn default_value() -> Self::Type {
+		DEFAULT_FEE
+	}
+
+	fn try_from_value(value: ParameterValue) -> Option<Self::Type> {
+		match value {
+			ParameterValue::Fee(fee) => Some(fee),
+			_ => None,
+		}
+	}
+}
+
+pub struct MinTradingLimit;
+impl RuntimeParameter<ParameterKey, ParameterValue> for MinTradingLimit {
+	type Type = u128;
+
+	fn key() -> ParameterKey {
+		ParameterKey::MinTradingLimit
+	}
+
+	fn default_value() -> Self::Type {
+		DEFAULT_MIN_TRADING_LIMIT
+	}
+
+	fn try_from_value(value: ParameterValue) -> Option<Self::Type> {
+		match value {
+			ParameterValue::Balance(limit) => Some(limit),
+			_ => None,
+		}
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<ParameterKey, ParameterValue> for () {
+	fn parameter() -> (ParameterKey, ParameterValue) {
+		(ParameterKey::ExchangeFee, ParameterValue::Fee((5, 1_000)))
+	}
+}
+
+impl pallet_parameters::Config for Test {
+	type ParameterKey = ParameterKey;
+	type ParameterValue = ParameterValue;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
 
 impl system::Config for Test {
 	type BaseCallFilter = Everything;
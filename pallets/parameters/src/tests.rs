@@ -1,4 +1,6 @@
-use crate::{mock::*, IsTestnet, Pallet as Parameters, RelayParentOffsetOverride};
+use crate::{mock::*, Error, Event, IsTestnet, Pallet as Parameters, ParameterOf, RelayParentOffsetOverride, Values};
+use frame_support::{assert_noop, assert_ok, traits::Get};
+use sp_runtime::traits::BadOrigin;
 
 #[test]
 fn is_testnet_is_false_by_default() {
@@ -29,3 +31,126 @@ fn relay_parent_offset_override_can_be_read_from_storage() {
 		assert!(Parameters::<Test>::relay_parent_offset_override());
 	});
 }
+
+#[test]
+fn parameters_return_default_value_when_not_set() {
+	ExtBuilder.build().execute_with(|| {
+		assert_eq!(ParameterOf::<Test, ExchangeFee>::get(), DEFAULT_FEE);
+		assert_eq!(ParameterOf::<Test, MinTradingLimit>::get(), DEFAULT_MIN_TRADING_LIMIT);
+	});
+}
+
+#[test]
+fn set_parameter_should_work() {
+	ExtBuilder.build().execute_with(|| {
+		assert_ok!(Parameters::<Test>::set_parameter(
+			RuntimeOrigin::root(),
+			ParameterKey::ExchangeFee,
+			Some(ParameterValue::Fee((5, 1_000)))
+		));
+
+		assert_eq!(ParameterOf::<Test, ExchangeFee>::get(), (5, 1_000));
+		assert_eq!(
+			Parameters::<Test>::parameter(ParameterKey::ExchangeFee),
+			Some(ParameterValue::Fee((5, 1_000)))
+		);
+		System::assert_last_event(
+			Event::ParameterSet {
+				key: ParameterKey::ExchangeFee,
+				value: Some(ParameterValue::Fee((5, 1_000))),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn set_parameter_to_none_should_reset_to_default_value() {
+	ExtBuilder.build().execute_with(|| {
+		assert_ok!(Parameters::<Test>::set_parameter(
+			RuntimeOrigin::signed(ALICE),
+			ParameterKey::MinTradingLimit,
+			Some(ParameterValue::Balance(5_000))
+		));
+		assert_eq!(ParameterOf::<Test, MinTradingLimit>::get(), 5_000);
+
+		assert_ok!(Parameters::<Test>::set_parameter(
+			RuntimeOrigin::signed(ALICE),
+			ParameterKey::MinTradingLimit,
+			None
+		));
+
+		assert_eq!(ParameterOf::<Test, MinTradingLimit>::get(), DEFAULT_MIN_TRADING_LIMIT);
+		assert!(!Values::<Test>::contains_key(ParameterKey::MinTradingLimit));
+	});
+}
+
+#[test]
+fn set_parameter_should_check_origin_of_the_key() {
+	ExtBuilder.build().execute_with(|| {
+		assert_noop!(
+			Parameters::<Test>::set_parameter(
+				RuntimeOrigin::signed(ALICE),
+				ParameterKey::ExchangeFee,
+				Some(ParameterValue::Fee((5, 1_000)))
+			),
+			BadOrigin
+		);
+
+		assert_noop!(
+			Parameters::<Test>::set_parameter(
+				RuntimeOrigin::signed(BOB),
+				ParameterKey::MinTradingLimit,
+				Some(ParameterValue::Balance(5_000))
+			),
+			BadOrigin
+		);
+
+		assert_ok!(Parameters::<Test>::set_parameter(
+			RuntimeOrigin::root(),
+			ParameterKey::MinTradingLimit,
+			Some(ParameterValue::Balance(5_000))
+		));
+	});
+}
+
+#[test]
+fn set_parameter_should_not_work_when_value_is_invalid() {
+	ExtBuilder.build().execute_with(|| {
+		// value of a different type
+		assert_noop!(
+			Parameters::<Test>::set_parameter(
+				RuntimeOrigin::root(),
+				ParameterKey::ExchangeFee,
+				Some(ParameterValue::Balance(5))
+			),
+			Error::<Test>::InvalidValue
+		);
+
+		// value out of bounds
+		assert_noop!(
+			Parameters::<Test>::set_parameter(
+				RuntimeOrigin::root(),
+				ParameterKey::ExchangeFee,
+				Some(ParameterValue::Fee((2, 10)))
+			),
+			Error::<Test>::InvalidValue
+		);
+		assert_noop!(
+			Parameters::<Test>::set_parameter(
+				RuntimeOrigin::root(),
+				ParameterKey::ExchangeFee,
+				Some(ParameterValue::Fee((0, 0)))
+			),
+			Error::<Test>::InvalidValue
+		);
+		assert_noop!(
+			Parameters::<Test>::set_parameter(
+				RuntimeOrigin::root(),
+				ParameterKey::MinTradingLimit,
+				Some(ParameterValue::Balance(0))
+			),
+			Error::<Test>::InvalidValue
+		);
+	});
+}
@@ -0,0 +1,26 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_parameters.
+pub trait WeightInfo {
+	fn set_parameter() -> Weight;
+}
+
+/// Weights for pallet_parameters using the hydraDX node and recommended hardware.
+impl WeightInfo for () {
+	/// Storage: `Parameters::Values` (r:0 w:1)
+	/// Proof: `Parameters::Values` (`max_values`: None, `max_size`: Some(35), added: 2510, mode: `MaxEncodedLen`)
+	fn set_parameter() -> Weight {
+		// Not benchmarked yet, estimated from a single storage write.
+		Weight::from_parts(10_212_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
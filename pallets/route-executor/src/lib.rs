@@ -31,11 +31,13 @@ use frame_support::{
 };
 use hydra_dx_math::support::rational::{round_u512_to_rational, Rounding};
 
+use basilisk_traits::oracle::PriceOracle;
 use basilisk_traits::router::{
-	inverse_route, AssetPair, RefundEdCalculator, RouteProvider, RouteSpotPriceProvider, TradeExecution,
+	inverse_route, AssetPair, RefundEdCalculator, RouteCandidateProvider, RouteProvider, RouteSpotPriceProvider,
+	TradeExecution,
 };
 pub use basilisk_traits::router::{AmmTradeWeights, AmountInAndOut, ExecutorError, PoolType, RouterT, Trade};
-use frame_system::pallet_prelude::OriginFor;
+use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
 use frame_system::{ensure_signed, Origin};
 use hydradx_traits::registry::Inspect as RegistryInspect;
 
@@ -43,8 +45,10 @@ use orml_traits::arithmetic::{CheckedAdd, CheckedSub};
 use pallet_broadcast::types::IncrementalIdType;
 pub use pallet_broadcast::types::{ExecutionType, Fee};
 use sp_core::U512;
-use sp_runtime::traits::{AccountIdConversion, CheckedDiv};
-use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128, Saturating, TransactionOutcome};
+use sp_runtime::traits::{AccountIdConversion, BlockNumberProvider, CheckedDiv, Zero};
+use sp_runtime::{
+	ArithmeticError, DispatchError, FixedPointNumber, FixedU128, Permill, Saturating, TransactionOutcome,
+};
 use sp_std::{vec, vec::Vec};
 
 #[cfg(test)]
@@ -52,6 +56,7 @@ mod tests;
 pub mod weights;
 
 mod types;
+pub use types::{MaintenanceCursor, RouteUpdateReason, SplitRoute, TradeGuard};
 
 pub use weights::WeightInfo;
 
@@ -59,6 +64,7 @@ pub use weights::WeightInfo;
 pub use pallet::*;
 
 pub const MAX_NUMBER_OF_TRADES: u32 = 5;
+pub const MAX_NUMBER_OF_SPLIT_ROUTES: u32 = 4;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -67,7 +73,7 @@ pub mod pallet {
 	use basilisk_traits::oracle::{OraclePeriod, PriceOracle};
 	use basilisk_traits::router::ExecutorError;
 	use frame_support::traits::fungibles::Mutate;
-	use frame_system::pallet_prelude::OriginFor;
+	use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
 	use sp_runtime::traits::{AtLeast32BitUnsigned, CheckedDiv, Zero};
 	use sp_runtime::Saturating;
 
@@ -86,6 +92,7 @@ pub mod pallet {
 			+ PartialOrd
 			+ MaybeSerializeDeserialize
 			+ From<u128>
+			+ Into<u128>
 			+ Default
 			+ CheckedSub
 			+ CheckedAdd
@@ -128,6 +135,16 @@ pub mod pallet {
 		/// Origin able to set route without validation
 		type ForceInsertOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// Pools checked as route candidates by the route maintenance. LBP candidates are ignored.
+		type RouteCandidates: RouteCandidateProvider<Self::AssetId>;
+
+		/// Max number of routes checked by the route maintenance in one block
+		#[pallet::constant]
+		type MaxRoutesMaintainedPerBlock: Get<u32>;
+
+		/// Block number provider used to check the deadline of guarded trades.
+		type BlockNumberProvider: BlockNumberProvider<BlockNumber = BlockNumberFor<Self>>;
+
 		/// Weight information for the extrinsics.
 		type WeightInfo: AmmTradeWeights<Trade<Self::AssetId>>;
 	}
@@ -143,8 +160,11 @@ pub mod pallet {
 			amount_out: T::Balance,
 			event_id: IncrementalIdType,
 		},
-		///The route with trades has been successfully executed
-		RouteUpdated { asset_ids: Vec<T::AssetId> },
+		///The on-chain route of the asset pair has been updated
+		RouteUpdated {
+			asset_ids: Vec<T::AssetId>,
+			reason: RouteUpdateReason,
+		},
 	}
 
 	#[pallet::error]
@@ -169,6 +189,16 @@ pub mod pallet {
 		InvalidRouteExecution,
 		/// Trading same assets is not allowed.
 		NotAllowed,
+		///The max number of routes in a split trade is exceeded
+		MaxRoutesExceeded,
+		///The deadline of the trade has passed, the current relay chain block is after the deadline
+		DeadlineExpired,
+		///The execution price deviates from the oracle price more than allowed
+		OraclePriceDeviationExceeded,
+		///The account balance of `asset_out` is already at or above the target balance
+		TargetBalanceAlreadyReached,
+		///The account balance of `asset_in` was not fully cleared by the trade
+		BalanceNotCleared,
 	}
 
 	///Flag to indicate when to skip ED handling
@@ -186,6 +216,47 @@ pub mod pallet {
 		BoundedVec<Trade<T::AssetId>, ConstU32<MAX_NUMBER_OF_TRADES>>,
 	>;
 
+	/// Position of the on-chain route maintenance
+	#[pallet::storage]
+	pub type RouteMaintenanceCursor<T: Config> = StorageValue<_, MaintenanceCursor, ValueQuery>;
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// Routes stored at genesis.
+		///
+		/// Routes are validated by being executed in a dry-run mode in both directions, so the pools
+		/// of the routes have to exist and be tradable at genesis.
+		pub routes: Vec<(AssetPair<T::AssetId>, Vec<Trade<T::AssetId>>)>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			for (asset_pair, route) in self.routes.iter() {
+				let (mut asset_pair, mut route) = (*asset_pair, route.clone());
+				Pallet::<T>::ensure_route_size(route.len()).expect("Route in genesis is too long");
+				Pallet::<T>::ensure_route_arguments(&asset_pair, &route).expect("Invalid route in genesis");
+
+				if !asset_pair.is_ordered() {
+					asset_pair = asset_pair.ordered_pair();
+					route = inverse_route(route);
+				}
+
+				Pallet::<T>::validate_route(&route).expect("Route in genesis is not tradable");
+				Pallet::<T>::insert_route(asset_pair, route, RouteUpdateReason::ForceInserted)
+					.expect("Failed to insert route in genesis");
+			}
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::maintain_routes(remaining_weight)
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Executes a sell with a series of trades specified in the route.
@@ -212,7 +283,15 @@ pub mod pallet {
 			min_amount_out: T::Balance,
 			route: Vec<Trade<T::AssetId>>,
 		) -> DispatchResult {
-			Self::do_sell(origin, asset_in, asset_out, amount_in, min_amount_out, route)
+			Self::do_sell(
+				origin,
+				asset_in,
+				asset_out,
+				amount_in,
+				min_amount_out,
+				route,
+				TradeGuard::default(),
+			)
 		}
 
 		/// Executes a buy with a series of trades specified in the route.
@@ -239,72 +318,15 @@ pub mod pallet {
 			max_amount_in: T::Balance,
 			route: Vec<Trade<T::AssetId>>,
 		) -> DispatchResult {
-			let who = ensure_signed(origin.clone())?;
-
-			ensure!(asset_in != asset_out, Error::<T>::NotAllowed);
-			Self::ensure_route_size(route.len())?;
-
-			let asset_pair = AssetPair::new(asset_in, asset_out);
-			let route = Self::get_route_or_default(route, asset_pair)?;
-			Self::ensure_route_arguments(&asset_pair, &route)?;
-
-			let user_balance_of_asset_in_before_trade =
-				T::Currency::reducible_balance(asset_in, &who, Preservation::Expendable, Fortitude::Polite);
-
-			let trade_amounts = Self::calculate_buy_trade_amounts(&route, amount_out)?;
-
-			let first_trade = trade_amounts.last().ok_or(Error::<T>::RouteCalculationFailed)?;
-			ensure!(first_trade.amount_in <= max_amount_in, Error::<T>::TradingLimitReached);
-
-			let route_length = route.len();
-
-			let next_event_id = pallet_broadcast::Pallet::<T>::add_to_context(ExecutionType::Router)?;
-
-			for (trade_index, (trade_amount, trade)) in trade_amounts.iter().rev().zip(route).enumerate() {
-				Self::disable_ed_handling_for_insufficient_assets(route_length, trade_index, trade);
-				let user_balance_of_asset_out_before_trade =
-					T::Currency::reducible_balance(trade.asset_out, &who, Preservation::Preserve, Fortitude::Polite);
-				let execution_result = T::AMM::execute_buy(
-					origin.clone(),
-					trade.pool,
-					trade.asset_in,
-					trade.asset_out,
-					trade_amount.amount_out,
-					trade_amount.amount_in,
-				);
-
-				handle_execution_error!(execution_result);
-
-				Self::ensure_that_user_received_asset_out_at_most(
-					who.clone(),
-					trade.asset_in,
-					trade.asset_out,
-					user_balance_of_asset_out_before_trade,
-					trade_amount.amount_out,
-				)?;
-			}
-
-			SkipEd::<T>::kill();
-
-			Self::ensure_that_user_spent_asset_in_at_least(
-				who,
-				asset_in,
-				user_balance_of_asset_in_before_trade,
-				first_trade.amount_in,
-			)?;
-
-			//TODO: we want to deprecate it once unified events are working fine
-			Self::deposit_event(Event::Executed {
+			Self::do_buy(
+				origin,
 				asset_in,
 				asset_out,
-				amount_in: first_trade.amount_in,
 				amount_out,
-				event_id: next_event_id,
-			});
-
-			let _ = pallet_broadcast::Pallet::<T>::remove_from_context();
-
-			Ok(())
+				max_amount_in,
+				route,
+				TradeGuard::default(),
+			)
 		}
 
 		/// Sets the on-chain route for a given asset pair.
@@ -349,50 +371,9 @@ pub mod pallet {
 
 			let existing_route = Self::get_route(asset_pair);
 
-			match Self::validate_route(&existing_route) {
-				Ok((reference_amount_in, reference_amount_in_for_inverse)) => {
-					let new_route_validation = Self::validate_sell(new_route.clone(), reference_amount_in);
-
-					let inverse_new_route = inverse_route(new_route.to_vec());
-					let inverse_new_route_validation =
-						Self::validate_sell(inverse_new_route.clone(), reference_amount_in_for_inverse);
-
-					match (new_route_validation, inverse_new_route_validation) {
-						(Ok(_), Ok(_)) => (),
-						(Err(_), Ok(amount_out)) => {
-							Self::validate_sell(new_route.to_vec(), amount_out).map(|_| ())?;
-						}
-						(Ok(amount_out), Err(_)) => {
-							Self::validate_sell(inverse_new_route.clone(), amount_out).map(|_| ())?;
-						}
-						(Err(err), Err(_)) => return Err(err.into()),
-					}
-
-					let amount_out_for_existing_route =
-						Self::calculate_expected_amount_out(&existing_route, reference_amount_in)?;
-					let amount_out_for_new_route =
-						Self::calculate_expected_amount_out(&new_route, reference_amount_in)?;
-
-					let inverse_existing_route = inverse_route(existing_route.to_vec());
-					let amount_out_for_existing_inversed_route =
-						Self::calculate_expected_amount_out(&inverse_existing_route, reference_amount_in_for_inverse)?;
-					let amount_out_for_new_inversed_route =
-						Self::calculate_expected_amount_out(&inverse_new_route, reference_amount_in_for_inverse)?;
-
-					if amount_out_for_new_route > amount_out_for_existing_route
-						&& amount_out_for_new_inversed_route > amount_out_for_existing_inversed_route
-					{
-						return Self::insert_route(asset_pair, new_route);
-					}
-				}
-				Err(_) => {
-					Self::validate_route(&new_route)?;
-
-					return Self::insert_route(asset_pair, new_route);
-				}
-			}
+			Self::validate_route_update(&existing_route, &new_route)?;
 
-			Err(Error::<T>::RouteUpdateIsNotSuccessful.into())
+			Self::insert_route(asset_pair, new_route, RouteUpdateReason::Set)
 		}
 
 		/// Force inserts the on-chain route for a given asset pair, so there is no any validation for the route
@@ -425,7 +406,7 @@ pub mod pallet {
 				new_route = inverse_route(new_route)
 			}
 
-			Self::insert_route(asset_pair, new_route)
+			Self::insert_route(asset_pair, new_route, RouteUpdateReason::ForceInserted)
 		}
 
 		/// Executes a sell with a series of trades specified in the route.
@@ -455,7 +436,234 @@ pub mod pallet {
 			let who = ensure_signed(origin.clone())?;
 			let amount_in = T::Currency::reducible_balance(asset_in, &who, Preservation::Expendable, Fortitude::Polite);
 
-			Self::do_sell(origin, asset_in, asset_out, amount_in, min_amount_out, route)
+			Self::do_sell(
+				origin,
+				asset_in,
+				asset_out,
+				amount_in,
+				min_amount_out,
+				route,
+				TradeGuard::default(),
+			)
+		}
+
+		/// Executes a sell split across multiple routes, executed atomically one after another.
+		/// The price for each trade is determined by the corresponding AMM.
+		///
+		/// - `origin`: The executor of the trade
+		/// - `asset_in`: The identifier of the asset to sell
+		/// - `asset_out`: The identifier of the asset to receive
+		/// - `min_amount_out`: The minimum amount of `asset_out` to receive in total over all routes.
+		/// - `routes`: Up to `MAX_NUMBER_OF_SPLIT_ROUTES` routes, each with the amount of `asset_in` to sell through it.
+		/// 		   If a route is not specified, than the on-chain route is used.
+		///
+		/// Emits one `Executed` event with the aggregated amounts when successful.
+		#[pallet::call_index(5)]
+		#[pallet::weight(Pallet::<T>::sell_split_weight(routes))]
+		#[transactional]
+		pub fn sell_split(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			min_amount_out: T::Balance,
+			routes: Vec<SplitRoute<T::AssetId, T::Balance>>,
+		) -> DispatchResult {
+			Self::do_sell_split(origin, asset_in, asset_out, min_amount_out, routes)
+		}
+
+		/// Executes a buy split across multiple routes, executed atomically one after another.
+		/// The price for each trade is determined by the corresponding AMM.
+		///
+		/// - `origin`: The executor of the trade
+		/// - `asset_in`: The identifier of the asset to be swapped to buy `asset_out`
+		/// - `asset_out`: The identifier of the asset to buy
+		/// - `max_amount_in`: The max amount of `asset_in` to spend in total over all routes.
+		/// - `routes`: Up to `MAX_NUMBER_OF_SPLIT_ROUTES` routes, each with the amount of `asset_out` to buy through it.
+		/// 		   If a route is not specified, than the on-chain route is used.
+		///
+		/// Emits one `Executed` event with the aggregated amounts when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(Pallet::<T>::buy_split_weight(routes))]
+		#[transactional]
+		pub fn buy_split(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			max_amount_in: T::Balance,
+			routes: Vec<SplitRoute<T::AssetId, T::Balance>>,
+		) -> DispatchResult {
+			Self::do_buy_split(origin, asset_in, asset_out, max_amount_in, routes)
+		}
+
+		/// Executes a sell with a series of trades specified in the route, protected against execution at stale prices.
+		/// The price for each trade is determined by the corresponding AMM.
+		///
+		/// - `origin`: The executor of the trade
+		/// - `asset_in`: The identifier of the asset to sell
+		/// - `asset_out`: The identifier of the asset to receive
+		/// - `amount_in`: The amount of `asset_in` to sell
+		/// - `min_amount_out`: The minimum amount of `asset_out` to receive.
+		/// - `route`: Series of [`Trade<AssetId>`] to be executed. A [`Trade<AssetId>`] specifies the asset pair (`asset_in`, `asset_out`) and the AMM (`pool`) in which the trade is executed.
+		/// 		   If not specified, than the on-chain route is used.
+		/// - `deadline`: The last relay chain block in which the trade can be executed.
+		/// - `max_oracle_deviation`: The max deviation of the execution price from the oracle price of the route.
+		///
+		/// Emits `RouteExecuted` when successful.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::sell_weight(route).saturating_add(T::WeightInfo::get_oracle_price_weight(route)).saturating_add(T::DbWeight::get().reads(1)))]
+		#[transactional]
+		#[allow(clippy::too_many_arguments)]
+		pub fn sell_with_guard(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: T::Balance,
+			min_amount_out: T::Balance,
+			route: Vec<Trade<T::AssetId>>,
+			deadline: Option<BlockNumberFor<T>>,
+			max_oracle_deviation: Option<Permill>,
+		) -> DispatchResult {
+			Self::do_sell(
+				origin,
+				asset_in,
+				asset_out,
+				amount_in,
+				min_amount_out,
+				route,
+				TradeGuard {
+					deadline,
+					max_oracle_deviation,
+				},
+			)
+		}
+
+		/// Executes a buy with a series of trades specified in the route, protected against execution at stale prices.
+		/// The price for each trade is determined by the corresponding AMM.
+		///
+		/// - `origin`: The executor of the trade
+		/// - `asset_in`: The identifier of the asset to be swapped to buy `asset_out`
+		/// - `asset_out`: The identifier of the asset to buy
+		/// - `amount_out`: The amount of `asset_out` to buy
+		/// - `max_amount_in`: The max amount of `asset_in` to spend on the buy.
+		/// - `route`: Series of [`Trade<AssetId>`] to be executed. A [`Trade<AssetId>`] specifies the asset pair (`asset_in`, `asset_out`) and the AMM (`pool`) in which the trade is executed.
+		/// 		   If not specified, than the on-chain route is used.
+		/// - `deadline`: The last relay chain block in which the trade can be executed.
+		/// - `max_oracle_deviation`: The max deviation of the execution price from the oracle price of the route.
+		///
+		/// Emits `RouteExecuted` when successful.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::buy_weight(route).saturating_add(T::WeightInfo::get_oracle_price_weight(route)).saturating_add(T::DbWeight::get().reads(1)))]
+		#[transactional]
+		#[allow(clippy::too_many_arguments)]
+		pub fn buy_with_guard(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_out: T::Balance,
+			max_amount_in: T::Balance,
+			route: Vec<Trade<T::AssetId>>,
+			deadline: Option<BlockNumberFor<T>>,
+			max_oracle_deviation: Option<Permill>,
+		) -> DispatchResult {
+			Self::do_buy(
+				origin,
+				asset_in,
+				asset_out,
+				amount_out,
+				max_amount_in,
+				route,
+				TradeGuard {
+					deadline,
+					max_oracle_deviation,
+				},
+			)
+		}
+
+		/// Executes a buy of exactly the amount of `asset_out` needed to bring the balance of the origin to `target_balance`.
+		/// The price for each trade is determined by the corresponding AMM.
+		///
+		/// - `origin`: The executor of the trade
+		/// - `asset_in`: The identifier of the asset to be swapped to buy `asset_out`
+		/// - `asset_out`: The identifier of the asset to buy
+		/// - `target_balance`: The total balance of `asset_out` the origin should hold after the trade
+		/// - `max_amount_in`: The max amount of `asset_in` to spend on the buy.
+		/// - `route`: Series of [`Trade<AssetId>`] to be executed. A [`Trade<AssetId>`] specifies the asset pair (`asset_in`, `asset_out`) and the AMM (`pool`) in which the trade is executed.
+		/// 		   If not specified, than the on-chain route is used.
+		///
+		/// Emits `RouteExecuted` when successful.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::buy_weight(route))]
+		#[transactional]
+		pub fn buy_exact_for_account(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			target_balance: T::Balance,
+			max_amount_in: T::Balance,
+			route: Vec<Trade<T::AssetId>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+			let amount_out = target_balance
+				.checked_sub(&T::Currency::balance(asset_out, &who))
+				.filter(|amount| !amount.is_zero())
+				.ok_or(Error::<T>::TargetBalanceAlreadyReached)?;
+
+			Self::do_buy(
+				origin,
+				asset_in,
+				asset_out,
+				amount_out,
+				max_amount_in,
+				route,
+				TradeGuard::default(),
+			)
+		}
+
+		/// Executes a sell of the whole balance of `asset_in`, leaving no dust behind.
+		/// The price for each trade is determined by the corresponding AMM.
+		///
+		/// Unlike `sell_all`, the trade fails if any `asset_in` balance is left on the account after the trade.
+		/// The pallet does not refund any ED. If the runtime refunds an ED in `asset_out` when the `asset_in`
+		/// balance is killed, up to `EdToRefundCalculator` of extra `asset_out` is tolerated.
+		///
+		/// - `origin`: The executor of the trade
+		/// - `asset_in`: The identifier of the asset to sell
+		/// - `asset_out`: The identifier of the asset to receive
+		/// - `min_amount_out`: The minimum amount of `asset_out` to receive.
+		/// - `route`: Series of [`Trade<AssetId>`] to be executed. A [`Trade<AssetId>`] specifies the asset pair (`asset_in`, `asset_out`) and the AMM (`pool`) in which the trade is executed.
+		/// 		   If not specified, than the on-chain route is used.
+		///
+		/// Emits `RouteExecuted` when successful.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::sell_weight(route))]
+		#[transactional]
+		pub fn swap_to_zero(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			min_amount_out: T::Balance,
+			route: Vec<Trade<T::AssetId>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+			let amount_in = T::Currency::balance(asset_in, &who);
+			ensure!(!amount_in.is_zero(), Error::<T>::InsufficientBalance);
+
+			Self::do_sell(
+				origin,
+				asset_in,
+				asset_out,
+				amount_in,
+				min_amount_out,
+				route,
+				TradeGuard::default(),
+			)?;
+
+			ensure!(
+				T::Currency::balance(asset_in, &who).is_zero(),
+				Error::<T>::BalanceNotCleared
+			);
+
+			Ok(())
 		}
 	}
 }
@@ -473,10 +681,12 @@ impl<T: Config> Pallet<T> {
 		amount_in: T::Balance,
 		min_amount_out: T::Balance,
 		route: Vec<Trade<T::AssetId>>,
+		guard: TradeGuard<BlockNumberFor<T>>,
 	) -> Result<(), DispatchError> {
 		let who = ensure_signed(origin.clone())?;
 
 		ensure!(asset_in != asset_out, Error::<T>::NotAllowed);
+		Self::ensure_deadline(guard.deadline)?;
 
 		Self::ensure_route_size(route.len())?;
 
@@ -494,16 +704,238 @@ impl<T: Config> Pallet<T> {
 			last_trade_amount.amount_out >= min_amount_out,
 			Error::<T>::TradingLimitReached
 		);
+		Self::ensure_oracle_deviation(
+			&route,
+			amount_in,
+			last_trade_amount.amount_out,
+			guard.max_oracle_deviation,
+		)?;
 
-		let route_length = route.len();
+		let next_event_id = pallet_broadcast::Pallet::<T>::add_to_context(ExecutionType::Router)?;
+
+		Self::execute_sell_trades(origin, &who, route, &trade_amounts)?;
+
+		Self::ensure_that_user_received_asset_out_at_most(
+			who,
+			asset_in,
+			asset_out,
+			user_balance_of_asset_out_before_trade,
+			last_trade_amount.amount_out,
+		)?;
+
+		Self::deposit_event(Event::Executed {
+			asset_in,
+			asset_out,
+			amount_in,
+			amount_out: last_trade_amount.amount_out,
+			event_id: next_event_id,
+		});
+
+		let _ = pallet_broadcast::Pallet::<T>::remove_from_context();
+
+		Ok(())
+	}
+
+	fn do_buy(
+		origin: T::RuntimeOrigin,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_out: T::Balance,
+		max_amount_in: T::Balance,
+		route: Vec<Trade<T::AssetId>>,
+		guard: TradeGuard<BlockNumberFor<T>>,
+	) -> Result<(), DispatchError> {
+		let who = ensure_signed(origin.clone())?;
+
+		ensure!(asset_in != asset_out, Error::<T>::NotAllowed);
+		Self::ensure_deadline(guard.deadline)?;
+		Self::ensure_route_size(route.len())?;
+
+		let asset_pair = AssetPair::new(asset_in, asset_out);
+		let route = Self::get_route_or_default(route, asset_pair)?;
+		Self::ensure_route_arguments(&asset_pair, &route)?;
+
+		let user_balance_of_asset_in_before_trade =
+			T::Currency::reducible_balance(asset_in, &who, Preservation::Expendable, Fortitude::Polite);
+
+		let trade_amounts = Self::calculate_buy_trade_amounts(&route, amount_out)?;
+
+		let first_trade = trade_amounts.last().ok_or(Error::<T>::RouteCalculationFailed)?;
+		ensure!(first_trade.amount_in <= max_amount_in, Error::<T>::TradingLimitReached);
+		Self::ensure_oracle_deviation(&route, first_trade.amount_in, amount_out, guard.max_oracle_deviation)?;
 
 		let next_event_id = pallet_broadcast::Pallet::<T>::add_to_context(ExecutionType::Router)?;
 
-		for (trade_index, (trade_amount, trade)) in trade_amounts.iter().zip(route.clone()).enumerate() {
+		Self::execute_buy_trades(origin, &who, route, &trade_amounts)?;
+
+		Self::ensure_that_user_spent_asset_in_at_least(
+			who,
+			asset_in,
+			user_balance_of_asset_in_before_trade,
+			first_trade.amount_in,
+		)?;
+
+		//TODO: we want to deprecate it once unified events are working fine
+		Self::deposit_event(Event::Executed {
+			asset_in,
+			asset_out,
+			amount_in: first_trade.amount_in,
+			amount_out,
+			event_id: next_event_id,
+		});
+
+		let _ = pallet_broadcast::Pallet::<T>::remove_from_context();
+
+		Ok(())
+	}
+
+	fn do_sell_split(
+		origin: T::RuntimeOrigin,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		min_amount_out: T::Balance,
+		routes: Vec<SplitRoute<T::AssetId, T::Balance>>,
+	) -> Result<(), DispatchError> {
+		let who = ensure_signed(origin.clone())?;
+
+		ensure!(asset_in != asset_out, Error::<T>::NotAllowed);
+
+		Self::ensure_split_routes_size(routes.len())?;
+
+		let asset_pair = AssetPair::new(asset_in, asset_out);
+
+		let user_balance_of_asset_out_before_trade =
+			T::Currency::reducible_balance(asset_out, &who, Preservation::Preserve, Fortitude::Polite);
+
+		let next_event_id = pallet_broadcast::Pallet::<T>::add_to_context(ExecutionType::Router)?;
+
+		let mut amount_in = T::Balance::zero();
+		let mut amount_out = T::Balance::zero();
+
+		for split_route in routes {
+			ensure!(!split_route.amount.is_zero(), Error::<T>::InvalidRoute);
+			Self::ensure_route_size(split_route.route.len())?;
+
+			let route = Self::get_route_or_default(split_route.route, asset_pair)?;
+			Self::ensure_route_arguments(&asset_pair, &route)?;
+
+			// Amounts are calculated right before the execution as the routes can share pools.
+			let trade_amounts = Self::calculate_sell_trade_amounts(&route, split_route.amount)?;
+			let last_trade_amount = trade_amounts.last().ok_or(Error::<T>::RouteCalculationFailed)?;
+
+			amount_in = amount_in
+				.checked_add(&split_route.amount)
+				.ok_or(ArithmeticError::Overflow)?;
+			amount_out = amount_out
+				.checked_add(&last_trade_amount.amount_out)
+				.ok_or(ArithmeticError::Overflow)?;
+
+			Self::execute_sell_trades(origin.clone(), &who, route, &trade_amounts)?;
+		}
+
+		ensure!(amount_out >= min_amount_out, Error::<T>::TradingLimitReached);
+
+		Self::ensure_that_user_received_asset_out_at_most(
+			who,
+			asset_in,
+			asset_out,
+			user_balance_of_asset_out_before_trade,
+			amount_out,
+		)?;
+
+		Self::deposit_event(Event::Executed {
+			asset_in,
+			asset_out,
+			amount_in,
+			amount_out,
+			event_id: next_event_id,
+		});
+
+		let _ = pallet_broadcast::Pallet::<T>::remove_from_context();
+
+		Ok(())
+	}
+
+	fn do_buy_split(
+		origin: T::RuntimeOrigin,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		max_amount_in: T::Balance,
+		routes: Vec<SplitRoute<T::AssetId, T::Balance>>,
+	) -> Result<(), DispatchError> {
+		let who = ensure_signed(origin.clone())?;
+
+		ensure!(asset_in != asset_out, Error::<T>::NotAllowed);
+
+		Self::ensure_split_routes_size(routes.len())?;
+
+		let asset_pair = AssetPair::new(asset_in, asset_out);
+
+		let user_balance_of_asset_in_before_trade =
+			T::Currency::reducible_balance(asset_in, &who, Preservation::Expendable, Fortitude::Polite);
+
+		let next_event_id = pallet_broadcast::Pallet::<T>::add_to_context(ExecutionType::Router)?;
+
+		let mut amount_in = T::Balance::zero();
+		let mut amount_out = T::Balance::zero();
+
+		for split_route in routes {
+			ensure!(!split_route.amount.is_zero(), Error::<T>::InvalidRoute);
+			Self::ensure_route_size(split_route.route.len())?;
+
+			let route = Self::get_route_or_default(split_route.route, asset_pair)?;
+			Self::ensure_route_arguments(&asset_pair, &route)?;
+
+			// Amounts are calculated right before the execution as the routes can share pools.
+			let trade_amounts = Self::calculate_buy_trade_amounts(&route, split_route.amount)?;
+			let first_trade = trade_amounts.last().ok_or(Error::<T>::RouteCalculationFailed)?;
+
+			amount_in = amount_in
+				.checked_add(&first_trade.amount_in)
+				.ok_or(ArithmeticError::Overflow)?;
+			amount_out = amount_out
+				.checked_add(&split_route.amount)
+				.ok_or(ArithmeticError::Overflow)?;
+
+			Self::execute_buy_trades(origin.clone(), &who, route, &trade_amounts)?;
+		}
+
+		ensure!(amount_in <= max_amount_in, Error::<T>::TradingLimitReached);
+
+		Self::ensure_that_user_spent_asset_in_at_least(
+			who,
+			asset_in,
+			user_balance_of_asset_in_before_trade,
+			amount_in,
+		)?;
+
+		Self::deposit_event(Event::Executed {
+			asset_in,
+			asset_out,
+			amount_in,
+			amount_out,
+			event_id: next_event_id,
+		});
+
+		let _ = pallet_broadcast::Pallet::<T>::remove_from_context();
+
+		Ok(())
+	}
+
+	/// Executes the sell trades of a route with the given trade amounts.
+	fn execute_sell_trades(
+		origin: T::RuntimeOrigin,
+		who: &T::AccountId,
+		route: Vec<Trade<T::AssetId>>,
+		trade_amounts: &[AmountInAndOut<T::Balance>],
+	) -> Result<(), DispatchError> {
+		let route_length = route.len();
+
+		for (trade_index, (trade_amount, trade)) in trade_amounts.iter().zip(route).enumerate() {
 			Self::disable_ed_handling_for_insufficient_assets(route_length, trade_index, trade);
 
 			let user_balance_of_asset_in_before_trade =
-				T::Currency::reducible_balance(trade.asset_in, &who, Preservation::Expendable, Fortitude::Polite);
+				T::Currency::reducible_balance(trade.asset_in, who, Preservation::Expendable, Fortitude::Polite);
 
 			let execution_result = T::AMM::execute_sell(
 				origin.clone(),
@@ -526,23 +958,114 @@ impl<T: Config> Pallet<T> {
 
 		SkipEd::<T>::kill();
 
-		Self::ensure_that_user_received_asset_out_at_most(
-			who,
-			asset_in,
-			asset_out,
-			user_balance_of_asset_out_before_trade,
-			last_trade_amount.amount_out,
-		)?;
+		Ok(())
+	}
 
-		Self::deposit_event(Event::Executed {
-			asset_in,
-			asset_out,
-			amount_in,
-			amount_out: last_trade_amount.amount_out,
-			event_id: next_event_id,
-		});
+	/// Executes the buy trades of a route with the given trade amounts, which are in reversed order
+	/// as returned by `calculate_buy_trade_amounts`.
+	fn execute_buy_trades(
+		origin: T::RuntimeOrigin,
+		who: &T::AccountId,
+		route: Vec<Trade<T::AssetId>>,
+		trade_amounts: &[AmountInAndOut<T::Balance>],
+	) -> Result<(), DispatchError> {
+		let route_length = route.len();
 
-		let _ = pallet_broadcast::Pallet::<T>::remove_from_context();
+		for (trade_index, (trade_amount, trade)) in trade_amounts.iter().rev().zip(route).enumerate() {
+			Self::disable_ed_handling_for_insufficient_assets(route_length, trade_index, trade);
+			let user_balance_of_asset_out_before_trade =
+				T::Currency::reducible_balance(trade.asset_out, who, Preservation::Preserve, Fortitude::Polite);
+			let execution_result = T::AMM::execute_buy(
+				origin.clone(),
+				trade.pool,
+				trade.asset_in,
+				trade.asset_out,
+				trade_amount.amount_out,
+				trade_amount.amount_in,
+			);
+
+			handle_execution_error!(execution_result);
+
+			Self::ensure_that_user_received_asset_out_at_most(
+				who.clone(),
+				trade.asset_in,
+				trade.asset_out,
+				user_balance_of_asset_out_before_trade,
+				trade_amount.amount_out,
+			)?;
+		}
+
+		SkipEd::<T>::kill();
+
+		Ok(())
+	}
+
+	/// Weight of `sell_split`, the sum of the sell weights of all routes.
+	pub fn sell_split_weight(routes: &[SplitRoute<T::AssetId, T::Balance>]) -> Weight {
+		routes.iter().fold(Weight::zero(), |weight, split_route| {
+			weight.saturating_add(T::WeightInfo::sell_weight(&split_route.route))
+		})
+	}
+
+	/// Weight of `buy_split`, the sum of the buy weights of all routes.
+	pub fn buy_split_weight(routes: &[SplitRoute<T::AssetId, T::Balance>]) -> Weight {
+		routes.iter().fold(Weight::zero(), |weight, split_route| {
+			weight.saturating_add(T::WeightInfo::buy_weight(&split_route.route))
+		})
+	}
+
+	fn ensure_deadline(deadline: Option<BlockNumberFor<T>>) -> Result<(), DispatchError> {
+		if let Some(deadline) = deadline {
+			ensure!(
+				T::BlockNumberProvider::current_block_number() <= deadline,
+				Error::<T>::DeadlineExpired
+			);
+		}
+
+		Ok(())
+	}
+
+	/// Ensures that the execution price of the route does not deviate from its oracle price by more
+	/// than `max_deviation`.
+	fn ensure_oracle_deviation(
+		route: &[Trade<T::AssetId>],
+		amount_in: T::Balance,
+		amount_out: T::Balance,
+		max_deviation: Option<Permill>,
+	) -> Result<(), DispatchError> {
+		let Some(max_deviation) = max_deviation else {
+			return Ok(());
+		};
+
+		// Both prices are the amount of `asset_in` per one unit of `asset_out`.
+		let oracle_price =
+			T::OraclePriceProvider::price(route, T::OraclePeriod::get()).ok_or(Error::<T>::RouteHasNoOracle)?;
+		let oracle_price =
+			FixedU128::checked_from_rational(oracle_price.n, oracle_price.d).ok_or(ArithmeticError::Overflow)?;
+		let (amount_in, amount_out): (u128, u128) = (amount_in.into(), amount_out.into());
+		let execution_price =
+			FixedU128::checked_from_rational(amount_in, amount_out).ok_or(ArithmeticError::Overflow)?;
+
+		let deviation = execution_price
+			.max(oracle_price)
+			.saturating_sub(execution_price.min(oracle_price))
+			.checked_div(&oracle_price)
+			.ok_or(ArithmeticError::DivisionByZero)?;
+
+		ensure!(
+			deviation <= FixedU128::from(max_deviation),
+			Error::<T>::OraclePriceDeviationExceeded
+		);
+
+		Ok(())
+	}
+
+	fn ensure_split_routes_size(number_of_routes: usize) -> Result<(), DispatchError> {
+		ensure!(number_of_routes > 0, Error::<T>::InvalidRoute);
+		ensure!(
+			(number_of_routes as u32) <= MAX_NUMBER_OF_SPLIT_ROUTES,
+			Error::<T>::MaxRoutesExceeded
+		);
 
 		Ok(())
 	}
@@ -806,7 +1329,65 @@ impl<T: Config> Pallet<T> {
 		Ok(amount_in_and_outs)
 	}
 
-	fn insert_route(asset_pair: AssetPair<T::AssetId>, route: Vec<Trade<T::AssetId>>) -> DispatchResultWithPostInfo {
+	/// Checks whether `new_route` should replace `existing_route`.
+	///
+	/// The new route replaces the existing one if the existing route is invalid and the new one is
+	/// valid, or if the new route gives a better price in both directions.
+	/// Returns whether the existing route is valid.
+	fn validate_route_update(
+		existing_route: &[Trade<T::AssetId>],
+		new_route: &[Trade<T::AssetId>],
+	) -> Result<bool, DispatchError> {
+		match Self::validate_route(existing_route) {
+			Ok((reference_amount_in, reference_amount_in_for_inverse)) => {
+				let new_route_validation = Self::validate_sell(new_route.to_vec(), reference_amount_in);
+
+				let inverse_new_route = inverse_route(new_route.to_vec());
+				let inverse_new_route_validation =
+					Self::validate_sell(inverse_new_route.clone(), reference_amount_in_for_inverse);
+
+				match (new_route_validation, inverse_new_route_validation) {
+					(Ok(_), Ok(_)) => (),
+					(Err(_), Ok(amount_out)) => {
+						Self::validate_sell(new_route.to_vec(), amount_out).map(|_| ())?;
+					}
+					(Ok(amount_out), Err(_)) => {
+						Self::validate_sell(inverse_new_route.clone(), amount_out).map(|_| ())?;
+					}
+					(Err(err), Err(_)) => return Err(err),
+				}
+
+				let amount_out_for_existing_route =
+					Self::calculate_expected_amount_out(existing_route, reference_amount_in)?;
+				let amount_out_for_new_route = Self::calculate_expected_amount_out(new_route, reference_amount_in)?;
+
+				let inverse_existing_route = inverse_route(existing_route.to_vec());
+				let amount_out_for_existing_inversed_route =
+					Self::calculate_expected_amount_out(&inverse_existing_route, reference_amount_in_for_inverse)?;
+				let amount_out_for_new_inversed_route =
+					Self::calculate_expected_amount_out(&inverse_new_route, reference_amount_in_for_inverse)?;
+
+				ensure!(
+					amount_out_for_new_route > amount_out_for_existing_route
+						&& amount_out_for_new_inversed_route > amount_out_for_existing_inversed_route,
+					Error::<T>::RouteUpdateIsNotSuccessful
+				);
+
+				Ok(true)
+			}
+			Err(_) => {
+				Self::validate_route(new_route)?;
+
+				Ok(false)
+			}
+		}
+	}
+
+	fn insert_route(
+		asset_pair: AssetPair<T::AssetId>,
+		route: Vec<Trade<T::AssetId>>,
+		reason: RouteUpdateReason,
+	) -> DispatchResultWithPostInfo {
 		let route_as_bounded_vec: BoundedVec<Trade<T::AssetId>, sp_runtime::traits::ConstU32<MAX_NUMBER_OF_TRADES>> =
 			route.try_into().map_err(|_| Error::<T>::MaxTradesExceeded)?;
 
@@ -814,10 +1395,123 @@ impl<T: Config> Pallet<T> {
 
 		Self::deposit_event(Event::RouteUpdated {
 			asset_ids: asset_pair.to_ordered_vec(),
+			reason,
 		});
 
 		Ok(Pays::No.into())
 	}
+
+	/// Revalidates stored routes and checks route candidates within `remaining_weight`.
+	///
+	/// Stored routes which became invalid are replaced by the default route if it is valid. Route
+	/// candidates replace the stored (or default) route if they are better, as in `set_route`.
+	/// Once all route candidates are checked, the maintenance starts over with the stored routes.
+	fn maintain_routes(remaining_weight: Weight) -> Weight {
+		let step_weight = T::WeightInfo::maintain_route_weight();
+		let mut used_weight = T::DbWeight::get().reads_writes(1, 1);
+
+		if remaining_weight.any_lt(used_weight.saturating_add(step_weight)) {
+			return Weight::zero();
+		}
+
+		let mut cursor = RouteMaintenanceCursor::<T>::get();
+
+		for _ in 0..T::MaxRoutesMaintainedPerBlock::get() {
+			if remaining_weight.any_lt(used_weight.saturating_add(step_weight)) {
+				break;
+			}
+
+			cursor = Self::maintain_next_route(cursor);
+			used_weight.saturating_accrue(step_weight);
+		}
+
+		RouteMaintenanceCursor::<T>::put(cursor);
+
+		used_weight
+	}
+
+	fn maintain_next_route(cursor: MaintenanceCursor) -> MaintenanceCursor {
+		match cursor {
+			MaintenanceCursor::StoredRoutes(last_key) => {
+				let next = if last_key.is_empty() {
+					Routes::<T>::iter().next()
+				} else {
+					Routes::<T>::iter_from(last_key.into_inner()).next()
+				};
+
+				let Some((asset_pair, route)) = next else {
+					return MaintenanceCursor::Candidates(Default::default());
+				};
+
+				Self::maintain_stored_route(asset_pair, route.into_inner());
+
+				BoundedVec::try_from(Routes::<T>::hashed_key_for(asset_pair))
+					.map(MaintenanceCursor::StoredRoutes)
+					.unwrap_or_else(|_| MaintenanceCursor::Candidates(Default::default()))
+			}
+			MaintenanceCursor::Candidates(last_cursor) => {
+				let Some((next_cursor, trade)) = T::RouteCandidates::next_candidate(&last_cursor) else {
+					return MaintenanceCursor::default();
+				};
+
+				Self::maintain_route_candidate(trade);
+
+				BoundedVec::try_from(next_cursor)
+					.map(MaintenanceCursor::Candidates)
+					.unwrap_or_default()
+			}
+		}
+	}
+
+	fn maintain_stored_route(asset_pair: AssetPair<T::AssetId>, route: Vec<Trade<T::AssetId>>) {
+		if Self::validate_route(&route).is_ok() {
+			return;
+		}
+
+		let default_route = vec![Trade {
+			pool: T::DefaultRoutePoolType::get(),
+			asset_in: asset_pair.asset_in,
+			asset_out: asset_pair.asset_out,
+		}];
+		if default_route == route || Self::validate_route(&default_route).is_err() {
+			return;
+		}
+
+		Routes::<T>::remove(asset_pair);
+
+		Self::deposit_event(Event::RouteUpdated {
+			asset_ids: asset_pair.to_ordered_vec(),
+			reason: RouteUpdateReason::Replaced,
+		});
+	}
+
+	fn maintain_route_candidate(trade: Trade<T::AssetId>) {
+		// LBP pools are temporary and their price follows the weight schedule, they must not become
+		// the stored route automatically.
+		if trade.pool == PoolType::LBP {
+			return;
+		}
+
+		let mut asset_pair = AssetPair::new(trade.asset_in, trade.asset_out);
+		let mut new_route = vec![trade];
+		if !asset_pair.is_ordered() {
+			asset_pair = asset_pair.ordered_pair();
+			new_route = inverse_route(new_route);
+		}
+
+		let existing_route = Self::get_route(asset_pair);
+		if existing_route == new_route || T::OraclePriceProvider::price(&new_route, T::OraclePeriod::get()).is_none() {
+			return;
+		}
+
+		let reason = match Self::validate_route_update(&existing_route, &new_route) {
+			Ok(true) => RouteUpdateReason::Improved,
+			Ok(false) => RouteUpdateReason::Replaced,
+			Err(_) => return,
+		};
+
+		let _ = Self::insert_route(asset_pair, new_route, reason);
+	}
 }
 
 impl<T: Config> RouterT<T::RuntimeOrigin, T::AssetId, T::Balance, Trade<T::AssetId>, AmountInAndOut<T::Balance>>
@@ -0,0 +1,123 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{Error, Event, Trade};
+use basilisk_traits::router::PoolType;
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+
+const KSM_AUSD_TRADE_IN_XYK: Trade<AssetId> = Trade {
+	pool: PoolType::XYK,
+	asset_in: KSM,
+	asset_out: AUSD,
+};
+
+#[test]
+fn buy_exact_for_account_should_buy_target_balance_when_account_has_no_asset_out() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, KSM, 1000)])
+		.build()
+		.execute_with(|| {
+			//Act
+			assert_ok!(Router::buy_exact_for_account(
+				RuntimeOrigin::signed(ALICE),
+				KSM,
+				AUSD,
+				10,
+				5,
+				vec![KSM_AUSD_TRADE_IN_XYK]
+			));
+
+			//Assert
+			assert_executed_buy_trades(vec![(PoolType::XYK, 10, KSM, AUSD)]);
+			assert_eq!(Currencies::free_balance(AUSD, &ALICE), 10);
+			expect_events(vec![Event::Executed {
+				asset_in: KSM,
+				asset_out: AUSD,
+				amount_in: XYK_BUY_CALCULATION_RESULT,
+				amount_out: 10,
+				event_id: 0,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn buy_exact_for_account_should_buy_only_missing_amount_when_account_has_asset_out() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, KSM, 1000), (ALICE, AUSD, 4)])
+		.build()
+		.execute_with(|| {
+			//Act
+			assert_ok!(Router::buy_exact_for_account(
+				RuntimeOrigin::signed(ALICE),
+				KSM,
+				AUSD,
+				10,
+				5,
+				vec![KSM_AUSD_TRADE_IN_XYK]
+			));
+
+			//Assert
+			assert_executed_buy_trades(vec![(PoolType::XYK, 6, KSM, AUSD)]);
+			assert_eq!(Currencies::free_balance(AUSD, &ALICE), 10);
+		});
+}
+
+#[test]
+fn buy_exact_for_account_should_fail_when_target_balance_is_already_reached() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, KSM, 1000), (ALICE, AUSD, 10)])
+		.build()
+		.execute_with(|| {
+			//Act and assert
+			assert_noop!(
+				Router::buy_exact_for_account(
+					RuntimeOrigin::signed(ALICE),
+					KSM,
+					AUSD,
+					10,
+					5,
+					vec![KSM_AUSD_TRADE_IN_XYK]
+				),
+				Error::<Test>::TargetBalanceAlreadyReached
+			);
+		});
+}
+
+#[test]
+fn buy_exact_for_account_should_fail_when_max_amount_in_is_exceeded() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, KSM, 1000)])
+		.build()
+		.execute_with(|| {
+			//Act and assert
+			assert_noop!(
+				Router::buy_exact_for_account(
+					RuntimeOrigin::signed(ALICE),
+					KSM,
+					AUSD,
+					10,
+					XYK_BUY_CALCULATION_RESULT - 1,
+					vec![KSM_AUSD_TRADE_IN_XYK]
+				),
+				Error::<Test>::TradingLimitReached
+			);
+		});
+}
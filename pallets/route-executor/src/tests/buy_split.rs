@@ -0,0 +1,196 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{Error, Event, SplitRoute, Trade, MAX_NUMBER_OF_SPLIT_ROUTES};
+use basilisk_traits::router::PoolType;
+use frame_support::{assert_noop, assert_ok};
+
+const HDX_AUSD_TRADE_IN_LBP: Trade<AssetId> = Trade {
+	pool: PoolType::LBP,
+	asset_in: HDX,
+	asset_out: AUSD,
+};
+
+#[test]
+fn buy_split_should_work_when_routes_have_single_trade() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![
+			SplitRoute {
+				amount: 10,
+				route: vec![HDX_AUSD_TRADE_IN_XYK],
+			},
+			SplitRoute {
+				amount: 20,
+				route: vec![HDX_AUSD_TRADE_IN_LBP],
+			},
+		];
+
+		//Act
+		assert_ok!(Router::buy_split(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			XYK_BUY_CALCULATION_RESULT + LBP_BUY_CALCULATION_RESULT,
+			routes
+		));
+
+		//Assert
+		assert_executed_buy_trades(vec![(PoolType::XYK, 10, HDX, AUSD), (PoolType::LBP, 20, HDX, AUSD)]);
+		expect_events(vec![Event::Executed {
+			asset_in: HDX,
+			asset_out: AUSD,
+			amount_in: XYK_BUY_CALCULATION_RESULT + LBP_BUY_CALCULATION_RESULT,
+			amount_out: 30,
+			event_id: 0,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn buy_split_should_work_when_routes_have_multiple_trades() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![
+			SplitRoute {
+				amount: 10,
+				route: vec![HDX_AUSD_TRADE_IN_XYK],
+			},
+			SplitRoute {
+				amount: 20,
+				route: vec![
+					Trade {
+						pool: PoolType::LBP,
+						asset_in: HDX,
+						asset_out: DOT,
+					},
+					Trade {
+						pool: PoolType::XYK,
+						asset_in: DOT,
+						asset_out: AUSD,
+					},
+				],
+			},
+		];
+
+		//Act
+		assert_ok!(Router::buy_split(RuntimeOrigin::signed(ALICE), HDX, AUSD, 100, routes));
+
+		//Assert
+		assert_executed_buy_trades(vec![
+			(PoolType::XYK, 10, HDX, AUSD),
+			(PoolType::LBP, XYK_BUY_CALCULATION_RESULT, HDX, DOT),
+			(PoolType::XYK, 20, DOT, AUSD),
+		]);
+		expect_events(vec![Event::Executed {
+			asset_in: HDX,
+			asset_out: AUSD,
+			amount_in: XYK_BUY_CALCULATION_RESULT + LBP_BUY_CALCULATION_RESULT,
+			amount_out: 30,
+			event_id: 0,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn buy_split_should_fail_when_aggregated_amount_in_is_above_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![
+			SplitRoute {
+				amount: 10,
+				route: vec![HDX_AUSD_TRADE_IN_XYK],
+			},
+			SplitRoute {
+				amount: 20,
+				route: vec![HDX_AUSD_TRADE_IN_LBP],
+			},
+		];
+
+		//Act and assert
+		assert_noop!(
+			Router::buy_split(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				AUSD,
+				XYK_BUY_CALCULATION_RESULT + LBP_BUY_CALCULATION_RESULT - 1,
+				routes
+			),
+			Error::<Test>::TradingLimitReached
+		);
+	});
+}
+
+#[test]
+fn buy_split_should_fail_when_no_route_is_specified() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Router::buy_split(RuntimeOrigin::signed(ALICE), HDX, AUSD, 100, vec![]),
+			Error::<Test>::InvalidRoute
+		);
+	});
+}
+
+#[test]
+fn buy_split_should_fail_when_max_number_of_routes_is_exceeded() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![
+			SplitRoute {
+				amount: 10,
+				route: vec![HDX_AUSD_TRADE_IN_XYK],
+			};
+			MAX_NUMBER_OF_SPLIT_ROUTES as usize + 1
+		];
+
+		//Act and assert
+		assert_noop!(
+			Router::buy_split(RuntimeOrigin::signed(ALICE), HDX, AUSD, 100, routes),
+			Error::<Test>::MaxRoutesExceeded
+		);
+	});
+}
+
+#[test]
+fn buy_split_should_fail_when_route_does_not_match_asset_pair() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![
+			SplitRoute {
+				amount: 10,
+				route: vec![HDX_AUSD_TRADE_IN_XYK],
+			},
+			SplitRoute {
+				amount: 20,
+				route: vec![Trade {
+					pool: PoolType::XYK,
+					asset_in: DOT,
+					asset_out: AUSD,
+				}],
+			},
+		];
+
+		//Act and assert
+		assert_noop!(
+			Router::buy_split(RuntimeOrigin::signed(ALICE), HDX, AUSD, 100, routes),
+			Error::<Test>::InvalidRoute
+		);
+	});
+}
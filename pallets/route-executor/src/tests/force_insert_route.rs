@@ -16,7 +16,7 @@
 // limitations under the License.
 
 use crate::tests::mock::*;
-use crate::{Error, Trade};
+use crate::{Error, Event, RouteUpdateReason, Trade};
 use basilisk_traits::router::{AssetPair, PoolType};
 use frame_support::pallet_prelude::*;
 use frame_support::{assert_noop, assert_ok};
@@ -58,6 +58,13 @@ fn force_insert_should_work_when_called_with_technical_origin() {
 			Router::force_insert_route(RuntimeOrigin::root(), asset_pair, route),
 			Pays::No.into()
 		);
+
+		//Assert
+		expect_events(vec![Event::RouteUpdated {
+			asset_ids: vec![HDX, AUSD],
+			reason: RouteUpdateReason::ForceInserted,
+		}
+		.into()]);
 	});
 }
 
@@ -0,0 +1,103 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::Trade;
+use basilisk_traits::router::{AssetPair, PoolType, RouteProvider};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn genesis_config_should_store_routes_in_ordered_fashion() {
+	//Arrange
+	let asset_pair = AssetPair::new(DOT, HDX);
+	let route = vec![
+		Trade {
+			pool: PoolType::XYK,
+			asset_in: DOT,
+			asset_out: STABLE_SHARE_ASSET,
+		},
+		Trade {
+			pool: PoolType::Stableswap(STABLE_SHARE_ASSET),
+			asset_in: STABLE_SHARE_ASSET,
+			asset_out: AUSD,
+		},
+		Trade {
+			pool: PoolType::XYK,
+			asset_in: AUSD,
+			asset_out: HDX,
+		},
+	];
+
+	//Act
+	ExtBuilder::default()
+		.with_routes(vec![(asset_pair, route)])
+		.build()
+		.execute_with(|| {
+			//Assert
+			let route_ordered = vec![
+				Trade {
+					pool: PoolType::XYK,
+					asset_in: HDX,
+					asset_out: AUSD,
+				},
+				Trade {
+					pool: PoolType::Stableswap(STABLE_SHARE_ASSET),
+					asset_in: AUSD,
+					asset_out: STABLE_SHARE_ASSET,
+				},
+				Trade {
+					pool: PoolType::XYK,
+					asset_in: STABLE_SHARE_ASSET,
+					asset_out: DOT,
+				},
+			];
+			assert_eq!(Router::get_route(asset_pair.ordered_pair()), route_ordered);
+		});
+}
+
+#[test]
+#[should_panic(expected = "Invalid route in genesis")]
+fn genesis_config_should_panic_when_route_does_not_match_asset_pair() {
+	ExtBuilder::default()
+		.with_routes(vec![(
+			AssetPair::new(HDX, DOT),
+			vec![Trade {
+				pool: PoolType::XYK,
+				asset_in: HDX,
+				asset_out: AUSD,
+			}],
+		)])
+		.build();
+}
+
+#[test]
+#[should_panic(expected = "Route in genesis is not tradable")]
+fn genesis_config_should_panic_when_route_is_not_tradable() {
+	// No pool holds any liquidity of the asset
+	const ASSET_WITHOUT_LIQUIDITY: AssetId = 2000;
+
+	ExtBuilder::default()
+		.with_routes(vec![(
+			AssetPair::new(HDX, ASSET_WITHOUT_LIQUIDITY),
+			vec![Trade {
+				pool: PoolType::XYK,
+				asset_in: HDX,
+				asset_out: ASSET_WITHOUT_LIQUIDITY,
+			}],
+		)])
+		.build();
+}
@@ -18,7 +18,9 @@
 use crate as router;
 use crate::{Config, Trade};
 use basilisk_math::ratio::Ratio;
-use basilisk_traits::router::{ExecutorError, PoolType, RefundEdCalculator, TradeExecution};
+use basilisk_traits::router::{
+	AssetPair, ExecutorError, PoolType, RefundEdCalculator, RouteCandidateProvider, TradeExecution,
+};
 use frame_support::{
 	parameter_types,
 	traits::{Everything, Nothing},
@@ -155,7 +157,7 @@ parameter_types! {
 	pub NativeCurrencyId: AssetId = HDX;
 	pub DefaultRoutePoolType: PoolType<AssetId> = PoolType::Omnipool;
 	pub const RouteValidationOraclePeriod: OraclePeriod = OraclePeriod::TenMinutes;
-
+	pub const MaxRoutesMaintainedPerBlock: u32 = 3;
 }
 
 impl Config for Test {
@@ -170,9 +172,37 @@ impl Config for Test {
 	type OraclePeriod = RouteValidationOraclePeriod;
 	type DefaultRoutePoolType = DefaultRoutePoolType;
 	type ForceInsertOrigin = EnsureRoot<Self::AccountId>;
+	type RouteCandidates = RouteCandidatesMock;
+	type MaxRoutesMaintainedPerBlock = MaxRoutesMaintainedPerBlock;
+	type BlockNumberProvider = System;
 	type WeightInfo = ();
 }
 
+thread_local! {
+	pub static ROUTE_CANDIDATES: RefCell<Vec<Trade<AssetId>>> = RefCell::new(Vec::default());
+}
+
+pub fn set_route_candidates(candidates: Vec<Trade<AssetId>>) {
+	ROUTE_CANDIDATES.with(|v| *v.borrow_mut() = candidates);
+}
+
+/// Provides the route candidates set by `set_route_candidates`. The cursor is the index of the candidate.
+pub struct RouteCandidatesMock;
+
+impl RouteCandidateProvider<AssetId> for RouteCandidatesMock {
+	fn next_candidate(cursor: &[u8]) -> Option<(Vec<u8>, Trade<AssetId>)> {
+		let index = if cursor.is_empty() {
+			0
+		} else {
+			u32::from_le_bytes(cursor.try_into().ok()?).saturating_add(1)
+		};
+
+		ROUTE_CANDIDATES
+			.with(|v| v.borrow().get(index as usize).copied())
+			.map(|trade| (index.to_le_bytes().to_vec(), trade))
+	}
+}
+
 pub struct MockedEdCalculator;
 
 impl RefundEdCalculator<Balance> for MockedEdCalculator {
@@ -281,6 +311,7 @@ pub const HDX_AUSD_TRADE_IN_XYK: Trade<AssetId> = Trade {
 
 pub struct ExtBuilder {
 	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+	routes: Vec<(AssetPair<AssetId>, Vec<Trade<AssetId>>)>,
 }
 
 // Returns default values for genesis config
@@ -288,6 +319,7 @@ impl Default for ExtBuilder {
 	fn default() -> Self {
 		Self {
 			endowed_accounts: vec![(ALICE, HDX, 1000u128)],
+			routes: vec![],
 		}
 	}
 }
@@ -298,6 +330,11 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn with_routes(mut self, routes: Vec<(AssetPair<AssetId>, Vec<Trade<AssetId>>)>) -> Self {
+		self.routes = routes;
+		self
+	}
+
 	pub fn build(self) -> sp_io::TestExternalities {
 		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 
@@ -330,6 +367,10 @@ impl ExtBuilder {
 		.assimilate_storage(&mut t)
 		.unwrap();
 
+		router::GenesisConfig::<Test> { routes: self.routes }
+			.assimilate_storage(&mut t)
+			.unwrap();
+
 		let mut ext = sp_io::TestExternalities::new(t);
 		ext.execute_with(|| System::set_block_number(1));
 		ext
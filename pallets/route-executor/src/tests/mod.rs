@@ -1,7 +1,14 @@
 pub mod buy;
+pub mod buy_exact_for_account;
+pub mod buy_split;
 pub mod force_insert_route;
+pub mod genesis;
 pub mod mock;
+pub mod route_maintenance;
 pub mod sell;
 pub mod sell_all;
+pub mod sell_split;
 pub mod set_route;
 pub mod spot_price;
+pub mod swap_to_zero;
+pub mod trade_guard;
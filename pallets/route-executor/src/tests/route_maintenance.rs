@@ -0,0 +1,206 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{Event, MaintenanceCursor, RouteMaintenanceCursor, RouteUpdateReason, Routes, Trade};
+use basilisk_traits::router::RouteProvider;
+use basilisk_traits::router::{AssetPair, PoolType};
+use frame_support::traits::Hooks;
+use frame_support::weights::Weight;
+use frame_support::{assert_ok, BoundedVec};
+use pretty_assertions::assert_eq;
+
+fn xyk_trade(asset_in: AssetId, asset_out: AssetId) -> Trade<AssetId> {
+	Trade {
+		pool: PoolType::XYK,
+		asset_in,
+		asset_out,
+	}
+}
+
+fn run_route_maintenance() {
+	Router::on_idle(1, Weight::MAX);
+}
+
+#[test]
+fn route_maintenance_should_store_better_route_candidate() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		set_route_candidates(vec![xyk_trade(HDX, AUSD)]);
+
+		//Act
+		run_route_maintenance();
+
+		//Assert
+		assert_eq!(Router::get_route(AssetPair::new(HDX, AUSD)), vec![xyk_trade(HDX, AUSD)]);
+		expect_events(vec![Event::RouteUpdated {
+			asset_ids: vec![HDX, AUSD],
+			reason: RouteUpdateReason::Improved,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn route_maintenance_should_store_route_candidate_in_ordered_fashion() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		set_route_candidates(vec![xyk_trade(AUSD, HDX)]);
+
+		//Act
+		run_route_maintenance();
+
+		//Assert
+		assert_eq!(
+			Routes::<Test>::get(AssetPair::new(HDX, AUSD)).map(|route| route.into_inner()),
+			Some(vec![xyk_trade(HDX, AUSD)])
+		);
+	});
+}
+
+#[test]
+fn route_maintenance_should_not_replace_better_route() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		assert_ok!(Router::force_insert_route(
+			RuntimeOrigin::root(),
+			AssetPair::new(HDX, AUSD),
+			vec![xyk_trade(HDX, AUSD)]
+		));
+		set_route_candidates(vec![Trade {
+			pool: PoolType::LBP,
+			asset_in: HDX,
+			asset_out: AUSD,
+		}]);
+
+		//Act
+		run_route_maintenance();
+
+		//Assert
+		assert_eq!(Router::get_route(AssetPair::new(HDX, AUSD)), vec![xyk_trade(HDX, AUSD)]);
+		expect_events(vec![Event::RouteUpdated {
+			asset_ids: vec![HDX, AUSD],
+			reason: RouteUpdateReason::ForceInserted,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn route_maintenance_should_skip_route_candidate_without_oracle() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		set_route_candidates(vec![xyk_trade(HDX, INSUFFICIENT_ASSET)]);
+
+		//Act
+		run_route_maintenance();
+
+		//Assert
+		assert_eq!(Routes::<Test>::get(AssetPair::new(HDX, INSUFFICIENT_ASSET)), None);
+	});
+}
+
+#[test]
+fn route_maintenance_should_skip_lbp_route_candidate() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		set_route_candidates(vec![Trade {
+			pool: PoolType::LBP,
+			asset_in: HDX,
+			asset_out: AUSD,
+		}]);
+
+		//Act
+		run_route_maintenance();
+
+		//Assert
+		assert_eq!(Routes::<Test>::get(AssetPair::new(HDX, AUSD)), None);
+	});
+}
+
+#[test]
+fn route_maintenance_should_replace_invalid_stored_route_with_default_route() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let unavailable_asset = 4000;
+		assert_ok!(Router::force_insert_route(
+			RuntimeOrigin::root(),
+			AssetPair::new(HDX, AUSD),
+			vec![xyk_trade(HDX, unavailable_asset), xyk_trade(unavailable_asset, AUSD)]
+		));
+
+		//Act
+		run_route_maintenance();
+
+		//Assert
+		assert_eq!(Routes::<Test>::get(AssetPair::new(HDX, AUSD)), None);
+		assert_eq!(Router::get_route(AssetPair::new(HDX, AUSD)), default_omnipool_route());
+		expect_events(vec![
+			Event::RouteUpdated {
+				asset_ids: vec![HDX, AUSD],
+				reason: RouteUpdateReason::ForceInserted,
+			}
+			.into(),
+			Event::RouteUpdated {
+				asset_ids: vec![HDX, AUSD],
+				reason: RouteUpdateReason::Replaced,
+			}
+			.into(),
+		]);
+	});
+}
+
+#[test]
+fn route_maintenance_should_continue_from_cursor_in_next_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		set_route_candidates(vec![
+			xyk_trade(HDX, AUSD),
+			xyk_trade(HDX, MOVR),
+			xyk_trade(HDX, KSM),
+			xyk_trade(HDX, RMRK),
+			xyk_trade(HDX, SDN),
+		]);
+
+		//Act
+		run_route_maintenance();
+
+		//Assert
+		// the first step finds no stored routes and moves to the route candidates
+		assert_eq!(Routes::<Test>::iter().count(), 2);
+		assert_eq!(
+			RouteMaintenanceCursor::<Test>::get(),
+			MaintenanceCursor::Candidates(BoundedVec::truncate_from(1u32.to_le_bytes().to_vec()))
+		);
+
+		//Act
+		run_route_maintenance();
+
+		//Assert
+		assert_eq!(Routes::<Test>::iter().count(), 5);
+
+		//Act
+		run_route_maintenance();
+
+		//Assert
+		// all route candidates were checked, so the maintenance starts over with the stored routes
+		assert!(matches!(
+			RouteMaintenanceCursor::<Test>::get(),
+			MaintenanceCursor::StoredRoutes(_)
+		));
+	});
+}
@@ -16,7 +16,7 @@
 // limitations under the License.
 
 use crate::tests::mock::*;
-use crate::{Error, Event, Trade};
+use crate::{Error, Event, RouteUpdateReason, Trade};
 use basilisk_traits::router::RouteProvider;
 use basilisk_traits::router::{AssetPair, PoolType};
 use frame_support::pallet_prelude::*;
@@ -47,6 +47,7 @@ fn set_route_should_work_when_overriting_default_omnipool() {
 
 		expect_events(vec![Event::RouteUpdated {
 			asset_ids: vec![HDX, AUSD],
+			reason: RouteUpdateReason::Set,
 		}
 		.into()]);
 
@@ -131,6 +132,7 @@ fn set_route_should_work_when_new_price_is_better() {
 
 		expect_events(vec![Event::RouteUpdated {
 			asset_ids: vec![HDX, AUSD],
+			reason: RouteUpdateReason::Set,
 		}
 		.into()]);
 
@@ -153,10 +155,12 @@ fn set_route_should_work_when_new_price_is_better() {
 		expect_events(vec![
 			Event::RouteUpdated {
 				asset_ids: vec![HDX, AUSD],
+				reason: RouteUpdateReason::Set,
 			}
 			.into(),
 			Event::RouteUpdated {
 				asset_ids: vec![HDX, AUSD],
+				reason: RouteUpdateReason::Set,
 			}
 			.into(),
 		]);
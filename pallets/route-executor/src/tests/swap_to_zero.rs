@@ -0,0 +1,119 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{Error, Event, Trade};
+use basilisk_traits::router::PoolType;
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn swap_to_zero_should_sell_whole_balance_of_asset_in() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, KSM, 1000)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let trades = vec![Trade {
+				pool: PoolType::XYK,
+				asset_in: KSM,
+				asset_out: AUSD,
+			}];
+
+			//Act
+			assert_ok!(Router::swap_to_zero(RuntimeOrigin::signed(ALICE), KSM, AUSD, 5, trades));
+
+			//Assert
+			assert_executed_sell_trades(vec![(PoolType::XYK, 1000, KSM, AUSD)]);
+			assert_eq!(Currencies::free_balance(KSM, &ALICE), 0);
+			expect_events(vec![Event::Executed {
+				asset_in: KSM,
+				asset_out: AUSD,
+				amount_in: 1000,
+				amount_out: XYK_SELL_CALCULATION_RESULT,
+				event_id: 0,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn swap_to_zero_should_clear_insufficient_asset_balance_when_selling_for_native_asset() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, INSUFFICIENT_ASSET, 1000)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let trades = vec![Trade {
+				pool: PoolType::XYK,
+				asset_in: INSUFFICIENT_ASSET,
+				asset_out: HDX,
+			}];
+
+			//Act
+			assert_ok!(Router::swap_to_zero(
+				RuntimeOrigin::signed(ALICE),
+				INSUFFICIENT_ASSET,
+				HDX,
+				5,
+				trades
+			));
+
+			//Assert
+			assert_executed_sell_trades(vec![(PoolType::XYK, 1000, INSUFFICIENT_ASSET, HDX)]);
+			assert_eq!(Currencies::free_balance(INSUFFICIENT_ASSET, &ALICE), 0);
+		});
+}
+
+#[test]
+fn swap_to_zero_should_fail_when_account_has_no_asset_in() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and assert
+		assert_noop!(
+			Router::swap_to_zero(RuntimeOrigin::signed(ALICE), KSM, AUSD, 5, vec![]),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn swap_to_zero_should_fail_when_min_amount_out_is_not_reached() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, KSM, 1000)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let trades = vec![Trade {
+				pool: PoolType::XYK,
+				asset_in: KSM,
+				asset_out: AUSD,
+			}];
+
+			//Act and assert
+			assert_noop!(
+				Router::swap_to_zero(
+					RuntimeOrigin::signed(ALICE),
+					KSM,
+					AUSD,
+					XYK_SELL_CALCULATION_RESULT + 1,
+					trades
+				),
+				Error::<Test>::TradingLimitReached
+			);
+		});
+}
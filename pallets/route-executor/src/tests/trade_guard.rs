@@ -0,0 +1,241 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{Error, Event, Trade};
+use basilisk_traits::router::PoolType;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::Permill;
+
+// Oracle price of the mocked price provider is 0.88 `asset_in` per `asset_out`.
+
+#[test]
+fn sell_with_guard_should_work_when_no_guard_is_specified() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		assert_ok!(Router::sell_with_guard(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			10,
+			5,
+			vec![HDX_AUSD_TRADE_IN_XYK],
+			None,
+			None
+		));
+
+		//Assert
+		assert_executed_sell_trades(vec![(PoolType::XYK, 10, HDX, AUSD)]);
+		expect_events(vec![Event::Executed {
+			asset_in: HDX,
+			asset_out: AUSD,
+			amount_in: 10,
+			amount_out: XYK_SELL_CALCULATION_RESULT,
+			event_id: 0,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn sell_with_guard_should_work_when_deadline_is_current_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		System::set_block_number(5);
+
+		//Act
+		assert_ok!(Router::sell_with_guard(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			10,
+			5,
+			vec![HDX_AUSD_TRADE_IN_XYK],
+			Some(5),
+			None
+		));
+
+		//Assert
+		assert_executed_sell_trades(vec![(PoolType::XYK, 10, HDX, AUSD)]);
+	});
+}
+
+#[test]
+fn sell_with_guard_should_fail_when_deadline_has_passed() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		System::set_block_number(5);
+
+		//Act and assert
+		assert_noop!(
+			Router::sell_with_guard(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				AUSD,
+				10,
+				5,
+				vec![HDX_AUSD_TRADE_IN_XYK],
+				Some(4),
+				None
+			),
+			Error::<Test>::DeadlineExpired
+		);
+	});
+}
+
+#[test]
+fn sell_with_guard_should_work_when_execution_price_is_within_oracle_deviation() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		// execution price is 10 / 13, so the deviation from the oracle price is ~12.6%
+		assert_ok!(Router::sell_with_guard(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			10,
+			5,
+			vec![HDX_AUSD_TRADE_IN_XYK],
+			None,
+			Some(Permill::from_percent(13))
+		));
+
+		//Assert
+		assert_executed_sell_trades(vec![(PoolType::XYK, 10, HDX, AUSD)]);
+	});
+}
+
+#[test]
+fn sell_with_guard_should_fail_when_execution_price_exceeds_oracle_deviation() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and assert
+		assert_noop!(
+			Router::sell_with_guard(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				AUSD,
+				10,
+				5,
+				vec![HDX_AUSD_TRADE_IN_XYK],
+				None,
+				Some(Permill::from_percent(12))
+			),
+			Error::<Test>::OraclePriceDeviationExceeded
+		);
+	});
+}
+
+#[test]
+fn sell_with_guard_should_fail_when_route_has_no_oracle() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, INSUFFICIENT_ASSET, 1000)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let trades = vec![Trade {
+				pool: PoolType::XYK,
+				asset_in: INSUFFICIENT_ASSET,
+				asset_out: AUSD,
+			}];
+
+			//Act and assert
+			assert_noop!(
+				Router::sell_with_guard(
+					RuntimeOrigin::signed(ALICE),
+					INSUFFICIENT_ASSET,
+					AUSD,
+					10,
+					5,
+					trades,
+					None,
+					Some(Permill::from_percent(50))
+				),
+				Error::<Test>::RouteHasNoOracle
+			);
+		});
+}
+
+#[test]
+fn buy_with_guard_should_work_when_guard_is_satisfied() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		// execution price is 1 / 10, so the deviation from the oracle price is ~88.6%
+		assert_ok!(Router::buy_with_guard(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			10,
+			5,
+			vec![HDX_AUSD_TRADE_IN_XYK],
+			Some(1),
+			Some(Permill::from_percent(89))
+		));
+
+		//Assert
+		assert_executed_buy_trades(vec![(PoolType::XYK, 10, HDX, AUSD)]);
+		expect_events(vec![Event::Executed {
+			asset_in: HDX,
+			asset_out: AUSD,
+			amount_in: XYK_BUY_CALCULATION_RESULT,
+			amount_out: 10,
+			event_id: 0,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn buy_with_guard_should_fail_when_deadline_has_passed() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		System::set_block_number(5);
+
+		//Act and assert
+		assert_noop!(
+			Router::buy_with_guard(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				AUSD,
+				10,
+				5,
+				vec![HDX_AUSD_TRADE_IN_XYK],
+				Some(4),
+				None
+			),
+			Error::<Test>::DeadlineExpired
+		);
+	});
+}
+
+#[test]
+fn buy_with_guard_should_fail_when_execution_price_exceeds_oracle_deviation() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and assert
+		assert_noop!(
+			Router::buy_with_guard(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				AUSD,
+				10,
+				5,
+				vec![HDX_AUSD_TRADE_IN_XYK],
+				None,
+				Some(Permill::from_percent(50))
+			),
+			Error::<Test>::OraclePriceDeviationExceeded
+		);
+	});
+}
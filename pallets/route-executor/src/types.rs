@@ -1,5 +1,9 @@
-use codec::{Decode, Encode, MaxEncodedLen};
-use frame_support::pallet_prelude::TypeInfo;
+use basilisk_traits::router::Trade;
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::pallet_prelude::{ConstU32, TypeInfo};
+use frame_support::BoundedVec;
+use sp_runtime::Permill;
+use sp_std::vec::Vec;
 
 #[derive(Debug, Encode, Decode, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub enum SkipEd {
@@ -7,3 +11,56 @@ pub enum SkipEd {
 	LockAndUnlock,
 	Unlock,
 }
+
+/// One leg of a split trade: a route and the amount traded through it.
+///
+/// For sells `amount` is the amount of `asset_in` sold through the route, for buys it is the amount
+/// of `asset_out` bought through the route.
+#[derive(Debug, Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, TypeInfo)]
+pub struct SplitRoute<AssetId, Balance> {
+	pub amount: Balance,
+	pub route: Vec<Trade<AssetId>>,
+}
+
+/// Reason of an update of the on-chain route.
+#[derive(Debug, Encode, Decode, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum RouteUpdateReason {
+	/// Route was set via `set_route`.
+	Set,
+	/// Route was inserted via `force_insert_route`.
+	ForceInserted,
+	/// Route was replaced by a route with better price during route maintenance.
+	Improved,
+	/// Invalid route was replaced by a valid one during route maintenance.
+	Replaced,
+}
+
+/// Maximum length of the route maintenance cursor.
+pub const MAX_CURSOR_LENGTH: u32 = 128;
+
+pub type Cursor = BoundedVec<u8, ConstU32<MAX_CURSOR_LENGTH>>;
+
+/// Position of the route maintenance. The stored routes are revalidated first, then the route
+/// candidates are checked against the stored routes.
+#[derive(Debug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum MaintenanceCursor {
+	/// Raw storage key of the last maintained stored route.
+	StoredRoutes(Cursor),
+	/// Cursor of the last checked route candidate.
+	Candidates(Cursor),
+}
+
+impl Default for MaintenanceCursor {
+	fn default() -> Self {
+		MaintenanceCursor::StoredRoutes(Cursor::default())
+	}
+}
+
+/// Optional protection of a trade against execution at stale prices.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TradeGuard<BlockNumber> {
+	/// Last block in which the trade can be executed.
+	pub deadline: Option<BlockNumber>,
+	/// Max deviation of the execution price from the oracle price.
+	pub max_oracle_deviation: Option<Permill>,
+}
@@ -0,0 +1,59 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Config, Pallet};
+use frame_support::{
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+use hydradx_traits::{nft::CreateTypedCollection, pools::DustRemovalAccountWhitelist};
+use pallet_nft::CollectionType;
+use sp_std::marker::PhantomData;
+
+/// Creates the deposit NFT collection and whitelists the pallet account, i.e. does what the
+/// genesis build does for chains which were started before the pallet was added.
+///
+/// Runs only once, storage version is set to 1 afterwards.
+pub struct InitLiquidityMining<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for InitLiquidityMining<T> {
+	fn on_runtime_upgrade() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() != 0 {
+			log::info!(target: "runtime::stableswap-liquidity-mining", "Liquidity mining is already initialized");
+			return T::DbWeight::get().reads(1);
+		}
+
+		let pallet_account = Pallet::<T>::account_id();
+
+		if let Err(e) = T::NonDustableWhitelistHandler::add_account(&pallet_account) {
+			log::error!(target: "runtime::stableswap-liquidity-mining", "Failed to whitelist pallet account: {e:?}");
+		}
+
+		if let Err(e) = T::NFTHandler::create_typed_collection(
+			pallet_account,
+			T::NftCollectionId::get(),
+			CollectionType::LiquidityMining,
+			None,
+		) {
+			log::error!(target: "runtime::stableswap-liquidity-mining", "Failed to create NFT collection: {e:?}");
+		}
+
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(3, 5)
+	}
+}
@@ -0,0 +1,96 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn create_yield_farm_should_work_when_global_farm_exist() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, BSX, 1_000_000 * ONE)])
+		.with_stableswap_pool(USDT_USDC_POOL, USDT_USDC_POOL_ACCOUNT, vec![USDT, USDC])
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			USDT,
+			BSX,
+			ALICE,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.build()
+		.execute_with(|| {
+			let multiplier = One::one();
+			let loyalty_curve = Some(LoyaltyCurve {
+				initial_reward_percentage: FixedU128::from_float(0.558),
+				scale_coef: 20,
+			});
+
+			set_block_number(17_850);
+
+			//Act
+			assert_ok!(LiquidityMining::create_yield_farm(
+				Origin::signed(ALICE),
+				ALICE_FARM,
+				USDT_USDC_POOL,
+				multiplier,
+				loyalty_curve.clone()
+			));
+
+			//Assert
+			assert_last_event!(crate::Event::YieldFarmCreated {
+				global_farm_id: ALICE_FARM,
+				yield_farm_id: 2,
+				multiplier,
+				loyalty_curve,
+				pool_id: USDT_USDC_POOL,
+			}
+			.into());
+		})
+}
+
+#[test]
+fn create_yield_farm_should_fail_when_stableswap_pool_doesnt_exists() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, BSX, 1_000_000 * ONE)])
+		.with_stableswap_pool(USDT_USDC_POOL, USDT_USDC_POOL_ACCOUNT, vec![USDT, USDC])
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			USDT,
+			BSX,
+			ALICE,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				LiquidityMining::create_yield_farm(
+					Origin::signed(ALICE),
+					ALICE_FARM,
+					NON_EXISTING_POOL,
+					One::one(),
+					Some(LoyaltyCurve::default())
+				),
+				Error::<Test>::StableswapPoolDoesntExist
+			);
+		});
+}
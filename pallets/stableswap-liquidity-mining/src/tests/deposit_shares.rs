@@ -0,0 +1,171 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn deposit_shares_should_work() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, BSX, 1_000_000 * ONE),
+			(CHARLIE, USDT_USDC_POOL, 200 * ONE),
+			(USDT_USDC_POOL_ACCOUNT, USDT, 1_000 * ONE),
+			(USDT_USDC_POOL_ACCOUNT, USDC, 1_000 * ONE),
+		])
+		.with_stableswap_pool(USDT_USDC_POOL, USDT_USDC_POOL_ACCOUNT, vec![USDT, USDC])
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			USDT,
+			BSX,
+			ALICE,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_yield_farm(ALICE, 1, One::one(), None, USDT_USDC_POOL)
+		.build()
+		.execute_with(|| {
+			pretty_assertions::assert_eq!(Tokens::total_balance(USDT_USDC_POOL, &LiquidityMining::account_id()), 0);
+
+			set_block_number(1_800);
+			let deposited_amount = 50 * ONE;
+
+			//Act
+			assert_ok!(LiquidityMining::deposit_shares(
+				Origin::signed(CHARLIE),
+				1,
+				2,
+				USDT_USDC_POOL,
+				deposited_amount,
+			));
+
+			//Assert
+			assert_last_event!(crate::Event::SharesDeposited {
+				global_farm_id: 1,
+				yield_farm_id: 2,
+				who: CHARLIE,
+				lp_token: USDT_USDC_POOL,
+				amount: deposited_amount,
+				deposit_id: 1
+			}
+			.into());
+
+			pretty_assertions::assert_eq!(
+				Tokens::total_balance(USDT_USDC_POOL, &LiquidityMining::account_id()),
+				deposited_amount
+			);
+			pretty_assertions::assert_eq!(Tokens::free_balance(USDT_USDC_POOL, &CHARLIE), 150 * ONE);
+
+			let nft_owner: AccountId = DummyNFT::owner(&LM_NFT_COLLECTION, &1).unwrap();
+			pretty_assertions::assert_eq!(nft_owner, CHARLIE);
+		});
+}
+
+#[test]
+fn deposit_shares_should_fail_when_account_balance_is_insufficient() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, BSX, 1_000_000 * ONE),
+			(CHARLIE, USDT_USDC_POOL, 200 * ONE),
+			(USDT_USDC_POOL_ACCOUNT, USDT, 1_000 * ONE),
+			(USDT_USDC_POOL_ACCOUNT, USDC, 1_000 * ONE),
+		])
+		.with_stableswap_pool(USDT_USDC_POOL, USDT_USDC_POOL_ACCOUNT, vec![USDT, USDC])
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			USDT,
+			BSX,
+			ALICE,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_yield_farm(ALICE, 1, One::one(), None, USDT_USDC_POOL)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				LiquidityMining::deposit_shares(Origin::signed(CHARLIE), 1, 2, USDT_USDC_POOL, 201 * ONE),
+				Error::<Test>::InsufficientSharesBalance
+			);
+		});
+}
+
+#[test]
+fn deposit_shares_should_fail_when_origin_is_not_signed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, BSX, 1_000_000 * ONE),
+			(CHARLIE, USDT_USDC_POOL, 200 * ONE),
+			(USDT_USDC_POOL_ACCOUNT, USDT, 1_000 * ONE),
+			(USDT_USDC_POOL_ACCOUNT, USDC, 1_000 * ONE),
+		])
+		.with_stableswap_pool(USDT_USDC_POOL, USDT_USDC_POOL_ACCOUNT, vec![USDT, USDC])
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			USDT,
+			BSX,
+			ALICE,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_yield_farm(ALICE, 1, One::one(), None, USDT_USDC_POOL)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				LiquidityMining::deposit_shares(Origin::none(), 1, 2, USDT_USDC_POOL, 50 * ONE),
+				BadOrigin
+			);
+		});
+}
+
+#[test]
+fn deposit_shares_should_fail_when_stableswap_pool_does_not_exist() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, BSX, 1_000_000 * ONE),
+			(CHARLIE, USDT_USDC_POOL, 200 * ONE),
+			(USDT_USDC_POOL_ACCOUNT, USDT, 1_000 * ONE),
+			(USDT_USDC_POOL_ACCOUNT, USDC, 1_000 * ONE),
+		])
+		.with_stableswap_pool(USDT_USDC_POOL, USDT_USDC_POOL_ACCOUNT, vec![USDT, USDC])
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			USDT,
+			BSX,
+			ALICE,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_yield_farm(ALICE, 1, One::one(), None, USDT_USDC_POOL)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				LiquidityMining::deposit_shares(Origin::signed(CHARLIE), 1, 2, NON_EXISTING_POOL, 50 * ONE),
+				Error::<Test>::StableswapPoolDoesntExist
+			);
+		});
+}
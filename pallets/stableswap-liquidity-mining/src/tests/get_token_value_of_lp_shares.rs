@@ -0,0 +1,67 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//NOTE: This function is provided as callback for other pallets.
+
+use super::*;
+
+#[test]
+fn get_token_value_of_lp_shares_should_return_value_of_shares_in_requested_asset() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, USDT_USDC_POOL, 1_000 * ONE),
+			(USDT_USDC_POOL_ACCOUNT, USDT, 500 * ONE),
+			(USDT_USDC_POOL_ACCOUNT, USDC, 2_000 * ONE),
+		])
+		.with_stableswap_pool(USDT_USDC_POOL, USDT_USDC_POOL_ACCOUNT, vec![USDT, USDC])
+		.build()
+		.execute_with(|| {
+			//Act & Assert
+			pretty_assertions::assert_eq!(
+				LiquidityMining::get_token_value_of_lp_shares(USDT, USDT_USDC_POOL, 100 * ONE).unwrap(),
+				50 * ONE
+			);
+			pretty_assertions::assert_eq!(
+				LiquidityMining::get_token_value_of_lp_shares(USDC, USDT_USDC_POOL, 100 * ONE).unwrap(),
+				200 * ONE
+			);
+		});
+}
+
+#[test]
+fn get_token_value_of_lp_shares_should_fail_when_requested_asset_is_not_in_the_pool() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, USDT_USDC_POOL, 1_000 * ONE)])
+		.with_stableswap_pool(USDT_USDC_POOL, USDT_USDC_POOL_ACCOUNT, vec![USDT, USDC])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				LiquidityMining::get_token_value_of_lp_shares(DAI, USDT_USDC_POOL, 1_000),
+				Error::<Test>::AssetNotInPool
+			);
+		});
+}
+
+#[test]
+fn get_token_value_of_lp_shares_should_fail_when_pool_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			LiquidityMining::get_token_value_of_lp_shares(USDT, NON_EXISTING_POOL, 1_000),
+			Error::<Test>::StableswapPoolDoesntExist
+		);
+	});
+}
@@ -0,0 +1,191 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn redeposit_shares_should_work_when_deposit_already_exists() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, BSX, 1_000_000 * ONE),
+			(CHARLIE, USDT_USDC_POOL, 200 * ONE),
+			(CHARLIE, USDT_USDC_DAI_POOL, 200 * ONE),
+			(USDT_USDC_POOL_ACCOUNT, USDT, 1_000 * ONE),
+			(USDT_USDC_POOL_ACCOUNT, USDC, 1_000 * ONE),
+			(USDT_USDC_DAI_POOL_ACCOUNT, USDT, 1_000 * ONE),
+			(USDT_USDC_DAI_POOL_ACCOUNT, USDC, 1_000 * ONE),
+			(USDT_USDC_DAI_POOL_ACCOUNT, DAI, 1_000 * ONE),
+		])
+		.with_stableswap_pool(USDT_USDC_POOL, USDT_USDC_POOL_ACCOUNT, vec![USDT, USDC])
+		.with_stableswap_pool(USDT_USDC_DAI_POOL, USDT_USDC_DAI_POOL_ACCOUNT, vec![USDT, USDC, DAI])
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			USDT,
+			BSX,
+			ALICE,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			USDT,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_yield_farm(ALICE, 1, One::one(), None, USDT_USDC_POOL)
+		.with_yield_farm(BOB, 2, One::one(), None, USDT_USDC_POOL)
+		.with_yield_farm(BOB, 2, One::one(), None, USDT_USDC_DAI_POOL)
+		.with_deposit(CHARLIE, 1, 3, USDT_USDC_POOL, 100 * ONE)
+		.build()
+		.execute_with(|| {
+			set_block_number(50_000);
+
+			//Act
+			assert_ok!(LiquidityMining::redeposit_shares(
+				Origin::signed(CHARLIE),
+				2,
+				4,
+				USDT_USDC_POOL,
+				1,
+			));
+
+			//Assert
+			assert_last_event!(crate::Event::SharesRedeposited {
+				global_farm_id: 2,
+				yield_farm_id: 4,
+				who: CHARLIE,
+				amount: 100 * ONE,
+				lp_token: USDT_USDC_POOL,
+				deposit_id: 1,
+			}
+			.into());
+
+			//NOTE: shares are not transferred again
+			pretty_assertions::assert_eq!(
+				Tokens::free_balance(USDT_USDC_POOL, &LiquidityMining::account_id()),
+				100 * ONE
+			);
+		});
+}
+
+#[test]
+fn redeposit_shares_should_fail_when_pool_id_does_not_match_deposit() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, BSX, 1_000_000 * ONE),
+			(CHARLIE, USDT_USDC_POOL, 200 * ONE),
+			(CHARLIE, USDT_USDC_DAI_POOL, 200 * ONE),
+			(USDT_USDC_POOL_ACCOUNT, USDT, 1_000 * ONE),
+			(USDT_USDC_POOL_ACCOUNT, USDC, 1_000 * ONE),
+			(USDT_USDC_DAI_POOL_ACCOUNT, USDT, 1_000 * ONE),
+			(USDT_USDC_DAI_POOL_ACCOUNT, USDC, 1_000 * ONE),
+			(USDT_USDC_DAI_POOL_ACCOUNT, DAI, 1_000 * ONE),
+		])
+		.with_stableswap_pool(USDT_USDC_POOL, USDT_USDC_POOL_ACCOUNT, vec![USDT, USDC])
+		.with_stableswap_pool(USDT_USDC_DAI_POOL, USDT_USDC_DAI_POOL_ACCOUNT, vec![USDT, USDC, DAI])
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			USDT,
+			BSX,
+			ALICE,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			USDT,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_yield_farm(ALICE, 1, One::one(), None, USDT_USDC_POOL)
+		.with_yield_farm(BOB, 2, One::one(), None, USDT_USDC_POOL)
+		.with_yield_farm(BOB, 2, One::one(), None, USDT_USDC_DAI_POOL)
+		.with_deposit(CHARLIE, 1, 3, USDT_USDC_POOL, 100 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				LiquidityMining::redeposit_shares(Origin::signed(CHARLIE), 2, 5, USDT_USDC_DAI_POOL, 1),
+				Error::<Test>::InvalidPoolId
+			);
+		});
+}
+
+#[test]
+fn redeposit_shares_should_fail_when_origin_is_not_deposit_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, BSX, 1_000_000 * ONE),
+			(CHARLIE, USDT_USDC_POOL, 200 * ONE),
+			(CHARLIE, USDT_USDC_DAI_POOL, 200 * ONE),
+			(USDT_USDC_POOL_ACCOUNT, USDT, 1_000 * ONE),
+			(USDT_USDC_POOL_ACCOUNT, USDC, 1_000 * ONE),
+			(USDT_USDC_DAI_POOL_ACCOUNT, USDT, 1_000 * ONE),
+			(USDT_USDC_DAI_POOL_ACCOUNT, USDC, 1_000 * ONE),
+			(USDT_USDC_DAI_POOL_ACCOUNT, DAI, 1_000 * ONE),
+		])
+		.with_stableswap_pool(USDT_USDC_POOL, USDT_USDC_POOL_ACCOUNT, vec![USDT, USDC])
+		.with_stableswap_pool(USDT_USDC_DAI_POOL, USDT_USDC_DAI_POOL_ACCOUNT, vec![USDT, USDC, DAI])
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			USDT,
+			BSX,
+			ALICE,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			USDT,
+			BSX,
+			BOB,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_yield_farm(ALICE, 1, One::one(), None, USDT_USDC_POOL)
+		.with_yield_farm(BOB, 2, One::one(), None, USDT_USDC_POOL)
+		.with_yield_farm(BOB, 2, One::one(), None, USDT_USDC_DAI_POOL)
+		.with_deposit(CHARLIE, 1, 3, USDT_USDC_POOL, 100 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				LiquidityMining::redeposit_shares(Origin::signed(BOB), 2, 4, USDT_USDC_POOL, 1),
+				Error::<Test>::NotDepositOwner
+			);
+		});
+}
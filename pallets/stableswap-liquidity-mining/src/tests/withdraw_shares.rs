@@ -0,0 +1,154 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn withdraw_shares_should_work_and_destroy_deposit() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, BSX, 1_000_000 * ONE),
+			(CHARLIE, USDT_USDC_POOL, 200 * ONE),
+			(USDT_USDC_POOL_ACCOUNT, USDT, 1_000 * ONE),
+			(USDT_USDC_POOL_ACCOUNT, USDC, 1_000 * ONE),
+		])
+		.with_stableswap_pool(USDT_USDC_POOL, USDT_USDC_POOL_ACCOUNT, vec![USDT, USDC])
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			USDT,
+			BSX,
+			ALICE,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_yield_farm(ALICE, 1, One::one(), None, USDT_USDC_POOL)
+		.with_deposit(CHARLIE, 1, 2, USDT_USDC_POOL, 100 * ONE)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(1_000);
+			pretty_assertions::assert_eq!(Tokens::free_balance(USDT_USDC_POOL, &CHARLIE), 100 * ONE);
+
+			//Act
+			assert_ok!(LiquidityMining::withdraw_shares(
+				Origin::signed(CHARLIE),
+				1,
+				2,
+				USDT_USDC_POOL
+			));
+
+			//Assert
+			assert!(has_event(
+				crate::Event::RewardClaimed {
+					global_farm_id: 1,
+					yield_farm_id: 2,
+					who: CHARLIE,
+					claimed: 20_000_000 * ONE,
+					reward_currency: BSX,
+					deposit_id: 1,
+				}
+				.into(),
+			));
+			assert!(has_event(
+				crate::Event::SharesWithdrawn {
+					global_farm_id: 1,
+					yield_farm_id: 2,
+					who: CHARLIE,
+					lp_token: USDT_USDC_POOL,
+					amount: 100 * ONE,
+					deposit_id: 1,
+				}
+				.into(),
+			));
+			assert_last_event!(crate::Event::DepositDestroyed {
+				who: CHARLIE,
+				deposit_id: 1,
+			}
+			.into());
+
+			pretty_assertions::assert_eq!(Tokens::free_balance(USDT_USDC_POOL, &CHARLIE), 200 * ONE);
+			pretty_assertions::assert_eq!(Tokens::free_balance(USDT_USDC_POOL, &LiquidityMining::account_id()), 0);
+			pretty_assertions::assert_eq!(DummyNFT::owner(&LM_NFT_COLLECTION, &1), None::<AccountId>);
+		});
+}
+
+#[test]
+fn withdraw_shares_should_fail_when_deposit_data_not_found() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, BSX, 1_000_000 * ONE),
+			(CHARLIE, USDT_USDC_POOL, 200 * ONE),
+			(USDT_USDC_POOL_ACCOUNT, USDT, 1_000 * ONE),
+			(USDT_USDC_POOL_ACCOUNT, USDC, 1_000 * ONE),
+		])
+		.with_stableswap_pool(USDT_USDC_POOL, USDT_USDC_POOL_ACCOUNT, vec![USDT, USDC])
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			USDT,
+			BSX,
+			ALICE,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_yield_farm(ALICE, 1, One::one(), None, USDT_USDC_POOL)
+		.with_deposit(CHARLIE, 1, 2, USDT_USDC_POOL, 100 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				LiquidityMining::withdraw_shares(Origin::signed(CHARLIE), 1, 10, USDT_USDC_POOL),
+				Error::<Test>::DepositDataNotFound
+			);
+		});
+}
+
+#[test]
+fn withdraw_shares_should_fail_when_origin_is_not_deposit_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, BSX, 1_000_000 * ONE),
+			(CHARLIE, USDT_USDC_POOL, 200 * ONE),
+			(USDT_USDC_POOL_ACCOUNT, USDT, 1_000 * ONE),
+			(USDT_USDC_POOL_ACCOUNT, USDC, 1_000 * ONE),
+		])
+		.with_stableswap_pool(USDT_USDC_POOL, USDT_USDC_POOL_ACCOUNT, vec![USDT, USDC])
+		.with_global_farm(
+			500_000 * ONE,
+			20_000,
+			10,
+			USDT,
+			BSX,
+			ALICE,
+			Perquintill::from_percent(1),
+			ONE,
+			One::one(),
+		)
+		.with_yield_farm(ALICE, 1, One::one(), None, USDT_USDC_POOL)
+		.with_deposit(CHARLIE, 1, 2, USDT_USDC_POOL, 100 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				LiquidityMining::withdraw_shares(Origin::signed(BOB), 1, 2, USDT_USDC_POOL),
+				Error::<Test>::NotDepositOwner
+			);
+		});
+}
@@ -0,0 +1,251 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use crate::types::Balance;
+use crate::Pallet as Stableswap;
+
+use basilisk_traits::router::{PoolType, TradeExecution};
+
+use frame_benchmarking::{account, benchmarks};
+use frame_support::traits::EnsureOrigin;
+use frame_system::RawOrigin;
+use orml_traits::MultiCurrencyExtended;
+
+const SEED: u32 = 1;
+
+const ONE: Balance = 1_000_000_000_000_000_000;
+const INITIAL_LIQUIDITY: Balance = 1_000_000 * ONE;
+const TRADE_AMOUNT: Balance = 1_000 * ONE;
+
+const AMPLIFICATION: u16 = 100;
+const DEFAULT_FEE: Permill = Permill::from_parts(400);
+
+fn pool_assets<T: Config>() -> Result<Vec<AssetId>, DispatchError> {
+	let assets: Vec<AssetId> = (1..=MAX_ASSETS_IN_POOL).map(|i| 1_000 + i).collect();
+	for asset in assets.iter() {
+		T::BenchmarkHelper::register_asset(*asset, 18)?;
+	}
+	Ok(assets)
+}
+
+fn funded_account<T: Config>(name: &'static str, index: u32, assets: &[AssetId]) -> T::AccountId {
+	let caller: T::AccountId = account(name, index, SEED);
+	for asset in assets {
+		T::Currency::update_balance(*asset, &caller, (INITIAL_LIQUIDITY * 10) as i128).unwrap();
+	}
+	caller
+}
+
+fn pool_id_of<T: Config>(assets: &[AssetId]) -> Option<AssetId> {
+	Pools::<T>::iter()
+		.find(|(_, pool)| pool.assets.as_slice() == assets)
+		.map(|(pool_id, _)| pool_id)
+}
+
+fn create_pool_with_liquidity<T: Config>(assets: &[AssetId]) -> Result<AssetId, DispatchError> {
+	let origin = T::AuthorityOrigin::try_successful_origin().map_err(|_| DispatchError::BadOrigin)?;
+	Stableswap::<T>::create_pool(origin, assets.to_vec(), AMPLIFICATION, DEFAULT_FEE)?;
+
+	let pool_id = pool_id_of::<T>(assets).ok_or(Error::<T>::PoolNotFound)?;
+
+	let provider = funded_account::<T>("provider", 0, assets);
+	let liquidity = assets
+		.iter()
+		.map(|asset| AssetAmount::new(*asset, INITIAL_LIQUIDITY))
+		.collect();
+	Stableswap::<T>::add_liquidity(RawOrigin::Signed(provider).into(), pool_id, liquidity, 0)?;
+
+	Ok(pool_id)
+}
+
+benchmarks! {
+	create_pool {
+		let assets = pool_assets::<T>()?;
+		let origin = T::AuthorityOrigin::try_successful_origin().map_err(|_| DispatchError::BadOrigin)?;
+	}: _<T::RuntimeOrigin>(origin, assets.clone(), AMPLIFICATION, DEFAULT_FEE)
+	verify {
+		assert!(pool_id_of::<T>(&assets).is_some());
+	}
+
+	update_pool_fee {
+		let assets = pool_assets::<T>()?;
+		let pool_id = create_pool_with_liquidity::<T>(&assets)?;
+		let origin = T::AuthorityOrigin::try_successful_origin().map_err(|_| DispatchError::BadOrigin)?;
+		let fee = Permill::from_percent(1);
+	}: _<T::RuntimeOrigin>(origin, pool_id, fee)
+	verify {
+		assert_eq!(Pools::<T>::get(pool_id).unwrap().fee, fee);
+	}
+
+	update_amplification {
+		let assets = pool_assets::<T>()?;
+		let pool_id = create_pool_with_liquidity::<T>(&assets)?;
+		let origin = T::AuthorityOrigin::try_successful_origin().map_err(|_| DispatchError::BadOrigin)?;
+		let now = T::BlockNumberProvider::current_block_number();
+		let start = now + 10u32.into();
+		let end = now + 100u32.into();
+	}: _<T::RuntimeOrigin>(origin, pool_id, 1_000, start, end)
+	verify {
+		assert_eq!(Pools::<T>::get(pool_id).unwrap().final_amplification, 1_000);
+	}
+
+	add_liquidity {
+		let assets = pool_assets::<T>()?;
+		let pool_id = create_pool_with_liquidity::<T>(&assets)?;
+		let caller = funded_account::<T>("caller", 0, &assets);
+
+		// Imbalanced liquidity is the most expensive case.
+		let liquidity: Vec<AssetAmount> = assets
+			.iter()
+			.enumerate()
+			.map(|(i, asset)| AssetAmount::new(*asset, TRADE_AMOUNT * (i as Balance + 1)))
+			.collect();
+	}: _(RawOrigin::Signed(caller.clone()), pool_id, liquidity, 0)
+	verify {
+		assert!(T::Currency::free_balance(pool_id, &caller) > 0);
+	}
+
+	remove_liquidity_one_asset {
+		let assets = pool_assets::<T>()?;
+		let pool_id = create_pool_with_liquidity::<T>(&assets)?;
+		let caller = funded_account::<T>("caller", 0, &assets);
+
+		Stableswap::<T>::add_liquidity(RawOrigin::Signed(caller.clone()).into(), pool_id, vec![AssetAmount::new(assets[0], TRADE_AMOUNT)], 0)?;
+		let shares = T::Currency::free_balance(pool_id, &caller);
+		let balance_before = T::Currency::free_balance(assets[1], &caller);
+	}: _(RawOrigin::Signed(caller.clone()), pool_id, assets[1], shares, 0)
+	verify {
+		assert_eq!(T::Currency::free_balance(pool_id, &caller), 0);
+		assert!(T::Currency::free_balance(assets[1], &caller) > balance_before);
+	}
+
+	remove_liquidity {
+		let assets = pool_assets::<T>()?;
+		let pool_id = create_pool_with_liquidity::<T>(&assets)?;
+		let caller = funded_account::<T>("caller", 0, &assets);
+
+		Stableswap::<T>::add_liquidity(RawOrigin::Signed(caller.clone()).into(), pool_id, vec![AssetAmount::new(assets[0], TRADE_AMOUNT)], 0)?;
+		let shares = T::Currency::free_balance(pool_id, &caller);
+	}: _(RawOrigin::Signed(caller.clone()), pool_id, shares, vec![])
+	verify {
+		assert_eq!(T::Currency::free_balance(pool_id, &caller), 0);
+	}
+
+	sell {
+		let assets = pool_assets::<T>()?;
+		let pool_id = create_pool_with_liquidity::<T>(&assets)?;
+		let caller = funded_account::<T>("caller", 0, &assets);
+		let (asset_in, asset_out) = (assets[0], assets[1]);
+		let balance_before = T::Currency::free_balance(asset_out, &caller);
+	}: _(RawOrigin::Signed(caller.clone()), pool_id, asset_in, asset_out, TRADE_AMOUNT, 0)
+	verify {
+		assert!(T::Currency::free_balance(asset_out, &caller) > balance_before);
+	}
+
+	buy {
+		let assets = pool_assets::<T>()?;
+		let pool_id = create_pool_with_liquidity::<T>(&assets)?;
+		let caller = funded_account::<T>("caller", 0, &assets);
+		let (asset_in, asset_out) = (assets[0], assets[1]);
+		let balance_before = T::Currency::free_balance(asset_out, &caller);
+	}: _(RawOrigin::Signed(caller.clone()), pool_id, asset_out, asset_in, TRADE_AMOUNT, Balance::MAX)
+	verify {
+		assert_eq!(T::Currency::free_balance(asset_out, &caller), balance_before + TRADE_AMOUNT);
+	}
+
+	// The following benchmarks should have similar setup as the corresponding benchmarks
+	// for the route executor in the runtime. Otherwise the router weights are incorrect.
+	router_execution_sell {
+		let c in 1..2;	// if c == 1, calculate_sell is executed
+		let e in 0..1;	// if e == 1, execute_sell is executed
+
+		let assets = pool_assets::<T>()?;
+		let pool_id = create_pool_with_liquidity::<T>(&assets)?;
+		let caller = funded_account::<T>("caller", 0, &assets);
+		let (asset_in, asset_out) = (assets[0], assets[1]);
+		let balance_before = T::Currency::free_balance(asset_out, &caller);
+	}: {
+		for _ in 1..c {
+			assert!(<Stableswap::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance>>::calculate_sell(PoolType::Stableswap(pool_id), asset_in, asset_out, TRADE_AMOUNT).is_ok());
+		}
+		if e != 0 {
+			assert!(<Stableswap::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance>>::execute_sell(RawOrigin::Signed(caller.clone()).into(), PoolType::Stableswap(pool_id), asset_in, asset_out, TRADE_AMOUNT, 0).is_ok());
+		}
+	}
+	verify {
+		if e != 0 {
+			assert!(T::Currency::free_balance(asset_out, &caller) > balance_before);
+		}
+	}
+
+	router_execution_buy {
+		let c in 1..3;	// number of times calculate_buy is executed
+		let e in 0..1;	// if e == 1, execute_buy is executed
+
+		let assets = pool_assets::<T>()?;
+		let pool_id = create_pool_with_liquidity::<T>(&assets)?;
+		let caller = funded_account::<T>("caller", 0, &assets);
+		let (asset_in, asset_out) = (assets[0], assets[1]);
+		let balance_before = T::Currency::free_balance(asset_out, &caller);
+	}: {
+		for _ in 1..c {
+			assert!(<Stableswap::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance>>::calculate_buy(PoolType::Stableswap(pool_id), asset_in, asset_out, TRADE_AMOUNT).is_ok());
+		}
+		if e != 0 {
+			assert!(<Stableswap::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance>>::execute_buy(RawOrigin::Signed(caller.clone()).into(), PoolType::Stableswap(pool_id), asset_in, asset_out, TRADE_AMOUNT, Balance::MAX).is_ok());
+		}
+	}
+	verify {
+		if e != 0 {
+			assert_eq!(T::Currency::free_balance(asset_out, &caller), balance_before + TRADE_AMOUNT);
+		}
+	}
+
+	calculate_spot_price_with_fee {
+		let assets = pool_assets::<T>()?;
+		let pool_id = create_pool_with_liquidity::<T>(&assets)?;
+		let (asset_in, asset_out) = (assets[0], assets[1]);
+	}: {
+		assert!(<Stableswap::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance>>::calculate_spot_price_with_fee(PoolType::Stableswap(pool_id), asset_in, asset_out).is_ok());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tests::mock::{ExtBuilder, Test};
+	use frame_support::assert_ok;
+
+	#[test]
+	fn test_benchmarks() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<Test>::test_benchmark_create_pool());
+			assert_ok!(Pallet::<Test>::test_benchmark_update_pool_fee());
+			assert_ok!(Pallet::<Test>::test_benchmark_update_amplification());
+			assert_ok!(Pallet::<Test>::test_benchmark_add_liquidity());
+			assert_ok!(Pallet::<Test>::test_benchmark_remove_liquidity_one_asset());
+			assert_ok!(Pallet::<Test>::test_benchmark_remove_liquidity());
+			assert_ok!(Pallet::<Test>::test_benchmark_sell());
+			assert_ok!(Pallet::<Test>::test_benchmark_buy());
+			assert_ok!(Pallet::<Test>::test_benchmark_router_execution_sell());
+			assert_ok!(Pallet::<Test>::test_benchmark_router_execution_buy());
+			assert_ok!(Pallet::<Test>::test_benchmark_calculate_spot_price_with_fee());
+		});
+	}
+}
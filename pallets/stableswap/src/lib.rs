@@ -0,0 +1,1064 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Stableswap Pallet
+//!
+//! ## Overview
+//!
+//! Curve-style stableswap pools of up to `MAX_ASSETS_IN_POOL` assets which are expected to trade
+//! close to peg, e.g. stablecoins.
+//!
+//! The invariant is controlled by the amplification parameter. Higher amplification means lower
+//! slippage around the peg. The amplification can be changed linearly over a range of blocks to
+//! avoid sudden price changes.
+//!
+//! A pool is identified by the id of its share asset, which is registered in the asset registry
+//! when the pool is created. Pools are created by `AuthorityOrigin` without liquidity, the first
+//! liquidity provider has to provide all assets of the pool.
+//!
+//! Assets of a pool may have different decimals. Decimals are taken from the asset registry,
+//! so every asset of a pool must have metadata set.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::too_many_arguments)]
+
+use basilisk_math::ratio::Ratio;
+use basilisk_math::stableswap::{self as math, types::AssetReserve, MAX_D_ITERATIONS, MAX_Y_ITERATIONS};
+use basilisk_traits::{stableswap::StableswapPoolProvider, OnLiquidityChangedHandler, OnTradeHandler};
+use frame_support::{
+	dispatch::DispatchResult,
+	ensure,
+	traits::{ExistenceRequirement, Get},
+	transactional,
+	weights::Weight,
+	PalletId,
+};
+use frame_system::{ensure_signed, pallet_prelude::BlockNumberFor};
+use hydradx_traits::registry::Inspect;
+use orml_traits::MultiCurrency;
+use pallet_broadcast::types::{Asset, Destination, Fee};
+use sp_runtime::{
+	traits::{AccountIdConversion, BlockNumberProvider, Zero},
+	ArithmeticError, DispatchError, Permill, SaturatedConversion,
+};
+use sp_std::{vec, vec::Vec};
+
+use crate::types::{AssetAmount, AssetId, Balance, PoolInfo, MAX_ASSETS_IN_POOL, POOL_SHARE_SYMBOL};
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+mod trade_execution;
+pub mod types;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+const D_ITERATIONS: u8 = MAX_D_ITERATIONS;
+const Y_ITERATIONS: u8 = MAX_Y_ITERATIONS;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetId> {
+	/// Register `asset_id` in the asset registry with given decimals.
+	fn register_asset(asset_id: AssetId, decimals: u8) -> DispatchResult;
+}
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper<AssetId> for () {
+	fn register_asset(_asset_id: AssetId, _decimals: u8) -> DispatchResult {
+		Ok(())
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use crate::types::{pool_share_name, Amount};
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::OriginFor;
+	use hydradx_traits::{
+		pools::DustRemovalAccountWhitelist,
+		registry::{AssetKind, Create},
+		Source,
+	};
+	use orml_traits::MultiCurrencyExtended;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_broadcast::Config {
+		/// Registry support
+		type AssetRegistry: Create<Balance, AssetId = AssetId, Error = DispatchError>;
+
+		/// Multi currency for transfer of currencies
+		type Currency: MultiCurrencyExtended<Self::AccountId, CurrencyId = AssetId, Balance = Balance, Amount = Amount>;
+
+		/// Origin that can create pools and update their parameters.
+		type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Pallet id used to derive pool accounts.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Minimum amount of shares an account has to hold.
+		#[pallet::constant]
+		type MinPoolLiquidity: Get<Balance>;
+
+		/// Minimum trading limit
+		#[pallet::constant]
+		type MinTradingLimit: Get<Balance>;
+
+		/// Minimum amplification of a pool.
+		#[pallet::constant]
+		type MinAmplification: Get<u16>;
+
+		/// Maximum amplification of a pool.
+		#[pallet::constant]
+		type MaxAmplification: Get<u16>;
+
+		/// Oracle source identifier for this pallet.
+		#[pallet::constant]
+		type OracleSource: Get<Source>;
+
+		/// AMM handlers
+		type AMMHandler: OnTradeHandler<AssetId, Balance, Ratio> + OnLiquidityChangedHandler<AssetId, Balance, Ratio>;
+
+		/// Account whitelist manager to exclude pool accounts from dusting mechanism.
+		type NonDustableWhitelistHandler: DustRemovalAccountWhitelist<Self::AccountId, Error = DispatchError>;
+
+		/// Block number provider used to change the amplification over time.
+		type BlockNumberProvider: BlockNumberProvider<BlockNumber = BlockNumberFor<Self>>;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
+
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<AssetId>;
+	}
+
+	/// Existing pools, keyed by the share asset id.
+	#[pallet::storage]
+	#[pallet::getter(fn pools)]
+	pub type Pools<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, PoolInfo<BlockNumberFor<T>>, OptionQuery>;
+
+	#[pallet::error]
+	#[cfg_attr(test, derive(PartialEq, Eq))]
+	pub enum Error<T> {
+		/// Pool has to have at least two unique assets.
+		IncorrectAssets,
+
+		/// Number of assets exceeds `MAX_ASSETS_IN_POOL`.
+		MaxAssetsExceeded,
+
+		/// Pool with given assets already exists.
+		PoolExists,
+
+		/// Pool does not exist.
+		PoolNotFound,
+
+		/// Asset is not registered in the asset registry.
+		AssetNotRegistered,
+
+		/// Decimals of an asset are not set in the asset registry.
+		AssetDecimalsNotFound,
+
+		/// Amplification is outside of the allowed range.
+		InvalidAmplification,
+
+		/// Amplification change has to start in the future and end after it starts.
+		IncorrectAmplificationBlocks,
+
+		/// Asset is not in the pool.
+		AssetNotInPool,
+
+		/// Amount is less than min trading limit.
+		InsufficientTradingAmount,
+
+		/// Account balance is not sufficient.
+		InsufficientBalance,
+
+		/// Account does not hold enough shares or would be left with less than `MinPoolLiquidity` shares.
+		InsufficientShares,
+
+		/// Initial liquidity has to be provided in all assets of the pool.
+		InsufficientInitialLiquidity,
+
+		/// Not enough liquidity in the pool.
+		InsufficientLiquidity,
+
+		/// Last shares of the pool can only be withdrawn proportionally by `remove_liquidity`.
+		InsufficientLiquidityRemaining,
+
+		/// Asset amount is zero or the calculated amount of shares is zero.
+		InvalidAssetAmount,
+
+		/// Trade or liquidity amount is outside of the given limit.
+		SlippageLimit,
+
+		/// Share token name is too long.
+		CannotCreatePool,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Pool was created.
+		PoolCreated {
+			pool_id: AssetId,
+			assets: Vec<AssetId>,
+			amplification: u16,
+			fee: Permill,
+		},
+
+		/// Pool fee was updated.
+		FeeUpdated { pool_id: AssetId, fee: Permill },
+
+		/// Amplification of a pool is scheduled to change.
+		AmplificationChanging {
+			pool_id: AssetId,
+			current_amplification: u16,
+			final_amplification: u16,
+			start_block: BlockNumberFor<T>,
+			end_block: BlockNumberFor<T>,
+		},
+
+		/// Liquidity was added to the pool.
+		LiquidityAdded {
+			pool_id: AssetId,
+			who: T::AccountId,
+			shares: Balance,
+			assets: Vec<AssetAmount>,
+		},
+
+		/// Liquidity was removed from the pool.
+		LiquidityRemoved {
+			pool_id: AssetId,
+			who: T::AccountId,
+			shares: Balance,
+			amounts: Vec<AssetAmount>,
+			fee: Balance,
+		},
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a new pool for given assets.
+		///
+		/// Share asset of the pool is registered in the asset registry. Its id identifies the pool.
+		/// Shares have `TARGET_PRECISION` decimals, the precision the pool invariant is calculated in.
+		/// The pool is created empty, first liquidity has to be provided in all assets.
+		///
+		/// Parameters:
+		/// - `origin`: must be `T::AuthorityOrigin`.
+		/// - `assets`: assets of the pool. Must be unique, registered and have decimals set.
+		/// - `amplification`: pool amplification, must be within the allowed range.
+		/// - `fee`: trade fee.
+		///
+		/// Emits `PoolCreated` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_pool())]
+		pub fn create_pool(
+			origin: OriginFor<T>,
+			assets: Vec<AssetId>,
+			amplification: u16,
+			fee: Permill,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				assets.len() <= MAX_ASSETS_IN_POOL as usize,
+				Error::<T>::MaxAssetsExceeded
+			);
+			Self::ensure_amplification(amplification)?;
+
+			let mut assets = assets;
+			assets.sort();
+
+			let now = T::BlockNumberProvider::current_block_number();
+			let pool = PoolInfo {
+				assets: assets.clone().try_into().map_err(|_| Error::<T>::MaxAssetsExceeded)?,
+				initial_amplification: amplification,
+				final_amplification: amplification,
+				initial_block: now,
+				final_block: now,
+				fee,
+			};
+			ensure!(pool.is_valid(), Error::<T>::IncorrectAssets);
+
+			for asset in pool.assets.iter() {
+				ensure!(T::AssetRegistry::exists(*asset), Error::<T>::AssetNotRegistered);
+				ensure!(
+					T::AssetRegistry::decimals(*asset).is_some(),
+					Error::<T>::AssetDecimalsNotFound
+				);
+			}
+
+			let pool_id = T::AssetRegistry::get_or_register_insufficient_asset(
+				pool_share_name(&assets)
+					.try_into()
+					.map_err(|_| Error::<T>::CannotCreatePool)?,
+				AssetKind::StableSwap,
+				None,
+				Some(
+					POOL_SHARE_SYMBOL
+						.to_vec()
+						.try_into()
+						.map_err(|_| Error::<T>::CannotCreatePool)?,
+				),
+				Some(math::TARGET_PRECISION),
+				None,
+				None,
+			)?;
+
+			ensure!(!Pools::<T>::contains_key(pool_id), Error::<T>::PoolExists);
+
+			T::NonDustableWhitelistHandler::add_account(&Self::pool_account(pool_id))?;
+
+			Pools::<T>::insert(pool_id, pool);
+
+			Self::deposit_event(Event::PoolCreated {
+				pool_id,
+				assets,
+				amplification,
+				fee,
+			});
+
+			Ok(())
+		}
+
+		/// Update trade fee of a pool.
+		///
+		/// Parameters:
+		/// - `origin`: must be `T::AuthorityOrigin`.
+		/// - `pool_id`: share asset id of the pool.
+		/// - `fee`: new trade fee.
+		///
+		/// Emits `FeeUpdated` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_pool_fee())]
+		pub fn update_pool_fee(origin: OriginFor<T>, pool_id: AssetId, fee: Permill) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			Pools::<T>::try_mutate(pool_id, |maybe_pool| -> DispatchResult {
+				let pool = maybe_pool.as_mut().ok_or(Error::<T>::PoolNotFound)?;
+				pool.fee = fee;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::FeeUpdated { pool_id, fee });
+
+			Ok(())
+		}
+
+		/// Schedule a change of the pool amplification.
+		///
+		/// The amplification changes linearly from its current value at `start_block` to
+		/// `final_amplification` at `end_block`.
+		///
+		/// Parameters:
+		/// - `origin`: must be `T::AuthorityOrigin`.
+		/// - `pool_id`: share asset id of the pool.
+		/// - `final_amplification`: amplification at `end_block`.
+		/// - `start_block`: block the change starts at. Must not be in the past.
+		/// - `end_block`: block the change ends at. Must be after `start_block`.
+		///
+		/// Emits `AmplificationChanging` event when successful.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_amplification())]
+		pub fn update_amplification(
+			origin: OriginFor<T>,
+			pool_id: AssetId,
+			final_amplification: u16,
+			start_block: BlockNumberFor<T>,
+			end_block: BlockNumberFor<T>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			let now = T::BlockNumberProvider::current_block_number();
+			ensure!(
+				start_block >= now && end_block > start_block,
+				Error::<T>::IncorrectAmplificationBlocks
+			);
+			Self::ensure_amplification(final_amplification)?;
+
+			let current_amplification = Pools::<T>::try_mutate(pool_id, |maybe_pool| -> Result<u16, DispatchError> {
+				let pool = maybe_pool.as_mut().ok_or(Error::<T>::PoolNotFound)?;
+
+				let current_amplification: u16 = Self::get_amplification(pool)
+					.try_into()
+					.map_err(|_| ArithmeticError::Overflow)?;
+
+				pool.initial_amplification = current_amplification;
+				pool.final_amplification = final_amplification;
+				pool.initial_block = start_block;
+				pool.final_block = end_block;
+
+				Ok(current_amplification)
+			})?;
+
+			Self::deposit_event(Event::AmplificationChanging {
+				pool_id,
+				current_amplification,
+				final_amplification,
+				start_block,
+				end_block,
+			});
+
+			Ok(())
+		}
+
+		/// Add liquidity to a pool.
+		///
+		/// Liquidity can be provided in any subset of pool assets. Imbalanced liquidity is charged
+		/// with a fee derived from the pool fee. First liquidity has to be provided in all assets.
+		///
+		/// Parameters:
+		/// - `origin`: liquidity provider.
+		/// - `pool_id`: share asset id of the pool.
+		/// - `assets`: assets and amounts to provide.
+		/// - `min_shares`: minimum amount of shares to receive.
+		///
+		/// Emits `LiquidityAdded` event when successful.
+		#[pallet::call_index(3)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::add_liquidity()
+				.saturating_add(liquidity_changed_weight::<T>())
+		)]
+		#[transactional]
+		pub fn add_liquidity(
+			origin: OriginFor<T>,
+			pool_id: AssetId,
+			assets: Vec<AssetAmount>,
+			min_shares: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_add_liquidity(&who, pool_id, &assets, min_shares)?;
+
+			Ok(())
+		}
+
+		/// Remove liquidity from a pool in a single asset.
+		///
+		/// Withdrawal is charged with a fee derived from the pool fee. Last shares of a pool cannot
+		/// be withdrawn in a single asset.
+		///
+		/// Parameters:
+		/// - `origin`: liquidity provider.
+		/// - `pool_id`: share asset id of the pool.
+		/// - `asset_id`: asset to receive.
+		/// - `shares`: amount of shares to burn.
+		/// - `min_amount_out`: minimum amount of `asset_id` to receive.
+		///
+		/// Emits `LiquidityRemoved` event when successful.
+		#[pallet::call_index(4)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::remove_liquidity_one_asset()
+				.saturating_add(liquidity_changed_weight::<T>())
+		)]
+		#[transactional]
+		pub fn remove_liquidity_one_asset(
+			origin: OriginFor<T>,
+			pool_id: AssetId,
+			asset_id: AssetId,
+			shares: Balance,
+			min_amount_out: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			let asset_index = pool.find_asset(asset_id).ok_or(Error::<T>::AssetNotInPool)?;
+
+			Self::ensure_shares_withdrawable(&who, pool_id, shares)?;
+
+			let amplification = Self::get_amplification(&pool);
+			let reserves = Self::get_reserves(pool_id, &pool)?;
+			let share_issuance = T::Currency::total_issuance(pool_id);
+
+			let (amount, fee) = math::calculate_withdraw_one_asset::<D_ITERATIONS, Y_ITERATIONS>(
+				&reserves,
+				shares,
+				asset_index,
+				share_issuance,
+				amplification,
+				pool.fee,
+			)
+			.ok_or(Error::<T>::InsufficientLiquidityRemaining)?;
+
+			ensure!(!amount.is_zero(), Error::<T>::InvalidAssetAmount);
+			ensure!(amount >= min_amount_out, Error::<T>::SlippageLimit);
+			ensure!(amount < reserves[asset_index].amount, Error::<T>::InsufficientLiquidity);
+
+			let pool_account = Self::pool_account(pool_id);
+
+			T::Currency::withdraw(pool_id, &who, shares, ExistenceRequirement::AllowDeath)?;
+			T::Currency::transfer(asset_id, &pool_account, &who, amount, ExistenceRequirement::AllowDeath)?;
+
+			let mut amounts = vec![Balance::zero(); pool.assets.len()];
+			amounts[asset_index] = amount;
+			Self::notify_liquidity_changed(pool_id, &pool, &amounts)?;
+
+			Self::deposit_event(Event::LiquidityRemoved {
+				pool_id,
+				who,
+				shares,
+				amounts: vec![AssetAmount::new(asset_id, amount)],
+				fee,
+			});
+
+			Ok(())
+		}
+
+		/// Remove liquidity from a pool proportionally in all pool assets.
+		///
+		/// No fee is charged. This is the only way to withdraw the last shares of a pool.
+		///
+		/// Parameters:
+		/// - `origin`: liquidity provider.
+		/// - `pool_id`: share asset id of the pool.
+		/// - `shares`: amount of shares to burn.
+		/// - `min_amounts_out`: minimum amounts of assets to receive. Assets which are not listed
+		/// are not limited.
+		///
+		/// Emits `LiquidityRemoved` event when successful.
+		#[pallet::call_index(5)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::remove_liquidity()
+				.saturating_add(liquidity_changed_weight::<T>())
+		)]
+		#[transactional]
+		pub fn remove_liquidity(
+			origin: OriginFor<T>,
+			pool_id: AssetId,
+			shares: Balance,
+			min_amounts_out: Vec<AssetAmount>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+
+			Self::ensure_shares_withdrawable(&who, pool_id, shares)?;
+
+			let pool_account = Self::pool_account(pool_id);
+			let share_issuance = T::Currency::total_issuance(pool_id);
+
+			let mut amounts = Vec::with_capacity(pool.assets.len());
+			for asset in pool.assets.iter() {
+				let reserve = T::Currency::free_balance(*asset, &pool_account);
+				let amount =
+					math::calculate_liquidity_out(reserve, shares, share_issuance).ok_or(ArithmeticError::Overflow)?;
+				amounts.push(amount);
+			}
+
+			for limit in min_amounts_out.iter() {
+				let index = pool.find_asset(limit.asset_id).ok_or(Error::<T>::AssetNotInPool)?;
+				ensure!(amounts[index] >= limit.amount, Error::<T>::SlippageLimit);
+			}
+
+			T::Currency::withdraw(pool_id, &who, shares, ExistenceRequirement::AllowDeath)?;
+			for (asset, amount) in pool.assets.iter().zip(amounts.iter()) {
+				T::Currency::transfer(*asset, &pool_account, &who, *amount, ExistenceRequirement::AllowDeath)?;
+			}
+
+			Self::notify_liquidity_changed(pool_id, &pool, &amounts)?;
+
+			Self::deposit_event(Event::LiquidityRemoved {
+				pool_id,
+				who,
+				shares,
+				amounts: pool
+					.assets
+					.iter()
+					.zip(amounts.iter())
+					.map(|(asset, amount)| AssetAmount::new(*asset, *amount))
+					.collect(),
+				fee: Balance::zero(),
+			});
+
+			Ok(())
+		}
+
+		/// Trade `amount_in` of `asset_in` for `asset_out`.
+		///
+		/// Fee is deducted from the amount of `asset_out` and stays in the pool.
+		///
+		/// Parameters:
+		/// - `origin`: trader.
+		/// - `pool_id`: share asset id of the pool.
+		/// - `asset_in`: asset to sell.
+		/// - `asset_out`: asset to buy.
+		/// - `amount_in`: amount of `asset_in` to sell.
+		/// - `min_buy_amount`: minimum amount of `asset_out` to receive.
+		///
+		/// Emits `pallet_broadcast::Swapped` when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::sell() + <T as Config>::AMMHandler::on_trade_weight())]
+		#[transactional]
+		pub fn sell(
+			origin: OriginFor<T>,
+			pool_id: AssetId,
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_in: Balance,
+			min_buy_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				amount_in >= T::MinTradingLimit::get(),
+				Error::<T>::InsufficientTradingAmount
+			);
+			ensure!(
+				T::Currency::free_balance(asset_in, &who) >= amount_in,
+				Error::<T>::InsufficientBalance
+			);
+
+			let (amount_out, fee) = Self::calculate_sell(pool_id, asset_in, asset_out, amount_in)?;
+
+			ensure!(amount_out >= min_buy_amount, Error::<T>::SlippageLimit);
+
+			Self::execute_trade(
+				&who,
+				pool_id,
+				(asset_in, amount_in),
+				(asset_out, amount_out),
+				(asset_out, fee),
+				pallet_broadcast::types::TradeOperation::ExactIn,
+			)
+		}
+
+		/// Buy `amount_out` of `asset_out` for `asset_in`.
+		///
+		/// Fee is added to the amount of `asset_in` and stays in the pool.
+		///
+		/// Parameters:
+		/// - `origin`: trader.
+		/// - `pool_id`: share asset id of the pool.
+		/// - `asset_out`: asset to buy.
+		/// - `asset_in`: asset to sell.
+		/// - `amount_out`: amount of `asset_out` to buy.
+		/// - `max_sell_amount`: maximum amount of `asset_in` to pay.
+		///
+		/// Emits `pallet_broadcast::Swapped` when successful.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::buy() + <T as Config>::AMMHandler::on_trade_weight())]
+		#[transactional]
+		pub fn buy(
+			origin: OriginFor<T>,
+			pool_id: AssetId,
+			asset_out: AssetId,
+			asset_in: AssetId,
+			amount_out: Balance,
+			max_sell_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				amount_out >= T::MinTradingLimit::get(),
+				Error::<T>::InsufficientTradingAmount
+			);
+
+			let (amount_in, fee) = Self::calculate_buy(pool_id, asset_in, asset_out, amount_out)?;
+
+			ensure!(amount_in <= max_sell_amount, Error::<T>::SlippageLimit);
+			ensure!(
+				T::Currency::free_balance(asset_in, &who) >= amount_in,
+				Error::<T>::InsufficientBalance
+			);
+
+			Self::execute_trade(
+				&who,
+				pool_id,
+				(asset_in, amount_in),
+				(asset_out, amount_out),
+				(asset_in, fee),
+				pallet_broadcast::types::TradeOperation::ExactOut,
+			)
+		}
+	}
+}
+
+/// Weight of notifying the oracle about liquidity change of all asset pairs of the largest pool.
+pub fn liquidity_changed_weight<T: Config>() -> Weight {
+	let max_pairs = MAX_ASSETS_IN_POOL.saturating_mul(MAX_ASSETS_IN_POOL.saturating_sub(1)) / 2;
+	T::AMMHandler::on_liquidity_changed_weight().saturating_mul(max_pairs.into())
+}
+
+impl<T: Config> Pallet<T> {
+	/// Account holding reserves of the pool.
+	pub fn pool_account(pool_id: AssetId) -> T::AccountId {
+		T::PalletId::get().into_sub_account_truncating(pool_id)
+	}
+
+	/// Current amplification of the pool.
+	pub fn get_amplification(pool: &PoolInfo<BlockNumberFor<T>>) -> Balance {
+		math::calculate_amplification(
+			pool.initial_amplification.into(),
+			pool.final_amplification.into(),
+			pool.initial_block.saturated_into(),
+			pool.final_block.saturated_into(),
+			T::BlockNumberProvider::current_block_number().saturated_into(),
+		)
+	}
+
+	/// Reserves of the pool assets, in the order of `pool.assets`.
+	pub fn get_reserves(
+		pool_id: AssetId,
+		pool: &PoolInfo<BlockNumberFor<T>>,
+	) -> Result<Vec<AssetReserve>, DispatchError> {
+		let pool_account = Self::pool_account(pool_id);
+
+		pool.assets
+			.iter()
+			.map(|asset| {
+				let decimals = T::AssetRegistry::decimals(*asset).ok_or(Error::<T>::AssetDecimalsNotFound)?;
+				Ok(AssetReserve::new(
+					T::Currency::free_balance(*asset, &pool_account),
+					decimals,
+				))
+			})
+			.collect()
+	}
+
+	/// Calculate amount of `asset_out` received for `amount_in` of `asset_in`.
+	///
+	/// Returns amount out with the fee deducted and the fee, both in `asset_out`.
+	pub fn calculate_sell(
+		pool_id: AssetId,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+	) -> Result<(Balance, Balance), DispatchError> {
+		let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+		let index_in = pool.find_asset(asset_in).ok_or(Error::<T>::AssetNotInPool)?;
+		let index_out = pool.find_asset(asset_out).ok_or(Error::<T>::AssetNotInPool)?;
+		ensure!(index_in != index_out, Error::<T>::IncorrectAssets);
+
+		let reserves = Self::get_reserves(pool_id, &pool)?;
+		ensure!(reserves.iter().all(|r| !r.is_zero()), Error::<T>::InsufficientLiquidity);
+
+		let (amount_out, fee) = math::calculate_out_given_in_with_fee::<D_ITERATIONS, Y_ITERATIONS>(
+			&reserves,
+			index_in,
+			index_out,
+			amount_in,
+			Self::get_amplification(&pool),
+			pool.fee,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		ensure!(!amount_out.is_zero(), Error::<T>::InvalidAssetAmount);
+		ensure!(
+			amount_out < reserves[index_out].amount,
+			Error::<T>::InsufficientLiquidity
+		);
+
+		Ok((amount_out, fee))
+	}
+
+	/// Calculate amount of `asset_in` required to buy `amount_out` of `asset_out`.
+	///
+	/// Returns amount in including the fee and the fee, both in `asset_in`.
+	pub fn calculate_buy(
+		pool_id: AssetId,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_out: Balance,
+	) -> Result<(Balance, Balance), DispatchError> {
+		let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+		let index_in = pool.find_asset(asset_in).ok_or(Error::<T>::AssetNotInPool)?;
+		let index_out = pool.find_asset(asset_out).ok_or(Error::<T>::AssetNotInPool)?;
+		ensure!(index_in != index_out, Error::<T>::IncorrectAssets);
+
+		let reserves = Self::get_reserves(pool_id, &pool)?;
+		ensure!(reserves.iter().all(|r| !r.is_zero()), Error::<T>::InsufficientLiquidity);
+		ensure!(
+			amount_out < reserves[index_out].amount,
+			Error::<T>::InsufficientLiquidity
+		);
+
+		let (amount_in, fee) = math::calculate_in_given_out_with_fee::<D_ITERATIONS, Y_ITERATIONS>(
+			&reserves,
+			index_in,
+			index_out,
+			amount_out,
+			Self::get_amplification(&pool),
+			pool.fee,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		ensure!(!amount_in.is_zero(), Error::<T>::InvalidAssetAmount);
+
+		Ok((amount_in, fee))
+	}
+
+	/// Spot price of `asset_out` denominated in `asset_in`, as a rational number.
+	pub fn calculate_spot_price(
+		pool_id: AssetId,
+		asset_in: AssetId,
+		asset_out: AssetId,
+	) -> Result<(Balance, Balance), DispatchError> {
+		let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+		let index_in = pool.find_asset(asset_in).ok_or(Error::<T>::AssetNotInPool)?;
+		let index_out = pool.find_asset(asset_out).ok_or(Error::<T>::AssetNotInPool)?;
+
+		let reserves = Self::get_reserves(pool_id, &pool)?;
+
+		math::calculate_spot_price::<D_ITERATIONS>(&reserves, index_in, index_out, Self::get_amplification(&pool))
+			.ok_or_else(|| ArithmeticError::Overflow.into())
+	}
+
+	fn do_add_liquidity(
+		who: &T::AccountId,
+		pool_id: AssetId,
+		assets: &[AssetAmount],
+		min_shares: Balance,
+	) -> Result<Balance, DispatchError> {
+		let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+
+		ensure!(
+			!assets.is_empty() && assets.len() <= pool.assets.len(),
+			Error::<T>::IncorrectAssets
+		);
+
+		let initial_reserves = Self::get_reserves(pool_id, &pool)?;
+		let mut updated_reserves = initial_reserves.clone();
+		let mut amounts = vec![Balance::zero(); pool.assets.len()];
+
+		for asset in assets.iter() {
+			let index = pool.find_asset(asset.asset_id).ok_or(Error::<T>::AssetNotInPool)?;
+			ensure!(amounts[index].is_zero(), Error::<T>::IncorrectAssets);
+			ensure!(
+				asset.amount >= T::MinTradingLimit::get(),
+				Error::<T>::InsufficientTradingAmount
+			);
+			ensure!(
+				T::Currency::free_balance(asset.asset_id, who) >= asset.amount,
+				Error::<T>::InsufficientBalance
+			);
+
+			amounts[index] = asset.amount;
+			updated_reserves[index].amount = updated_reserves[index]
+				.amount
+				.checked_add(asset.amount)
+				.ok_or(ArithmeticError::Overflow)?;
+		}
+
+		let share_issuance = T::Currency::total_issuance(pool_id);
+		if share_issuance.is_zero() {
+			ensure!(
+				updated_reserves.iter().all(|r| !r.is_zero()),
+				Error::<T>::InsufficientInitialLiquidity
+			);
+		}
+
+		let shares = math::calculate_shares::<D_ITERATIONS>(
+			&initial_reserves,
+			&updated_reserves,
+			Self::get_amplification(&pool),
+			share_issuance,
+			pool.fee,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		ensure!(!shares.is_zero(), Error::<T>::InvalidAssetAmount);
+		ensure!(shares >= min_shares, Error::<T>::SlippageLimit);
+
+		// Make sure that account share liquidity is at least MinPoolLiquidity
+		ensure!(
+			T::Currency::free_balance(pool_id, who)
+				.checked_add(shares)
+				.ok_or(ArithmeticError::Overflow)?
+				>= T::MinPoolLiquidity::get(),
+			Error::<T>::InsufficientShares
+		);
+
+		let pool_account = Self::pool_account(pool_id);
+		for asset in assets.iter() {
+			T::Currency::transfer(
+				asset.asset_id,
+				who,
+				&pool_account,
+				asset.amount,
+				ExistenceRequirement::AllowDeath,
+			)?;
+		}
+
+		T::Currency::deposit(pool_id, who, shares)?;
+
+		Self::notify_liquidity_changed(pool_id, &pool, &amounts)?;
+
+		Self::deposit_event(Event::LiquidityAdded {
+			pool_id,
+			who: who.clone(),
+			shares,
+			assets: assets.to_vec(),
+		});
+
+		Ok(shares)
+	}
+
+	/// Account has to hold `shares` and be left with either no shares or at least `MinPoolLiquidity`.
+	fn ensure_shares_withdrawable(who: &T::AccountId, pool_id: AssetId, shares: Balance) -> DispatchResult {
+		ensure!(!shares.is_zero(), Error::<T>::InvalidAssetAmount);
+
+		let account_shares = T::Currency::free_balance(pool_id, who);
+		ensure!(account_shares >= shares, Error::<T>::InsufficientShares);
+
+		let remaining = account_shares.saturating_sub(shares);
+		ensure!(
+			remaining.is_zero() || remaining >= T::MinPoolLiquidity::get(),
+			Error::<T>::InsufficientShares
+		);
+
+		Ok(())
+	}
+
+	fn execute_trade(
+		who: &T::AccountId,
+		pool_id: AssetId,
+		(asset_in, amount_in): (AssetId, Balance),
+		(asset_out, amount_out): (AssetId, Balance),
+		(fee_asset, fee): (AssetId, Balance),
+		operation: pallet_broadcast::types::TradeOperation,
+	) -> DispatchResult {
+		let pool_account = Self::pool_account(pool_id);
+
+		T::Currency::transfer(
+			asset_in,
+			who,
+			&pool_account,
+			amount_in,
+			ExistenceRequirement::AllowDeath,
+		)?;
+		T::Currency::transfer(
+			asset_out,
+			&pool_account,
+			who,
+			amount_out,
+			ExistenceRequirement::AllowDeath,
+		)?;
+
+		let (price_n, price_d) = Self::calculate_spot_price(pool_id, asset_in, asset_out)?;
+		T::AMMHandler::on_trade(
+			T::OracleSource::get(),
+			asset_in,
+			asset_out,
+			amount_in,
+			amount_out,
+			T::Currency::total_balance(asset_in, &pool_account),
+			T::Currency::total_balance(asset_out, &pool_account),
+			Ratio::new(price_n, price_d),
+		)
+		.map_err(|(_w, e)| e)?;
+
+		pallet_broadcast::Pallet::<T>::deposit_trade_event(
+			who.clone(),
+			pool_account.clone(),
+			pallet_broadcast::types::Filler::Stableswap(pool_id),
+			operation,
+			vec![Asset::new(asset_in, amount_in)],
+			vec![Asset::new(asset_out, amount_out)],
+			vec![Fee {
+				asset: fee_asset,
+				amount: fee,
+				destination: Destination::Account(pool_account),
+			}],
+		);
+
+		Ok(())
+	}
+
+	/// Notify the oracle about the new state of all asset pairs of the pool.
+	///
+	/// `amounts` are the changes of the reserves, in the order of `pool.assets`.
+	fn notify_liquidity_changed(
+		pool_id: AssetId,
+		pool: &PoolInfo<BlockNumberFor<T>>,
+		amounts: &[Balance],
+	) -> DispatchResult {
+		let reserves = Self::get_reserves(pool_id, pool)?;
+
+		// The pool was emptied, there is no price to report.
+		if reserves.iter().any(|r| r.is_zero()) {
+			return Ok(());
+		}
+
+		let amplification = Self::get_amplification(pool);
+
+		for a in 0..pool.assets.len() {
+			for b in a.saturating_add(1)..pool.assets.len() {
+				let (price_n, price_d) = math::calculate_spot_price::<D_ITERATIONS>(&reserves, a, b, amplification)
+					.ok_or(ArithmeticError::Overflow)?;
+
+				T::AMMHandler::on_liquidity_changed(
+					T::OracleSource::get(),
+					pool.assets[a],
+					pool.assets[b],
+					amounts[a],
+					amounts[b],
+					reserves[a].amount,
+					reserves[b].amount,
+					Ratio::new(price_n, price_d),
+				)
+				.map_err(|(_w, e)| e)?;
+			}
+		}
+
+		Ok(())
+	}
+
+	fn ensure_amplification(amplification: u16) -> DispatchResult {
+		ensure!(
+			amplification >= T::MinAmplification::get() && amplification <= T::MaxAmplification::get(),
+			Error::<T>::InvalidAmplification
+		);
+
+		Ok(())
+	}
+}
+
+impl<T: Config> StableswapPoolProvider<AssetId, Balance> for Pallet<T> {
+	type Error = DispatchError;
+
+	fn pool_assets(pool_id: AssetId) -> Option<Vec<AssetId>> {
+		Pools::<T>::get(pool_id).map(|pool| pool.assets.into_inner())
+	}
+
+	fn shares_value(pool_id: AssetId, asset: AssetId, shares: Balance) -> Result<Balance, Self::Error> {
+		let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+		ensure!(pool.find_asset(asset).is_some(), Error::<T>::AssetNotInPool);
+
+		let decimals = T::AssetRegistry::decimals(asset).ok_or(Error::<T>::AssetDecimalsNotFound)?;
+		let reserves = Self::get_reserves(pool_id, &pool)?;
+
+		math::calculate_shares_value::<D_ITERATIONS>(
+			&reserves,
+			shares,
+			T::Currency::total_issuance(pool_id),
+			Self::get_amplification(&pool),
+			decimals,
+		)
+		.ok_or_else(|| ArithmeticError::Overflow.into())
+	}
+}
@@ -0,0 +1,159 @@
+pub use super::mock::*;
+use crate::types::AssetAmount;
+use crate::{Error, Event, Pools};
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiCurrency;
+use sp_runtime::{DispatchError, Permill};
+
+#[test]
+fn update_amplification_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = create_pool(vec![USDT, USDC], 100, Permill::zero());
+
+		assert_ok!(Stableswap::update_amplification(
+			RuntimeOrigin::root(),
+			pool_id,
+			1_000,
+			10,
+			20
+		));
+
+		let pool = Pools::<Test>::get(pool_id).unwrap();
+		assert_eq!(pool.initial_amplification, 100);
+		assert_eq!(pool.final_amplification, 1_000);
+		assert_eq!(pool.initial_block, 10);
+		assert_eq!(pool.final_block, 20);
+
+		expect_events(vec![Event::AmplificationChanging {
+			pool_id,
+			current_amplification: 100,
+			final_amplification: 1_000,
+			start_block: 10,
+			end_block: 20,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn amplification_should_change_linearly() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = create_pool(vec![USDT, USDC], 100, Permill::zero());
+		assert_ok!(Stableswap::update_amplification(
+			RuntimeOrigin::root(),
+			pool_id,
+			1_000,
+			10,
+			20
+		));
+
+		let amplification_at = |block: u64| {
+			System::set_block_number(block);
+			Stableswap::get_amplification(&Pools::<Test>::get(pool_id).unwrap())
+		};
+
+		assert_eq!(amplification_at(5), 100);
+		assert_eq!(amplification_at(10), 100);
+		assert_eq!(amplification_at(15), 550);
+		assert_eq!(amplification_at(20), 1_000);
+		assert_eq!(amplification_at(30), 1_000);
+	});
+}
+
+#[test]
+fn update_amplification_should_start_from_current_amplification() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = create_pool(vec![USDT, USDC], 100, Permill::zero());
+		assert_ok!(Stableswap::update_amplification(
+			RuntimeOrigin::root(),
+			pool_id,
+			1_000,
+			10,
+			20
+		));
+
+		System::set_block_number(15);
+		assert_ok!(Stableswap::update_amplification(
+			RuntimeOrigin::root(),
+			pool_id,
+			200,
+			15,
+			25
+		));
+
+		let pool = Pools::<Test>::get(pool_id).unwrap();
+		assert_eq!(pool.initial_amplification, 550);
+		assert_eq!(pool.final_amplification, 200);
+	});
+}
+
+#[test]
+fn update_amplification_should_fail_when_blocks_are_incorrect() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = create_pool(vec![USDT, USDC], 100, Permill::zero());
+		System::set_block_number(10);
+
+		assert_noop!(
+			Stableswap::update_amplification(RuntimeOrigin::root(), pool_id, 1_000, 9, 20),
+			Error::<Test>::IncorrectAmplificationBlocks
+		);
+		assert_noop!(
+			Stableswap::update_amplification(RuntimeOrigin::root(), pool_id, 1_000, 20, 20),
+			Error::<Test>::IncorrectAmplificationBlocks
+		);
+	});
+}
+
+#[test]
+fn update_amplification_should_fail_when_amplification_is_out_of_range() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = create_pool(vec![USDT, USDC], 100, Permill::zero());
+
+		assert_noop!(
+			Stableswap::update_amplification(RuntimeOrigin::root(), pool_id, 10_001, 10, 20),
+			Error::<Test>::InvalidAmplification
+		);
+		assert_noop!(
+			Stableswap::update_amplification(RuntimeOrigin::signed(ALICE), pool_id, 1_000, 10, 20),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn higher_amplification_should_result_in_lower_slippage() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = create_pool(vec![USDT, USDC], 2, Permill::zero());
+		assert_ok!(Stableswap::add_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			vec![
+				AssetAmount::new(USDT, 100_000 * ONE),
+				AssetAmount::new(USDC, 50_000 * ONE),
+			],
+			0,
+		));
+
+		let (low_amp_out, _) = Stableswap::calculate_sell(pool_id, USDT, USDC, 1_000 * ONE).unwrap();
+		assert_eq!(low_amp_out, 852_206_893_048_308);
+
+		assert_ok!(Stableswap::update_amplification(
+			RuntimeOrigin::root(),
+			pool_id,
+			1_000,
+			2,
+			3
+		));
+		System::set_block_number(3);
+
+		assert_ok!(Stableswap::sell(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			USDT,
+			USDC,
+			1_000 * ONE,
+			0
+		));
+		assert_eq!(Tokens::free_balance(USDC, &BOB), 1_000_000 * ONE + 999_565_671_571_763);
+	});
+}
@@ -0,0 +1,163 @@
+pub use super::mock::*;
+use crate::types::pool_share_name;
+use crate::{Error, Event, Pools};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::{DispatchError, Permill};
+
+#[test]
+fn create_pool_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Stableswap::create_pool(
+			RuntimeOrigin::root(),
+			vec![USDC, USDT],
+			100,
+			Permill::from_percent(1),
+		));
+
+		let pool_id =
+			AssetRegistry::asset_ids(AssetRegistry::to_bounded_name(pool_share_name(&[USDT, USDC])).unwrap()).unwrap();
+
+		let pool = Pools::<Test>::get(pool_id).unwrap();
+		assert_eq!(pool.assets.to_vec(), vec![USDT, USDC]);
+		assert_eq!(pool.initial_amplification, 100);
+		assert_eq!(pool.final_amplification, 100);
+		assert_eq!(pool.fee, Permill::from_percent(1));
+
+		let share_metadata = AssetRegistry::asset_metadata(pool_id).unwrap();
+		assert_eq!(share_metadata.symbol.to_vec(), b"STBLP".to_vec());
+		assert_eq!(share_metadata.decimals, 18);
+
+		assert!(WHITELISTED_ACCOUNTS.with(|v| v.borrow().contains(&Stableswap::pool_account(pool_id))));
+
+		expect_events(vec![Event::PoolCreated {
+			pool_id,
+			assets: vec![USDT, USDC],
+			amplification: 100,
+			fee: Permill::from_percent(1),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_origin_is_not_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Stableswap::create_pool(RuntimeOrigin::signed(ALICE), vec![USDT, USDC], 100, Permill::zero()),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_assets_are_not_unique() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Stableswap::create_pool(RuntimeOrigin::root(), vec![USDT, USDT], 100, Permill::zero()),
+			Error::<Test>::IncorrectAssets
+		);
+		assert_noop!(
+			Stableswap::create_pool(RuntimeOrigin::root(), vec![USDT], 100, Permill::zero()),
+			Error::<Test>::IncorrectAssets
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_too_many_assets() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Stableswap::create_pool(
+				RuntimeOrigin::root(),
+				vec![HDX, USDT, USDC, DAI, BTC, 5],
+				100,
+				Permill::zero()
+			),
+			Error::<Test>::MaxAssetsExceeded
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_asset_is_not_registered() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Stableswap::create_pool(RuntimeOrigin::root(), vec![USDT, BTC], 100, Permill::zero()),
+			Error::<Test>::AssetNotRegistered
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_asset_has_no_decimals() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AssetRegistry::register_asset(
+			AssetRegistry::to_bounded_name(b"BTC".to_vec()).unwrap(),
+			pallet_asset_registry::AssetType::Token,
+			1u128,
+			Some(BTC),
+			None,
+		));
+
+		assert_noop!(
+			Stableswap::create_pool(RuntimeOrigin::root(), vec![USDT, BTC], 100, Permill::zero()),
+			Error::<Test>::AssetDecimalsNotFound
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_amplification_is_out_of_range() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Stableswap::create_pool(RuntimeOrigin::root(), vec![USDT, USDC], 1, Permill::zero()),
+			Error::<Test>::InvalidAmplification
+		);
+		assert_noop!(
+			Stableswap::create_pool(RuntimeOrigin::root(), vec![USDT, USDC], 10_001, Permill::zero()),
+			Error::<Test>::InvalidAmplification
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_pool_exists() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_pool(vec![USDT, USDC], 100, Permill::zero());
+
+		assert_noop!(
+			Stableswap::create_pool(RuntimeOrigin::root(), vec![USDC, USDT], 200, Permill::zero()),
+			Error::<Test>::PoolExists
+		);
+	});
+}
+
+#[test]
+fn update_pool_fee_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = create_pool(vec![USDT, USDC], 100, Permill::zero());
+
+		assert_ok!(Stableswap::update_pool_fee(
+			RuntimeOrigin::root(),
+			pool_id,
+			Permill::from_percent(2)
+		));
+
+		assert_eq!(Pools::<Test>::get(pool_id).unwrap().fee, Permill::from_percent(2));
+		expect_events(vec![Event::FeeUpdated {
+			pool_id,
+			fee: Permill::from_percent(2),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn update_pool_fee_should_fail_when_pool_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Stableswap::update_pool_fee(RuntimeOrigin::root(), 1_000_000, Permill::from_percent(2)),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
@@ -0,0 +1,320 @@
+pub use super::mock::*;
+use crate::types::AssetAmount;
+use crate::{Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiCurrency;
+use sp_runtime::Permill;
+
+fn pool_with_liquidity() -> AssetId {
+	let pool_id = create_pool(vec![USDT, USDC, DAI], 100, Permill::from_percent(1));
+
+	assert_ok!(Stableswap::add_liquidity(
+		RuntimeOrigin::signed(ALICE),
+		pool_id,
+		vec![
+			AssetAmount::new(USDT, 100_000 * ONE),
+			AssetAmount::new(USDC, 100_000 * ONE),
+			AssetAmount::new(DAI, 100_000 * ONE),
+		],
+		0,
+	));
+
+	pool_id
+}
+
+#[test]
+fn add_initial_liquidity_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = pool_with_liquidity();
+		let pool_account = Stableswap::pool_account(pool_id);
+
+		assert_eq!(Tokens::free_balance(USDT, &pool_account), 100_000 * ONE);
+		assert_eq!(Tokens::free_balance(USDC, &pool_account), 100_000 * ONE);
+		assert_eq!(Tokens::free_balance(DAI, &pool_account), 100_000 * ONE);
+		assert_eq!(Tokens::free_balance(USDT, &ALICE), 900_000 * ONE);
+
+		// Balanced liquidity of 12 decimals assets is equal to D in 18 decimals.
+		let shares = Tokens::free_balance(pool_id, &ALICE);
+		assert_eq!(shares, 300_000 * ONE * 1_000_000);
+		assert_eq!(Tokens::total_issuance(pool_id), shares);
+
+		expect_events(vec![Event::LiquidityAdded {
+			pool_id,
+			who: ALICE,
+			shares,
+			assets: vec![
+				AssetAmount::new(USDT, 100_000 * ONE),
+				AssetAmount::new(USDC, 100_000 * ONE),
+				AssetAmount::new(DAI, 100_000 * ONE),
+			],
+		}
+		.into()]);
+
+		// Oracle is notified about all pairs of the pool.
+		ORACLE_LIQUIDITY_CHANGES.with(|v| {
+			assert_eq!(*v.borrow(), vec![(USDT, USDC), (USDT, DAI), (USDC, DAI)]);
+		});
+	});
+}
+
+#[test]
+fn add_initial_liquidity_should_fail_when_not_all_assets_are_provided() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = create_pool(vec![USDT, USDC, DAI], 100, Permill::zero());
+
+		assert_noop!(
+			Stableswap::add_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				vec![
+					AssetAmount::new(USDT, 100_000 * ONE),
+					AssetAmount::new(USDC, 100_000 * ONE),
+				],
+				0,
+			),
+			Error::<Test>::InsufficientInitialLiquidity
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_asset_is_not_in_pool() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = pool_with_liquidity();
+
+		assert_noop!(
+			Stableswap::add_liquidity(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				vec![AssetAmount::new(BTC, 100 * ONE)],
+				0,
+			),
+			Error::<Test>::AssetNotInPool
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_asset_is_duplicated() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = pool_with_liquidity();
+
+		assert_noop!(
+			Stableswap::add_liquidity(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				vec![AssetAmount::new(USDT, 100 * ONE), AssetAmount::new(USDT, 100 * ONE)],
+				0,
+			),
+			Error::<Test>::IncorrectAssets
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_balance_is_insufficient() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = pool_with_liquidity();
+
+		assert_noop!(
+			Stableswap::add_liquidity(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				vec![AssetAmount::new(USDT, 2_000_000 * ONE)],
+				0,
+			),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn imbalanced_liquidity_should_receive_less_shares_than_balanced() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = pool_with_liquidity();
+
+		assert_ok!(Stableswap::add_liquidity(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			vec![AssetAmount::new(USDT, 300 * ONE)],
+			0,
+		));
+		let imbalanced_shares = Tokens::free_balance(pool_id, &BOB);
+
+		assert_ok!(Stableswap::add_liquidity(
+			RuntimeOrigin::signed(CHARLIE),
+			pool_id,
+			vec![AssetAmount::new(USDT, 100 * ONE)],
+			0,
+		));
+
+		let balanced_shares = 300 * ONE * 1_000_000;
+		assert!(imbalanced_shares < balanced_shares);
+		assert!(imbalanced_shares > balanced_shares * 99 / 100);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_min_shares_is_not_reached() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = pool_with_liquidity();
+
+		assert_noop!(
+			Stableswap::add_liquidity(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				vec![AssetAmount::new(USDT, 300 * ONE)],
+				300 * ONE * 1_000_000,
+			),
+			Error::<Test>::SlippageLimit
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_return_proportional_amounts() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = pool_with_liquidity();
+		let shares = Tokens::free_balance(pool_id, &ALICE);
+
+		assert_ok!(Stableswap::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			shares / 2,
+			vec![AssetAmount::new(USDT, 50_000 * ONE)],
+		));
+
+		assert_eq!(Tokens::free_balance(USDT, &ALICE), 950_000 * ONE);
+		assert_eq!(Tokens::free_balance(USDC, &ALICE), 950_000 * ONE);
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), 950_000 * ONE);
+		assert_eq!(Tokens::free_balance(pool_id, &ALICE), shares / 2);
+
+		expect_events(vec![Event::LiquidityRemoved {
+			pool_id,
+			who: ALICE,
+			shares: shares / 2,
+			amounts: vec![
+				AssetAmount::new(USDT, 50_000 * ONE),
+				AssetAmount::new(USDC, 50_000 * ONE),
+				AssetAmount::new(DAI, 50_000 * ONE),
+			],
+			fee: 0,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_allow_to_withdraw_all_shares() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = pool_with_liquidity();
+		let shares = Tokens::free_balance(pool_id, &ALICE);
+
+		assert_ok!(Stableswap::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			shares,
+			vec![],
+		));
+
+		let pool_account = Stableswap::pool_account(pool_id);
+		assert_eq!(Tokens::free_balance(USDT, &pool_account), 0);
+		assert_eq!(Tokens::total_issuance(pool_id), 0);
+		assert_eq!(Tokens::free_balance(USDT, &ALICE), 1_000_000 * ONE);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_min_amount_is_not_reached() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = pool_with_liquidity();
+		let shares = Tokens::free_balance(pool_id, &ALICE);
+
+		assert_noop!(
+			Stableswap::remove_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				shares / 2,
+				vec![AssetAmount::new(DAI, 50_000 * ONE + 1)],
+			),
+			Error::<Test>::SlippageLimit
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_remaining_shares_are_below_min_pool_liquidity() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = pool_with_liquidity();
+		let shares = Tokens::free_balance(pool_id, &ALICE);
+
+		assert_noop!(
+			Stableswap::remove_liquidity(RuntimeOrigin::signed(ALICE), pool_id, shares - 1, vec![]),
+			Error::<Test>::InsufficientShares
+		);
+		assert_noop!(
+			Stableswap::remove_liquidity(RuntimeOrigin::signed(ALICE), pool_id, shares + 1, vec![]),
+			Error::<Test>::InsufficientShares
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_one_asset_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = pool_with_liquidity();
+
+		assert_ok!(Stableswap::add_liquidity(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			vec![AssetAmount::new(USDT, 100 * ONE)],
+			0,
+		));
+		let shares = Tokens::free_balance(pool_id, &BOB);
+
+		assert_ok!(Stableswap::remove_liquidity_one_asset(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			USDC,
+			shares,
+			0,
+		));
+
+		// Fees are charged on both imbalanced operations.
+		let received = Tokens::free_balance(USDC, &BOB) - 1_000_000 * ONE;
+		assert!(received < 100 * ONE);
+		assert!(received > 99 * ONE);
+		assert_eq!(Tokens::free_balance(pool_id, &BOB), 0);
+	});
+}
+
+#[test]
+fn remove_liquidity_one_asset_should_fail_when_withdrawing_all_shares() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = pool_with_liquidity();
+		let shares = Tokens::free_balance(pool_id, &ALICE);
+
+		assert_noop!(
+			Stableswap::remove_liquidity_one_asset(RuntimeOrigin::signed(ALICE), pool_id, USDT, shares, 0),
+			Error::<Test>::InsufficientLiquidityRemaining
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_one_asset_should_fail_when_min_amount_is_not_reached() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = pool_with_liquidity();
+
+		assert_noop!(
+			Stableswap::remove_liquidity_one_asset(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				USDT,
+				100 * ONE * 1_000_000,
+				100 * ONE,
+			),
+			Error::<Test>::SlippageLimit
+		);
+	});
+}
@@ -0,0 +1,338 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as stableswap;
+use crate::*;
+use frame_support::{assert_ok, parameter_types};
+use frame_system as system;
+use frame_system::{EnsureRoot, EnsureSigned};
+use orml_traits::parameter_type_with_key;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup, One},
+	BuildStorage,
+};
+
+use crate::types::{AssetId, Balance};
+use frame_support::traits::{Everything, Nothing};
+use hydradx_traits::{pools::DustRemovalAccountWhitelist, Source};
+use std::cell::RefCell;
+
+pub type Amount = i128;
+pub type AccountId = u64;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const CHARLIE: AccountId = 3;
+
+pub const HDX: AssetId = 0;
+pub const USDT: AssetId = 1;
+pub const USDC: AssetId = 2;
+pub const DAI: AssetId = 3;
+pub const BTC: AssetId = 4;
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+type AssetLocation = u8;
+
+frame_support::construct_runtime!(
+	pub enum Test
+	 {
+		 System: frame_system,
+		 Stableswap: stableswap,
+		 Tokens: orml_tokens,
+		 AssetRegistry: pallet_asset_registry,
+		 Broadcast: pallet_broadcast,
+	 }
+);
+
+thread_local! {
+	pub static ORACLE_TRADES: RefCell<Vec<(AssetId, AssetId, Balance, Balance)>> = const { RefCell::new(vec![]) };
+	pub static ORACLE_LIQUIDITY_CHANGES: RefCell<Vec<(AssetId, AssetId)>> = const { RefCell::new(vec![]) };
+	pub static WHITELISTED_ACCOUNTS: RefCell<Vec<AccountId>> = const { RefCell::new(vec![]) };
+}
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const NativeAssetId: AssetId = HDX;
+	#[derive(PartialEq, Debug)]
+	pub RegistryStringLimit: u32 = 100;
+	pub const SequentialIdOffset: u32 = 1_000_000;
+	pub const ExternalAssetDeposit: Balance = 1_000;
+	pub const MaxExternalRegistrationsPerBlock: u32 = 1;
+	pub const MetadataUrlLimit: u32 = 128;
+}
+
+impl pallet_asset_registry::Config for Test {
+	type RegistryOrigin = EnsureSigned<AccountId>;
+	type AssetId = AssetId;
+	type Balance = Balance;
+	type AssetNativeLocation = AssetLocation;
+	type StringLimit = RegistryStringLimit;
+	type MetadataUrlLimit = MetadataUrlLimit;
+	type SequentialIdStartAt = SequentialIdOffset;
+	type NativeAssetId = NativeAssetId;
+	type Currency = orml_tokens::CurrencyAdapter<Test, NativeAssetId>;
+	type ExternalAssetDeposit = ExternalAssetDeposit;
+	type MaxExternalRegistrationsPerBlock = MaxExternalRegistrationsPerBlock;
+	type WeightInfo = ();
+}
+
+impl system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeTask = RuntimeTask;
+	type Nonce = u64;
+	type Block = Block;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type SingleBlockMigrations = ();
+	type MultiBlockMigrator = ();
+	type PreInherents = ();
+	type PostInherents = ();
+	type PostTransactions = ();
+	type ExtensionsWeightInfo = ();
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		One::one()
+	};
+}
+
+impl orml_tokens::Config for Test {
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = ();
+	type DustRemovalWhitelist = Nothing;
+	type ReserveIdentifier = ();
+	type MaxReserves = ();
+	type CurrencyHooks = ();
+}
+
+pub struct MockAMMHandler;
+
+impl basilisk_traits::OnTradeHandler<AssetId, Balance, basilisk_math::ratio::Ratio> for MockAMMHandler {
+	fn on_trade(
+		_source: Source,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		amount_a: Balance,
+		amount_b: Balance,
+		_liquidity_a: Balance,
+		_liquidity_b: Balance,
+		_price: basilisk_math::ratio::Ratio,
+	) -> Result<frame_support::weights::Weight, (frame_support::weights::Weight, sp_runtime::DispatchError)> {
+		ORACLE_TRADES.with(|v| v.borrow_mut().push((asset_a, asset_b, amount_a, amount_b)));
+		Ok(frame_support::weights::Weight::zero())
+	}
+
+	fn on_trade_weight() -> frame_support::weights::Weight {
+		frame_support::weights::Weight::zero()
+	}
+}
+
+impl basilisk_traits::OnLiquidityChangedHandler<AssetId, Balance, basilisk_math::ratio::Ratio> for MockAMMHandler {
+	fn on_liquidity_changed(
+		_source: Source,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		_amount_a: Balance,
+		_amount_b: Balance,
+		_liquidity_a: Balance,
+		_liquidity_b: Balance,
+		_price: basilisk_math::ratio::Ratio,
+	) -> Result<frame_support::weights::Weight, (frame_support::weights::Weight, sp_runtime::DispatchError)> {
+		ORACLE_LIQUIDITY_CHANGES.with(|v| v.borrow_mut().push((asset_a, asset_b)));
+		Ok(frame_support::weights::Weight::zero())
+	}
+
+	fn on_liquidity_changed_weight() -> frame_support::weights::Weight {
+		frame_support::weights::Weight::zero()
+	}
+}
+
+pub struct Whitelist;
+
+impl DustRemovalAccountWhitelist<AccountId> for Whitelist {
+	type Error = DispatchError;
+
+	fn add_account(account: &AccountId) -> Result<(), Self::Error> {
+		WHITELISTED_ACCOUNTS.with(|v| v.borrow_mut().push(*account));
+		Ok(())
+	}
+
+	fn remove_account(account: &AccountId) -> Result<(), Self::Error> {
+		WHITELISTED_ACCOUNTS.with(|v| v.borrow_mut().retain(|a| a != account));
+		Ok(())
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct MockBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<AssetId> for MockBenchmarkHelper {
+	fn register_asset(asset_id: AssetId, decimals: u8) -> DispatchResult {
+		register_asset(asset_id, decimals);
+		Ok(())
+	}
+}
+
+impl pallet_broadcast::Config for Test {}
+
+parameter_types! {
+	pub const StableswapPalletId: PalletId = PalletId(*b"stblswap");
+	pub const MinTradingLimit: Balance = 1_000;
+	pub const MinPoolLiquidity: Balance = 1_000;
+	pub const MinAmplification: u16 = 2;
+	pub const MaxAmplification: u16 = 10_000;
+	pub const OracleSourceIdentifier: Source = *b"snek/stb";
+}
+
+impl Config for Test {
+	type AssetRegistry = AssetRegistry;
+	type Currency = Tokens;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type PalletId = StableswapPalletId;
+	type MinPoolLiquidity = MinPoolLiquidity;
+	type MinTradingLimit = MinTradingLimit;
+	type MinAmplification = MinAmplification;
+	type MaxAmplification = MaxAmplification;
+	type OracleSource = OracleSourceIdentifier;
+	type AMMHandler = MockAMMHandler;
+	type NonDustableWhitelistHandler = Whitelist;
+	type BlockNumberProvider = System;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = MockBenchmarkHelper;
+}
+
+/// Register `asset_id` with given decimals, unless it is already registered.
+pub fn register_asset(asset_id: AssetId, decimals: u8) {
+	if !pallet_asset_registry::Assets::<Test>::contains_key(asset_id) {
+		let name = AssetRegistry::to_bounded_name(asset_id.to_le_bytes().to_vec()).unwrap();
+		assert_ok!(AssetRegistry::register_asset(
+			name,
+			pallet_asset_registry::AssetType::Token,
+			1u128,
+			Some(asset_id),
+			None,
+		));
+	}
+	assert_ok!(AssetRegistry::set_metadata(
+		RuntimeOrigin::signed(ALICE),
+		asset_id,
+		b"xTKN".to_vec(),
+		decimals
+	));
+}
+
+pub struct ExtBuilder {
+	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+	registered_assets: Vec<(AssetId, u8)>,
+}
+
+// Returns default values for genesis config
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		ORACLE_TRADES.with(|v| v.borrow_mut().clear());
+		ORACLE_LIQUIDITY_CHANGES.with(|v| v.borrow_mut().clear());
+		WHITELISTED_ACCOUNTS.with(|v| v.borrow_mut().clear());
+
+		Self {
+			endowed_accounts: vec![
+				(ALICE, USDT, 1_000_000 * ONE),
+				(ALICE, USDC, 1_000_000 * ONE),
+				(ALICE, DAI, 1_000_000 * ONE),
+				(BOB, USDT, 1_000_000 * ONE),
+				(BOB, USDC, 1_000_000 * ONE),
+				(BOB, DAI, 1_000_000 * ONE),
+				(CHARLIE, USDT, 1_000_000 * ONE),
+			],
+			registered_assets: vec![(USDT, 12), (USDC, 12), (DAI, 12)],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn with_accounts(mut self, accounts: Vec<(AccountId, AssetId, Balance)>) -> Self {
+		self.endowed_accounts = accounts;
+		self
+	}
+
+	pub fn with_registered_asset(mut self, asset_id: AssetId, decimals: u8) -> Self {
+		self.registered_assets.push((asset_id, decimals));
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+		orml_tokens::GenesisConfig::<Test> {
+			balances: self.endowed_accounts,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut ext: sp_io::TestExternalities = t.into();
+		ext.execute_with(|| {
+			System::set_block_number(1);
+			for (asset_id, decimals) in self.registered_assets {
+				register_asset(asset_id, decimals);
+			}
+		});
+		ext
+	}
+}
+
+pub fn expect_events(e: Vec<RuntimeEvent>) {
+	e.into_iter().for_each(frame_system::Pallet::<Test>::assert_has_event);
+}
+
+/// Create a pool of given assets with default parameters and return its id.
+pub fn create_pool(assets: Vec<AssetId>, amplification: u16, fee: Permill) -> AssetId {
+	assert_ok!(Stableswap::create_pool(
+		RuntimeOrigin::root(),
+		assets,
+		amplification,
+		fee
+	));
+	Pools::<Test>::iter_keys().max().unwrap()
+}
@@ -0,0 +1,5 @@
+mod amplification;
+mod creation;
+mod liquidity;
+pub(crate) mod mock;
+mod trades;
@@ -0,0 +1,249 @@
+pub use super::mock::*;
+use crate::types::AssetAmount;
+use crate::Error;
+use basilisk_traits::router::{PoolType, TradeExecution};
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiCurrency;
+use sp_runtime::{FixedU128, Permill};
+
+fn pool_with_liquidity(assets: Vec<AssetId>, reserve: impl Fn(AssetId) -> Balance) -> AssetId {
+	let pool_id = create_pool(assets.clone(), 100, Permill::from_percent(1));
+
+	assert_ok!(Stableswap::add_liquidity(
+		RuntimeOrigin::signed(ALICE),
+		pool_id,
+		assets
+			.iter()
+			.map(|asset| AssetAmount::new(*asset, reserve(*asset)))
+			.collect(),
+		0,
+	));
+
+	pool_id
+}
+
+fn default_pool() -> AssetId {
+	pool_with_liquidity(vec![USDT, USDC, DAI], |_| 100_000 * ONE)
+}
+
+#[test]
+fn sell_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = default_pool();
+		let pool_account = Stableswap::pool_account(pool_id);
+
+		assert_ok!(Stableswap::sell(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			USDT,
+			USDC,
+			1_000 * ONE,
+			989 * ONE,
+		));
+
+		assert_eq!(Tokens::free_balance(USDT, &BOB), 999_000 * ONE);
+		assert_eq!(Tokens::free_balance(USDC, &BOB), 1_000_000 * ONE + 989_989_011_234_192);
+		assert_eq!(Tokens::free_balance(USDT, &pool_account), 101_000 * ONE);
+		assert_eq!(
+			Tokens::free_balance(USDC, &pool_account),
+			100_000 * ONE - 989_989_011_234_192
+		);
+
+		ORACLE_TRADES.with(|v| {
+			assert_eq!(v.borrow().last(), Some(&(USDT, USDC, 1_000 * ONE, 989_989_011_234_192)));
+		});
+	});
+}
+
+#[test]
+fn buy_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = default_pool();
+
+		assert_ok!(Stableswap::buy(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			USDC,
+			USDT,
+			1_000 * ONE,
+			1_011 * ONE,
+		));
+
+		assert_eq!(Tokens::free_balance(USDC, &BOB), 1_001_000 * ONE);
+		assert_eq!(
+			Tokens::free_balance(USDT, &BOB),
+			1_000_000 * ONE - 1_010_011_211_009_981
+		);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_limit_is_not_reached() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = default_pool();
+
+		assert_noop!(
+			Stableswap::sell(RuntimeOrigin::signed(BOB), pool_id, USDT, USDC, 1_000 * ONE, 990 * ONE),
+			Error::<Test>::SlippageLimit
+		);
+	});
+}
+
+#[test]
+fn buy_should_fail_when_limit_is_exceeded() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = default_pool();
+
+		assert_noop!(
+			Stableswap::buy(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				USDC,
+				USDT,
+				1_000 * ONE,
+				1_010 * ONE
+			),
+			Error::<Test>::SlippageLimit
+		);
+	});
+}
+
+#[test]
+fn trade_should_fail_when_asset_is_not_in_pool() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = default_pool();
+
+		assert_noop!(
+			Stableswap::sell(RuntimeOrigin::signed(BOB), pool_id, USDT, BTC, 1_000 * ONE, 0),
+			Error::<Test>::AssetNotInPool
+		);
+		assert_noop!(
+			Stableswap::sell(RuntimeOrigin::signed(BOB), pool_id, USDT, USDT, 1_000 * ONE, 0),
+			Error::<Test>::IncorrectAssets
+		);
+	});
+}
+
+#[test]
+fn trade_should_fail_when_amount_is_below_min_trading_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = default_pool();
+
+		assert_noop!(
+			Stableswap::sell(RuntimeOrigin::signed(BOB), pool_id, USDT, USDC, 999, 0),
+			Error::<Test>::InsufficientTradingAmount
+		);
+		assert_noop!(
+			Stableswap::buy(RuntimeOrigin::signed(BOB), pool_id, USDC, USDT, 999, u128::MAX),
+			Error::<Test>::InsufficientTradingAmount
+		);
+	});
+}
+
+#[test]
+fn trade_should_fail_when_pool_is_empty() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = create_pool(vec![USDT, USDC], 100, Permill::zero());
+
+		assert_noop!(
+			Stableswap::sell(RuntimeOrigin::signed(BOB), pool_id, USDT, USDC, 1_000 * ONE, 0),
+			Error::<Test>::InsufficientLiquidity
+		);
+	});
+}
+
+#[test]
+fn sell_should_respect_asset_decimals() {
+	ExtBuilder::default()
+		.with_registered_asset(BTC, 6)
+		.with_accounts(vec![
+			(ALICE, USDT, 1_000_000 * ONE),
+			(ALICE, BTC, 1_000_000 * 1_000_000),
+			(BOB, USDT, 1_000_000 * ONE),
+		])
+		.build()
+		.execute_with(|| {
+			let pool_id = pool_with_liquidity(vec![USDT, BTC], |asset| {
+				if asset == BTC {
+					100_000 * 1_000_000
+				} else {
+					100_000 * ONE
+				}
+			});
+			assert_ok!(Stableswap::update_pool_fee(
+				RuntimeOrigin::root(),
+				pool_id,
+				Permill::zero()
+			));
+
+			assert_ok!(Stableswap::sell(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				USDT,
+				BTC,
+				1_000 * ONE,
+				0,
+			));
+
+			assert_eq!(Tokens::free_balance(BTC, &BOB), 999_950_246);
+		});
+}
+
+#[test]
+fn router_execution_should_match_calculation() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = default_pool();
+		let pool_type = PoolType::Stableswap(pool_id);
+
+		let amount_out = <Stableswap as TradeExecution<RuntimeOrigin, AccountId, AssetId, Balance>>::calculate_sell(
+			pool_type,
+			USDT,
+			USDC,
+			1_000 * ONE,
+		)
+		.unwrap();
+
+		assert_ok!(<Stableswap as TradeExecution<
+			RuntimeOrigin,
+			AccountId,
+			AssetId,
+			Balance,
+		>>::execute_sell(
+			RuntimeOrigin::signed(BOB),
+			pool_type,
+			USDT,
+			USDC,
+			1_000 * ONE,
+			amount_out,
+		));
+		assert_eq!(Tokens::free_balance(USDC, &BOB), 1_000_000 * ONE + amount_out);
+
+		assert_eq!(
+			<Stableswap as TradeExecution<RuntimeOrigin, AccountId, AssetId, Balance>>::calculate_sell(
+				PoolType::XYK,
+				USDT,
+				USDC,
+				1_000 * ONE,
+			),
+			Err(basilisk_traits::router::ExecutorError::NotSupported)
+		);
+	});
+}
+
+#[test]
+fn spot_price_with_fee_should_include_pool_fee() {
+	ExtBuilder::default().build().execute_with(|| {
+		let pool_id = default_pool();
+
+		let price =
+			<Stableswap as TradeExecution<RuntimeOrigin, AccountId, AssetId, Balance>>::calculate_spot_price_with_fee(
+				PoolType::Stableswap(pool_id),
+				USDT,
+				USDC,
+			)
+			.unwrap();
+
+		// Balanced pool trades at peg, fee is 1%.
+		assert_eq!(price, FixedU128::from_rational(100, 99));
+	});
+}
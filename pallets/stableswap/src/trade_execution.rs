@@ -0,0 +1,163 @@
+use crate::types::{AssetId, Balance};
+use crate::{Config, Error, Pallet, Pools};
+use basilisk_traits::router::{ExecutorError, PoolType, RouteCandidateProvider, Trade, TradeExecution};
+use codec::{Decode, Encode};
+use orml_traits::MultiCurrency;
+use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128, Permill};
+use sp_std::vec::Vec;
+
+impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance> for Pallet<T> {
+	type Error = DispatchError;
+
+	fn calculate_sell(
+		pool_type: PoolType<AssetId>,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		let PoolType::Stableswap(pool_id) = pool_type else {
+			return Err(ExecutorError::NotSupported);
+		};
+
+		let (amount_out, _fee) =
+			Self::calculate_sell(pool_id, asset_in, asset_out, amount_in).map_err(ExecutorError::Error)?;
+
+		Ok(amount_out)
+	}
+
+	fn calculate_buy(
+		pool_type: PoolType<AssetId>,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_out: Balance,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		let PoolType::Stableswap(pool_id) = pool_type else {
+			return Err(ExecutorError::NotSupported);
+		};
+
+		let (amount_in, _fee) =
+			Self::calculate_buy(pool_id, asset_in, asset_out, amount_out).map_err(ExecutorError::Error)?;
+
+		Ok(amount_in)
+	}
+
+	fn execute_sell(
+		who: T::RuntimeOrigin,
+		pool_type: PoolType<AssetId>,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		min_limit: Balance,
+	) -> Result<(), ExecutorError<Self::Error>> {
+		let PoolType::Stableswap(pool_id) = pool_type else {
+			return Err(ExecutorError::NotSupported);
+		};
+
+		Self::sell(who, pool_id, asset_in, asset_out, amount_in, min_limit).map_err(ExecutorError::Error)
+	}
+
+	fn execute_buy(
+		who: T::RuntimeOrigin,
+		pool_type: PoolType<AssetId>,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_out: Balance,
+		max_limit: Balance,
+	) -> Result<(), ExecutorError<Self::Error>> {
+		let PoolType::Stableswap(pool_id) = pool_type else {
+			return Err(ExecutorError::NotSupported);
+		};
+
+		Self::buy(who, pool_id, asset_out, asset_in, amount_out, max_limit).map_err(ExecutorError::Error)
+	}
+
+	fn get_liquidity_depth(
+		pool_type: PoolType<AssetId>,
+		asset_a: AssetId,
+		_asset_b: AssetId,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		let PoolType::Stableswap(pool_id) = pool_type else {
+			return Err(ExecutorError::NotSupported);
+		};
+
+		let pool = Pools::<T>::get(pool_id).ok_or(ExecutorError::Error(Error::<T>::PoolNotFound.into()))?;
+		if pool.find_asset(asset_a).is_none() {
+			return Err(ExecutorError::Error(Error::<T>::AssetNotInPool.into()));
+		}
+
+		Ok(T::Currency::free_balance(asset_a, &Self::pool_account(pool_id)))
+	}
+
+	fn calculate_spot_price_with_fee(
+		pool_type: PoolType<AssetId>,
+		asset_a: AssetId,
+		asset_b: AssetId,
+	) -> Result<FixedU128, ExecutorError<Self::Error>> {
+		let PoolType::Stableswap(pool_id) = pool_type else {
+			return Err(ExecutorError::NotSupported);
+		};
+
+		let pool = Pools::<T>::get(pool_id).ok_or(ExecutorError::Error(Error::<T>::PoolNotFound.into()))?;
+
+		// Price of `asset_b` in `asset_a`, increased by the fee paid when selling `asset_a`.
+		let (n, d) = Self::calculate_spot_price(pool_id, asset_a, asset_b).map_err(ExecutorError::Error)?;
+		let spot_price =
+			FixedU128::checked_from_rational(n, d).ok_or(ExecutorError::Error(ArithmeticError::Overflow.into()))?;
+
+		let fee_complement = FixedU128::from(Permill::one().saturating_sub(pool.fee));
+
+		spot_price
+			.checked_div(&fee_complement)
+			.ok_or(ExecutorError::Error(ArithmeticError::DivisionByZero.into()))
+	}
+}
+
+/// Every pair of pool assets is a route candidate. The cursor is the encoded pool id followed by
+/// the indices of the last returned pair.
+impl<T: Config> RouteCandidateProvider<AssetId> for Pallet<T> {
+	fn next_candidate(cursor: &[u8]) -> Option<(Vec<u8>, Trade<AssetId>)> {
+		let candidate = |pool_id: AssetId, assets: &[AssetId], a: usize, b: usize| {
+			let mut cursor = pool_id.encode();
+			cursor.extend([a as u8, b as u8]);
+
+			(
+				cursor,
+				Trade {
+					pool: PoolType::Stableswap(pool_id),
+					asset_in: assets[a],
+					asset_out: assets[b],
+				},
+			)
+		};
+
+		let mut pools = if cursor.is_empty() {
+			Pools::<T>::iter()
+		} else {
+			let mut input = cursor;
+			let pool_id = AssetId::decode(&mut input).ok()?;
+			let [a, b] = <[u8; 2]>::try_from(input).ok()?;
+			let (a, b) = (a as usize, b as usize);
+
+			if let Some(pool) = Pools::<T>::get(pool_id) {
+				let len = pool.assets.len();
+				if b.saturating_add(1) < len {
+					return Some(candidate(pool_id, &pool.assets, a, b.saturating_add(1)));
+				}
+				if a.saturating_add(2) < len {
+					return Some(candidate(
+						pool_id,
+						&pool.assets,
+						a.saturating_add(1),
+						a.saturating_add(2),
+					));
+				}
+			}
+
+			Pools::<T>::iter_from(Pools::<T>::hashed_key_for(pool_id))
+		};
+
+		pools
+			.next()
+			.map(|(pool_id, pool)| candidate(pool_id, &pool.assets, 0, 1))
+	}
+}
@@ -0,0 +1,88 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub type AssetId = u32;
+pub type Amount = i128;
+pub type Balance = u128;
+
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::{traits::ConstU32, BoundedVec};
+use scale_info::TypeInfo;
+use sp_runtime::{Permill, RuntimeDebug};
+use sp_std::vec::Vec;
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+/// Max number of assets in a single pool.
+pub const MAX_ASSETS_IN_POOL: u32 = 5;
+
+/// Pool properties. The pool is identified by its share asset id.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PoolInfo<BlockNumber> {
+	/// Assets of the pool, sorted by asset id.
+	pub assets: BoundedVec<AssetId, ConstU32<MAX_ASSETS_IN_POOL>>,
+	/// Amplification at `initial_block`.
+	pub initial_amplification: u16,
+	/// Amplification at `final_block` and afterwards.
+	pub final_amplification: u16,
+	pub initial_block: BlockNumber,
+	pub final_block: BlockNumber,
+	/// Trade fee, also used to charge imbalanced liquidity provision and withdrawal.
+	pub fee: Permill,
+}
+
+impl<BlockNumber> PoolInfo<BlockNumber> {
+	/// Return position of `asset` in the pool.
+	pub fn find_asset(&self, asset: AssetId) -> Option<usize> {
+		self.assets.iter().position(|id| *id == asset)
+	}
+
+	/// Return true if all assets of the pool are unique.
+	pub fn is_valid(&self) -> bool {
+		self.assets.len() >= 2 && self.assets.windows(2).all(|w| w[0] < w[1])
+	}
+}
+
+/// Amount of an asset used to provide or withdraw liquidity.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo)]
+pub struct AssetAmount {
+	pub asset_id: AssetId,
+	pub amount: Balance,
+}
+
+impl AssetAmount {
+	pub fn new(asset_id: AssetId, amount: Balance) -> Self {
+		Self { asset_id, amount }
+	}
+}
+
+/// Symbol of pool share assets.
+pub const POOL_SHARE_SYMBOL: &[u8] = b"STBLP";
+
+/// Return share token name of a pool with given assets.
+pub fn pool_share_name(assets: &[AssetId]) -> Vec<u8> {
+	let mut buf: Vec<u8> = Vec::new();
+
+	buf.extend_from_slice(b"stb");
+	for asset in assets {
+		buf.extend_from_slice(&asset.to_le_bytes());
+	}
+
+	buf
+}
@@ -309,14 +309,24 @@ impl AssetPairAccountIdFor<AssetId, AccountId> for AssetPairAccountIdTest {
 	}
 }
 
+parameter_types! {
+	pub const ExternalAssetDeposit: Balance = 1_000;
+	pub const MaxExternalRegistrationsPerBlock: u32 = 1;
+	pub const MetadataUrlLimit: u32 = 128;
+}
+
 impl pallet_asset_registry::Config for Test {
 	type RegistryOrigin = EnsureSigned<AccountId>;
 	type AssetId = AssetId;
 	type Balance = Balance;
 	type AssetNativeLocation = u8;
 	type StringLimit = RegistryStringLimit;
+	type MetadataUrlLimit = MetadataUrlLimit;
 	type SequentialIdStartAt = SequentialIdOffset;
 	type NativeAssetId = BSXAssetId;
+	type Currency = Balances;
+	type ExternalAssetDeposit = ExternalAssetDeposit;
+	type MaxExternalRegistrationsPerBlock = MaxExternalRegistrationsPerBlock;
 	type WeightInfo = ();
 }
 
@@ -29,7 +29,9 @@
 #![allow(clippy::upper_case_acronyms)]
 #![allow(clippy::manual_inspect)]
 
-use basilisk_traits::{AMMTransfer, OnCreatePoolHandler, OnLiquidityChangedHandler, OnTradeHandler, AMM};
+use basilisk_traits::{
+	AMMTransfer, CreateXYKPool, OnCreatePoolHandler, OnLiquidityChangedHandler, OnTradeHandler, AMM,
+};
 use frame_support::sp_runtime::{traits::Zero, DispatchError};
 use frame_support::{
 	dispatch::DispatchResult,
@@ -296,6 +298,25 @@ pub mod pallet {
 	pub(crate) type PoolAssets<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AccountId, (AssetId, AssetId), OptionQuery>;
 
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// Pools created at genesis: (owner, asset a, amount a, asset b, amount b).
+		///
+		/// The initial liquidity is provided by the owner, who receives the shares.
+		pub pools: Vec<(T::AccountId, AssetId, Balance, AssetId, Balance)>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			for (owner, asset_a, amount_a, asset_b, amount_b) in self.pools.iter() {
+				Pallet::<T>::do_create_pool(owner.clone(), *asset_a, *amount_a, *asset_b, *amount_b)
+					.expect("Failed to create XYK pool in genesis");
+			}
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Create new pool for given asset pair.
@@ -318,73 +339,7 @@ pub mod pallet {
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			ensure!(
-				T::CanCreatePool::can_create(asset_a, asset_b),
-				Error::<T>::CannotCreatePool
-			);
-
-			ensure!(
-				amount_a >= T::MinPoolLiquidity::get() && amount_b >= T::MinPoolLiquidity::get(),
-				Error::<T>::InsufficientLiquidity
-			);
-
-			ensure!(asset_a != asset_b, Error::<T>::CannotCreatePoolWithSameAssets);
-
-			let asset_pair = AssetPair {
-				asset_in: asset_a,
-				asset_out: asset_b,
-			};
-
-			ensure!(!Self::exists(asset_pair), Error::<T>::TokenPoolAlreadyExists);
-
-			let shares_added = if asset_a < asset_b { amount_a } else { amount_b };
-
-			ensure!(
-				T::Currency::free_balance(asset_a, &who) >= amount_a,
-				Error::<T>::InsufficientAssetBalance
-			);
-
-			ensure!(
-				T::Currency::free_balance(asset_b, &who) >= amount_b,
-				Error::<T>::InsufficientAssetBalance
-			);
-
-			let pair_account = Self::get_pair_id(asset_pair);
-
-			let token_name = asset_pair.name();
-
-			let share_token = T::AssetRegistry::get_or_register_insufficient_asset(
-				token_name.try_into().map_err(|_| Error::<T>::CannotCreatePool)?,
-				AssetKind::XYK,
-				None,
-				None,
-				None,
-				None,
-				None,
-			)?;
-
-			let _ = T::AMMHandler::on_create_pool(asset_pair.asset_in, asset_pair.asset_out);
-
-			T::NonDustableWhitelistHandler::add_account(&pair_account)?;
-
-			<ShareToken<T>>::insert(&pair_account, share_token);
-			<PoolAssets<T>>::insert(&pair_account, (asset_a, asset_b));
-
-			Self::deposit_event(Event::PoolCreated {
-				who: who.clone(),
-				asset_a,
-				asset_b,
-				initial_shares_amount: shares_added,
-				share_token,
-				pool: pair_account.clone(),
-			});
-
-			T::Currency::transfer(asset_a, &who, &pair_account, amount_a, ExistenceRequirement::AllowDeath)?;
-			T::Currency::transfer(asset_b, &who, &pair_account, amount_b, ExistenceRequirement::AllowDeath)?;
-
-			T::Currency::deposit(share_token, &who, shares_added)?;
-
-			<TotalLiquidity<T>>::insert(&pair_account, shares_added);
+			Self::do_create_pool(who, asset_a, amount_a, asset_b, amount_b)?;
 
 			Ok(())
 		}
@@ -605,6 +560,84 @@ pub mod pallet {
 }
 
 impl<T: Config> Pallet<T> {
+	fn do_create_pool(
+		who: T::AccountId,
+		asset_a: AssetId,
+		amount_a: Balance,
+		asset_b: AssetId,
+		amount_b: Balance,
+	) -> Result<(AssetId, Balance), DispatchError> {
+		ensure!(
+			T::CanCreatePool::can_create(asset_a, asset_b),
+			Error::<T>::CannotCreatePool
+		);
+
+		ensure!(
+			amount_a >= T::MinPoolLiquidity::get() && amount_b >= T::MinPoolLiquidity::get(),
+			Error::<T>::InsufficientLiquidity
+		);
+
+		ensure!(asset_a != asset_b, Error::<T>::CannotCreatePoolWithSameAssets);
+
+		let asset_pair = AssetPair {
+			asset_in: asset_a,
+			asset_out: asset_b,
+		};
+
+		ensure!(!Self::exists(asset_pair), Error::<T>::TokenPoolAlreadyExists);
+
+		let shares_added = if asset_a < asset_b { amount_a } else { amount_b };
+
+		ensure!(
+			T::Currency::free_balance(asset_a, &who) >= amount_a,
+			Error::<T>::InsufficientAssetBalance
+		);
+
+		ensure!(
+			T::Currency::free_balance(asset_b, &who) >= amount_b,
+			Error::<T>::InsufficientAssetBalance
+		);
+
+		let pair_account = Self::get_pair_id(asset_pair);
+
+		let token_name = asset_pair.name();
+
+		let share_token = T::AssetRegistry::get_or_register_insufficient_asset(
+			token_name.try_into().map_err(|_| Error::<T>::CannotCreatePool)?,
+			AssetKind::XYK,
+			None,
+			None,
+			None,
+			None,
+			None,
+		)?;
+
+		let _ = T::AMMHandler::on_create_pool(asset_pair.asset_in, asset_pair.asset_out);
+
+		T::NonDustableWhitelistHandler::add_account(&pair_account)?;
+
+		<ShareToken<T>>::insert(&pair_account, share_token);
+		<PoolAssets<T>>::insert(&pair_account, (asset_a, asset_b));
+
+		Self::deposit_event(Event::PoolCreated {
+			who: who.clone(),
+			asset_a,
+			asset_b,
+			initial_shares_amount: shares_added,
+			share_token,
+			pool: pair_account.clone(),
+		});
+
+		T::Currency::transfer(asset_a, &who, &pair_account, amount_a, ExistenceRequirement::AllowDeath)?;
+		T::Currency::transfer(asset_b, &who, &pair_account, amount_b, ExistenceRequirement::AllowDeath)?;
+
+		T::Currency::deposit(share_token, &who, shares_added)?;
+
+		<TotalLiquidity<T>>::insert(&pair_account, shares_added);
+
+		Ok((share_token, shares_added))
+	}
+
 	fn do_add_liquidity(
 		who: T::AccountId,
 		asset_a: AssetId,
@@ -1206,3 +1239,15 @@ impl<T: Config> AMMAddLiquidity<T::AccountId, AssetId, Balance> for Pallet<T> {
 		Self::do_add_liquidity(who, asset_a, asset_b, amount_a, amount_b_max_limit)
 	}
 }
+
+impl<T: Config> CreateXYKPool<T::AccountId, AssetId, Balance> for Pallet<T> {
+	fn create_pool(
+		who: &T::AccountId,
+		asset_a: AssetId,
+		amount_a: Balance,
+		asset_b: AssetId,
+		amount_b: Balance,
+	) -> Result<(AssetId, Balance), DispatchError> {
+		Self::do_create_pool(who.clone(), asset_a, amount_a, asset_b, amount_b)
+	}
+}
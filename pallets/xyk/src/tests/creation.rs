@@ -430,3 +430,32 @@ fn share_asset_id_should_be_offset() {
 		);
 	});
 }
+
+#[test]
+fn genesis_config_should_create_pools() {
+	ExtBuilder::default()
+		.with_pools(vec![(ALICE, HDX, 100_000_000_000_000, ACA, 1_000_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			let pair_account = XYK::get_pair_id(AssetPair {
+				asset_in: HDX,
+				asset_out: ACA,
+			});
+			let share_token = XYK::share_token(pair_account);
+
+			assert_eq!(XYK::get_pool_assets(&pair_account), Some(vec![HDX, ACA]));
+			assert_eq!(Currency::free_balance(HDX, &pair_account), 100_000_000_000_000);
+			assert_eq!(Currency::free_balance(ACA, &pair_account), 1_000_000_000_000_000);
+			assert_eq!(Currency::free_balance(HDX, &ALICE), 900_000_000_000_000);
+			assert_eq!(Currency::free_balance(share_token, &ALICE), 100_000_000_000_000);
+			assert_eq!(XYK::total_liquidity(pair_account), 100_000_000_000_000);
+		});
+}
+
+#[test]
+#[should_panic(expected = "Failed to create XYK pool in genesis")]
+fn genesis_config_should_panic_when_owner_has_insufficient_balance() {
+	ExtBuilder::default()
+		.with_pools(vec![(CHARLIE, HDX, 100_000_000_000_000, ACA, 1_000_000_000_000_000)])
+		.build();
+}
@@ -106,14 +106,24 @@ parameter_types! {
 	pub const FeesBeneficiarry: u64 = TREASURY;
 }
 
+parameter_types! {
+	pub const ExternalAssetDeposit: Balance = 1_000;
+	pub const MaxExternalRegistrationsPerBlock: u32 = 1;
+	pub const MetadataUrlLimit: u32 = 128;
+}
+
 impl pallet_asset_registry::Config for Test {
 	type RegistryOrigin = EnsureSigned<AccountId>;
 	type AssetId = AssetId;
 	type Balance = Balance;
 	type AssetNativeLocation = AssetLocation;
 	type StringLimit = RegistryStringLimit;
+	type MetadataUrlLimit = MetadataUrlLimit;
 	type SequentialIdStartAt = SequentialIdOffset;
 	type NativeAssetId = NativeAssetId;
+	type Currency = orml_tokens::CurrencyAdapter<Test, NativeAssetId>;
+	type ExternalAssetDeposit = ExternalAssetDeposit;
+	type MaxExternalRegistrationsPerBlock = MaxExternalRegistrationsPerBlock;
 	type WeightInfo = ();
 }
 
@@ -274,6 +284,7 @@ impl xyk::Config for Test {
 
 pub struct ExtBuilder {
 	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+	pools: Vec<(AccountId, AssetId, Balance, AssetId, Balance)>,
 }
 
 // Returns default values for genesis config
@@ -289,6 +300,7 @@ impl Default for ExtBuilder {
 				(BOB, DOT, 1_000_000_000_000_000u128),
 				(CHARLIE, HDX, 1_000_000_000_000_000u128),
 			],
+			pools: vec![],
 		}
 	}
 }
@@ -301,6 +313,11 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn with_pools(mut self, pools: Vec<(AccountId, AssetId, Balance, AssetId, Balance)>) -> Self {
+		self.pools = pools;
+		self
+	}
+
 	pub fn with_exchange_fee(self, f: (u32, u32)) -> Self {
 		EXCHANGE_FEE.with(|v| *v.borrow_mut() = f);
 		self
@@ -325,6 +342,10 @@ impl ExtBuilder {
 		.assimilate_storage(&mut t)
 		.unwrap();
 
+		crate::GenesisConfig::<Test> { pools: self.pools }
+			.assimilate_storage(&mut t)
+			.unwrap();
+
 		t.into()
 	}
 }
@@ -1,12 +1,13 @@
 use crate::types::{AssetId, AssetPair, Balance};
-use crate::{Config, Error, Pallet};
-use basilisk_traits::router::{ExecutorError, PoolType, TradeExecution};
+use crate::{Config, Error, Pallet, PoolAssets};
+use basilisk_traits::router::{ExecutorError, PoolType, RouteCandidateProvider, Trade, TradeExecution};
 use basilisk_traits::AMM;
 use frame_support::ensure;
 use frame_support::traits::Get;
 use orml_traits::MultiCurrency;
 use sp_runtime::DispatchError::Corruption;
 use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128};
+use sp_std::vec::Vec;
 
 impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance> for Pallet<T> {
 	type Error = DispatchError;
@@ -171,3 +172,24 @@ impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance>
 		Ok(spot_price_with_fee)
 	}
 }
+
+impl<T: Config> RouteCandidateProvider<AssetId> for Pallet<T> {
+	fn next_candidate(cursor: &[u8]) -> Option<(Vec<u8>, Trade<AssetId>)> {
+		let mut pools = if cursor.is_empty() {
+			PoolAssets::<T>::iter()
+		} else {
+			PoolAssets::<T>::iter_from(cursor.to_vec())
+		};
+
+		pools.next().map(|(pool_account, (asset_a, asset_b))| {
+			(
+				PoolAssets::<T>::hashed_key_for(&pool_account),
+				Trade {
+					pool: PoolType::XYK,
+					asset_in: asset_a,
+					asset_out: asset_b,
+				},
+			)
+		})
+	}
+}
@@ -33,6 +33,7 @@ use sp_std::{collections::btree_map::BTreeMap, marker::PhantomData};
 use xcm_builder::TakeRevenue;
 use xcm_executor::{traits::WeightTrader, AssetsInHolding};
 
+pub mod xcm_delivery_fee;
 pub mod xcm_exchange;
 
 pub mod xyk;
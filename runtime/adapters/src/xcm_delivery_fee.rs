@@ -0,0 +1,165 @@
+use basilisk_traits::{oracle::NativePriceOracle, AccountFeeCurrency};
+use frame_support::sp_runtime::{
+	traits::{Get, Zero},
+	FixedPointNumber, FixedPointOperand, SaturatedConversion,
+};
+use frame_support::traits::{ExistenceRequirement, ProcessMessageError};
+use frame_support::weights::Weight;
+use orml_traits::MultiCurrency;
+use pallet_transaction_multi_payment::DepositFee;
+use polkadot_xcm::v5::prelude::*;
+use sp_std::marker::PhantomData;
+use xcm_executor::traits::{ConvertLocation, DenyExecution, FeeManager, FeeReason, Properties, TransactAsset};
+use xcm_executor::AssetsInHolding;
+
+/// Asset transactor charging the delivery fees of outbound messages in the fee currency of the sender.
+///
+/// Delivery fees are priced in native currency in the `FeeAsset` pseudo asset, which does not exist
+/// on any chain and can't be deposited. Withdrawing it from a local account converts the amount to the
+/// fee currency of the account set in `FeeCurrency` with the price of `AcceptedCurrencyPrices` and
+/// moves it from the account to `FeeReceiver`.
+///
+/// The executor passes the same arguments when it withdraws fees and when it executes `WithdrawAsset`, so
+/// programs withdrawing the pseudo asset have to be rejected by [`DenyFeeAssetWithdrawal`] in the barrier.
+///
+/// All other assets are left to the next transactor.
+#[allow(clippy::type_complexity)]
+pub struct DeliveryFeeInFeeCurrency<
+	AccountId,
+	CurrencyId,
+	Balance,
+	Price,
+	FeeAsset,
+	AccountIdConverter,
+	FeeCurrency,
+	AcceptedCurrencyPrices,
+	Currency,
+	Deposit,
+	FeeReceiver,
+>(
+	PhantomData<(
+		AccountId,
+		CurrencyId,
+		Balance,
+		Price,
+		FeeAsset,
+		AccountIdConverter,
+		FeeCurrency,
+		AcceptedCurrencyPrices,
+		Currency,
+		Deposit,
+		FeeReceiver,
+	)>,
+);
+
+impl<
+		AccountId,
+		CurrencyId: Copy,
+		Balance: FixedPointOperand,
+		Price: FixedPointNumber,
+		FeeAsset: Get<AssetId>,
+		AccountIdConverter: ConvertLocation<AccountId>,
+		FeeCurrency: AccountFeeCurrency<AccountId, AssetId = CurrencyId>,
+		AcceptedCurrencyPrices: NativePriceOracle<CurrencyId, Price>,
+		Currency: MultiCurrency<AccountId, CurrencyId = CurrencyId, Balance = Balance>,
+		Deposit: DepositFee<AccountId, CurrencyId, Balance>,
+		FeeReceiver: Get<AccountId>,
+	> TransactAsset
+	for DeliveryFeeInFeeCurrency<
+		AccountId,
+		CurrencyId,
+		Balance,
+		Price,
+		FeeAsset,
+		AccountIdConverter,
+		FeeCurrency,
+		AcceptedCurrencyPrices,
+		Currency,
+		Deposit,
+		FeeReceiver,
+	>
+{
+	fn withdraw_asset(
+		what: &Asset,
+		who: &Location,
+		_maybe_context: Option<&XcmContext>,
+	) -> Result<AssetsInHolding, XcmError> {
+		let amount = match what {
+			Asset {
+				id,
+				fun: Fungible(amount),
+			} if *id == FeeAsset::get() => *amount,
+			_ => return Err(XcmError::AssetNotFound),
+		};
+
+		let account = AccountIdConverter::convert_location(who).ok_or(XcmError::LocationNotInvertible)?;
+		let currency = FeeCurrency::get(&account);
+		let price = AcceptedCurrencyPrices::price(currency).ok_or(XcmError::TooExpensive)?;
+		let fee = price
+			.checked_mul_int(amount.saturated_into::<Balance>())
+			.ok_or(XcmError::Overflow)?;
+
+		log::trace!(
+			target: "xcm::delivery_fee", "DeliveryFeeInFeeCurrency::withdraw_asset who: {who:?}, fee: {amount:?} native"
+		);
+
+		if !fee.is_zero() {
+			Currency::withdraw(currency, &account, fee, ExistenceRequirement::AllowDeath)
+				.map_err(|e| XcmError::FailedToTransactAsset(e.into()))?;
+			Deposit::deposit_fee(&FeeReceiver::get(), currency, fee)
+				.map_err(|e| XcmError::FailedToTransactAsset(e.into()))?;
+		}
+
+		Ok(what.clone().into())
+	}
+}
+
+/// Barrier denying programs which withdraw the delivery fee pseudo asset `FeeAsset` from an account.
+///
+/// The pseudo asset is withdrawn by [`DeliveryFeeInFeeCurrency`] to pay delivery fees only. Instructions
+/// withdrawing it would charge the fee currency and put an asset which can't be deposited into holding.
+///
+/// Wrap in `DenyRecursively` to cover nested programs.
+pub struct DenyFeeAssetWithdrawal<FeeAsset>(PhantomData<FeeAsset>);
+
+impl<FeeAsset: Get<AssetId>> DenyExecution for DenyFeeAssetWithdrawal<FeeAsset> {
+	fn deny_execution<RuntimeCall>(
+		origin: &Location,
+		instructions: &mut [Instruction<RuntimeCall>],
+		_max_weight: Weight,
+		_properties: &mut Properties,
+	) -> Result<(), ProcessMessageError> {
+		let withdraws_fee_asset = instructions.iter().any(|instruction| match instruction {
+			WithdrawAsset(assets) | TransferAsset { assets, .. } | TransferReserveAsset { assets, .. } => {
+				assets.inner().iter().any(|asset| asset.id == FeeAsset::get())
+			}
+			_ => false,
+		});
+
+		if withdraws_fee_asset {
+			log::trace!(
+				target: "xcm::delivery_fee", "DenyFeeAssetWithdrawal denied withdrawal of the fee asset, origin: {origin:?}"
+			);
+			return Err(ProcessMessageError::Unsupported);
+		}
+
+		Ok(())
+	}
+}
+
+/// Charges delivery fees to local accounts only.
+///
+/// Fees of all other origins are waived. Programs sent by other chains already pay for their execution,
+/// including the forwarding of messages, with `BuyExecution`.
+///
+/// The fees are moved to the fee receiver when they are withdrawn by [`DeliveryFeeInFeeCurrency`],
+/// so there is nothing left to handle.
+pub struct ChargeLocalAccounts;
+
+impl FeeManager for ChargeLocalAccounts {
+	fn is_waived(origin: Option<&Location>, _reason: FeeReason) -> bool {
+		!matches!(origin.map(Location::unpack), Some((0, [AccountId32 { .. }])))
+	}
+
+	fn handle_fee(_fee: Assets, _context: Option<&XcmContext>, _reason: FeeReason) {}
+}
@@ -1,26 +1,279 @@
-use frame_support::traits::ExistenceRequirement;
+use basilisk_traits::router::{AssetPair, RouteProvider};
+use codec::{Decode, Encode};
+use frame_support::traits::{ExistenceRequirement, PalletInfoAccess};
 use orml_traits::MultiCurrency;
 use pallet_broadcast::types::ExecutionType;
+use pallet_route_executor::{RouterT, Trade, MAX_NUMBER_OF_TRADES};
 use polkadot_xcm::v5::prelude::*;
 use sp_core::Get;
 use sp_runtime::traits::{Convert, Zero};
+use sp_runtime::DispatchError;
 use sp_std::marker::PhantomData;
-use sp_std::vec;
+use sp_std::vec::Vec;
 use xcm_executor::traits::AssetExchange;
 use xcm_executor::AssetsInHolding;
 
+/// Route hint of the `ExchangeAsset` instruction.
+///
+/// The hint is passed as an additional non-fungible `want` asset identified by the location
+/// `(0, [PalletInstance(router), GeneralKey(trade), ..])`, where `router` is the index of pallet-route-executor
+/// and every `GeneralKey` holds one SCALE encoded [`Trade`] of the route.
+///
+/// The hint is used for the trade whose `asset_in` and `asset_out` match the first and the last asset of the route.
+/// All other trades use the on-chain route.
+pub struct RouteHint<CurrencyId>(PhantomData<CurrencyId>);
+
+impl<CurrencyId: Encode + Decode> RouteHint<CurrencyId> {
+	/// Encode `route` into a route hint asset. Returns `None` if the route is empty or too long.
+	pub fn encode(router_pallet_index: u8, route: &[Trade<CurrencyId>]) -> Option<Asset> {
+		if route.is_empty() || route.len() > MAX_NUMBER_OF_TRADES as usize {
+			return None;
+		}
+
+		let mut interior = Junctions::from([PalletInstance(router_pallet_index)]);
+		for trade in route {
+			let encoded = trade.encode();
+			let mut data = [0u8; 32];
+			data.get_mut(..encoded.len())?.copy_from_slice(&encoded);
+			interior
+				.push(GeneralKey {
+					length: encoded.len() as u8,
+					data,
+				})
+				.ok()?;
+		}
+
+		Some(Asset {
+			id: AssetId(Location::new(0, interior)),
+			fun: NonFungible(AssetInstance::Undefined),
+		})
+	}
+
+	/// Decode the route of a route hint asset. Returns `None` if `asset` is not a route hint.
+	pub fn decode(router_pallet_index: u8, asset: &Asset) -> Option<Vec<Trade<CurrencyId>>> {
+		let location = &asset.id.0;
+		if location.parents != 0 || location.first_interior() != Some(&PalletInstance(router_pallet_index)) {
+			return None;
+		}
+
+		location
+			.interior()
+			.iter()
+			.skip(1)
+			.map(|junction| match junction {
+				GeneralKey { length, data } => data
+					.get(..*length as usize)
+					.and_then(|mut encoded| Trade::decode(&mut encoded).ok()),
+				_ => None,
+			})
+			.collect()
+	}
+}
+
+/// Remote swap-and-forward XCM program.
+///
+/// Lets an account on another chain swap an asset on Basilisk via the router and receive the proceeds on its
+/// chain in a single XCM program. The program executed on Basilisk has the shape:
+///
+/// ```text
+/// WithdrawAsset(give) | ReserveAssetDeposited(give), ClearOrigin
+/// BuyExecution { fees, Unlimited }
+/// ExchangeAsset { give: AllOf(give), want: [want, route hint], maximal: true }
+/// DepositReserveAsset { AllOf(want), dest, [BuyExecution, DepositAsset(beneficiary)] }
+///   | InitiateReserveWithdraw { AllOf(want), reserve: dest, [BuyExecution, DepositAsset(beneficiary)] }
+/// RefundSurplus
+/// DepositAsset { AllCounted(1), refund }
+/// ```
+///
+/// The first instructions load the incoming asset into holding and are added by the sender, the rest is built by
+/// [`SwapAndForward::instructions`]:
+/// - the execution on Basilisk is paid with `fees` in the incoming asset, which must be an accepted currency of
+///   `MultiCurrencyTrader`. The weight of the program can be estimated with `XcmPaymentApi::query_xcm_weight`
+///   and converted to `fees` with `XcmPaymentApi::query_weight_to_asset_fee`.
+/// - the rest of the incoming asset is sold for `want`, see [`XcmAssetExchanger`].
+/// - the proceeds are forwarded with `DepositReserveAsset` when Basilisk is the reserve of `want`, or with
+///   `InitiateReserveWithdraw` when `dest` is. Proceeds reserved on other chains can't be forwarded.
+/// - the assets left in holding, e.g. unused fees, are deposited to `refund` on Basilisk.
+///
+/// When the swap fails, e.g. because less than `want` would be received, the execution stops and the holding is
+/// trapped under the origin of the program. The origin can recover it with a program starting with
+/// `ClaimAsset { assets, ticket: Here }` instead of loading the incoming asset.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SwapAndForward {
+	/// Incoming asset paying for the execution on Basilisk.
+	pub fees: Asset,
+	/// Asset to swap the incoming asset for and the minimum amount to receive.
+	pub want: Asset,
+	/// Optional route of the swap, see [`RouteHint`].
+	pub route_hint: Option<Asset>,
+	/// Chain the proceeds are forwarded to.
+	pub dest: Location,
+	/// Amount of the proceeds paying for the execution on `dest`.
+	pub dest_fee: u128,
+	/// Recipient of the proceeds on `dest`.
+	pub beneficiary: Location,
+	/// Recipient on Basilisk of the assets left in holding.
+	pub refund: Location,
+}
+
+impl SwapAndForward {
+	/// Instructions executed on Basilisk after the incoming asset is loaded into holding.
+	///
+	/// Assets are given in the context of Basilisk, whose universal location is `context`. Returns `None` if the
+	/// proceeds can't be forwarded to `dest`.
+	pub fn instructions<Call>(&self, context: &InteriorLocation) -> Option<Vec<Instruction<Call>>> {
+		let (_, interior) = context.clone().split_global().ok()?;
+		let here = Location::new(1, interior);
+
+		let want_id = self.want.id.clone();
+		let dest_fees: Asset = (want_id.clone().reanchored(&self.dest, context).ok()?, self.dest_fee).into();
+		let forward_xcm = Xcm(sp_std::vec![
+			BuyExecution {
+				fees: dest_fees,
+				weight_limit: Unlimited,
+			},
+			DepositAsset {
+				assets: Wild(AllCounted(1)),
+				beneficiary: self.beneficiary.clone(),
+			},
+		]);
+		let proceeds: AssetFilter = Wild(AllOf {
+			id: want_id.clone(),
+			fun: WildFungible,
+		});
+
+		let forward = if want_id.0.starts_with(&self.dest) {
+			InitiateReserveWithdraw {
+				assets: proceeds,
+				reserve: self.dest.clone(),
+				xcm: forward_xcm,
+			}
+		} else if want_id.0.parents == 0 || want_id.0.starts_with(&here) {
+			DepositReserveAsset {
+				assets: proceeds,
+				dest: self.dest.clone(),
+				xcm: forward_xcm,
+			}
+		} else {
+			return None;
+		};
+
+		let mut want: Vec<Asset> = sp_std::vec![self.want.clone()];
+		want.extend(self.route_hint.clone());
+
+		Some(sp_std::vec![
+			BuyExecution {
+				fees: self.fees.clone(),
+				weight_limit: Unlimited,
+			},
+			ExchangeAsset {
+				give: Wild(AllOf {
+					id: self.fees.id.clone(),
+					fun: WildFungible,
+				}),
+				want: want.into(),
+				maximal: true,
+			},
+			forward,
+			RefundSurplus,
+			DepositAsset {
+				assets: Wild(AllCounted(1)),
+				beneficiary: self.refund.clone(),
+			},
+		])
+	}
+}
+
 /// Implements `AssetExchange` to support the `ExchangeAsset` XCM instruction.
 ///
 /// Uses pallet-route-executor to execute trades.
 ///
-/// Will map exchange instructions with `maximal = true` to sell (selling all of `give` asset) and `false` to buy
-/// (buying exactly `want` amount of asset).
+/// Will map exchange instructions with `maximal = true` to sells and `false` to buys:
+/// - sell: each of the `give` assets is sold for the single `want` asset. The `want` amount is the minimum
+///   amount to receive for all the `give` assets together.
+/// - buy: exactly the amount of each `want` asset is bought with the single `give` asset, in the given order.
+///
+/// Trades are filled partially: a `give` asset which can't be sold, or a `want` asset which can't be bought,
+/// is skipped and the unused `give` assets are returned to holding. The exchange fails when no trade was
+/// executed or when a sell receives less than the `want` amount.
 ///
-/// NOTE: Currenty limited to one asset each for `give` and `want`.
+/// A route can be provided with a [`RouteHint`] in `want`, otherwise the on-chain routes are used.
 pub struct XcmAssetExchanger<Runtime, TempAccount, CurrencyIdConvert, Currency>(
 	PhantomData<(Runtime, TempAccount, CurrencyIdConvert, Currency)>,
 );
 
+impl<Runtime, TempAccount, CurrencyIdConvert, Currency>
+	XcmAssetExchanger<Runtime, TempAccount, CurrencyIdConvert, Currency>
+where
+	Runtime: pallet_route_executor::Config,
+	TempAccount: Get<Runtime::AccountId>,
+	CurrencyIdConvert: Convert<Asset, Option<Runtime::AssetId>>,
+	Currency: MultiCurrency<Runtime::AccountId, CurrencyId = Runtime::AssetId, Balance = Runtime::Balance>,
+	Runtime::Balance: From<u128> + Zero + Into<u128>,
+	Runtime::AssetId: Into<u32>,
+{
+	/// Convert fungible `assets` to the (location, asset id, amount) of every asset.
+	fn convert_fungibles(assets: impl IntoIterator<Item = Asset>) -> Option<Vec<(AssetId, Runtime::AssetId, u128)>> {
+		assets
+			.into_iter()
+			.map(|asset| {
+				let Fungible(amount) = asset.fun else { return None };
+				let asset_id = CurrencyIdConvert::convert(asset.clone())?;
+				Some((asset.id, asset_id, amount))
+			})
+			.collect()
+	}
+
+	/// Split `want` into the route hint and the wanted assets. Returns `None` if there is more than one route hint.
+	fn split_route_hint(want: &Assets) -> Option<(Option<Vec<Trade<Runtime::AssetId>>>, Vec<Asset>)> {
+		let router_pallet_index = pallet_route_executor::Pallet::<Runtime>::index() as u8;
+		let mut route_hint = None;
+		let mut wanted = Vec::new();
+		for asset in want.inner() {
+			match RouteHint::<Runtime::AssetId>::decode(router_pallet_index, asset) {
+				Some(route) if route_hint.is_none() => route_hint = Some(route),
+				Some(_) => return None,
+				None => wanted.push(asset.clone()),
+			}
+		}
+		Some((route_hint, wanted))
+	}
+
+	/// Returns the route hint if it trades `asset_in` for `asset_out`, otherwise the on-chain route is used.
+	fn route(
+		route_hint: &Option<Vec<Trade<Runtime::AssetId>>>,
+		asset_in: Runtime::AssetId,
+		asset_out: Runtime::AssetId,
+	) -> Vec<Trade<Runtime::AssetId>> {
+		match route_hint {
+			Some(route)
+				if route.first().map(|trade| trade.asset_in) == Some(asset_in)
+					&& route.last().map(|trade| trade.asset_out) == Some(asset_out) =>
+			{
+				route.clone()
+			}
+			_ => Vec::new(),
+		}
+	}
+
+	/// Burn the balances of `assets` held by the temporary account and return them as holding.
+	fn take_holding(
+		account: &Runtime::AccountId,
+		assets: impl Iterator<Item = (AssetId, Runtime::AssetId)>,
+	) -> Result<AssetsInHolding, DispatchError> {
+		let mut holding = AssetsInHolding::new();
+		for (location, asset_id) in assets {
+			let amount = Currency::free_balance(asset_id, account);
+			if amount > Runtime::Balance::zero() {
+				Currency::withdraw(asset_id, account, amount, ExistenceRequirement::AllowDeath)?; // burn the tokens
+				let amount: u128 = amount.into();
+				holding.subsume((location, amount).into());
+			}
+		}
+		Ok(holding)
+	}
+}
+
 impl<Runtime, TempAccount, CurrencyIdConvert, Currency> AssetExchange
 	for XcmAssetExchanger<Runtime, TempAccount, CurrencyIdConvert, Currency>
 where
@@ -42,96 +295,120 @@ where
 		let account = TempAccount::get();
 		let origin = Runtime::RuntimeOrigin::from(frame_system::RawOrigin::Signed(account.clone()));
 
-		if give.len() != 1 {
-			log::warn!(target: "xcm::exchange-asset", "Only one give asset is supported.");
+		if give.non_fungible_assets_iter().next().is_some() {
+			log::warn!(target: "xcm::exchange-asset", "Non-fungible give assets are not supported.");
+			return Err(give);
+		}
+
+		let Some((route_hint, wanted)) = Self::split_route_hint(want) else {
+			log::warn!(target: "xcm::exchange-asset", "Only one route hint is supported.");
 			return Err(give);
 		};
 
-		//We assume only one asset wanted as translating into buy and sell is ambigous for multiple want assets
-		if want.len() != 1 {
-			log::warn!(target: "xcm::exchange-asset", "Only one want asset is supported.");
+		let Some(given) = Self::convert_fungibles(give.fungible_assets_iter()) else {
 			return Err(give);
 		};
-		let Some(given) = give.fungible_assets_iter().next() else {
+		let Some(wanted) = Self::convert_fungibles(wanted) else {
 			return Err(give);
 		};
 
-		let Some(asset_in) = CurrencyIdConvert::convert(given.clone()) else {
+		//Translating into sells is ambiguous for multiple want assets and into buys for multiple give assets
+		if maximal && wanted.len() != 1 {
+			log::warn!(target: "xcm::exchange-asset", "Only one want asset is supported for sell.");
 			return Err(give);
-		};
-		let Some(wanted) = want.get(0) else { return Err(give) };
-		let Some(asset_out) = CurrencyIdConvert::convert(wanted.clone()) else {
+		}
+		if !maximal && given.len() != 1 {
+			log::warn!(target: "xcm::exchange-asset", "Only one give asset is supported for buy.");
 			return Err(give);
-		};
-		let use_onchain_route = vec![];
+		}
+
+		if given
+			.iter()
+			.any(|(_, asset_in, _)| wanted.iter().any(|(_, asset_out, _)| asset_in == asset_out))
+		{
+			log::warn!(target: "xcm::exchange-asset", "Give asset can not be a want asset.");
+			return Err(give);
+		}
 
 		let _ = pallet_broadcast::Pallet::<Runtime>::add_to_context(ExecutionType::XcmExchange);
 
 		let trade_result = if maximal {
 			// sell
-			let Fungible(amount) = given.fun else { return Err(give) };
-			let Fungible(min_buy_amount) = wanted.fun else {
-				return Err(give);
-			};
+			let (want_location, asset_out, min_buy_amount) = &wanted[0];
 
 			with_transaction_result(|| {
-				Currency::deposit(asset_in, &account, amount.into())?; // mint the incoming tokens
-				pallet_route_executor::Pallet::<Runtime>::sell(
-					origin,
-					asset_in,
-					asset_out,
-					amount.into(),
-					min_buy_amount.into(),
-					use_onchain_route,
-				)?;
-				debug_assert!(
-					Currency::free_balance(asset_in, &account) == Runtime::Balance::zero(),
-					"Sell should not leave any of the incoming asset."
-				);
-				let amount_received = Currency::free_balance(asset_out, &account);
-				debug_assert!(
-					amount_received >= min_buy_amount.into(),
-					"Sell should return more than mininum buy amount."
-				);
-				Currency::withdraw(asset_out, &account, amount_received, ExistenceRequirement::AllowDeath)?; // burn the received tokens
-				let holding: Asset = (wanted.id.clone(), amount_received.into()).into();
-
-				Ok(holding.into())
+				let mut sold = false;
+				// only the amount bought by the sells counts towards `min_buy_amount`
+				let mut amount_received: u128 = 0;
+				for (_, asset_in, amount) in given.iter() {
+					Currency::deposit(*asset_in, &account, (*amount).into())?; // mint the incoming tokens
+					let balance_before: u128 = Currency::free_balance(*asset_out, &account).into();
+					let result = pallet_route_executor::Pallet::<Runtime>::sell(
+						origin.clone(),
+						*asset_in,
+						*asset_out,
+						(*amount).into(),
+						Runtime::Balance::zero(),
+						Self::route(&route_hint, *asset_in, *asset_out),
+					);
+					match result {
+						Ok(()) => {
+							sold = true;
+							let balance_after: u128 = Currency::free_balance(*asset_out, &account).into();
+							amount_received =
+								amount_received.saturating_add(balance_after.saturating_sub(balance_before));
+						}
+						Err(e) => log::debug!(target: "xcm::exchange-asset", "Sell of {:?} failed: {:?}", asset_in, e),
+					}
+				}
+				if !sold || amount_received < *min_buy_amount {
+					return Err(pallet_route_executor::Error::<Runtime>::TradingLimitReached.into());
+				}
+
+				// the unsold give assets are returned to holding
+				Self::take_holding(
+					&account,
+					given
+						.iter()
+						.map(|(location, asset_id, _)| (location.clone(), *asset_id))
+						.chain([(want_location.clone(), *asset_out)]),
+				)
 			})
 			.map_err(|_| give.clone())
 		} else {
 			// buy
-			let Fungible(amount) = wanted.fun else { return Err(give) };
-			let Fungible(max_sell_amount) = given.fun else {
-				return Err(give);
-			};
+			let (give_location, asset_in, max_sell_amount) = &given[0];
 
 			with_transaction_result(|| {
-				Currency::deposit(asset_in, &account, max_sell_amount.into())?; // mint the incoming tokens
-				pallet_route_executor::Pallet::<Runtime>::buy(
-					origin,
-					asset_in,
-					asset_out,
-					amount.into(),
-					max_sell_amount.into(),
-					use_onchain_route,
-				)?;
-				let mut assets = sp_std::vec::Vec::with_capacity(2);
-				let left_over = Currency::free_balance(asset_in, &account);
-				if left_over > Runtime::Balance::zero() {
-					Currency::withdraw(asset_in, &account, left_over, ExistenceRequirement::AllowDeath)?; // burn left over tokens
-					let holding: Asset = (given.id.clone(), left_over.into()).into();
-					assets.push(holding);
+				Currency::deposit(*asset_in, &account, (*max_sell_amount).into())?; // mint the incoming tokens
+				let mut bought = false;
+				for (_, asset_out, amount) in wanted.iter() {
+					let result = pallet_route_executor::Pallet::<Runtime>::buy(
+						origin.clone(),
+						*asset_in,
+						*asset_out,
+						(*amount).into(),
+						Currency::free_balance(*asset_in, &account),
+						Self::route(&route_hint, *asset_in, *asset_out),
+					);
+					match result {
+						Ok(()) => bought = true,
+						Err(e) => log::debug!(target: "xcm::exchange-asset", "Buy of {:?} failed: {:?}", asset_out, e),
+					}
+				}
+				if !bought {
+					return Err(pallet_route_executor::Error::<Runtime>::TradingLimitReached.into());
 				}
-				let amount_received = Currency::free_balance(asset_out, &account);
-				debug_assert!(
-					amount_received == amount.into(),
-					"Buy should return exactly the amount we specified."
-				);
-				Currency::withdraw(asset_out, &account, amount_received, ExistenceRequirement::AllowDeath)?; // burn the received tokens
-				let holding: Asset = (wanted.id.clone(), amount_received.into()).into();
-				assets.push(holding);
-				Ok(assets.into())
+
+				// the left over of the give asset is returned to holding
+				Self::take_holding(
+					&account,
+					[(give_location.clone(), *asset_in)].into_iter().chain(
+						wanted
+							.iter()
+							.map(|(location, asset_id, _)| (location.clone(), *asset_id)),
+					),
+				)
 			})
 			.map_err(|_| give.clone())
 		};
@@ -141,7 +418,46 @@ where
 		trade_result
 	}
 
-	fn quote_exchange_price(_give: &Assets, _want: &Assets, _maximal: bool) -> Option<Assets> {
-		todo!() // TODO:
+	/// Quotes the amount of `want` received for `give` when `maximal`, otherwise the amount of `give` needed to
+	/// buy `want`.
+	///
+	/// NOTE: Only one asset each for `give` and `want` is supported.
+	fn quote_exchange_price(give: &Assets, want: &Assets, maximal: bool) -> Option<Assets> {
+		let (route_hint, wanted) = Self::split_route_hint(want)?;
+		let [given] = give.inner().as_slice() else { return None };
+		let [wanted] = wanted.as_slice() else { return None };
+		let (Fungible(give_amount), Fungible(want_amount)) = (&given.fun, &wanted.fun) else {
+			return None;
+		};
+
+		let asset_in = CurrencyIdConvert::convert(given.clone())?;
+		let asset_out = CurrencyIdConvert::convert(wanted.clone())?;
+		let mut route = Self::route(&route_hint, asset_in, asset_out);
+		if route.is_empty() {
+			route = <pallet_route_executor::Pallet<Runtime> as RouteProvider<_>>::get_route(AssetPair::new(
+				asset_in, asset_out,
+			));
+		}
+
+		if maximal {
+			let amounts =
+				<pallet_route_executor::Pallet<Runtime> as RouterT<_, _, _, _, _>>::calculate_sell_trade_amounts(
+					&route,
+					(*give_amount).into(),
+				)
+				.ok()?;
+			let amount_out: u128 = amounts.last()?.amount_out.into();
+			Some(Asset::from((wanted.id.clone(), amount_out)).into())
+		} else {
+			let amounts =
+				<pallet_route_executor::Pallet<Runtime> as RouterT<_, _, _, _, _>>::calculate_buy_trade_amounts(
+					&route,
+					(*want_amount).into(),
+				)
+				.ok()?;
+			// amounts of the buy are in the reverse order of the route
+			let amount_in: u128 = amounts.last()?.amount_in.into();
+			Some(Asset::from((given.id.clone(), amount_in)).into())
+		}
 	}
 }
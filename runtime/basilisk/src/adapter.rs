@@ -339,6 +339,20 @@ where
 						Err(_) => return None,
 					}
 				}
+				PoolType::Stableswap(_) => {
+					let price_result = AggregatedPriceGetter::get_price(
+						asset_a,
+						asset_b,
+						period,
+						crate::StableswapOracleSourceIdentifier::get(),
+					);
+
+					match price_result {
+						Ok(price) => price.0,
+						Err(OracleError::SameAsset) => EmaPrice::from(1),
+						Err(_) => return None,
+					}
+				}
 				_ => return None,
 			};
 
@@ -18,7 +18,7 @@
 use crate::*;
 use basilisk_traits::oracle::NativePriceOracle;
 use frame_support::{
-	genesis_builder_helper::{build_state, get_preset},
+	genesis_builder_helper::get_preset,
 	sp_runtime::{
 		traits::Convert,
 		transaction_validity::{TransactionSource, TransactionValidity},
@@ -178,9 +178,31 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_asset_registry_runtime_api::AssetRegistryApi<Block, AssetId, Balance, AssetLocation> for Runtime {
+		fn asset_metadata(asset_id: AssetId) -> Option<pallet_asset_registry_runtime_api::AssetMetadataInfo> {
+			AssetRegistry::metadata_info(asset_id)
+		}
+
+		fn asset(asset_id: AssetId) -> Option<pallet_asset_registry_runtime_api::AssetInfo<AssetId, Balance, AssetLocation>> {
+			AssetRegistry::asset_info(asset_id)
+		}
+
+		fn assets(start_after: Option<AssetId>, limit: u32) -> Vec<pallet_asset_registry_runtime_api::AssetInfo<AssetId, Balance, AssetLocation>> {
+			AssetRegistry::assets_info(start_after, limit)
+		}
+
+		fn assets_by_symbol(symbol: Vec<u8>) -> Vec<pallet_asset_registry_runtime_api::AssetInfo<AssetId, Balance, AssetLocation>> {
+			AssetRegistry::assets_info_by_symbol(symbol)
+		}
+
+		fn asset_by_location(location: AssetLocation) -> Option<pallet_asset_registry_runtime_api::AssetInfo<AssetId, Balance, AssetLocation>> {
+			AssetRegistry::asset_info_by_location(location)
+		}
+	}
+
 	impl sp_genesis_builder::GenesisBuilder<Block> for Runtime {
 		fn build_state(config: Vec<u8>) -> sp_genesis_builder::Result {
-			build_state::<RuntimeGenesisConfig>(config)
+			genesis::build_state(config)
 		}
 
 		fn get_preset(id: &Option<sp_genesis_builder::PresetId>) -> Option<Vec<u8>> {
@@ -20,7 +20,7 @@ use crate::governance::origins::GeneralAdmin;
 use crate::system::NativeAssetId;
 
 use basilisk_traits::{
-	OnTradeHandler, AMM,
+	OnTradeHandler, VestedTransfer, AMM,
 	{
 		oracle::OraclePeriod,
 		router::{inverse_route, AmmTradeWeights, PoolType, Trade},
@@ -35,11 +35,13 @@ use pallet_currencies::fungibles::FungibleCurrencies;
 use pallet_currencies::BasicCurrencyAdapter;
 use pallet_lbp::weights::WeightInfo as LbpWeights;
 use pallet_route_executor::weights::WeightInfo as RouterWeights;
+use pallet_stableswap::weights::WeightInfo as StableswapWeights;
 use pallet_transaction_multi_payment::{AddTxAssetOnAccount, RemoveTxAssetOnKilled};
 use pallet_xyk::weights::WeightInfo as XykWeights;
 use primitives::constants::{
-	chain::{CORE_ASSET_ID, DISCOUNTED_FEE, MAX_IN_RATIO, MAX_OUT_RATIO, MIN_POOL_LIQUIDITY, MIN_TRADING_LIMIT},
+	chain::{CORE_ASSET_ID, DISCOUNTED_FEE},
 	currency::{NATIVE_EXISTENTIAL_DEPOSIT, UNITS},
+	time::DAYS,
 };
 
 use frame_support::{
@@ -284,6 +286,9 @@ impl pallet_currencies::Config for Runtime {
 
 parameter_types! {
 	pub const SequentialIdOffset: u32 = 1_000_000;
+	pub const ExternalAssetDeposit: Balance = 10_000 * UNITS;
+	pub const MaxExternalRegistrationsPerBlock: u32 = 1;
+	pub const MetadataUrlLimit: u32 = 256;
 }
 impl pallet_asset_registry::Config for Runtime {
 	type RegistryOrigin = EitherOf<EnsureRoot<Self::AccountId>, GeneralAdmin>;
@@ -291,8 +296,12 @@ impl pallet_asset_registry::Config for Runtime {
 	type Balance = Balance;
 	type AssetNativeLocation = AssetLocation;
 	type StringLimit = RegistryStrLimit;
+	type MetadataUrlLimit = MetadataUrlLimit;
 	type SequentialIdStartAt = SequentialIdOffset;
 	type NativeAssetId = NativeAssetId;
+	type Currency = Balances;
+	type ExternalAssetDeposit = ExternalAssetDeposit;
+	type MaxExternalRegistrationsPerBlock = MaxExternalRegistrationsPerBlock;
 	type WeightInfo = weights::pallet_asset_registry::BasiliskWeight<Runtime>;
 }
 
@@ -347,11 +356,6 @@ where
 }
 
 parameter_types! {
-	pub XYKExchangeFee: (u32, u32) = (3, 1_000);
-	pub const MinTradingLimit: Balance = MIN_TRADING_LIMIT;
-	pub const MinPoolLiquidity: Balance = MIN_POOL_LIQUIDITY;
-	pub const MaxInRatio: u128 = MAX_IN_RATIO;
-	pub const MaxOutRatio: u128 = MAX_OUT_RATIO;
 	pub const RegistryStrLimit: u32 = 32;
 	pub const DiscountedFee: (u32, u32) = DISCOUNTED_FEE;
 	pub const XYKOracleSourceIdentifier: Source = *b"snek/xyk";
@@ -407,6 +411,11 @@ where
 
 parameter_types! {
 	pub LBPExchangeFee: (u32, u32) = (2, 1_000);
+	pub const LBPMaxAllowlistUpdate: u32 = 100;
+	pub const LBPPoolDeposit: Balance = 100_000 * UNITS;
+	pub const LBPMinSaleDuration: BlockNumber = 3 * DAYS;
+	pub const LBPMaxInitialWeight: u32 = 90_000_000;
+	pub const LBPRefundPeriod: BlockNumber = 30 * DAYS;
 }
 
 impl pallet_lbp::Config for Runtime {
@@ -421,6 +430,19 @@ impl pallet_lbp::Config for Runtime {
 	type MaxInRatio = MaxInRatio;
 	type MaxOutRatio = MaxOutRatio;
 	type BlockNumberProvider = RelayChainBlockNumberProvider<Runtime>;
+	type XYKPool = XYK;
+	type AllowlistOrigin = EitherOf<EnsureRoot<Self::AccountId>, GeneralAdmin>;
+	type MaxAllowlistUpdate = LBPMaxAllowlistUpdate;
+	type Vesting = NativeVestedTransfer;
+	type DepositCurrency = Balances;
+	type PoolDeposit = LBPPoolDeposit;
+	type SlashedDeposit = Treasury;
+	type CancelPoolOrigin = EitherOf<EnsureRoot<Self::AccountId>, GeneralAdmin>;
+	type MinSaleDuration = LBPMinSaleDuration;
+	type MaxInitialWeight = LBPMaxInitialWeight;
+	type RefundPeriod = LBPRefundPeriod;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = benchmarking::BenchmarkHelper;
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -462,6 +484,93 @@ impl orml_vesting::Config for Runtime {
 	type BlockNumberProvider = RelayChainBlockNumberProvider<Runtime>;
 }
 
+/// Vested transfers of the native asset by `orml_vesting`.
+///
+/// Only the native asset is supported because `orml_vesting` locks a single currency. LBP sales selling any
+/// other asset can not be configured with vesting.
+///
+/// Transferred amount is moved to the vesting pallet account which then transfers it with a vesting schedule
+/// releasing an equal part every block. Remainder of the division by the duration is transferred unlocked.
+/// If the recipient already has a schedule with the same start and duration, the amount is added to it
+/// instead, so repeated purchases do not run into `MaxVestingSchedules`.
+pub struct NativeVestedTransfer;
+impl VestedTransfer<AccountId, AssetId, Balance, BlockNumber> for NativeVestedTransfer {
+	fn is_supported(asset: AssetId) -> bool {
+		asset == NativeAssetId::get()
+	}
+
+	fn vested_transfer(
+		asset: AssetId,
+		from: &AccountId,
+		to: &AccountId,
+		amount: Balance,
+		start: BlockNumber,
+		duration: BlockNumber,
+	) -> DispatchResult {
+		if !Self::is_supported(asset) {
+			return Err(DispatchError::Other("Vesting of the asset is not supported"));
+		}
+
+		let per_period = amount
+			.checked_div(duration.into())
+			.ok_or(ArithmeticError::DivisionByZero)?;
+		let vested_amount = per_period.saturating_mul(duration.into());
+		let remainder = amount.saturating_sub(vested_amount);
+
+		if !remainder.is_zero() {
+			<Currencies as MultiCurrency<AccountId>>::transfer(
+				asset,
+				from,
+				to,
+				remainder,
+				ExistenceRequirement::AllowDeath,
+			)?;
+		}
+
+		let mut schedules = orml_vesting::VestingSchedules::<Runtime>::get(to);
+		if let Some(schedule) = schedules
+			.iter_mut()
+			.find(|schedule| schedule.start == start && schedule.period == 1 && schedule.period_count == duration)
+		{
+			schedule.per_period = schedule
+				.per_period
+				.checked_add(per_period)
+				.ok_or(ArithmeticError::Overflow)?;
+
+			<Currencies as MultiCurrency<AccountId>>::transfer(
+				asset,
+				from,
+				to,
+				vested_amount,
+				ExistenceRequirement::AllowDeath,
+			)?;
+
+			return Vesting::update_vesting_schedules(RuntimeOrigin::root(), to.clone(), schedules.into_inner());
+		}
+
+		let vesting_account: AccountId = VestingPalletId::get().into_account_truncating();
+
+		<Currencies as MultiCurrency<AccountId>>::transfer(
+			asset,
+			from,
+			&vesting_account,
+			vested_amount,
+			ExistenceRequirement::AllowDeath,
+		)?;
+
+		Vesting::vested_transfer(
+			RuntimeOrigin::root(),
+			to.clone(),
+			orml_vesting::VestingSchedule {
+				start,
+				period: 1,
+				period_count: duration,
+				per_period,
+			},
+		)
+	}
+}
+
 parameter_types! {
 	pub const MinimumOfferAmount: Balance = UNITS / 100;
 	pub const RoyaltyBondAmount: Balance = 0;
@@ -540,7 +649,6 @@ parameter_types! {
 	pub const MaxEntriesPerDeposit: u8 = 5; //NOTE: Rebenchmark when this change, TODO:
 	pub const MaxYieldFarmsPerGlobalFarm: u8 = 50; //NOTE: Includes deleted/destroyed farms, TODO:
 	pub const MinPlannedYieldingPeriods: BlockNumber = 100_800;  //1w, TODO:
-	pub const MinTotalFarmRewards: Balance = NATIVE_EXISTENTIAL_DEPOSIT * 100; //TODO:
 }
 
 type XYKLiquidityMiningInstance = warehouse_liquidity_mining::Instance1;
@@ -560,6 +668,69 @@ impl warehouse_liquidity_mining::Config<XYKLiquidityMiningInstance> for Runtime
 	type TreasuryAccountId = TreasuryAccount;
 }
 
+parameter_types! {
+	pub const StableswapPalletId: PalletId = PalletId(*b"stblswap");
+	pub const StableswapMinAmplification: u16 = 2;
+	pub const StableswapMaxAmplification: u16 = 10_000;
+	pub const StableswapOracleSourceIdentifier: Source = *b"snek/stb";
+}
+
+impl pallet_stableswap::Config for Runtime {
+	type AssetRegistry = AssetRegistry;
+	type Currency = Currencies;
+	type AuthorityOrigin = EitherOf<EnsureRoot<Self::AccountId>, GeneralAdmin>;
+	type PalletId = StableswapPalletId;
+	type MinPoolLiquidity = MinPoolLiquidity;
+	type MinTradingLimit = MinTradingLimit;
+	type MinAmplification = StableswapMinAmplification;
+	type MaxAmplification = StableswapMaxAmplification;
+	type OracleSource = StableswapOracleSourceIdentifier;
+	type AMMHandler = pallet_ema_oracle::OnActivityHandler<Runtime>;
+	type NonDustableWhitelistHandler = Duster;
+	type BlockNumberProvider = RelayChainBlockNumberProvider<Runtime>;
+	type WeightInfo = weights::pallet_stableswap::BasiliskWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = benchmarking::BenchmarkHelper;
+}
+
+parameter_types! {
+	pub const StableswapLMPalletId: PalletId = PalletId(*b"StbLmId_");
+	pub const StableswapLiquidityMiningNftCollectionId: primitives::CollectionId = 2;
+}
+
+impl pallet_stableswap_liquidity_mining::Config for Runtime {
+	type MultiCurrency = Currencies;
+	type Stableswap = Stableswap;
+	type CreateOrigin = EitherOf<EnsureRoot<Self::AccountId>, GeneralAdmin>;
+	type PalletId = StableswapLMPalletId;
+	type NftCollectionId = StableswapLiquidityMiningNftCollectionId;
+	type NFTHandler = NFT;
+	type LiquidityMiningHandler = StableswapWarehouseLM;
+	type NonDustableWhitelistHandler = Duster;
+	type WeightInfo = weights::pallet_stableswap_liquidity_mining::BasiliskWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const StableswapWarehouseLMPalletId: PalletId = PalletId(*b"StbWhsLm");
+}
+
+type StableswapLiquidityMiningInstance = warehouse_liquidity_mining::Instance2;
+impl warehouse_liquidity_mining::Config<StableswapLiquidityMiningInstance> for Runtime {
+	type AssetId = AssetId;
+	type MultiCurrency = Currencies;
+	type PalletId = StableswapWarehouseLMPalletId;
+	type MinTotalFarmRewards = MinTotalFarmRewards;
+	type MinPlannedYieldingPeriods = MinPlannedYieldingPeriods;
+	type BlockNumberProvider = RelayChainBlockNumberProvider<Runtime>;
+	type AmmPoolId = AssetId;
+	type MaxFarmEntriesPerDeposit = MaxEntriesPerDeposit;
+	type MaxYieldFarmsPerGlobalFarm = MaxYieldFarmsPerGlobalFarm;
+	type AssetRegistry = AssetRegistry;
+	type NonDustableWhitelistHandler = Duster;
+	type PriceAdjustment = warehouse_liquidity_mining::DefaultPriceAdjustment;
+	type TreasuryAccountId = TreasuryAccount;
+}
+
 // Provides weight info for the router. Router extrinsics can be executed with different AMMs, so we split the router weights into two parts:
 // the router extrinsic overhead and the AMM weight.
 pub struct RouterWeightInfo;
@@ -638,11 +809,14 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 			let lbp_weight = weights::pallet_lbp::BasiliskWeight::<Runtime>::router_execution_sell(c, e);
 			let xyk_weight = weights::pallet_xyk::BasiliskWeight::<Runtime>::router_execution_sell(c, e)
 				.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight());
+			let stableswap_weight = weights::pallet_stableswap::BasiliskWeight::<Runtime>::router_execution_sell(c, e)
+				.saturating_add(<Runtime as pallet_stableswap::Config>::AMMHandler::on_trade_weight());
 
 			let amm_weight = match trade.pool {
 				PoolType::LBP => lbp_weight,
 				PoolType::XYK => xyk_weight,
-				_ => lbp_weight.max(xyk_weight),
+				PoolType::Stableswap(_) => stableswap_weight,
+				_ => lbp_weight.max(xyk_weight).max(stableswap_weight),
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -662,11 +836,14 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 			let lbp_weight = weights::pallet_lbp::BasiliskWeight::<Runtime>::router_execution_buy(c, e);
 			let xyk_weight = weights::pallet_xyk::BasiliskWeight::<Runtime>::router_execution_buy(c, e)
 				.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight());
+			let stableswap_weight = weights::pallet_stableswap::BasiliskWeight::<Runtime>::router_execution_buy(c, e)
+				.saturating_add(<Runtime as pallet_stableswap::Config>::AMMHandler::on_trade_weight());
 
 			let amm_weight = match trade.pool {
 				PoolType::LBP => lbp_weight,
 				PoolType::XYK => xyk_weight,
-				_ => lbp_weight.max(xyk_weight),
+				PoolType::Stableswap(_) => stableswap_weight,
+				_ => lbp_weight.max(xyk_weight).max(stableswap_weight),
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -686,11 +863,14 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 			let lbp_weight = weights::pallet_lbp::BasiliskWeight::<Runtime>::router_execution_buy(c, e);
 			let xyk_weight = weights::pallet_xyk::BasiliskWeight::<Runtime>::router_execution_buy(c, e)
 				.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight());
+			let stableswap_weight = weights::pallet_stableswap::BasiliskWeight::<Runtime>::router_execution_buy(c, e)
+				.saturating_add(<Runtime as pallet_stableswap::Config>::AMMHandler::on_trade_weight());
 
 			let amm_weight = match trade.pool {
 				PoolType::LBP => lbp_weight,
 				PoolType::XYK => xyk_weight,
-				_ => lbp_weight.max(xyk_weight),
+				PoolType::Stableswap(_) => stableswap_weight,
+				_ => lbp_weight.max(xyk_weight).max(stableswap_weight),
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -710,11 +890,14 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 			let lbp_weight = weights::pallet_lbp::BasiliskWeight::<Runtime>::router_execution_sell(c, e);
 			let xyk_weight = weights::pallet_xyk::BasiliskWeight::<Runtime>::router_execution_sell(c, e)
 				.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight());
+			let stableswap_weight = weights::pallet_stableswap::BasiliskWeight::<Runtime>::router_execution_sell(c, e)
+				.saturating_add(<Runtime as pallet_stableswap::Config>::AMMHandler::on_trade_weight());
 
 			let amm_weight = match trade.pool {
 				PoolType::LBP => lbp_weight,
 				PoolType::XYK => xyk_weight,
-				_ => lbp_weight.max(xyk_weight),
+				PoolType::Stableswap(_) => stableswap_weight,
+				_ => lbp_weight.max(xyk_weight).max(stableswap_weight),
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -734,11 +917,14 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 			let lbp_weight = weights::pallet_lbp::BasiliskWeight::<Runtime>::router_execution_buy(c, e);
 			let xyk_weight = weights::pallet_xyk::BasiliskWeight::<Runtime>::router_execution_buy(c, e)
 				.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight());
+			let stableswap_weight = weights::pallet_stableswap::BasiliskWeight::<Runtime>::router_execution_buy(c, e)
+				.saturating_add(<Runtime as pallet_stableswap::Config>::AMMHandler::on_trade_weight());
 
 			let amm_weight = match trade.pool {
 				PoolType::LBP => lbp_weight,
 				PoolType::XYK => xyk_weight,
-				_ => lbp_weight.max(xyk_weight),
+				PoolType::Stableswap(_) => stableswap_weight,
+				_ => lbp_weight.max(xyk_weight).max(stableswap_weight),
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -764,13 +950,15 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 
 		let lbp_weight = weights::pallet_lbp::BasiliskWeight::<Runtime>::router_execution_sell(1, 0);
 		let xyk_weight = weights::pallet_xyk::BasiliskWeight::<Runtime>::router_execution_sell(1, 0);
+		let stableswap_weight = weights::pallet_stableswap::BasiliskWeight::<Runtime>::router_execution_sell(1, 0);
 
 		//Calculate sell amounts for the new route
 		for trade in route {
 			let amm_weight = match trade.pool {
 				PoolType::LBP => lbp_weight,
 				PoolType::XYK => xyk_weight,
-				_ => lbp_weight.max(xyk_weight),
+				PoolType::Stableswap(_) => stableswap_weight,
+				_ => lbp_weight.max(xyk_weight).max(stableswap_weight),
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -780,18 +968,14 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 			let amm_weight = match trade.pool {
 				PoolType::LBP => lbp_weight,
 				PoolType::XYK => xyk_weight,
-				_ => lbp_weight.max(xyk_weight),
+				PoolType::Stableswap(_) => stableswap_weight,
+				_ => lbp_weight.max(xyk_weight).max(stableswap_weight),
 			};
 			weight.saturating_accrue(amm_weight);
 		}
 
 		// Incorporate oracle price calculation
-		// We use xyk as reference
-		let weight_of_get_oracle_price_for_2_assets =
-			weights::pallet_route_executor::BasiliskWeight::<Runtime>::get_oracle_price_for_xyk();
-		let weight_of_get_oracle_price_for_route =
-			weight_of_get_oracle_price_for_2_assets.saturating_mul(route.len() as u64);
-		weight.saturating_accrue(weight_of_get_oracle_price_for_route);
+		weight.saturating_accrue(Self::get_oracle_price_weight(route));
 
 		weight
 	}
@@ -807,12 +991,14 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 
 		let lbp_weight = weights::pallet_lbp::BasiliskWeight::<Runtime>::calculate_spot_price_with_fee();
 		let xyk_weight = weights::pallet_xyk::BasiliskWeight::<Runtime>::calculate_spot_price_with_fee();
+		let stableswap_weight = weights::pallet_stableswap::BasiliskWeight::<Runtime>::calculate_spot_price_with_fee();
 
 		for trade in route {
 			let amm_weight = match trade.pool {
 				PoolType::LBP => lbp_weight,
 				PoolType::XYK => xyk_weight,
-				_ => lbp_weight.max(xyk_weight),
+				PoolType::Stableswap(_) => stableswap_weight,
+				_ => lbp_weight.max(xyk_weight).max(stableswap_weight),
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -823,6 +1009,32 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 	fn get_route_weight() -> Weight {
 		weights::pallet_route_executor::BasiliskWeight::<Runtime>::get_route()
 	}
+
+	// Used in Router::sell_with_guard and Router::buy_with_guard, which check the oracle price of the route
+	fn get_oracle_price_weight(route: &[Trade<AssetId>]) -> Weight {
+		// We use xyk as reference
+		let weight_of_get_oracle_price_for_2_assets =
+			weights::pallet_route_executor::BasiliskWeight::<Runtime>::get_oracle_price_for_xyk();
+		weight_of_get_oracle_price_for_2_assets.saturating_mul(route.len() as u64)
+	}
+
+	// Used in Router::on_idle to maintain one route.
+	fn maintain_route_weight() -> Weight {
+		// Worst case is a stored route with max number of trades in the most expensive pool which is LBP.
+		// Both the stored route and the compared route are validated the same way as in set_route.
+		let worst_case_route = vec![
+			Trade {
+				pool: PoolType::LBP,
+				asset_in: NativeAssetId::get(),
+				asset_out: NativeAssetId::get(),
+			};
+			pallet_route_executor::MAX_NUMBER_OF_TRADES as usize
+		];
+
+		Self::set_route_weight(&worst_case_route)
+			.saturating_mul(2)
+			.saturating_add(Self::get_route_weight())
+	}
 }
 
 parameter_types! {
@@ -835,7 +1047,8 @@ pub struct RefundAndLockedEdCalculator;
 use basilisk_traits::router::RefundEdCalculator;
 impl RefundEdCalculator<Balance> for RefundAndLockedEdCalculator {
 	fn calculate() -> Balance {
-		// all assets are sufficient so `RefundAndLockedEdCalculator` is never called.
+		// Existential deposits are paid in the asset itself (`ExistentialDeposits` of orml_tokens are
+		// taken from the registry). No ED is charged in native currency, so there is nothing to refund.
 		Zero::zero()
 	}
 }
@@ -846,12 +1059,15 @@ impl pallet_route_executor::Config for Runtime {
 	type NativeAssetId = NativeAssetId;
 	type Currency = FungibleCurrencies<Runtime>;
 	type InspectRegistry = AssetRegistry;
-	type AMM = (XYK, LBP);
+	type AMM = (XYK, LBP, Stableswap);
 	type EdToRefundCalculator = RefundAndLockedEdCalculator;
 	type OraclePriceProvider = adapter::OraclePriceProvider<AssetId, EmaOracle>;
 	type OraclePeriod = RouteValidationOraclePeriod;
 	type DefaultRoutePoolType = DefaultRoutePoolType;
 	type ForceInsertOrigin = EitherOf<EnsureRoot<Self::AccountId>, GeneralAdmin>;
+	type RouteCandidates = (XYK, Stableswap);
+	type MaxRoutesMaintainedPerBlock = MaxRoutesMaintainedPerBlock;
+	type BlockNumberProvider = RelayChainBlockNumberProvider<Runtime>;
 	type WeightInfo = RouterWeightInfo;
 }
 
@@ -862,6 +1078,8 @@ parameter_types! {
 	// There are currently only a few pools, so the number of entries per block is limited.
 	// NOTE: Needs to be updated once the number of pools grows.
 	pub MaxUniqueOracleEntries: u32 = 30;
+	// Every custom period adds an oracle update per traded pair to `on_finalize`.
+	pub const MaxCustomOraclePeriods: u32 = 4;
 }
 
 impl pallet_ema_oracle::Config for Runtime {
@@ -869,6 +1087,8 @@ impl pallet_ema_oracle::Config for Runtime {
 	type AuthorityOrigin = EitherOf<EnsureRoot<Self::AccountId>, GeneralAdmin>;
 	type BlockNumberProvider = RelayChainBlockNumberProvider<Runtime>;
 	type SupportedPeriods = SupportedPeriods;
+	type MaxCustomPeriods = MaxCustomOraclePeriods;
+	type NativeAssetId = NativeAssetId;
 	type OracleWhitelist = Everything;
 	type MaxUniqueEntries = MaxUniqueOracleEntries;
 	#[cfg(feature = "runtime-benchmarks")]
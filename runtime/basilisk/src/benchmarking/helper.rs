@@ -1,7 +1,10 @@
 use crate::AssetRegistry;
 use frame_support::dispatch::DispatchResult;
+use frame_system::RawOrigin;
 use pallet_asset_registry::traits::InspectRegistry;
 use pallet_ema_oracle::BenchmarkHelper as EmaOracleBenchmarkHelper;
+use pallet_lbp::BenchmarkHelper as LBPBenchmarkHelper;
+use pallet_stableswap::BenchmarkHelper as StableswapBenchmarkHelper;
 use pallet_treasury::ArgumentsFactory;
 use primitives::{AccountId, AssetId};
 
@@ -34,3 +37,29 @@ impl EmaOracleBenchmarkHelper<AssetId> for BenchmarkHelper {
 		Ok(())
 	}
 }
+
+// LBP helper
+impl LBPBenchmarkHelper<AssetId> for BenchmarkHelper {
+	fn register_asset(asset_id: AssetId) -> DispatchResult {
+		<Self as EmaOracleBenchmarkHelper<AssetId>>::register_asset(asset_id)
+	}
+}
+
+// Stableswap helper
+impl StableswapBenchmarkHelper<AssetId> for BenchmarkHelper {
+	fn register_asset(asset_id: AssetId, decimals: u8) -> DispatchResult {
+		let name = asset_id.to_ne_bytes().to_vec();
+
+		if !AssetRegistry::exists(asset_id) {
+			let _ = AssetRegistry::register_asset(
+				AssetRegistry::to_bounded_name(name.clone())?,
+				pallet_asset_registry::AssetType::<AssetId>::Token,
+				1u128,
+				Some(asset_id),
+				None,
+			)?;
+		}
+
+		AssetRegistry::set_metadata(RawOrigin::Root.into(), asset_id, name, decimals)
+	}
+}
@@ -6,6 +6,7 @@ mod helper;
 pub mod marketplace;
 pub mod multi_payment;
 pub mod route_executor;
+pub mod stableswap_liquidity_mining;
 pub mod tokens;
 pub mod vesting;
 pub mod xyk;
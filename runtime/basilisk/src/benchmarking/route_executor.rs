@@ -65,6 +65,7 @@ fn funded_account(name: &'static str, index: u32, assets: &[AssetId]) -> Account
 	account
 }
 
+/// Creates a sale of `asset_out` with vesting of the bought tokens, `asset_out` has to be the native asset.
 fn setup_lbp(caller: AccountId, asset_in: AssetId, asset_out: AssetId) -> DispatchResult {
 	let asset_in_amount = 1_000_000_000;
 	let asset_out_amount = 2_000_000_000;
@@ -99,8 +100,8 @@ fn setup_lbp(caller: AccountId, asset_in: AssetId, asset_out: AssetId) -> Dispat
 	let end = 11u32;
 
 	LBP::update_pool_data(
-		RawOrigin::Signed(caller).into(),
-		pool_id,
+		RawOrigin::Signed(caller.clone()).into(),
+		pool_id.clone(),
 		None,
 		Some(start),
 		Some(end),
@@ -111,6 +112,16 @@ fn setup_lbp(caller: AccountId, asset_in: AssetId, asset_out: AssetId) -> Dispat
 		None,
 	)?;
 
+	// worst case: bought native asset is transferred with a new vesting schedule
+	LBP::set_sale_vesting(
+		RawOrigin::Signed(caller).into(),
+		pool_id,
+		Some(pallet_lbp::SaleVesting {
+			cliff: 10u32,
+			duration: 100u32,
+		}),
+	)?;
+
 	System::set_block_number(2u32);
 	Ok(())
 }
@@ -191,7 +202,7 @@ runtime_benchmarks! {
 		let c in 0..1;	// if c == 1, calculate_sell_trade_amounts is executed
 
 		let asset_in = register_asset(b"FCA".to_vec(), 1u128).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
-		let asset_out = register_asset(b"FCB".to_vec(), 1u128).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+		let asset_out = HDX;
 		let caller: AccountId = funded_account("caller", 7, &[asset_in, asset_out]);
 		let seller: AccountId = funded_account("seller", 8, &[asset_in, asset_out]);
 
@@ -224,7 +235,7 @@ runtime_benchmarks! {
 		let b in 0..1;	// if e == 1, buy is executed
 
 		let asset_in = register_asset(b"FCA".to_vec(), 1u128).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
-		let asset_out = register_asset(b"FCB".to_vec(), 1u128).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+		let asset_out = HDX;
 		let caller: AccountId = funded_account("caller", 0, &[asset_in, asset_out]);
 		let buyer: AccountId = funded_account("buyer", 1, &[asset_in, asset_out]);
 
@@ -449,7 +460,7 @@ runtime_benchmarks! {
 	// Calculates the weight of LBP spot price with fee calculation. Used in the calculation to determine the weight of the overhead.
 	calculate_spot_price_with_fee_in_lbp {
 		let asset_in = register_asset(b"FCA".to_vec(), 1u128).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
-		let asset_out = register_asset(b"FCB".to_vec(), 1u128).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+		let asset_out = HDX;
 		let caller: AccountId = funded_account("caller", 7, &[asset_in, asset_out]);
 		let seller: AccountId = funded_account("seller", 8, &[asset_in, asset_out]);
 
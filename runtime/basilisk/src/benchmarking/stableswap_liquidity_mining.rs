@@ -0,0 +1,328 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2025  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{AccountId, AssetId, Balance, Currencies, Runtime, Stableswap, StableswapLiquidityMining, System};
+
+use super::*;
+
+use frame_benchmarking::{account, BenchmarkError};
+use frame_system::RawOrigin;
+use orml_benchmarking::runtime_benchmarks;
+use orml_traits::MultiCurrency;
+use pallet_stableswap::types::AssetAmount;
+use pallet_stableswap::BenchmarkHelper as StableswapBenchmarkHelper;
+use sp_arithmetic::Perquintill;
+use sp_runtime::{DispatchResult, Permill};
+use sp_std::prelude::*;
+use warehouse_liquidity_mining::{GlobalFarmId, LoyaltyCurve, YieldFarmId};
+
+const SEED: u32 = 0;
+
+const GLOBAL_FARM_ID: GlobalFarmId = 1;
+const GLOBAL_FARM_ID_2: GlobalFarmId = 3;
+const YIELD_FARM_ID: YieldFarmId = 2;
+const YIELD_FARM_ID_2: YieldFarmId = 4;
+const DEPOSIT_ID: u128 = 1;
+
+const NATIVE: AssetId = 0;
+// Pool assets and shares have 18 decimals.
+const ONE: Balance = 1_000_000_000_000_000_000;
+const INITIAL_BALANCE: Balance = 100_000_000;
+
+fn funded_account(name: &'static str, index: u32, assets: &[AssetId]) -> AccountId {
+	let caller: AccountId = account(name, index, SEED);
+	update_balance(NATIVE, &caller, INITIAL_BALANCE * BSX);
+	for asset in assets {
+		update_balance(*asset, &caller, INITIAL_BALANCE * ONE);
+	}
+	caller
+}
+
+#[allow(clippy::result_large_err)]
+fn pool_assets(assets: &[AssetId]) -> Result<Vec<AssetId>, BenchmarkError> {
+	for asset in assets {
+		<BenchmarkHelper as StableswapBenchmarkHelper<AssetId>>::register_asset(*asset, 18)
+			.map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+	}
+	Ok(assets.to_vec())
+}
+
+/// Creates a stableswap pool of `assets` and returns its id. `provider` adds liquidity to the pool
+/// and receives the shares.
+#[allow(clippy::result_large_err)]
+fn create_pool(assets: &[AssetId], provider: AccountId) -> Result<AssetId, BenchmarkError> {
+	Stableswap::create_pool(RawOrigin::Root.into(), assets.to_vec(), 100, Permill::from_parts(400))?;
+
+	let pool_id = pallet_stableswap::Pools::<Runtime>::iter()
+		.find(|(_, pool)| pool.assets.as_slice() == assets)
+		.map(|(pool_id, _)| pool_id)
+		.ok_or(BenchmarkError::Stop("Pool not created"))?;
+
+	let liquidity = assets
+		.iter()
+		.map(|asset| AssetAmount::new(*asset, 1_000_000 * ONE))
+		.collect();
+	Stableswap::add_liquidity(RawOrigin::Signed(provider).into(), pool_id, liquidity, 0)?;
+
+	Ok(pool_id)
+}
+
+fn lm_create_global_farm(incentivized_asset: AssetId, owner: AccountId) -> DispatchResult {
+	StableswapLiquidityMining::create_global_farm(
+		RawOrigin::Root.into(),
+		100_000 * BSX,
+		1_000_000,
+		1,
+		incentivized_asset,
+		NATIVE,
+		owner,
+		Perquintill::from_percent(20),
+		1_000,
+		FixedU128::one(),
+	)
+}
+
+fn lm_create_yield_farm(owner: AccountId, global_farm_id: GlobalFarmId, pool_id: AssetId) -> DispatchResult {
+	StableswapLiquidityMining::create_yield_farm(
+		RawOrigin::Signed(owner).into(),
+		global_farm_id,
+		pool_id,
+		FixedU128::one(),
+		Some(LoyaltyCurve::default()),
+	)
+}
+
+fn set_period(block: u32) {
+	//NOTE: global farms are created with period size = 1 block.
+	System::set_block_number(block);
+}
+
+runtime_benchmarks! {
+	{ Runtime, pallet_stableswap_liquidity_mining }
+
+	create_global_farm {
+		let assets = pool_assets(&[1_001, 1_002])?;
+		let owner = funded_account("owner", 0, &[]);
+	}: _(RawOrigin::Root, 100_000 * BSX, 1_000_000, 1, assets[0], NATIVE, owner, Perquintill::from_percent(20), 1_000, FixedU128::one())
+
+	update_global_farm {
+		let assets = pool_assets(&[1_001, 1_002])?;
+		let owner = funded_account("owner", 0, &[]);
+		let provider = funded_account("provider", 1, &assets);
+		let pool_id = create_pool(&assets, provider.clone())?;
+
+		lm_create_global_farm(assets[0], owner.clone())?;
+		lm_create_yield_farm(owner.clone(), GLOBAL_FARM_ID, pool_id)?;
+		StableswapLiquidityMining::deposit_shares(RawOrigin::Signed(provider).into(), GLOBAL_FARM_ID, YIELD_FARM_ID, pool_id, 10 * ONE)?;
+		set_period(200_000);
+	}: _(RawOrigin::Signed(owner), GLOBAL_FARM_ID, FixedU128::from_inner(234_456_677_000_000_000_u128))
+
+	terminate_global_farm {
+		let assets = pool_assets(&[1_001, 1_002])?;
+		let owner = funded_account("owner", 0, &[]);
+		let provider = funded_account("provider", 1, &assets);
+		let pool_id = create_pool(&assets, provider.clone())?;
+
+		lm_create_global_farm(assets[0], owner.clone())?;
+		lm_create_yield_farm(owner.clone(), GLOBAL_FARM_ID, pool_id)?;
+		StableswapLiquidityMining::deposit_shares(RawOrigin::Signed(provider).into(), GLOBAL_FARM_ID, YIELD_FARM_ID, pool_id, 10 * ONE)?;
+		set_period(100_000);
+
+		StableswapLiquidityMining::stop_yield_farm(RawOrigin::Signed(owner.clone()).into(), GLOBAL_FARM_ID, pool_id)?;
+		StableswapLiquidityMining::terminate_yield_farm(RawOrigin::Signed(owner.clone()).into(), GLOBAL_FARM_ID, YIELD_FARM_ID, pool_id)?;
+		set_period(200_000);
+	}: _(RawOrigin::Signed(owner), GLOBAL_FARM_ID)
+
+	create_yield_farm {
+		let assets = pool_assets(&[1_001, 1_002, 1_003])?;
+		let owner = funded_account("owner", 0, &[]);
+		let provider = funded_account("provider", 1, &assets);
+		let pool_id = create_pool(&assets[..2], provider.clone())?;
+
+		lm_create_global_farm(assets[0], owner.clone())?;
+		lm_create_yield_farm(owner.clone(), GLOBAL_FARM_ID, pool_id)?;
+		StableswapLiquidityMining::deposit_shares(RawOrigin::Signed(provider.clone()).into(), GLOBAL_FARM_ID, YIELD_FARM_ID, pool_id, 10 * ONE)?;
+		set_period(100_000);
+
+		let pool_id_2 = create_pool(&[assets[0], assets[2]], provider)?;
+	}: _(RawOrigin::Signed(owner), GLOBAL_FARM_ID, pool_id_2, FixedU128::from(50_000_000_u128), Some(LoyaltyCurve::default()))
+
+	update_yield_farm {
+		let assets = pool_assets(&[1_001, 1_002])?;
+		let owner = funded_account("owner", 0, &[]);
+		let provider = funded_account("provider", 1, &assets);
+		let pool_id = create_pool(&assets, provider.clone())?;
+
+		lm_create_global_farm(assets[0], owner.clone())?;
+		lm_create_yield_farm(owner.clone(), GLOBAL_FARM_ID, pool_id)?;
+		StableswapLiquidityMining::deposit_shares(RawOrigin::Signed(provider).into(), GLOBAL_FARM_ID, YIELD_FARM_ID, pool_id, 10 * ONE)?;
+		set_period(100_000);
+	}: _(RawOrigin::Signed(owner), GLOBAL_FARM_ID, pool_id, FixedU128::from_inner(500_000_000_000_000_000_u128))
+
+	stop_yield_farm {
+		let assets = pool_assets(&[1_001, 1_002])?;
+		let owner = funded_account("owner", 0, &[]);
+		let provider = funded_account("provider", 1, &assets);
+		let pool_id = create_pool(&assets, provider.clone())?;
+
+		lm_create_global_farm(assets[0], owner.clone())?;
+		lm_create_yield_farm(owner.clone(), GLOBAL_FARM_ID, pool_id)?;
+		StableswapLiquidityMining::deposit_shares(RawOrigin::Signed(provider).into(), GLOBAL_FARM_ID, YIELD_FARM_ID, pool_id, 10 * ONE)?;
+		set_period(100_000);
+	}: _(RawOrigin::Signed(owner), GLOBAL_FARM_ID, pool_id)
+
+	resume_yield_farm {
+		let assets = pool_assets(&[1_001, 1_002, 1_003])?;
+		let owner = funded_account("owner", 0, &[]);
+		let provider = funded_account("provider", 1, &assets);
+		let pool_id = create_pool(&assets[..2], provider.clone())?;
+		let pool_id_2 = create_pool(&[assets[0], assets[2]], provider.clone())?;
+
+		//global id: 1
+		lm_create_global_farm(assets[0], owner.clone())?;
+		//yield id: 2
+		lm_create_yield_farm(owner.clone(), GLOBAL_FARM_ID, pool_id)?;
+		//yield id: 3
+		lm_create_yield_farm(owner.clone(), GLOBAL_FARM_ID, pool_id_2)?;
+
+		StableswapLiquidityMining::stop_yield_farm(RawOrigin::Signed(owner.clone()).into(), GLOBAL_FARM_ID, pool_id)?;
+		set_period(200_000);
+
+		StableswapLiquidityMining::deposit_shares(RawOrigin::Signed(provider).into(), GLOBAL_FARM_ID, 3, pool_id_2, 10 * ONE)?;
+		set_period(400_000);
+	}: _(RawOrigin::Signed(owner), GLOBAL_FARM_ID, YIELD_FARM_ID, pool_id, FixedU128::from(12_452))
+
+	terminate_yield_farm {
+		let assets = pool_assets(&[1_001, 1_002])?;
+		let owner = funded_account("owner", 0, &[]);
+		let provider = funded_account("provider", 1, &assets);
+		let pool_id = create_pool(&assets, provider.clone())?;
+
+		lm_create_global_farm(assets[0], owner.clone())?;
+		lm_create_yield_farm(owner.clone(), GLOBAL_FARM_ID, pool_id)?;
+		StableswapLiquidityMining::deposit_shares(RawOrigin::Signed(provider).into(), GLOBAL_FARM_ID, YIELD_FARM_ID, pool_id, 10 * ONE)?;
+		set_period(100_000);
+
+		StableswapLiquidityMining::stop_yield_farm(RawOrigin::Signed(owner.clone()).into(), GLOBAL_FARM_ID, pool_id)?;
+	}: _(RawOrigin::Signed(owner), GLOBAL_FARM_ID, YIELD_FARM_ID, pool_id)
+
+	deposit_shares {
+		let assets = pool_assets(&[1_001, 1_002])?;
+		let owner = funded_account("owner", 0, &[]);
+		let provider = funded_account("provider", 1, &assets);
+		let pool_id = create_pool(&assets, provider.clone())?;
+
+		lm_create_global_farm(assets[0], owner.clone())?;
+		lm_create_yield_farm(owner, GLOBAL_FARM_ID, pool_id)?;
+		StableswapLiquidityMining::deposit_shares(RawOrigin::Signed(provider.clone()).into(), GLOBAL_FARM_ID, YIELD_FARM_ID, pool_id, 5 * ONE)?;
+		set_period(100_000);
+	}: _(RawOrigin::Signed(provider), GLOBAL_FARM_ID, YIELD_FARM_ID, pool_id, 5 * ONE)
+
+	redeposit_shares {
+		let assets = pool_assets(&[1_001, 1_002])?;
+		let owner = funded_account("owner", 0, &[]);
+		let provider = funded_account("provider", 1, &assets);
+		let pool_id = create_pool(&assets, provider.clone())?;
+
+		// global farm ids: 1, 3, 5, 7, 9 with yield farm ids: 2, 4, 6, 8, 10
+		for global_farm_id in [1, 3, 5, 7, 9] {
+			lm_create_global_farm(assets[0], owner.clone())?;
+			lm_create_yield_farm(owner.clone(), global_farm_id, pool_id)?;
+		}
+		set_period(200_000);
+
+		StableswapLiquidityMining::deposit_shares(RawOrigin::Signed(provider.clone()).into(), GLOBAL_FARM_ID, YIELD_FARM_ID, pool_id, 10 * ONE)?;
+		StableswapLiquidityMining::redeposit_shares(RawOrigin::Signed(provider.clone()).into(), GLOBAL_FARM_ID_2, YIELD_FARM_ID_2, pool_id, DEPOSIT_ID)?;
+		StableswapLiquidityMining::redeposit_shares(RawOrigin::Signed(provider.clone()).into(), 5, 6, pool_id, DEPOSIT_ID)?;
+		StableswapLiquidityMining::redeposit_shares(RawOrigin::Signed(provider.clone()).into(), 7, 8, pool_id, DEPOSIT_ID)?;
+	}: _(RawOrigin::Signed(provider), 9, 10, pool_id, DEPOSIT_ID)
+
+	claim_rewards {
+		let assets = pool_assets(&[1_001, 1_002])?;
+		let owner = funded_account("owner", 0, &[]);
+		let provider = funded_account("provider", 1, &assets);
+		let pool_id = create_pool(&assets, provider.clone())?;
+
+		// global farm ids: 1, 3, 5, 7, 9 with yield farm ids: 2, 4, 6, 8, 10
+		for global_farm_id in [1, 3, 5, 7, 9] {
+			lm_create_global_farm(assets[0], owner.clone())?;
+			lm_create_yield_farm(owner.clone(), global_farm_id, pool_id)?;
+		}
+		set_period(200_000);
+
+		StableswapLiquidityMining::deposit_shares(RawOrigin::Signed(provider.clone()).into(), GLOBAL_FARM_ID, YIELD_FARM_ID, pool_id, 10 * ONE)?;
+		for (global_farm_id, yield_farm_id) in [(3, 4), (5, 6), (7, 8), (9, 10)] {
+			StableswapLiquidityMining::redeposit_shares(RawOrigin::Signed(provider.clone()).into(), global_farm_id, yield_farm_id, pool_id, DEPOSIT_ID)?;
+		}
+
+		set_period(400_000);
+		let bsx_balance = Currencies::free_balance(NATIVE, &provider);
+	}: _(RawOrigin::Signed(provider.clone()), DEPOSIT_ID, 10)
+	verify {
+		assert!(Currencies::free_balance(NATIVE, &provider) > bsx_balance);
+	}
+
+	// Withdrawal of the last farm entry is the most expensive one as the deposit is destroyed.
+	withdraw_shares {
+		let assets = pool_assets(&[1_001, 1_002])?;
+		let owner = funded_account("owner", 0, &[]);
+		let provider = funded_account("provider", 1, &assets);
+		let pool_id = create_pool(&assets, provider.clone())?;
+
+		lm_create_global_farm(assets[0], owner.clone())?;
+		lm_create_yield_farm(owner, GLOBAL_FARM_ID, pool_id)?;
+		set_period(200_000);
+
+		StableswapLiquidityMining::deposit_shares(RawOrigin::Signed(provider.clone()).into(), GLOBAL_FARM_ID, YIELD_FARM_ID, pool_id, 10 * ONE)?;
+		set_period(400_000);
+
+		let bsx_balance = Currencies::free_balance(NATIVE, &provider);
+	}: _(RawOrigin::Signed(provider.clone()), DEPOSIT_ID, YIELD_FARM_ID, pool_id)
+	verify {
+		assert!(Currencies::free_balance(NATIVE, &provider) > bsx_balance);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::NativeExistentialDeposit;
+	use orml_benchmarking::impl_benchmark_test_suite;
+	use sp_runtime::BuildStorage;
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.unwrap();
+
+		pallet_asset_registry::GenesisConfig::<Runtime> {
+			registered_assets: vec![],
+			native_asset_name: b"BSX".to_vec(),
+			native_existential_deposit: NativeExistentialDeposit::get(),
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		pallet_stableswap_liquidity_mining::GenesisConfig::<Runtime>::default()
+			.assimilate_storage(&mut t)
+			.unwrap();
+
+		sp_io::TestExternalities::new(t)
+	}
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
@@ -0,0 +1,46 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2026  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Genesis of the runtime.
+//!
+//! The genesis of pallets is built in the order of `construct_runtime!`, where XYK, LBP and the
+//! router are declared before Tokens. Pools and routes are therefore built in a separate step
+//! after the genesis of all other pallets, when the balances of the pool owners are already set.
+
+use crate::*;
+use frame_support::traits::BuildGenesisConfig;
+
+/// Builds the genesis state from `config` in the current externalities.
+pub fn build(mut config: RuntimeGenesisConfig) {
+	let xyk = core::mem::take(&mut config.xyk);
+	let lbp = core::mem::take(&mut config.lbp);
+	let router = core::mem::take(&mut config.router);
+
+	<RuntimeGenesisConfig as BuildGenesisConfig>::build(&config);
+
+	xyk.build();
+	lbp.build();
+	router.build();
+}
+
+/// Builds the genesis state from the JSON representation of `RuntimeGenesisConfig`.
+pub fn build_state(json: Vec<u8>) -> sp_genesis_builder::Result {
+	let config = serde_json::from_slice::<RuntimeGenesisConfig>(&json)
+		.map_err(|e| alloc::format!("Invalid JSON blob: {}", e))?;
+	build(config);
+	Ok(())
+}
@@ -39,13 +39,16 @@ pub mod weights;
 mod adapter;
 pub mod apis;
 mod assets;
+pub mod genesis;
 mod governance;
+mod parameters;
 mod system;
 pub mod xcm;
 
 pub use assets::*;
 pub use governance::origins::pallet_custom_origins;
 pub use governance::*;
+pub use parameters::*;
 pub use system::*;
 pub use xcm::*;
 
@@ -103,7 +106,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: Cow::Borrowed("basilisk"),
 	impl_name: Cow::Borrowed("basilisk"),
 	authoring_version: 1,
-	spec_version: 135,
+	spec_version: 158,
 	impl_version: 0,
 	apis: apis::RUNTIME_API_VERSIONS,
 	transaction_version: 1,
@@ -227,6 +230,9 @@ construct_runtime!(
 		CollatorRotation: pallet_collator_rotation = 119,
 
 		EmaOracle: pallet_ema_oracle = 120,
+		Stableswap: pallet_stableswap = 121,
+		StableswapLiquidityMining: pallet_stableswap_liquidity_mining = 122,
+		StableswapWarehouseLM: warehouse_liquidity_mining::<Instance2> = 123,
 
 		// ORML related modules - runtime module index for orml starts at 150
 		Currencies: pallet_currencies = 150,
@@ -310,6 +316,8 @@ mod benches {
 		[pallet_whitelist, Whitelist]
 		[pallet_transaction_payment, TransactionPayment]
 		[pallet_xyk, benchmarking::xyk::Benchmark]
+		[pallet_stableswap, Stableswap]
+		[pallet_stableswap_liquidity_mining, benchmarking::stableswap_liquidity_mining::Benchmark]
 		[pallet_currencies, benchmarking::currencies::Benchmark]
 		[orml_tokens, benchmarking::tokens::Benchmark]
 		[orml_vesting, benchmarking::vesting::Benchmark]
@@ -318,6 +326,7 @@ mod benches {
 		[pallet_route_executor, benchmarking::route_executor::Benchmark]
 		[pallet_marketplace, benchmarking::marketplace::Benchmark]
 		[pallet_migrations, MultiBlockMigrations]
+		[pallet_parameters, Parameters]
 	);
 }
 
@@ -24,7 +24,12 @@ frame_support::parameter_types! {
 }
 
 // New migrations which need to be cleaned up after Runtime upgrade
-pub type UnreleasedSingleBlockMigrations = RemovePallet<DemocracyPalletName, RocksDbWeight>;
+pub type UnreleasedSingleBlockMigrations = (
+	RemovePallet<DemocracyPalletName, RocksDbWeight>,
+	pallet_asset_registry::migration::v2::MigrateV1ToV2<Runtime>,
+	pallet_ema_oracle::migration::v1::MigrateV0ToV1<Runtime>,
+	pallet_stableswap_liquidity_mining::migration::InitLiquidityMining<Runtime>,
+);
 
 // These migrations can run on every runtime upgrade
 pub type PermanentSingleBlockMigrations = pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>;
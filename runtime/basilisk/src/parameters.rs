@@ -0,0 +1,159 @@
+// This file is part of Basilisk-node.
+
+// Copyright (C) 2020-2026  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime parameters which can be changed by governance without a runtime upgrade.
+//!
+//! Every parameter has a key, a typed value with bounds, the origin which can set it and a default value
+//! used until the parameter is set. Pallet configs read the parameters through `Get` adapters.
+
+use super::*;
+use crate::governance::origins::GeneralAdmin;
+
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::{
+	dispatch::DispatchResult,
+	sp_runtime::RuntimeDebug,
+	traits::{EitherOf, EnsureOrigin},
+};
+use frame_system::EnsureRoot;
+use pallet_parameters::{ParameterOf, ParameterRules, RuntimeParameter};
+use primitives::constants::{
+	chain::{MAX_IN_RATIO, MAX_OUT_RATIO, MIN_POOL_LIQUIDITY, MIN_TRADING_LIMIT},
+	currency::{NATIVE_EXISTENTIAL_DEPOSIT, UNITS},
+};
+use scale_info::TypeInfo;
+
+/// Max trade fee of XYK pools, 5%.
+const MAX_XYK_EXCHANGE_FEE: (u32, u32) = (5, 100);
+/// The exponentiation used in the LBP math can overflow for ratios smaller than 3.
+const MIN_TRADE_RATIO: u128 = 3;
+const MAX_MIN_TRADING_LIMIT: Balance = 1_000 * UNITS;
+const MAX_ROUTES_MAINTAINED_PER_BLOCK: u32 = 20;
+
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ParameterKey {
+	XYKExchangeFee,
+	MinTradingLimit,
+	MinPoolLiquidity,
+	MaxInRatio,
+	MaxOutRatio,
+	MaxRoutesMaintainedPerBlock,
+	MinTotalFarmRewards,
+}
+
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ParameterValue {
+	Balance(Balance),
+	Ratio(u128),
+	Fee((u32, u32)),
+	U32(u32),
+}
+
+impl ParameterRules<RuntimeOrigin, ParameterValue> for ParameterKey {
+	fn ensure_origin(&self, origin: RuntimeOrigin) -> DispatchResult {
+		match self {
+			// parameters affecting the pool math require a root referendum
+			ParameterKey::XYKExchangeFee | ParameterKey::MaxInRatio | ParameterKey::MaxOutRatio => {
+				EnsureRoot::<AccountId>::ensure_origin(origin)?;
+			}
+			ParameterKey::MinTradingLimit
+			| ParameterKey::MinPoolLiquidity
+			| ParameterKey::MaxRoutesMaintainedPerBlock
+			| ParameterKey::MinTotalFarmRewards => {
+				EitherOf::<EnsureRoot<AccountId>, GeneralAdmin>::ensure_origin(origin)?;
+			}
+		}
+		Ok(())
+	}
+
+	fn validate(&self, value: &ParameterValue) -> bool {
+		match (self, value) {
+			(ParameterKey::XYKExchangeFee, ParameterValue::Fee((numerator, denominator))) => {
+				let (max_numerator, max_denominator) = MAX_XYK_EXCHANGE_FEE;
+				*denominator > 0
+					&& (*numerator as u64).saturating_mul(max_denominator as u64)
+						<= (*denominator as u64).saturating_mul(max_numerator as u64)
+			}
+			(ParameterKey::MinTradingLimit, ParameterValue::Balance(limit)) => {
+				*limit > 0 && *limit <= MAX_MIN_TRADING_LIMIT
+			}
+			(ParameterKey::MinPoolLiquidity, ParameterValue::Balance(liquidity)) => *liquidity > 0,
+			(ParameterKey::MaxInRatio | ParameterKey::MaxOutRatio, ParameterValue::Ratio(ratio)) => {
+				*ratio >= MIN_TRADE_RATIO
+			}
+			(ParameterKey::MaxRoutesMaintainedPerBlock, ParameterValue::U32(routes)) => {
+				(1..=MAX_ROUTES_MAINTAINED_PER_BLOCK).contains(routes)
+			}
+			(ParameterKey::MinTotalFarmRewards, ParameterValue::Balance(rewards)) => {
+				*rewards >= NATIVE_EXISTENTIAL_DEPOSIT
+			}
+			_ => false,
+		}
+	}
+}
+
+macro_rules! runtime_parameter {
+	( $name:ident, $parameter:ident: $type:ty = $default:expr, $variant:ident ) => {
+		pub struct $parameter;
+		impl RuntimeParameter<ParameterKey, ParameterValue> for $parameter {
+			type Type = $type;
+
+			fn key() -> ParameterKey {
+				ParameterKey::$name
+			}
+
+			fn default_value() -> Self::Type {
+				$default
+			}
+
+			fn try_from_value(value: ParameterValue) -> Option<Self::Type> {
+				match value {
+					ParameterValue::$variant(value) => Some(value),
+					_ => None,
+				}
+			}
+		}
+
+		pub type $name = ParameterOf<Runtime, $parameter>;
+	};
+}
+
+runtime_parameter!(XYKExchangeFee, XYKExchangeFeeParameter: (u32, u32) = (3, 1_000), Fee);
+runtime_parameter!(MinTradingLimit, MinTradingLimitParameter: Balance = MIN_TRADING_LIMIT, Balance);
+runtime_parameter!(MinPoolLiquidity, MinPoolLiquidityParameter: Balance = MIN_POOL_LIQUIDITY, Balance);
+runtime_parameter!(MaxInRatio, MaxInRatioParameter: u128 = MAX_IN_RATIO, Ratio);
+runtime_parameter!(MaxOutRatio, MaxOutRatioParameter: u128 = MAX_OUT_RATIO, Ratio);
+runtime_parameter!(MaxRoutesMaintainedPerBlock, MaxRoutesMaintainedPerBlockParameter: u32 = 5, U32);
+runtime_parameter!(MinTotalFarmRewards, MinTotalFarmRewardsParameter: Balance = NATIVE_EXISTENTIAL_DEPOSIT * 100, Balance);
+
+impl pallet_parameters::Config for Runtime {
+	type ParameterKey = ParameterKey;
+	type ParameterValue = ParameterValue;
+	type WeightInfo = weights::pallet_parameters::BasiliskWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ParametersBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct ParametersBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_parameters::BenchmarkHelper<ParameterKey, ParameterValue> for ParametersBenchmarkHelper {
+	fn parameter() -> (ParameterKey, ParameterValue) {
+		(ParameterKey::XYKExchangeFee, ParameterValue::Fee((2, 1_000)))
+	}
+}
@@ -218,8 +218,6 @@ impl pallet_migrations::Config for Runtime {
 	type WeightInfo = weights::pallet_migrations::BasiliskWeight<Runtime>;
 }
 
-impl pallet_parameters::Config for Runtime {}
-
 pub struct LogErrorAndForceUnstuck;
 impl frame_support::migrations::FailedMigrationHandler for LogErrorAndForceUnstuck {
 	fn failed(migration: Option<u32>) -> FailedMigrationHandling {
@@ -549,7 +547,7 @@ parameter_types! {
 
 impl pallet_authorship::Config for Runtime {
 	type FindAuthor = pallet_session::FindAccountFromAuthorIndex<Self, Aura>;
-	type EventHandler = (CollatorSelection,);
+	type EventHandler = (CollatorSelection, CollatorRotation);
 }
 
 parameter_types! {
@@ -631,8 +629,14 @@ impl pallet_collator_rewards::Config for Runtime {
 	type RewardsBag = TreasuryAccount;
 }
 
+parameter_types! {
+	pub MaxRotatedCollators: u32 = MaxCandidates::get() + MaxInvulnerables::get();
+}
+
 impl pallet_collator_rotation::Config for Runtime {
 	type Inner = CollatorSelection;
+	type BenchPolicy = pallet_collator_rotation::LeastAuthored;
+	type MaxCollators = MaxRotatedCollators;
 }
 
 parameter_types! {
@@ -226,3 +226,65 @@ fn governance_tracks_use_testnet_timings_when_enabled() {
 		assert_eq!(general_admin_track.info.confirm_period, 1);
 	});
 }
+
+#[test]
+fn runtime_parameters_default_to_chain_constants() {
+	let mut ext: sp_io::TestExternalities = frame_system::GenesisConfig::<Runtime>::default()
+		.build_storage()
+		.unwrap()
+		.into();
+
+	ext.execute_with(|| {
+		assert_eq!(XYKExchangeFee::get(), (3, 1_000));
+		assert_eq!(MinTradingLimit::get(), primitives::constants::chain::MIN_TRADING_LIMIT);
+		assert_eq!(MaxInRatio::get(), primitives::constants::chain::MAX_IN_RATIO);
+		assert_eq!(
+			<Runtime as pallet_xyk::Config>::MinTradingLimit::get(),
+			MinTradingLimit::get()
+		);
+	});
+}
+
+#[test]
+fn runtime_parameters_can_be_set_by_their_origin() {
+	let mut ext: sp_io::TestExternalities = frame_system::GenesisConfig::<Runtime>::default()
+		.build_storage()
+		.unwrap()
+		.into();
+
+	ext.execute_with(|| {
+		assert!(Parameters::set_parameter(
+			RuntimeOrigin::from(pallet_custom_origins::Origin::GeneralAdmin),
+			ParameterKey::XYKExchangeFee,
+			Some(ParameterValue::Fee((5, 1_000)))
+		)
+		.is_err());
+		assert!(Parameters::set_parameter(
+			RuntimeOrigin::root(),
+			ParameterKey::XYKExchangeFee,
+			Some(ParameterValue::Fee((6, 100)))
+		)
+		.is_err());
+
+		assert!(Parameters::set_parameter(
+			RuntimeOrigin::root(),
+			ParameterKey::XYKExchangeFee,
+			Some(ParameterValue::Fee((5, 1_000)))
+		)
+		.is_ok());
+		assert_eq!(<Runtime as pallet_xyk::Config>::GetExchangeFee::get(), (5, 1_000));
+
+		assert!(Parameters::set_parameter(
+			RuntimeOrigin::from(pallet_custom_origins::Origin::GeneralAdmin),
+			ParameterKey::MinTradingLimit,
+			Some(ParameterValue::Balance(
+				2 * primitives::constants::chain::MIN_TRADING_LIMIT
+			))
+		)
+		.is_ok());
+		assert_eq!(
+			<Runtime as pallet_lbp::Config>::MinTradingLimit::get(),
+			2 * primitives::constants::chain::MIN_TRADING_LIMIT
+		);
+	});
+}
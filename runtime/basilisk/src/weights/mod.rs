@@ -19,11 +19,14 @@ pub mod pallet_message_queue;
 pub mod pallet_migrations;
 pub mod pallet_multisig;
 pub mod pallet_nft;
+pub mod pallet_parameters;
 pub mod pallet_preimage;
 pub mod pallet_proxy;
 pub mod pallet_referenda;
 pub mod pallet_route_executor;
 pub mod pallet_scheduler;
+pub mod pallet_stableswap;
+pub mod pallet_stableswap_liquidity_mining;
 pub mod pallet_state_trie_migration;
 pub mod pallet_timestamp;
 pub mod pallet_transaction_multi_payment;
@@ -113,4 +113,70 @@ impl<T: frame_system::Config> pallet_asset_registry::WeightInfo for BasiliskWeig
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
-}
\ No newline at end of file
+	/// Storage: `AssetRegistry::AssetIds` (r:1 w:1)
+	/// Proof: `AssetRegistry::AssetIds` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::LocationAssets` (r:1 w:1)
+	/// Proof: `AssetRegistry::LocationAssets` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::ExternalRegistrationsInBlock` (r:1 w:1)
+	/// Proof: `AssetRegistry::ExternalRegistrationsInBlock` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::NextAssetId` (r:1 w:1)
+	/// Proof: `AssetRegistry::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetMetadataMap` (r:0 w:1)
+	/// Proof: `AssetRegistry::AssetMetadataMap` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetLocations` (r:0 w:1)
+	/// Proof: `AssetRegistry::AssetLocations` (`max_values`: None, `max_size`: Some(614), added: 3089, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::ExternalAssetDeposits` (r:0 w:1)
+	/// Proof: `AssetRegistry::ExternalAssetDeposits` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::Assets` (r:0 w:1)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	fn register_external() -> Weight {
+		// Not benchmarked yet, estimated from register and set_location.
+		Weight::from_parts(57_402_000, 4087)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: `AssetRegistry::ExternalAssetDeposits` (r:1 w:1)
+	/// Proof: `AssetRegistry::ExternalAssetDeposits` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn approve_external() -> Weight {
+		// Not benchmarked yet, estimated from set_location.
+		Weight::from_parts(27_190_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `AssetRegistry::AssetMetadataMap` (r:1 w:1)
+	/// Proof: `AssetRegistry::AssetMetadataMap` (`max_values`: None, `max_size`: Some(601), added: 3076, mode: `MaxEncodedLen`)
+	fn set_extended_metadata() -> Weight {
+		// Not benchmarked yet, estimated from set_metadata.
+		Weight::from_parts(22_903_000, 4066)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AssetRegistry::AssetMetadataMap` (r:1 w:1)
+	/// Proof: `AssetRegistry::AssetMetadataMap` (`max_values`: None, `max_size`: Some(601), added: 3076, mode: `MaxEncodedLen`)
+	fn set_verified() -> Weight {
+		// Not benchmarked yet, estimated from set_metadata.
+		Weight::from_parts(18_611_000, 4066)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AssetRegistry::ExternalAssetDeposits` (r:1 w:1)
+	/// Proof: `AssetRegistry::ExternalAssetDeposits` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::RejectedExternalAssets` (r:0 w:1)
+	/// Proof: `AssetRegistry::RejectedExternalAssets` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetLocations` (r:1 w:1)
+	/// Proof: `AssetRegistry::AssetLocations` (`max_values`: None, `max_size`: Some(614), added: 3089, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::LocationAssets` (r:0 w:1)
+	/// Proof: `AssetRegistry::LocationAssets` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn reject_external() -> Weight {
+		// Not benchmarked yet, estimated from approve_external and set_location.
+		Weight::from_parts(40_000_000, 4087)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+}
@@ -77,6 +77,38 @@ impl<T: frame_system::Config> pallet_ema_oracle::WeightInfo for BasiliskWeight<T
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
+	/// Storage: `EmaOracle::CustomPeriods` (r:1 w:1)
+	/// Proof: `EmaOracle::CustomPeriods` (`max_values`: Some(1), `max_size`: Some(41), added: 536, mode: `MaxEncodedLen`)
+	fn add_period() -> Weight {
+		// Not benchmarked yet, estimated from add_oracle.
+		Weight::from_parts(12_170_000, 1526)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EmaOracle::CustomPeriods` (r:1 w:1)
+	/// Proof: `EmaOracle::CustomPeriods` (`max_values`: Some(1), `max_size`: Some(41), added: 536, mode: `MaxEncodedLen`)
+	fn remove_period() -> Weight {
+		// Not benchmarked yet, estimated from add_oracle.
+		Weight::from_parts(11_782_000, 1526)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EmaOracle::CriticalPairs` (r:1 w:1)
+	/// Proof: `EmaOracle::CriticalPairs` (`max_values`: Some(1), `max_size`: Some(641), added: 1136, mode: `MaxEncodedLen`)
+	fn add_critical_pair() -> Weight {
+		// Not benchmarked yet, estimated from add_oracle.
+		Weight::from_parts(18_602_000, 2126)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EmaOracle::CriticalPairs` (r:1 w:1)
+	/// Proof: `EmaOracle::CriticalPairs` (`max_values`: Some(1), `max_size`: Some(641), added: 1136, mode: `MaxEncodedLen`)
+	fn remove_critical_pair() -> Weight {
+		// Not benchmarked yet, estimated from remove_oracle.
+		Weight::from_parts(19_045_000, 2126)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 	/// Storage: `EmaOracle::Accumulator` (r:1 w:0)
 	/// Proof: `EmaOracle::Accumulator` (`max_values`: Some(1), `max_size`: Some(4441), added: 4936, mode: `MaxEncodedLen`)
 	fn on_finalize_no_entry() -> Weight {
@@ -120,6 +152,22 @@ impl<T: frame_system::Config> pallet_ema_oracle::WeightInfo for BasiliskWeight<T
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `EmaOracle::WhitelistedAssets` (r:1 w:0)
+	/// Proof: `EmaOracle::WhitelistedAssets` (`max_values`: Some(1), `max_size`: Some(641), added: 1136, mode: `MaxEncodedLen`)
+	/// Storage: `EmaOracle::Accumulator` (r:1 w:1)
+	/// Proof: `EmaOracle::Accumulator` (`max_values`: Some(1), `max_size`: Some(5921), added: 6416, mode: `MaxEncodedLen`)
+	/// Storage: `EmaOracle::CriticalPairs` (r:1 w:0)
+	/// Proof: `EmaOracle::CriticalPairs` (`max_values`: Some(1), `max_size`: Some(641), added: 1136, mode: `MaxEncodedLen`)
+	/// Storage: `EmaOracle::Oracles` (r:62 w:0)
+	/// Proof: `EmaOracle::Oracles` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `System::Events` (r:1 w:1)
+	/// Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn on_trade_full_accumulator() -> Weight {
+		// Not benchmarked yet, estimated from on_trade_multiple_tokens plus two `Oracles` reads per valued entry.
+		Weight::from_parts(130_604_000, 171830)
+			.saturating_add(T::DbWeight::get().reads(66_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 	/// Storage: `EmaOracle::Accumulator` (r:1 w:1)
 	/// Proof: `EmaOracle::Accumulator` (`max_values`: Some(1), `max_size`: Some(4441), added: 4936, mode: `MaxEncodedLen`)
 	/// The range of component `b` is `[1, 29]`.
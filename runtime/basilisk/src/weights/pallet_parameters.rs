@@ -0,0 +1,45 @@
+// This file is part of Basilisk.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+//! Weights for `pallet_parameters`
+//!
+//! Not benchmarked yet, the weights are estimates and must be regenerated with
+//! `scripts/benchmarking.sh`.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weights for `pallet_parameters`.
+pub struct WeightInfo<T>(PhantomData<T>);
+
+/// Weights for `pallet_parameters` using the Basilisk node and recommended hardware.
+pub struct BasiliskWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_parameters::WeightInfo for BasiliskWeight<T> {
+	/// Storage: `Parameters::Values` (r:0 w:1)
+	/// Proof: `Parameters::Values` (`max_values`: None, `max_size`: Some(35), added: 2510, mode: `MaxEncodedLen`)
+	fn set_parameter() -> Weight {
+		// Not benchmarked yet, estimated from a single storage write.
+		Weight::from_parts(9_851_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
\ No newline at end of file
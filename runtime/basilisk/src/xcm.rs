@@ -20,6 +20,7 @@ use crate::governance::TreasuryAccount;
 use crate::origins::GeneralAdmin;
 use crate::system::WeightToFee;
 
+use basilisk_adapters::xcm_delivery_fee::{ChargeLocalAccounts, DeliveryFeeInFeeCurrency, DenyFeeAssetWithdrawal};
 use basilisk_adapters::xcm_exchange::XcmAssetExchanger;
 use basilisk_adapters::{MultiCurrencyTrader, ToFeeReceiver};
 use basilisk_traits::router::PoolType;
@@ -29,7 +30,8 @@ use frame_support::traits::TransformOrigin;
 use frame_support::{
 	parameter_types,
 	sp_runtime::traits::Convert,
-	traits::{Contains, ContainsPair, Disabled, EitherOf, Everything, Get, Nothing},
+	traits::{Contains, ContainsPair, Disabled, EitherOf, Everything, Get, Nothing, PalletInfoAccess},
+	weights::WeightToFee as _,
 	PalletId,
 };
 use frame_system::EnsureRoot;
@@ -39,6 +41,7 @@ use pallet_transaction_multi_payment::DepositAll;
 use pallet_xcm::XcmPassthrough;
 use parachains_common::message_queue::{NarrowOriginToSibling, ParaIdToSibling};
 use polkadot_parachain::primitives::{RelayChainBlockNumber, Sibling};
+use polkadot_runtime_common::xcm_sender::ExponentialPrice;
 use polkadot_xcm::v5::{prelude::*, Location, Weight as XcmWeight};
 use primitives::AssetId;
 use scale_info::TypeInfo;
@@ -47,13 +50,14 @@ use sp_runtime::Perbill;
 use sp_std::marker::PhantomData;
 use xcm_builder::{
 	AccountId32Aliases, AllowKnownQueryResponses, AllowSubscriptionsFrom, AllowTopLevelPaidExecutionFrom,
-	DescribeAllTerminal, DescribeFamily, EnsureXcmOrigin, FixedWeightBounds, HashedDescription, ParentIsPreset,
-	RelayChainAsNative, SiblingParachainAsNative, SiblingParachainConvertsVia, SignedAccountId32AsNative,
-	SignedToAccountId32, SovereignSignedViaLocation, TakeWeightCredit, WithComputedOrigin,
+	DenyRecursively, DenyThenTry, DescribeAllTerminal, DescribeFamily, EnsureXcmOrigin, FixedWeightBounds,
+	HashedDescription, ParentIsPreset, RelayChainAsNative, SiblingParachainAsNative, SiblingParachainConvertsVia,
+	SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation, TakeWeightCredit, WithComputedOrigin,
 };
 use xcm_executor::{Config, XcmExecutor};
 
 #[derive(Debug, Default, Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub struct AssetLocation(pub Location);
 
 impl Into<Option<Location>> for AssetLocation {
@@ -81,20 +85,24 @@ parameter_types! {
 
 pub type LocalOriginToLocation = SignedToAccountId32<RuntimeOrigin, AccountId, RelayNetwork>;
 
-pub type Barrier = (
-	TakeWeightCredit,
-	// Expected responses are OK.
-	AllowKnownQueryResponses<PolkadotXcm>,
-	WithComputedOrigin<
-		(
-			AllowTopLevelPaidExecutionFrom<Everything>,
-			// Subscriptions for version tracking are OK.
-			AllowSubscriptionsFrom<Everything>,
-		),
-		UniversalLocation,
-		ConstU32<8>,
-	>,
-);
+pub type Barrier = DenyThenTry<
+	// The delivery fee pseudo asset can be withdrawn only to pay delivery fees.
+	DenyRecursively<DenyFeeAssetWithdrawal<DeliveryFeeAssetId>>,
+	(
+		TakeWeightCredit,
+		// Expected responses are OK.
+		AllowKnownQueryResponses<PolkadotXcm>,
+		WithComputedOrigin<
+			(
+				AllowTopLevelPaidExecutionFrom<Everything>,
+				// Subscriptions for version tracking are OK.
+				AllowSubscriptionsFrom<Everything>,
+			),
+			UniversalLocation,
+			ConstU32<8>,
+		>,
+	),
+>;
 
 use sp_std::sync::Arc;
 parameter_types! {
@@ -168,7 +176,7 @@ impl Config for XcmConfig {
 	type RuntimeCall = RuntimeCall;
 	type XcmSender = XcmRouter;
 
-	type AssetTransactor = LocalAssetTransactor;
+	type AssetTransactor = (DeliveryFeeTransactor, LocalAssetTransactor);
 	type OriginConverter = XcmOriginToCallOrigin;
 	type IsReserve = Reserves;
 
@@ -198,7 +206,7 @@ impl Config for XcmConfig {
 	type SubscriptionService = PolkadotXcm;
 	type PalletInstancesInfo = AllPalletsWithSystem;
 	type MaxAssetsIntoHolding = ConstU32<64>;
-	type FeeManager = ();
+	type FeeManager = ChargeLocalAccounts;
 	type MessageExporter = ();
 	type UniversalAliases = Nothing;
 	type CallDispatcher = RuntimeCall;
@@ -234,7 +242,7 @@ impl cumulus_pallet_xcmp_queue::Config for Runtime {
 	type MaxPageSize = ConstU32<{ 128 * 1024 }>;
 	type ControllerOrigin = EitherOf<EnsureRoot<Self::AccountId>, GeneralAdmin>;
 	type ControllerOriginConverter = XcmOriginToCallOrigin;
-	type PriceForSiblingDelivery = polkadot_runtime_common::xcm_sender::NoPriceForMessageDelivery<ParaId>;
+	type PriceForSiblingDelivery = PriceForSiblingDelivery;
 	type WeightInfo = weights::cumulus_pallet_xcmp_queue::BasiliskWeight<Runtime>;
 }
 parameter_type_with_key! {
@@ -448,7 +456,7 @@ impl Convert<AccountId, Location> for AccountIdToMultiLocation {
 /// queues.
 pub type XcmRouter = (
 	// Two routers - use UMP to communicate with the relay chain:
-	cumulus_primitives_utility::ParentAsUmp<ParachainSystem, PolkadotXcm, ()>,
+	cumulus_primitives_utility::ParentAsUmp<ParachainSystem, PolkadotXcm, PriceForParentDelivery>,
 	// ..and XCMP to communicate with the sibling chains.
 	XcmpQueue,
 );
@@ -483,6 +491,36 @@ pub type LocalAssetTransactor = MultiCurrencyAdapter<
 	DepositToAlternative<Alternative, Currencies, AssetId, AccountId, Balance>,
 >;
 
+parameter_types! {
+	/// Pseudo asset in which the delivery fees are priced, the amount is denominated in native currency.
+	pub DeliveryFeeAssetId: cumulus_primitives_core::AssetId = cumulus_primitives_core::AssetId(
+		PalletInstance(<MultiTransactionPayment as PalletInfoAccess>::index() as u8).into()
+	);
+	pub BaseDeliveryFee: Balance = WeightToFee::weight_to_fee(&BaseXcmWeight::get()).saturating_mul(3);
+}
+
+/// Delivery fees grow with the size of the message and exponentially with the congestion of the channel,
+/// which makes flooding the HRMP channels and the UMP queue expensive.
+pub type PriceForSiblingDelivery = ExponentialPrice<DeliveryFeeAssetId, BaseDeliveryFee, TransactionByteFee, XcmpQueue>;
+pub type PriceForParentDelivery =
+	ExponentialPrice<DeliveryFeeAssetId, BaseDeliveryFee, TransactionByteFee, ParachainSystem>;
+
+/// Charges the delivery fees of messages sent by local accounts in their fee currency and sends them
+/// to the treasury.
+pub type DeliveryFeeTransactor = DeliveryFeeInFeeCurrency<
+	AccountId,
+	AssetId,
+	Balance,
+	Price,
+	DeliveryFeeAssetId,
+	LocationToAccountId,
+	MultiTransactionPayment,
+	MultiTransactionPayment,
+	Currencies,
+	DepositAll<Runtime>,
+	TreasuryAccount,
+>;
+
 /// A call filter for the XCM Transact instruction. This is a temporary measure until we properly
 /// account for proof size weights.
 ///
@@ -568,6 +606,33 @@ impl Contains<RuntimeCall> for SafeCallFilter {
 			| RuntimeCall::Currencies(..)
 			| RuntimeCall::Tokens(..)
 			| RuntimeCall::OrmlXcm(..) => true,
+			// trades only, `force_insert_route` requires the technical origin
+			RuntimeCall::Router(
+				pallet_route_executor::Call::sell { .. }
+				| pallet_route_executor::Call::buy { .. }
+				| pallet_route_executor::Call::sell_all { .. }
+				| pallet_route_executor::Call::sell_split { .. }
+				| pallet_route_executor::Call::buy_split { .. }
+				| pallet_route_executor::Call::sell_with_guard { .. }
+				| pallet_route_executor::Call::buy_with_guard { .. }
+				| pallet_route_executor::Call::buy_exact_for_account { .. }
+				| pallet_route_executor::Call::swap_to_zero { .. }
+				| pallet_route_executor::Call::set_route { .. },
+			) => true,
+			// participation in sales only, pool management stays with local accounts
+			RuntimeCall::LBP(
+				pallet_lbp::Call::sell { .. }
+				| pallet_lbp::Call::buy { .. }
+				| pallet_lbp::Call::prove_allowlisted { .. }
+				| pallet_lbp::Call::claim_refund { .. },
+			) => true,
+			RuntimeCall::Marketplace(
+				pallet_marketplace::Call::buy { .. }
+				| pallet_marketplace::Call::set_price { .. }
+				| pallet_marketplace::Call::make_offer { .. }
+				| pallet_marketplace::Call::withdraw_offer { .. }
+				| pallet_marketplace::Call::accept_offer { .. },
+			) => true,
 			_ => false,
 		}
 	}
@@ -2,6 +2,7 @@
 
 pub mod oracle;
 pub mod router;
+pub mod stableswap;
 
 use codec::{Decode, Encode};
 use frame_support::dispatch::{self};
@@ -222,3 +223,33 @@ pub trait AccountFeeCurrency<AccountId> {
 	type AssetId;
 	fn get(a: &AccountId) -> Self::AssetId;
 }
+
+/// Creation of XYK pools on behalf of an account, used by pallets which seed pools with their funds.
+pub trait CreateXYKPool<AccountId, AssetId, Balance> {
+	/// Create a pool funded by `who`.
+	/// Return the share token of the pool and the amount of shares issued to `who`.
+	fn create_pool(
+		who: &AccountId,
+		asset_a: AssetId,
+		amount_a: Balance,
+		asset_b: AssetId,
+		amount_b: Balance,
+	) -> Result<(AssetId, Balance), DispatchError>;
+}
+
+/// Transfer of an asset which is locked by a vesting schedule on the receiving account.
+pub trait VestedTransfer<AccountId, AssetId, Balance, BlockNumber> {
+	/// Return true if `asset` can be transferred with a vesting schedule.
+	fn is_supported(asset: AssetId) -> bool;
+
+	/// Transfer `amount` of `asset` from `from` to `to`.
+	/// Transferred amount is locked until `start` and then released linearly over `duration` blocks.
+	fn vested_transfer(
+		asset: AssetId,
+		from: &AccountId,
+		to: &AccountId,
+		amount: Balance,
+		start: BlockNumber,
+		duration: BlockNumber,
+	) -> DispatchResult;
+}
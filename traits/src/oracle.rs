@@ -57,6 +57,8 @@ pub enum OraclePeriod {
 	Day,
 	/// The oracle data was aggregated over the blocks of the last week.
 	Week,
+	/// The oracle data was aggregated over the given number of blocks.
+	Custom(u32),
 }
 
 impl OraclePeriod {
@@ -69,6 +71,22 @@ impl OraclePeriod {
 		use OraclePeriod::*;
 		&[Short, TenMinutes, Hour, Day, Week]
 	}
+
+	/// Return the length of the period in blocks. The smoothing factor of the oracle is derived from it.
+	///
+	/// Named periods assume a block time of 6 seconds.
+	pub const fn blocks(&self) -> u32 {
+		use OraclePeriod::*;
+		match self {
+			LastBlock => 1,
+			Short => 9,
+			TenMinutes => 100,
+			Hour => 600,
+			Day => 14_400,
+			Week => 100_800,
+			Custom(blocks) => *blocks,
+		}
+	}
 }
 
 /// Struct to represent oracle data aggregated over a time period. Includes the age of the oracle
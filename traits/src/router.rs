@@ -3,6 +3,7 @@ use frame_support::dispatch::DispatchResultWithPostInfo;
 use frame_support::sp_runtime::{DispatchError, DispatchResult};
 use frame_support::weights::Weight;
 use scale_info::TypeInfo;
+use serde::{Deserialize, Serialize};
 use sp_arithmetic::FixedU128;
 use sp_std::vec;
 use sp_std::vec::Vec;
@@ -24,6 +25,8 @@ pub trait RouteSpotPriceProvider<AssetId> {
 	Default,
 	TypeInfo,
 	MaxEncodedLen,
+	Serialize,
+	Deserialize,
 )]
 pub struct AssetPair<AssetId> {
 	pub asset_in: AssetId,
@@ -73,7 +76,20 @@ pub trait RouteProvider<AssetId> {
 	}
 }
 
-#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Copy, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Clone,
+	Copy,
+	Debug,
+	Eq,
+	PartialEq,
+	TypeInfo,
+	MaxEncodedLen,
+	Serialize,
+	Deserialize,
+)]
 pub enum PoolType<AssetId> {
 	XYK,
 	LBP,
@@ -88,7 +104,20 @@ pub enum ExecutorError<E> {
 }
 
 ///A single trade for buy/sell, describing the asset pair and the pool type in which the trade is executed
-#[derive(Encode, Decode, DecodeWithMemTracking, Debug, Eq, PartialEq, Copy, Clone, TypeInfo, MaxEncodedLen)]
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Debug,
+	Eq,
+	PartialEq,
+	Copy,
+	Clone,
+	TypeInfo,
+	MaxEncodedLen,
+	Serialize,
+	Deserialize,
+)]
 pub struct Trade<AssetId> {
 	pub pool: PoolType<AssetId>,
 	pub asset_in: AssetId,
@@ -334,6 +363,8 @@ pub trait AmmTradeWeights<Trade> {
 	fn force_insert_route_weight() -> Weight;
 	fn get_route_weight() -> Weight;
 	fn calculate_spot_price_with_fee_weight(route: &[Trade]) -> Weight;
+	fn maintain_route_weight() -> Weight;
+	fn get_oracle_price_weight(route: &[Trade]) -> Weight;
 }
 
 impl<Trade> AmmTradeWeights<Trade> for () {
@@ -364,8 +395,53 @@ impl<Trade> AmmTradeWeights<Trade> for () {
 	fn calculate_spot_price_with_fee_weight(_route: &[Trade]) -> Weight {
 		Weight::zero()
 	}
+	fn maintain_route_weight() -> Weight {
+		Weight::zero()
+	}
+	fn get_oracle_price_weight(_route: &[Trade]) -> Weight {
+		Weight::zero()
+	}
 }
 
 pub trait RefundEdCalculator<Balance> {
 	fn calculate() -> Balance;
 }
+
+/// Provides pools as candidates for the on-chain routes, one pool at a time.
+pub trait RouteCandidateProvider<AssetId> {
+	/// Returns the pool following `cursor` as a single trade route, together with the cursor of the
+	/// returned pool. An empty cursor starts from the first pool.
+	fn next_candidate(cursor: &[u8]) -> Option<(Vec<u8>, Trade<AssetId>)>;
+}
+
+impl<AssetId> RouteCandidateProvider<AssetId> for () {
+	fn next_candidate(_cursor: &[u8]) -> Option<(Vec<u8>, Trade<AssetId>)> {
+		None
+	}
+}
+
+/// Chains two providers. The first byte of the cursor identifies the provider the rest of the cursor
+/// belongs to.
+impl<AssetId, A: RouteCandidateProvider<AssetId>, B: RouteCandidateProvider<AssetId>> RouteCandidateProvider<AssetId>
+	for (A, B)
+{
+	fn next_candidate(cursor: &[u8]) -> Option<(Vec<u8>, Trade<AssetId>)> {
+		let with_index = |index: u8, (cursor, trade): (Vec<u8>, Trade<AssetId>)| {
+			let mut prefixed = Vec::with_capacity(cursor.len().saturating_add(1));
+			prefixed.push(index);
+			prefixed.extend(cursor);
+			(prefixed, trade)
+		};
+
+		match cursor
+			.split_first()
+			.map_or((0, &[][..]), |(index, inner)| (*index, inner))
+		{
+			(0, inner) => A::next_candidate(inner)
+				.map(|next| with_index(0, next))
+				.or_else(|| B::next_candidate(&[]).map(|next| with_index(1, next))),
+			(1, inner) => B::next_candidate(inner).map(|next| with_index(1, next)),
+			_ => None,
+		}
+	}
+}
@@ -0,0 +1,14 @@
+use sp_std::vec::Vec;
+
+/// Read access to stableswap pools for pallets building on top of them, e.g. liquidity mining.
+///
+/// Pools are identified by the id of their share asset.
+pub trait StableswapPoolProvider<AssetId, Balance> {
+	type Error;
+
+	/// Return assets of the pool or `None` if the pool does not exist.
+	fn pool_assets(pool_id: AssetId) -> Option<Vec<AssetId>>;
+
+	/// Return value of `shares` of the pool denominated in `asset`, which has to be one of the pool assets.
+	fn shares_value(pool_id: AssetId, asset: AssetId, shares: Balance) -> Result<Balance, Self::Error>;
+}